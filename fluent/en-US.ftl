@@ -38,6 +38,320 @@ iterations = Iterations
 best = Best
 median = Median
 worst = Worst
+label_macro = Macro
+button_record_macro = Record Macro
+button_stop_macro = Stop Recording
+button_play_macro = Replay macro
+button_delete_macro = Delete macro
+label_resize_mode = Resize
+resize_mode_crop = Crop
+resize_mode_nearest = Rescale (nearest)
+resize_mode_majority = Rescale (majority)
+label_filter = Filter
+filter_remove_isolated = Remove isolated cells
+filter_fill_holes = Fill single-cell holes
+filter_smooth = Majority smoothing
+button_apply_filter = Apply Filter
+changed_cells = { $count } changed cells
+title_color_usage = Color Usage
+label_replace_color = Replace color
+button_replace_color = Replace
+label_max_colors = Maximum colors
+button_reduce_colors = Reduce Colors
+title_layers = Layers
+button_show_layer = Show
+button_hide_layer = Hide
+button_solo_layer = Solo
+title_reference_image = Reference Image
+label_opacity = Opacity
+label_offset = Offset
+button_remove_reference = Remove Image
+title_diff = Compare Solutions
+label_compare_with = Compare with
+diff_matching = Matching
+diff_missing = Missing
+diff_extra = Extra
+warning_empty_lines = The nonogram has empty lines, their clues will be empty.
+button_crop = Crop
+button_save_anyway = Save Anyway
+title_guessing_lint = Logic Check
+button_check_logic = Check Logic
+button_hide_overlay = Hide Overlay
+logic_solvable = Solvable without guessing
+cells_deduced = { $count } cells deduced by line logic
+cells_single_guess = { $count } cells need a single guess
+cells_deep_guess = { $count } cells need deeper guessing
+label_check_mode = Color clues by slack
+lines_tight = { $count } tight lines
+lines_overlapping = { $count } overlapping lines
+lines_slack = { $count } slack lines
+selected_colors = { $count } selected colors
+button_delete_colors = Delete
+button_merge_colors = Merge
+button_lighten = Lighten
+button_darken = Darken
+button_export_palette = Export Palette
+button_clear_selection = Clear Selection
+title_palette_file = Palette File
+label_palette_name = Palette name
+label_palette_author = Author
+label_load_palette = Load palette
+palette_load_remap = Replace (remap colors)
+palette_load_append = Append colors
+label_checkerboard = Checkerboard background
+invalid_number = Enter a number from { $min } to { $max }
+clamped_number = Clamped to { $value } (from { $min } to { $max })
+title_session = Session
+session_empty = No puzzles touched yet
+untitled = Untitled
+label_auto_size = Auto
+button_fullscreen = Fullscreen
+button_exit_fullscreen = Exit Fullscreen
+title_feedback = Sound and Vibration
+label_volume = Volume
+label_vibration = Vibration
+button_test_feedback = Test
+timer_paused_idle = Paused (idle)
+timer_paused_unfocused = Paused (window inactive)
+label_idle_minutes = Idle pause (min)
+title_profiles = Who's playing?
+button_guest = Guest
+button_remove_profile = Remove profile
+label_profile_name = Profile name
+button_create_profile = Create Profile
+button_switch_profile = Switch profile
+title_clue_text = Import Clues
+placeholder_clue_text = Rows:
+    3
+    2 1:2
+    {"..."}
+    Columns:
+    1, 2
+    {"..."}
+button_import_clues = Solve Blind
+clues_missing_lines = The clues need rows and columns
+clues_invalid_clue = Invalid clue '{ $clue }' in line { $line }
+clues_line_too_long = The clues of line { $line } don't fit in the grid
+clues_color_mismatch = The rows and columns paint a different number of cells of color { $color }
+button_export_clues_text = Export Clues
+button_export_clues_markdown = Export Markdown
+title_replay_export = Replay GIF
+label_frame_rate = Frames per second
+label_cell_size = Cell size
+button_export_replay = Export GIF
+title_solver_runs = Solver Runs
+runs_unavailable = Solver runs are only recorded on desktop
+title_bench_puzzle = Benchmark Puzzle
+label_bench_seed = Seed
+button_open_bench_puzzle = Open in the Solver
+hint_bench_puzzle = The same size, colors and seed always generate the same puzzle, with a single solution
+runs_empty = No recorded runs
+label_puzzle_hash = Puzzle
+label_run_result = Result
+option_runs_all = All
+option_runs_solved = Solved
+option_runs_unsolved = Unsolved
+label_run_score = Score { $score }
+button_delete_listed_runs = Delete Listed Runs
+button_delete_run = Delete run
+title_filter_puzzle = Show only this puzzle
+column_run_date = Date (UTC)
+column_run_size = Size
+column_run_params = Parameters
+column_run_iterations = Generations
+column_run_duration = Time
+button_save_picture = Save Picture
+title_print = Print
+label_ink_saver = Ink saver
+button_print = Print
+button_export_print = Export Printable Page
+update_available = Version { $version } of NGRAM is available
+button_download_update = Download
+button_dismiss = Dismiss
+button_stop_update_checks = Don't check again
+label_autosave = Autosave
+autosave_off = Off
+autosave_sibling_file = Next to the puzzle
+autosave_run_history = In the run history
+button_save_run_solution = Save solution
+label_population_overlay = Show population while solving
+title_annotations = Annotations
+button_annotation_pointer = Pointer
+button_annotation_arrow = Arrow
+button_annotation_circle = Circle
+button_annotation_label = Label
+placeholder_annotation_label = Label text
+label_show_annotations = Show annotations
+button_annotation_undo = Undo
+button_annotation_clear = Clear annotations
+button_export_annotated = Export annotated picture
+label_completion = Completed when
+completion_constraints = The clues are satisfied
+completion_exact_image = The image matches
+completed_by_constraints = Every clue is satisfied.
+completed_by_image = The grid matches the puzzle's image.
+completed_alternate = You found an alternate solution, different from the puzzle's image!
+button_compare_official = Compare with the official solution
+button_hide_official = Hide the official solution
+session_ambiguous = Several solutions
+label_session_seed = Seed
+hint_session_seed = Every random action of the session is reproducible from this seed ({ $draws } drawn so far)
+label_import_nonograms_org = Import a page saved from nonograms.org
+button_new_puzzle = New puzzle
+title_new_puzzle = New puzzle
+size_custom = Custom
+label_colors = Colors
+label_template = Start with
+template_empty = An empty grid
+template_border = A border
+template_symmetric_mask = A symmetric mask
+button_create_puzzle = Create
+button_cancel = Cancel
+resize_warning = Resizing the grid to { $rows } × { $cols } would delete { $lost } colored cells.
+button_slide_and_resize = Move the drawing and resize
+button_crop_to_drawing = Crop around the drawing
+button_resize_anyway = Resize anyway
+title_interaction = Pointer and Stylus
+label_tap = Tap
+option_tap_paint = Paints with the brush
+option_tap_toggle = Toggles brush and background
+label_stylus_only = Draw only with a stylus
+label_pressure = Pressure chooses the color intensity
+progress_lines = Lines satisfied
+progress_cells = Cells matched
+title_accessibility = Accessibility
+label_font_scale = Font size
+label_reduced_motion = Reduce motion
+label_clue_alignment = Clues
+option_clues_grid = Next to the grid
+option_clues_edge = From the outer edge
+button_solve_logic = Solve (logic)
+logic_partial = { $cells } cells couldn't be deduced
+clue_edit_row = Row { $line } clue
+clue_edit_column = Column { $line } clue
+label_clue_length = Length
+button_add_clue = Add
+button_apply_clue = Apply
+button_remove_clue = Remove
+clue_edit_too_long = The clues don't fit in the line
+button_solve_exact = Solve (exact)
+exact_unsolvable = The puzzle has no solution
+label_assist_level = Assists
+assist_purist = Purist
+assist_casual = Casual
+assist_relaxed = Relaxed
+button_hint = Hint
+session_completed = Completed ({ $level })
+label_history = History
+title_signature = Signature
+title_puzzle_info = Puzzle info
+button_sign = Sign with my profile
+button_remove_signature = Remove signature
+label_signature_visible = Show a signature row below the grid
+signature_signed = Signed by { $author } on { $date } UTC
+signature_by = Signed by { $author }
+signature_none = Unsigned
+signature_guest = Choose a profile to sign your puzzles
+puzzle_info_size = { $rows }×{ $cols }, { $colors } colors
+title_weekly_challenge = Weekly challenge
+challenge_week = Week of { $date }
+challenge_seed = Seed: { $seed }
+button_play_challenge = Play the weekly challenge
+label_verification = Verification token
+placeholder_verification = Paste a verification token to compare
+challenge_token_valid = Valid solve: { $time }, { $actions } actions ({ $level })
+challenge_token_other_week = Valid solve of another week (week of { $date })
+challenge_token_invalid = Invalid verification token
+button_stop_solver = Stop
+title_theme = Theme
+button_reset_theme = Reset theme
+theme_malformed = The theme must be a JSON object with the colors background, toolbar, border and highlight
+theme_invalid_color = Invalid theme color: { $color }
+progress_solver = Generation { $iteration } of { $max }
+progress_solver_best = Best score: { $best }
+label_low_power = Low-power mode
+title_solver_parameters = Solver parameters
+label_population_size = Population
+label_cross_probability = Crossover (%)
+label_mutation_probability = Mutation (%)
+label_tournament_size = Tournament
+label_slide_tries = Slide tries
+label_max_iterations = Max. generations
+button_reset_parameters = Reset parameters
+label_grayscale_preview = Grayscale (photocopy preview)
+label_solver_seed = Seed
+placeholder_solver_seed = Automatic
+button_random_seed = Random
+label_adaptive_rates = Adaptive probabilities
+title_notes = Notes
+label_author_notes = Author notes
+label_my_notes = My notes
+placeholder_notes = # Strategy
+    - Start with the **longest** clues
+button_save_notes = Save notes
+button_notes_saved = Notes saved
+label_target_score = Target score
+label_stagnation_window = Stagnation window (0 = off)
+label_time_limit = Time limit in s (0 = off)
+label_crowding_factor = Crowding factor (0 = off)
+title_library = Library
+library_size = { $count } solutions in the library
+label_export_progress = Include the progress of the session
+button_export_all = Export all
+title_library_search = Search by drawing
+button_clear_sketch = Clear sketch
+library_search_empty = Sketch a shape to find similar puzzles in the library
+library_search_similarity = { $percent }% similar
+button_open_puzzle = Open
+label_zoom_preview = Preview of the whole grid
+title_import_image = Import image
+progress_import = Importing: { $percent }%
+label_slide_weight = Slide mutation weight
+label_reposition_weight = Segment reposition weight
+label_reshuffle_weight = Row reshuffle weight
+label_strict_mode = Strict mode
+button_unlock_solver = Hold to unlock
+title_hold_unlock = Hold down to unlock the solver and the hints for this puzzle
+title_unlock_solver = Unlock the solver?
+unlock_solver_warning = This puzzle will be marked as assisted in your progress, even if you don't use the solver or the hints.
+button_confirm_unlock = Unlock
+session_assisted = Assisted
+title_clipboard = Clipboard
+label_copy_top = Top row
+label_copy_left = Left column
+label_copy_bottom = Bottom row
+label_copy_right = Right column
+button_copy_region = Copy region
+label_paste_row = Paste at row
+label_paste_column = Paste at column
+button_paste_region = Paste region
+clipboard_no_region = The clipboard holds no region
+button_copy_palette = Copy palette
+button_paste_palette = Paste palette
+clipboard_no_palette = The clipboard holds no palette
+title_pinned_cell = Pinned as certain: the solver never changes this cell (Alt+click to unpin)
+title_loading_language = Loading the language…
+label_duplicate_load = Loading the same file
+duplicate_load_ask = Asks what to do
+duplicate_load_keep = Keeps the progress
+duplicate_load_clear = Clears the grid
+title_duplicate_load = Same puzzle loaded again
+duplicate_load_warning = This file is the puzzle being solved. Keep the progress, or start over with an empty grid?
+button_keep_progress = Keep progress
+button_start_over = Start over
+label_pareto_selection = Multi-objective (NSGA-II)
+title_pareto_front = Trade-off candidates
+hint_pareto_candidate = Show this candidate in the grid
+pareto_candidate = Rows off by { $rows }, columns off by { $columns }
+title_launch_file_error = The file couldn't be opened
+launch_file_error = "{ $file }" isn't a valid nonogram: { $error }
+button_evolve_more = Evolve more
+hint_evolve_more = Continue the last search from its final population
+button_continue_from_grid = Continue from grid
+hint_continue_from_grid = Start the search around the painted grid
+button_export_results_markdown = Export matrix (Markdown)
+button_export_results_json = Export matrix (JSON)
+hint_export_results = The results of each solver configuration on each puzzle, for the listed runs
 
 # non-translatable
 lang_en_US = English (US)
@@ -38,6 +38,320 @@ iterations = Iteraciones
 best = Mejor
 median = Mediana
 worst = Peor
+label_macro = Macro
+button_record_macro = Grabar Macro
+button_stop_macro = Detener Grabación
+button_play_macro = Reproducir macro
+button_delete_macro = Eliminar macro
+label_resize_mode = Redimensionar
+resize_mode_crop = Recortar
+resize_mode_nearest = Reescalar (más cercano)
+resize_mode_majority = Reescalar (mayoría)
+label_filter = Filtro
+filter_remove_isolated = Eliminar celdas aisladas
+filter_fill_holes = Rellenar huecos de una celda
+filter_smooth = Suavizado por mayoría
+button_apply_filter = Aplicar Filtro
+changed_cells = { $count } celdas cambiadas
+title_color_usage = Uso de Colores
+label_replace_color = Reemplazar color
+button_replace_color = Reemplazar
+label_max_colors = Máximo de colores
+button_reduce_colors = Reducir Colores
+title_layers = Capas
+button_show_layer = Mostrar
+button_hide_layer = Ocultar
+button_solo_layer = Solo
+title_reference_image = Imagen de Referencia
+label_opacity = Opacidad
+label_offset = Desplazamiento
+button_remove_reference = Quitar Imagen
+title_diff = Comparar Soluciones
+label_compare_with = Comparar con
+diff_matching = Coinciden
+diff_missing = Faltan
+diff_extra = Sobran
+warning_empty_lines = El nonograma tiene líneas vacías, sus pistas estarán vacías.
+button_crop = Recortar
+button_save_anyway = Guardar de Todos Modos
+title_guessing_lint = Verificación Lógica
+button_check_logic = Verificar Lógica
+button_hide_overlay = Ocultar Superposición
+logic_solvable = Resoluble sin adivinar
+cells_deduced = { $count } celdas deducidas por lógica de líneas
+cells_single_guess = { $count } celdas necesitan una suposición
+cells_deep_guess = { $count } celdas necesitan suposiciones más profundas
+label_check_mode = Colorear pistas por holgura
+lines_tight = { $count } líneas ajustadas
+lines_overlapping = { $count } líneas con traslape
+lines_slack = { $count } líneas holgadas
+selected_colors = { $count } colores seleccionados
+button_delete_colors = Eliminar
+button_merge_colors = Combinar
+button_lighten = Aclarar
+button_darken = Oscurecer
+button_export_palette = Exportar Paleta
+button_clear_selection = Limpiar Selección
+title_palette_file = Archivo de Paleta
+label_palette_name = Nombre de la paleta
+label_palette_author = Autor
+label_load_palette = Cargar paleta
+palette_load_remap = Reemplazar (reasignar colores)
+palette_load_append = Agregar colores
+label_checkerboard = Fondo de tablero
+invalid_number = Ingresa un número de { $min } a { $max }
+clamped_number = Ajustado a { $value } (de { $min } a { $max })
+title_session = Sesión
+session_empty = Aún no se ha tocado ningún nonograma
+untitled = Sin título
+label_auto_size = Automático
+button_fullscreen = Pantalla Completa
+button_exit_fullscreen = Salir de Pantalla Completa
+title_feedback = Sonido y Vibración
+label_volume = Volumen
+label_vibration = Vibración
+button_test_feedback = Probar
+timer_paused_idle = En pausa (inactivo)
+timer_paused_unfocused = En pausa (ventana inactiva)
+label_idle_minutes = Pausa por inactividad (min)
+title_profiles = ¿Quién juega?
+button_guest = Invitado
+button_remove_profile = Eliminar perfil
+label_profile_name = Nombre del perfil
+button_create_profile = Crear Perfil
+button_switch_profile = Cambiar de perfil
+title_clue_text = Importar Pistas
+placeholder_clue_text = Filas:
+    3
+    2 1:2
+    {"..."}
+    Columnas:
+    1, 2
+    {"..."}
+button_import_clues = Resolver a Ciegas
+clues_missing_lines = Las pistas necesitan filas y columnas
+clues_invalid_clue = Pista inválida '{ $clue }' en la línea { $line }
+clues_line_too_long = Las pistas de la línea { $line } no caben en la cuadrícula
+clues_color_mismatch = Las filas y columnas pintan un número distinto de celdas del color { $color }
+button_export_clues_text = Exportar Pistas
+button_export_clues_markdown = Exportar Markdown
+title_replay_export = GIF de la Repetición
+label_frame_rate = Cuadros por segundo
+label_cell_size = Tamaño de celda
+button_export_replay = Exportar GIF
+title_solver_runs = Ejecuciones del Solucionador
+runs_unavailable = Las ejecuciones del solucionador solo se registran en escritorio
+title_bench_puzzle = Puzzle de Referencia
+label_bench_seed = Semilla
+button_open_bench_puzzle = Abrir en el Solucionador
+hint_bench_puzzle = El mismo tamaño, colores y semilla siempre generan el mismo puzzle, con una única solución
+runs_empty = No hay ejecuciones registradas
+label_puzzle_hash = Puzzle
+label_run_result = Resultado
+option_runs_all = Todas
+option_runs_solved = Resuelto
+option_runs_unsolved = Sin resolver
+label_run_score = Puntaje { $score }
+button_delete_listed_runs = Borrar Ejecuciones Listadas
+button_delete_run = Borrar ejecución
+title_filter_puzzle = Mostrar solo este puzzle
+column_run_date = Fecha (UTC)
+column_run_size = Tamaño
+column_run_params = Parámetros
+column_run_iterations = Generaciones
+column_run_duration = Tiempo
+button_save_picture = Guardar Imagen
+title_print = Imprimir
+label_ink_saver = Ahorro de tinta
+button_print = Imprimir
+button_export_print = Exportar Página Imprimible
+update_available = La versión { $version } de NGRAM está disponible
+button_download_update = Descargar
+button_dismiss = Descartar
+button_stop_update_checks = No volver a buscar
+label_autosave = Autoguardado
+autosave_off = Desactivado
+autosave_sibling_file = Junto al puzzle
+autosave_run_history = En el historial de ejecuciones
+button_save_run_solution = Guardar solución
+label_population_overlay = Mostrar población al resolver
+title_annotations = Anotaciones
+button_annotation_pointer = Puntero
+button_annotation_arrow = Flecha
+button_annotation_circle = Círculo
+button_annotation_label = Etiqueta
+placeholder_annotation_label = Texto de la etiqueta
+label_show_annotations = Mostrar anotaciones
+button_annotation_undo = Deshacer
+button_annotation_clear = Borrar anotaciones
+button_export_annotated = Exportar imagen anotada
+label_completion = Completado cuando
+completion_constraints = Se cumplen las pistas
+completion_exact_image = La imagen coincide
+completed_by_constraints = Se cumplen todas las pistas.
+completed_by_image = La cuadrícula coincide con la imagen del acertijo.
+completed_alternate = ¡Encontraste una solución alternativa, distinta de la imagen del acertijo!
+button_compare_official = Comparar con la solución oficial
+button_hide_official = Ocultar la solución oficial
+session_ambiguous = Varias soluciones
+label_session_seed = Semilla
+hint_session_seed = Cada acción aleatoria de la sesión se puede reproducir con esta semilla ({ $draws } usadas hasta ahora)
+label_import_nonograms_org = Importar una página guardada de nonograms.org
+button_new_puzzle = Nuevo acertijo
+title_new_puzzle = Nuevo acertijo
+size_custom = Personalizado
+label_colors = Colores
+label_template = Empezar con
+template_empty = Una cuadrícula vacía
+template_border = Un borde
+template_symmetric_mask = Una máscara simétrica
+button_create_puzzle = Crear
+button_cancel = Cancelar
+resize_warning = Cambiar la cuadrícula a { $rows } × { $cols } borraría { $lost } celdas de color.
+button_slide_and_resize = Mover el dibujo y redimensionar
+button_crop_to_drawing = Recortar alrededor del dibujo
+button_resize_anyway = Redimensionar de todos modos
+title_interaction = Puntero y Lápiz
+label_tap = Toque
+option_tap_paint = Pinta con el pincel
+option_tap_toggle = Alterna pincel y fondo
+label_stylus_only = Dibujar solo con lápiz
+label_pressure = La presión elige la intensidad del color
+progress_lines = Líneas satisfechas
+progress_cells = Celdas coincidentes
+title_accessibility = Accesibilidad
+label_font_scale = Tamaño de letra
+label_reduced_motion = Reducir movimiento
+label_clue_alignment = Pistas
+option_clues_grid = Junto a la cuadrícula
+option_clues_edge = Desde el borde exterior
+button_solve_logic = Resolver (lógica)
+logic_partial = No se pudieron deducir { $cells } celdas
+clue_edit_row = Pista de la fila { $line }
+clue_edit_column = Pista de la columna { $line }
+label_clue_length = Longitud
+button_add_clue = Añadir
+button_apply_clue = Aplicar
+button_remove_clue = Quitar
+clue_edit_too_long = Las pistas no caben en la línea
+button_solve_exact = Resolver (exacto)
+exact_unsolvable = El acertijo no tiene solución
+label_assist_level = Ayudas
+assist_purist = Purista
+assist_casual = Casual
+assist_relaxed = Relajado
+button_hint = Pista
+session_completed = Completado ({ $level })
+label_history = Historial
+title_signature = Firma
+title_puzzle_info = Información del puzzle
+button_sign = Firmar con mi perfil
+button_remove_signature = Quitar la firma
+label_signature_visible = Mostrar una fila de firma debajo de la cuadrícula
+signature_signed = Firmado por { $author } el { $date } UTC
+signature_by = Firmado por { $author }
+signature_none = Sin firma
+signature_guest = Elige un perfil para firmar tus puzzles
+puzzle_info_size = { $rows }×{ $cols }, { $colors } colores
+title_weekly_challenge = Reto semanal
+challenge_week = Semana del { $date }
+challenge_seed = Semilla: { $seed }
+button_play_challenge = Jugar el reto semanal
+label_verification = Token de verificación
+placeholder_verification = Pega un token de verificación para comparar
+challenge_token_valid = Resolución válida: { $time }, { $actions } acciones ({ $level })
+challenge_token_other_week = Resolución válida de otra semana (semana del { $date })
+challenge_token_invalid = Token de verificación inválido
+button_stop_solver = Detener
+title_theme = Tema
+button_reset_theme = Restablecer tema
+theme_malformed = El tema debe ser un objeto JSON con los colores background, toolbar, border y highlight
+theme_invalid_color = Color de tema inválido: { $color }
+progress_solver = Generación { $iteration } de { $max }
+progress_solver_best = Mejor puntaje: { $best }
+label_low_power = Modo de bajo consumo
+title_solver_parameters = Parámetros del solucionador
+label_population_size = Población
+label_cross_probability = Cruce (%)
+label_mutation_probability = Mutación (%)
+label_tournament_size = Torneo
+label_slide_tries = Intentos de deslizamiento
+label_max_iterations = Máx. generaciones
+button_reset_parameters = Restablecer parámetros
+label_grayscale_preview = Escala de grises (vista de fotocopia)
+label_solver_seed = Semilla
+placeholder_solver_seed = Automática
+button_random_seed = Aleatoria
+label_adaptive_rates = Probabilidades adaptativas
+title_notes = Notas
+label_author_notes = Notas del autor
+label_my_notes = Mis notas
+placeholder_notes = # Estrategia
+    - Empieza con las pistas **más largas**
+button_save_notes = Guardar notas
+button_notes_saved = Notas guardadas
+label_target_score = Puntaje objetivo
+label_stagnation_window = Ventana de estancamiento (0 = no)
+label_time_limit = Límite de tiempo en s (0 = no)
+label_crowding_factor = Factor de aglomeración (0 = no)
+title_library = Biblioteca
+library_size = { $count } soluciones en la biblioteca
+label_export_progress = Incluir el progreso de la sesión
+button_export_all = Exportar todo
+title_library_search = Buscar por dibujo
+button_clear_sketch = Borrar boceto
+library_search_empty = Dibuja una forma para encontrar puzzles parecidos en la biblioteca
+library_search_similarity = { $percent }% parecido
+button_open_puzzle = Abrir
+label_zoom_preview = Vista previa de toda la cuadrícula
+title_import_image = Importar imagen
+progress_import = Importando: { $percent }%
+label_slide_weight = Peso de la mutación por deslizamiento
+label_reposition_weight = Peso de la recolocación de segmentos
+label_reshuffle_weight = Peso de la regeneración de filas
+label_strict_mode = Modo estricto
+button_unlock_solver = Mantén para desbloquear
+title_hold_unlock = Mantén presionado para desbloquear el solucionador y las pistas de este puzzle
+title_unlock_solver = ¿Desbloquear el solucionador?
+unlock_solver_warning = Este puzzle se marcará como asistido en tu progreso, aunque no uses el solucionador ni las pistas.
+button_confirm_unlock = Desbloquear
+session_assisted = Asistido
+title_clipboard = Portapapeles
+label_copy_top = Fila superior
+label_copy_left = Columna izquierda
+label_copy_bottom = Fila inferior
+label_copy_right = Columna derecha
+button_copy_region = Copiar región
+label_paste_row = Pegar en la fila
+label_paste_column = Pegar en la columna
+button_paste_region = Pegar región
+clipboard_no_region = El portapapeles no contiene una región
+button_copy_palette = Copiar paleta
+button_paste_palette = Pegar paleta
+clipboard_no_palette = El portapapeles no contiene una paleta
+title_pinned_cell = Fijada como segura: el solucionador nunca cambia esta celda (Alt+clic para soltarla)
+title_loading_language = Cargando el idioma…
+label_duplicate_load = Al cargar el mismo archivo
+duplicate_load_ask = Pregunta qué hacer
+duplicate_load_keep = Conserva el progreso
+duplicate_load_clear = Limpia la cuadrícula
+title_duplicate_load = Mismo puzzle cargado de nuevo
+duplicate_load_warning = Este archivo es el puzzle que se está resolviendo. ¿Conservar el progreso, o empezar de nuevo con la cuadrícula vacía?
+button_keep_progress = Conservar progreso
+button_start_over = Empezar de nuevo
+label_pareto_selection = Multiobjetivo (NSGA-II)
+title_pareto_front = Candidatos de compromiso
+hint_pareto_candidate = Mostrar este candidato en la cuadrícula
+pareto_candidate = Filas desviadas por { $rows }, columnas desviadas por { $columns }
+title_launch_file_error = No se pudo abrir el archivo
+launch_file_error = "{ $file }" no es un nonograma válido: { $error }
+button_evolve_more = Evolucionar más
+hint_evolve_more = Continuar la última búsqueda desde su población final
+button_continue_from_grid = Continuar desde la cuadrícula
+hint_continue_from_grid = Iniciar la búsqueda alrededor de la cuadrícula pintada
+button_export_results_markdown = Exportar matriz (Markdown)
+button_export_results_json = Exportar matriz (JSON)
+hint_export_results = Los resultados de cada configuración del solucionador en cada puzzle, para las ejecuciones listadas
 
 # no traducible
 lang_en_US = English (US)
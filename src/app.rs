@@ -0,0 +1,336 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! The user interface of the application: its routes, its header and the contexts shared by
+//! every page, built with one of the UI platform features.
+
+/// Application-level dependencies and libraries for building the UI and managing the app's lifecycle.
+use dioxus::logger::tracing::{info, Level};
+use dioxus::prelude::*;
+
+/// Internationalization (i18n) support for the application.
+use dioxus_i18n::prelude::*;
+use dioxus_i18n::t;
+
+/// Path to the main CSS file containing global styles for the application.
+const MAIN_CSS: Asset = asset!("/assets/main.css");
+
+/// Path to the Tailwind CSS file generated by the Tailwind compiler.
+const TAILWIND_CSS: Asset = asset!("/assets/tailwind.css");
+
+/// Translations of the default language, bundled in the binary.
+const EN_US_FTL: &str = include_str!("../fluent/en-US.ftl");
+
+/// Path to the translations of Mexican Spanish, fetched once the application is idle instead of
+/// being bundled in the binary (see `LazyLocales`).
+const ES_MX_FTL: Asset = asset!("/fluent/es-MX.ftl");
+
+/// Include Nonogram-related components for the application's user interface.
+use ngram::nonogram::component::{
+    load_asset, use_accessibility, use_asset_cache, use_fullscreen_mode, use_power_mode, use_theme,
+    Editor, ProfileMenu, ProfilePicker, SessionMenu, SessionSeed, Solver, SolverRuns, UpdateToast,
+};
+
+/// Cache of the assets fetched at runtime, such as the locales of the languages not in use.
+use ngram::nonogram::assets::AssetCache;
+
+/// Local profiles, each with its own settings.
+use ngram::nonogram::profile::{ActiveProfile, Profiles};
+
+/// Fullscreen mode, hiding the header.
+use ngram::nonogram::definitions::FullscreenMode;
+
+/// Random number generator service of the session, whose seed is displayed in the header.
+use ngram::nonogram::rng::SessionRng;
+
+/// History of the puzzles touched during the session, listed in the header.
+use ngram::nonogram::session::{SessionHistory, SessionPage};
+
+/// The Nonogram file the application was launched with, and the registration of the file type.
+use ngram::nonogram::filetype::LaunchFile;
+
+/// User settings shared by every page of the application.
+use ngram::nonogram::settings::Settings;
+
+/// Module for managing application localization (i18n), including supported languages.
+mod localization {
+    use dioxus_i18n::unic_langid::{langid, LanguageIdentifier};
+
+    /// The default language for the application (`en-US`).
+    pub const DEF_LANG: LanguageIdentifier = EN_US;
+    /// English (United States) language identifier.
+    pub const EN_US: LanguageIdentifier = langid!("en-US");
+    /// Spanish (Mexico) language identifier.
+    pub const ES_MX: LanguageIdentifier = langid!("es-MX");
+}
+
+/// Import the localization module into the application scope for managing languages.
+use localization::*;
+
+/// Routes available in the application, with associated layouts and components.
+#[derive(Routable, Clone)]
+enum Route {
+    /// Default route for the Nonogram Solver, using the `Header` layout.
+    #[layout(Header)]
+    #[route("/")]
+    Solver {},
+    /// Route for the Nonogram Editor, also using the `Header` layout.
+    #[route("/editor")]
+    Editor {},
+    /// Route for the browser of the recorded solver runs, also using the `Header` layout.
+    #[route("/runs")]
+    SolverRuns {},
+}
+
+/// Launches the application.
+///
+/// Initializes logging, registers the `.ngram` file type on desktop platforms and launches the
+/// Dioxus application, with the Nonogram file passed on the command line (as when a file is
+/// opened from the file manager).
+pub fn launch() {
+    dioxus::logger::init(Level::INFO).expect("Dioxus logger failed to init");
+    #[cfg(feature = "desktop")]
+    register_file_type();
+    let launch = LaunchFile::from_args(std::env::args());
+    dioxus::LaunchBuilder::new()
+        .with_context(launch)
+        .launch(App);
+}
+
+#[cfg(feature = "desktop")]
+/// Registers the `.ngram` file type for the user, opening the files with this executable.
+fn register_file_type() {
+    use dioxus::logger::tracing::error;
+
+    let registered = std::env::current_exe()
+        .and_then(|executable| ngram::nonogram::filetype::register_file_type(&executable));
+    match registered {
+        Ok(true) => info!("Registered the .ngram file type"),
+        Ok(false) => {}
+        Err(err) => error!("Couldn't register the .ngram file type: {err}"),
+    }
+}
+
+/// Main application component.
+///
+/// Handles initialization of internationalization (i18n) and the prefetching of the locales not in use, the file
+/// the application was launched with, profiles, settings, session history, session seed, fullscreen mode,
+/// accessibility settings, the custom theme and routes.
+/// The profile picker is displayed until a profile is chosen, if there are any, then the routes and the
+/// notification of newer releases.
+/// Also includes global stylesheets (main and Tailwind CSS).
+#[component]
+fn App() -> Element {
+    use_init_i18n(|| {
+        info!("Initializing i18n");
+        I18nConfig::new(DEF_LANG)
+            .with_fallback(DEF_LANG)
+            .with_locale(Locale::new_static(EN_US, EN_US_FTL))
+    });
+    let use_assets = use_asset_cache(vec![ES_MX_FTL.to_string()]);
+    use_context_provider(|| Signal::new(try_consume_context::<Option<LaunchFile>>().flatten()));
+    let use_profiles = use_context_provider(|| {
+        info!("Loading profiles");
+        Signal::new(Profiles::load())
+    });
+    let use_active = use_context_provider(|| {
+        // Without profiles there's nothing to choose, the guest settings are used.
+        Signal::new(ActiveProfile {
+            profile: None,
+            chosen: use_profiles.peek().profiles.is_empty(),
+        })
+    });
+    use_context_provider(|| {
+        info!("Loading settings");
+        Signal::new(Settings::load())
+    });
+    use_context_provider(|| {
+        info!("Initializing session history");
+        Signal::new(SessionHistory::default())
+    });
+    use_context_provider(|| {
+        let rng = SessionRng::from_entropy();
+        info!("Initializing session seed {}", rng.seed());
+        Signal::new(rng)
+    });
+    use_fullscreen_mode();
+    use_accessibility();
+    use_theme();
+    use_power_mode();
+
+    rsx! {
+        document::Link { rel: "stylesheet", href: MAIN_CSS }
+        document::Link { rel: "stylesheet", href: TAILWIND_CSS }
+        if let Some(spanish) = use_assets.read().content(&ES_MX_FTL.to_string()) {
+            LazyLocales { app: current_scope_id().expect("App is rendering"), spanish }
+        }
+        if use_active().chosen {
+            Router::<Route> {}
+            UpdateToast {}
+        } else {
+            ProfilePicker {}
+        }
+    }
+}
+
+/// Registers the locales fetched lazily, once their translations are loaded.
+///
+/// Locales can't be added once the translations are initialized, so new translations are
+/// initialized here with every locale, and replace the ones of the `App`. This component is
+/// never unmounted once the translations are loaded, so they live as long as the application.
+/// The language is then set again on the replaced translations, rendering again the components
+/// that used them.
+///
+/// # Arguments:
+/// - `app`: The scope of the `App`, where the translations are provided.
+/// - `spanish`: The translations of Mexican Spanish.
+///
+/// # Context:
+/// - `I18n`: The translations being replaced.
+#[component]
+fn LazyLocales(app: ScopeId, spanish: String) -> Element {
+    let mut use_replaced = use_hook(i18n);
+    let use_i18n = use_init_i18n(move || {
+        info!("Registering lazily loaded locales");
+        // The translations live as long as the application.
+        let spanish: &'static str = Box::leak(spanish.into_boxed_str());
+        I18nConfig::new(use_replaced.language())
+            .with_fallback(DEF_LANG)
+            .with_locale(Locale::new_static(EN_US, EN_US_FTL))
+            .with_locale(Locale::new_static(ES_MX, spanish))
+    });
+    use_hook(move || {
+        app.provide_context(use_i18n);
+        let language = use_replaced.language();
+        use_replaced.set_language(language);
+    });
+    rsx! {}
+}
+
+/// Header component used for navigation and language selection.
+///
+/// Includes a responsive layout for links to the Nonogram Solver and Editor,
+/// a dropdown with the puzzles touched during the session, the session seed, the profile in use,
+/// as well as a dropdown for switching application languages, fetching the translations of the
+/// selected language if they weren't prefetched yet.
+/// The header is hidden in fullscreen mode.
+///
+/// ### TODO
+/// - Fix the layout for smaller screens or mobile devices.
+#[component]
+fn Header() -> Element {
+    let mut i18n = i18n();
+    let use_fullscreen = use_context::<Signal<FullscreenMode>>();
+    let use_assets = use_context::<Signal<AssetCache>>();
+    let loading_language = use_assets.read().is_loading(&ES_MX_FTL.to_string());
+
+    // Event handler to change the current language based on user selection.
+    let change_language = move |event: FormEvent| {
+        info!("Changed language to: {}", event.value());
+        match event.value().as_str() {
+            "en-US" => i18n.set_language(EN_US),
+            "es-MX" => {
+                i18n.set_language(ES_MX);
+                // Only fetched if it wasn't prefetched yet, or failed to.
+                spawn(load_asset(use_assets, ES_MX_FTL.to_string()));
+            }
+            _ => {}
+        }
+    };
+
+    // Event handler to navigate to the page where a puzzle of the session is reopened.
+    let jump_to_page = move |page: SessionPage| {
+        let route = match page {
+            SessionPage::Solver => Route::Solver {},
+            SessionPage::Editor => Route::Editor {},
+        };
+        navigator().push(route);
+    };
+
+    // Helper function to format the current language as a string.
+    fn get_language(mut i18n: I18n) -> String {
+        let lang = i18n.language();
+        format!(
+            "{}-{}",
+            lang.language.as_str(),
+            if let Some(l) = lang.region {
+                l.as_str().to_string()
+            } else {
+                "".to_string()
+            }
+        )
+    }
+
+    if use_fullscreen().enabled {
+        return rsx! {
+            Outlet::<Route> {}
+        };
+    }
+
+    // Render the header with navigation links and language selection.
+    rsx! {
+        div { class: "mx-auto flex items-center justify-between py-4 px-6 bg-gray-800",
+            div { class: "text-white text-2xl font-bold",
+                Link { to: Route::Solver {}, "NGRAM" }
+            }
+            div { class: "flex-1 mx-4 overflow-x-auto whitespace-nowrap flex items-center gap-2",
+                Link {
+                    to: Route::Solver {},
+                    class: "inline-block text-white text-xl",
+                    {t!("title_nonogram_solver")}
+                }
+                span { class: "text-white", "|" }
+                Link {
+                    to: Route::Editor {},
+                    class: "inline-block text-white text-xl",
+                    {t!("title_nonogram_editor")}
+                }
+                span { class: "text-white", "|" }
+                Link {
+                    to: Route::SolverRuns {},
+                    class: "inline-block text-white text-xl",
+                    {t!("title_solver_runs")}
+                }
+            }
+            div { class: "mr-4",
+                SessionMenu { onjump: jump_to_page }
+            }
+            div { class: "mr-4", SessionSeed {} }
+            div { class: "mr-4", ProfileMenu {} }
+            if loading_language {
+                span {
+                    class: "mr-2 text-gray-300 animate-pulse",
+                    title: t!("title_loading_language"),
+                    "…"
+                }
+            }
+            select {
+                class: "appearance-none bg-gray-700 text-white border border-gray-600 rounded-md p-2 hover:bg-gray-600 transition ease-in-out duration-200",
+                value: "{get_language(i18n)}",
+                onchange: change_language,
+                option { value: "en-US", {t!("lang_en_US")} }
+                option { value: "es-MX", {t!("lang_es_MX")} }
+            }
+        }
+        Outlet::<Route> {}
+    }
+}
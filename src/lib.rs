@@ -0,0 +1,127 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! # NGRAM
+//!
+//! Core library of `ngram`: the Nonogram representation, the genetic operators and the evolutive
+//! search used by the application.
+//!
+//! The user interface components are only compiled when a Dioxus platform feature (`desktop`,
+//! `web` or `mobile`) is enabled, so the solver core can also be built on its own for the
+//! language bindings.
+
+/// Module containing logic, user interfaces, and helper functions for working with Nonogram puzzles.
+pub mod nonogram {
+    /// Annotation layer drawn over the grid, for explaining solving techniques.
+    pub mod annotation;
+    /// Zip archives bundling several files, such as the puzzles exported from the library.
+    pub mod archive;
+    /// Cache of the assets fetched at runtime, such as the locales of the languages not in use.
+    pub mod assets;
+    /// Weekly challenge puzzles generated from a published seed, with verifiable solves.
+    pub mod challenge;
+    /// Regions of the grids and palettes exchanged through the clipboard.
+    pub mod clipboard;
+    /// Parsing of puzzles from the plain-text clue notation.
+    pub mod clues;
+    /// Commands modifying the solution grid and the reducer applying them.
+    pub mod command;
+    /// User interface components for the Nonogram application.
+    #[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+    pub mod component;
+    /// Data definitions for Nonogram puzzles, including palettes, solutions, and constraints.
+    pub mod definitions;
+    /// Implements an evolutionary search algorithm for solving Nonograms.
+    pub mod evolutive;
+    /// Sound and vibration feedback of the actions performed on a Nonogram.
+    pub mod feedback;
+    /// Names of the Nonogram files, and the registration of their file type on desktop platforms.
+    pub mod filetype;
+    /// Cleanup filters removing noise from solution grids.
+    pub mod filters;
+    /// Deterministic generation of uniquely solvable puzzles, for tests and benchmarks.
+    pub mod generator;
+    /// Implements genetic algorithms for solving and optimizing Nonograms.
+    pub mod genetic;
+    /// Basic implementations for working with definitions in the Nonogram module.
+    pub mod implementations;
+    /// Conversion of photos into solution grids, downscaled in strips.
+    pub mod import;
+    /// Append-only journal of the actions performed on a Nonogram.
+    pub mod journal;
+    /// Local library of the solutions found for the puzzles without a stored solution.
+    pub mod library;
+    /// The single-line solver shared by the hints, the logic solver and the genetic operators.
+    pub mod line;
+    /// Locale-aware formatting of the numbers, durations and dates displayed.
+    pub mod locale;
+    /// Deterministic solving of Nonograms by constraint propagation.
+    pub mod logic;
+    /// Helper macros for simplifying the creation of Nonogram-related types.
+    pub mod macros;
+    /// Markdown-lite notes about a puzzle, written by its author or by the user.
+    pub mod notes;
+    /// PNG pictures of the solution grids, embedding the Nonogram file to import them back.
+    pub mod picture;
+    /// Pointer gestures on the solution grid, turned into commands.
+    pub mod pointer;
+    /// Printable sheets of the puzzles, with an ink-saver mode.
+    pub mod print;
+    /// Local user profiles, each with its own settings and data.
+    pub mod profile;
+    /// Predefined puzzles and utility functions for working with them.
+    pub mod puzzles;
+    /// Animated GIF replays of the solving process (only on desktop platforms).
+    #[cfg(not(any(target_os = "android", feature = "web")))]
+    pub mod replay;
+    /// Random number generator service of the session, reproducible from its seed.
+    pub mod rng;
+    /// Exact solving by encoding the puzzles as SAT formulas for a CDCL solver (optional).
+    #[cfg(feature = "sat")]
+    pub mod sat;
+    /// History of the puzzles touched during the session.
+    pub mod session;
+    /// User settings persisted between sessions.
+    pub mod settings;
+    /// Snapshots of the state of a page, serialized for automated UI tests.
+    pub mod snapshot;
+    /// Persistence of data between sessions, on the file system or the browser storage.
+    pub mod storage;
+    /// Records of the solver runs in a local SQLite database (only on desktop platforms).
+    #[cfg(feature = "telemetry")]
+    pub mod telemetry;
+    /// Downscaled thumbnails of the solution grids, updated incrementally while they're edited.
+    pub mod thumbnail;
+    /// Timer measuring the time spent actively solving a puzzle.
+    pub mod timer;
+    /// Notification of newer releases published on GitHub (only on desktop platforms).
+    #[cfg(feature = "update")]
+    pub mod update;
+}
+
+/// Python bindings of the solver core, built as the `ngram` Python module.
+#[cfg(feature = "pyo3")]
+pub mod python;
+
+/// WebAssembly bindings of the solver core, packaged for npm with `wasm-pack`.
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,346 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Nonogram structures for the grid rendered beneath the annotations.
+use super::definitions::{NonogramPalette, NonogramSolution};
+
+/// The drawing tools of the annotation layer.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AnnotationTool {
+    /// Draws a freehand stroke ending in an arrowhead.
+    Arrow,
+    /// Draws a circle, from its center to its edge.
+    Circle,
+    /// Places a short text label.
+    Label,
+}
+
+/// A mark drawn over the grid, independent from the puzzle data.
+///
+/// Positions are in cell units: `(x, y)` where `x` grows with the columns and `y` with the rows,
+/// so the marks keep their place when the block size changes.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Annotation {
+    /// A freehand stroke through the points, ending in an arrowhead.
+    Arrow {
+        points: Vec<(f32, f32)>,
+        color: String,
+    },
+    /// A circle around a center.
+    Circle {
+        center: (f32, f32),
+        radius: f32,
+        color: String,
+    },
+    /// A short text centered on a position.
+    Label {
+        position: (f32, f32),
+        text: String,
+        color: String,
+    },
+}
+
+/// The annotation layer drawn over the grid, for explaining solving techniques.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Annotations {
+    /// The marks drawn, in drawing order.
+    pub items: Vec<Annotation>,
+    /// Whether the marks are displayed over the grid.
+    pub visible: bool,
+    /// The tool drawing new marks, `None` to interact with the grid instead.
+    pub tool: Option<AnnotationTool>,
+    /// The color of the new marks.
+    pub color: String,
+    /// The text of the new labels.
+    pub text: String,
+}
+
+impl Default for Annotations {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            visible: true,
+            tool: None,
+            color: String::from("#ef4444"),
+            text: String::new(),
+        }
+    }
+}
+
+impl Annotations {
+    /// Returns whether the annotation layer captures the pointer, instead of the grid.
+    pub fn drawing(&self) -> bool {
+        self.visible && self.tool.is_some()
+    }
+
+    /// Starts a new mark at a position with the selected tool, `None` if no tool is selected or
+    /// the label text is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position where the pointer was pressed, in cell units.
+    pub fn start(&self, position: (f32, f32)) -> Option<Annotation> {
+        let color = self.color.clone();
+        match self.tool? {
+            AnnotationTool::Arrow => Some(Annotation::Arrow {
+                points: vec![position],
+                color,
+            }),
+            AnnotationTool::Circle => Some(Annotation::Circle {
+                center: position,
+                radius: 0.0,
+                color,
+            }),
+            AnnotationTool::Label => {
+                let text = self.text.trim();
+                (!text.is_empty()).then(|| Annotation::Label {
+                    position,
+                    text: text.to_string(),
+                    color,
+                })
+            }
+        }
+    }
+
+    /// Removes the last mark drawn, returning it.
+    pub fn undo(&mut self) -> Option<Annotation> {
+        self.items.pop()
+    }
+}
+
+impl Annotation {
+    /// Extends the mark while the pointer is dragged: arrows follow the pointer and circles grow
+    /// up to it, while labels stay in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The current position of the pointer, in cell units.
+    pub fn drag(&mut self, position: (f32, f32)) {
+        match self {
+            Annotation::Arrow { points, .. } => {
+                // Skip the jitter, so strokes don't accumulate thousands of points.
+                let far_enough = points
+                    .last()
+                    .is_none_or(|&(x, y)| (position.0 - x).hypot(position.1 - y) >= 0.1);
+                if far_enough {
+                    points.push(position);
+                }
+            }
+            Annotation::Circle { center, radius, .. } => {
+                *radius = (position.0 - center.0).hypot(position.1 - center.1);
+            }
+            Annotation::Label { .. } => {}
+        }
+    }
+
+    /// Returns whether the mark is too small to be seen, as after a click with the arrow or
+    /// circle tools.
+    pub fn is_degenerate(&self) -> bool {
+        match self {
+            Annotation::Arrow { points, .. } => points.len() < 2,
+            Annotation::Circle { radius, .. } => *radius < 0.1,
+            Annotation::Label { text, .. } => text.is_empty(),
+        }
+    }
+
+    /// Renders the mark as an SVG element, in cell units.
+    pub fn to_svg(&self) -> String {
+        match self {
+            Annotation::Arrow { points, color } => {
+                let points = points
+                    .iter()
+                    .map(|(x, y)| format!("{x:.2},{y:.2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                format!(
+                    r#"<polyline points="{points}" fill="none" stroke="{color}" stroke-width="0.15" stroke-linecap="round" stroke-linejoin="round" marker-end="url(#ngram-arrowhead-{id})"/>"#,
+                    color = escape(color),
+                    id = marker_id(color),
+                )
+            }
+            Annotation::Circle {
+                center: (x, y),
+                radius,
+                color,
+            } => format!(
+                r#"<circle cx="{x:.2}" cy="{y:.2}" r="{radius:.2}" fill="none" stroke="{}" stroke-width="0.15"/>"#,
+                escape(color)
+            ),
+            Annotation::Label {
+                position: (x, y),
+                text,
+                color,
+            } => format!(
+                r#"<text x="{x:.2}" y="{y:.2}" fill="{}" stroke="white" stroke-width="0.08" paint-order="stroke" font-family="sans-serif" font-size="0.8" font-weight="bold" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+                escape(color),
+                escape(text)
+            ),
+        }
+    }
+}
+
+/// Renders the marks as SVG elements in cell units, with the arrowhead markers they use.
+///
+/// # Arguments
+///
+/// * `annotations` - The marks to render, in drawing order.
+///
+/// # Returns
+///
+/// The markup to place inside an `svg` element whose view box is measured in cells.
+pub fn annotations_svg<'a>(annotations: impl IntoIterator<Item = &'a Annotation>) -> String {
+    let annotations = annotations.into_iter().collect::<Vec<_>>();
+    let mut colors = annotations
+        .iter()
+        .filter_map(|annotation| match annotation {
+            Annotation::Arrow { color, .. } => Some(color.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    colors.sort_unstable();
+    colors.dedup();
+    let mut svg = String::from("<defs>");
+    for color in colors {
+        svg.push_str(&format!(
+            r#"<marker id="ngram-arrowhead-{}" viewBox="0 0 10 10" refX="5" refY="5" markerWidth="4" markerHeight="4" orient="auto-start-reverse"><path d="M 0 0 L 10 5 L 0 10 z" fill="{}"/></marker>"#,
+            marker_id(color),
+            escape(color)
+        ));
+    }
+    svg.push_str("</defs>");
+    for annotation in annotations {
+        svg.push_str(&annotation.to_svg());
+    }
+    svg
+}
+
+/// Renders a standalone SVG picture of the solution grid with the marks drawn over it, to be
+/// used as a screenshot in explanations.
+///
+/// # Arguments
+///
+/// * `solution` - The solution grid to render.
+/// * `palette` - The palette giving the color of each cell.
+/// * `annotations` - The marks drawn over the grid.
+/// * `cell_size` - The size of each cell in pixels.
+///
+/// # Returns
+///
+/// The SVG document.
+pub fn annotated_svg(
+    solution: &NonogramSolution,
+    palette: &NonogramPalette,
+    annotations: &[Annotation],
+    cell_size: u32,
+) -> String {
+    let rows = solution.solution_grid.len();
+    let cols = solution.solution_grid.first().map_or(0, Vec::len);
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {cols} {rows}">"#,
+        cols as u32 * cell_size,
+        rows as u32 * cell_size
+    );
+    for (row, line) in solution.solution_grid.iter().enumerate() {
+        for (col, &cell) in line.iter().enumerate() {
+            let fill = palette
+                .color_palette
+                .get(cell)
+                .map_or("#ffffff", String::as_str);
+            svg.push_str(&format!(
+                r##"<rect x="{col}" y="{row}" width="1" height="1" fill="{}" stroke="#9ca3af" stroke-width="0.04"/>"##,
+                escape(fill)
+            ));
+        }
+    }
+    svg.push_str(&annotations_svg(annotations));
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Returns an identifier of the arrowhead marker of a color, safe to use in `url(#...)`.
+fn marker_id(color: &str) -> String {
+    color.chars().filter(char::is_ascii_alphanumeric).collect()
+}
+
+/// Escapes a text to be placed in SVG attributes and contents.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that arrows follow the pointer and clicks without dragging are discarded.
+    #[test]
+    fn drag_marks() {
+        let annotations = Annotations {
+            tool: Some(AnnotationTool::Arrow),
+            ..Default::default()
+        };
+        let mut arrow = annotations.start((1.0, 1.0)).unwrap();
+        assert!(arrow.is_degenerate());
+        arrow.drag((1.01, 1.0));
+        assert!(arrow.is_degenerate());
+        arrow.drag((3.0, 2.0));
+        assert!(!arrow.is_degenerate());
+
+        let annotations = Annotations {
+            tool: Some(AnnotationTool::Label),
+            ..Default::default()
+        };
+        assert_eq!(annotations.start((0.0, 0.0)), None);
+    }
+
+    // Test that the exported picture contains the grid, the marks and escaped labels.
+    #[test]
+    fn annotated_picture() {
+        let solution = NonogramSolution {
+            solution_grid: vec![vec![0, 1], vec![1, 0]],
+        };
+        let palette = NonogramPalette {
+            color_palette: vec![String::from("#ffffff"), String::from("#000000")],
+            brush: 1,
+        };
+        let annotations = [
+            Annotation::Arrow {
+                points: vec![(0.5, 0.5), (1.5, 1.5)],
+                color: String::from("#ef4444"),
+            },
+            Annotation::Label {
+                position: (1.0, 1.0),
+                text: String::from("a < b"),
+                color: String::from("#000000"),
+            },
+        ];
+        let svg = annotated_svg(&solution, &palette, &annotations, 20);
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>"));
+        assert!(svg.contains(r#"width="40" height="40""#));
+        assert_eq!(svg.matches("<rect").count(), 4);
+        assert!(svg.contains(r#"id="ngram-arrowhead-ef4444""#));
+        assert!(svg.contains("url(#ngram-arrowhead-ef4444)"));
+        assert!(svg.contains("a &lt; b"));
+    }
+}
@@ -0,0 +1,304 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the CRC-32 checksum, shared with the PNG pictures.
+use super::picture::crc32;
+
+// Import formatting traits for displaying the archive errors.
+use std::fmt;
+
+/// The signature of the local header of each entry of a zip archive.
+const LOCAL_HEADER_SIGNATURE: u32 = 0x0403_4B50;
+
+/// The signature of the central directory headers of a zip archive.
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x0201_4B50;
+
+/// The signature of the record ending a zip archive.
+const END_SIGNATURE: u32 = 0x0605_4B50;
+
+/// The size of the local header of an entry, without its name.
+const LOCAL_HEADER_SIZE: usize = 30;
+
+/// The size of a central directory header, without its name.
+const CENTRAL_HEADER_SIZE: usize = 46;
+
+/// The size of the record ending a zip archive, without its comment.
+const END_SIZE: usize = 22;
+
+/// The version of the zip specification needed to extract the entries (2.0).
+const ZIP_VERSION: u16 = 20;
+
+/// The flag marking the names of the entries as UTF-8.
+const UTF8_FLAG: u16 = 0x0800;
+
+/// The date of the entries, 1980-01-01 in the MS-DOS format (the archives aren't dated, so
+/// exporting the same files gives the same archive).
+const DOS_DATE: u16 = 0x0021;
+
+/// An error reading a zip archive.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ArchiveError {
+    /// The data isn't a zip archive.
+    NotZip,
+    /// A header or an entry is truncated, or its checksum doesn't match.
+    Corrupted,
+    /// An entry is compressed, while only stored entries are supported.
+    Compressed { name: String },
+}
+
+impl fmt::Display for ArchiveError {
+    /// Formats the error as a message for the logs.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArchiveError::NotZip => write!(f, "the file isn't a zip archive"),
+            ArchiveError::Corrupted => write!(f, "the zip archive is corrupted"),
+            ArchiveError::Compressed { name } => {
+                write!(f, "the entry '{name}' of the zip archive is compressed")
+            }
+        }
+    }
+}
+
+/// A file of an archive.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ArchiveEntry {
+    /// The path of the file in the archive, with `/` separating its folders.
+    pub name: String,
+    /// The contents of the file.
+    pub data: Vec<u8>,
+}
+
+/// A zip archive bundling several files, such as the puzzles exported from the library.
+///
+/// The entries are stored without compression: the files are small, and the archives stay
+/// readable by any zip tool while keeping this module free of dependencies.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct Archive {
+    /// The files of the archive, in order.
+    entries: Vec<ArchiveEntry>,
+}
+
+impl Archive {
+    /// Adds a file to the archive, replacing the file with the same name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The path of the file in the archive.
+    /// * `data` - The contents of the file.
+    pub fn add(&mut self, name: &str, data: Vec<u8>) {
+        let entry = ArchiveEntry {
+            name: name.to_string(),
+            data,
+        };
+        match self.entries.iter_mut().find(|entry| entry.name == name) {
+            Some(existing) => *existing = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// Returns the files of the archive, in order.
+    pub fn entries(&self) -> &[ArchiveEntry] {
+        &self.entries
+    }
+
+    /// Returns the number of files of the archive.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Checks if the archive has no files.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Writes the archive in the zip format.
+    ///
+    /// # Returns
+    ///
+    /// The bytes of the zip file.
+    pub fn to_zip(&self) -> Vec<u8> {
+        let mut zip = Vec::new();
+        let mut central = Vec::new();
+        for entry in &self.entries {
+            let offset = zip.len() as u32;
+            let name = entry.name.as_bytes();
+            let crc = crc32(&[&entry.data]);
+            let size = entry.data.len() as u32;
+            put_u32(&mut zip, LOCAL_HEADER_SIGNATURE);
+            put_common_header(&mut zip, crc, size, name.len());
+            put_u16(&mut zip, 0);
+            zip.extend_from_slice(name);
+            zip.extend_from_slice(&entry.data);
+
+            put_u32(&mut central, CENTRAL_HEADER_SIGNATURE);
+            put_u16(&mut central, ZIP_VERSION);
+            put_common_header(&mut central, crc, size, name.len());
+            // No extra field, comment, disk number, or attributes
+            central.extend_from_slice(&[0; 12]);
+            put_u32(&mut central, offset);
+            central.extend_from_slice(name);
+        }
+        let central_offset = zip.len() as u32;
+        let central_size = central.len() as u32;
+        zip.extend_from_slice(&central);
+        put_u32(&mut zip, END_SIGNATURE);
+        put_u32(&mut zip, 0);
+        put_u16(&mut zip, self.entries.len() as u16);
+        put_u16(&mut zip, self.entries.len() as u16);
+        put_u32(&mut zip, central_size);
+        put_u32(&mut zip, central_offset);
+        put_u16(&mut zip, 0);
+        zip
+    }
+
+    /// Reads an archive in the zip format, verifying the checksums of its files.
+    ///
+    /// # Arguments
+    ///
+    /// * `zip` - The bytes of the zip file.
+    ///
+    /// # Returns
+    ///
+    /// The archive, or the error found reading it. Only stored (uncompressed) entries are
+    /// supported, such as the ones written by `to_zip`.
+    pub fn from_zip(zip: &[u8]) -> Result<Self, ArchiveError> {
+        let end = (0..=zip.len().saturating_sub(END_SIZE))
+            .rev()
+            .find(|&start| get_u32(zip, start) == Some(END_SIGNATURE))
+            .ok_or(ArchiveError::NotZip)?;
+        let count = get_u16(zip, end + 10).ok_or(ArchiveError::Corrupted)? as usize;
+        let mut header = get_u32(zip, end + 16).ok_or(ArchiveError::Corrupted)? as usize;
+        let mut archive = Archive::default();
+        for _ in 0..count {
+            if get_u32(zip, header) != Some(CENTRAL_HEADER_SIGNATURE) {
+                return Err(ArchiveError::Corrupted);
+            }
+            let field = |offset: usize| get_u16(zip, header + offset).map(usize::from);
+            let (Some(method), Some(name_len), Some(extra_len), Some(comment_len)) =
+                (field(10), field(28), field(30), field(32))
+            else {
+                return Err(ArchiveError::Corrupted);
+            };
+            let (Some(crc), Some(size), Some(offset)) = (
+                get_u32(zip, header + 16),
+                get_u32(zip, header + 20),
+                get_u32(zip, header + 42),
+            ) else {
+                return Err(ArchiveError::Corrupted);
+            };
+            let name = zip
+                .get(header + CENTRAL_HEADER_SIZE..header + CENTRAL_HEADER_SIZE + name_len)
+                .ok_or(ArchiveError::Corrupted)?;
+            let name = String::from_utf8_lossy(name).into_owned();
+            if method != 0 {
+                return Err(ArchiveError::Compressed { name });
+            }
+            let offset = offset as usize;
+            let (Some(local_name_len), Some(local_extra_len)) =
+                (get_u16(zip, offset + 26), get_u16(zip, offset + 28))
+            else {
+                return Err(ArchiveError::Corrupted);
+            };
+            let start =
+                offset + LOCAL_HEADER_SIZE + local_name_len as usize + local_extra_len as usize;
+            let data = zip
+                .get(start..start + size as usize)
+                .ok_or(ArchiveError::Corrupted)?;
+            if crc32(&[data]) != crc {
+                return Err(ArchiveError::Corrupted);
+            }
+            archive.add(&name, data.to_vec());
+            header += CENTRAL_HEADER_SIZE + name_len + extra_len + comment_len;
+        }
+        Ok(archive)
+    }
+}
+
+/// Appends the fields shared by the local and the central headers of an entry, from the version
+/// needed to extract it to the length of its name.
+fn put_common_header(zip: &mut Vec<u8>, crc: u32, size: u32, name_len: usize) {
+    put_u16(zip, ZIP_VERSION);
+    put_u16(zip, UTF8_FLAG);
+    // Stored, without compression
+    put_u16(zip, 0);
+    put_u16(zip, 0);
+    put_u16(zip, DOS_DATE);
+    put_u32(zip, crc);
+    put_u32(zip, size);
+    put_u32(zip, size);
+    put_u16(zip, name_len as u16);
+}
+
+/// Appends a little-endian 16-bit number.
+fn put_u16(zip: &mut Vec<u8>, value: u16) {
+    zip.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Appends a little-endian 32-bit number.
+fn put_u32(zip: &mut Vec<u8>, value: u32) {
+    zip.extend_from_slice(&value.to_le_bytes());
+}
+
+/// Reads a little-endian 16-bit number, if it isn't truncated.
+fn get_u16(zip: &[u8], offset: usize) -> Option<u16> {
+    let bytes = zip.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+/// Reads a little-endian 32-bit number, if it isn't truncated.
+fn get_u32(zip: &[u8], offset: usize) -> Option<u32> {
+    let bytes = zip.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that an archive is read back from its zip file, with the names and the contents.
+    #[test]
+    fn zip_round_trip() {
+        let mut archive = Archive::default();
+        archive.add("library/tree.ngram", b"{\"solution\":[]}".to_vec());
+        archive.add("notes/árbol.md", b"# Strategy".to_vec());
+        archive.add("empty.txt", Vec::new());
+        archive.add("library/tree.ngram", b"{}".to_vec());
+        assert_eq!(archive.len(), 3);
+
+        let zip = archive.to_zip();
+        assert_eq!(&zip[..4], b"PK\x03\x04");
+        assert_eq!(Archive::from_zip(&zip), Ok(archive));
+    }
+
+    // Test that corrupted and foreign data is rejected.
+    #[test]
+    fn invalid_zips() {
+        assert_eq!(Archive::from_zip(b"not a zip"), Err(ArchiveError::NotZip));
+        let mut archive = Archive::default();
+        archive.add("tree.ngram", b"{}".to_vec());
+        let mut zip = archive.to_zip();
+        zip[LOCAL_HEADER_SIZE + "tree.ngram".len()] ^= 0xFF;
+        assert_eq!(Archive::from_zip(&zip), Err(ArchiveError::Corrupted));
+        zip.truncate(zip.len() - 10);
+        assert_eq!(Archive::from_zip(&zip), Err(ArchiveError::NotZip));
+    }
+}
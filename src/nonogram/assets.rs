@@ -0,0 +1,122 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the map of the assets by their URL
+use std::collections::HashMap;
+
+/// The loading state of an asset fetched at runtime.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub enum AssetState {
+    /// The asset wasn't requested yet.
+    #[default]
+    Idle,
+    /// The asset is being fetched.
+    Loading,
+    /// The asset was fetched, with its content.
+    Ready(String),
+    /// The asset couldn't be fetched, with the reason.
+    Failed(String),
+}
+
+/// A cache of the assets fetched at runtime instead of being bundled in the binary, such as the
+/// locales of the languages not in use, so each one is only fetched once.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct AssetCache {
+    /// The state of each asset requested, by its URL.
+    assets: HashMap<String, AssetState>,
+}
+
+impl AssetCache {
+    /// Returns the loading state of an asset.
+    pub fn state(&self, url: &str) -> AssetState {
+        self.assets.get(url).cloned().unwrap_or_default()
+    }
+
+    /// Returns the content of an asset, if it was fetched.
+    pub fn content(&self, url: &str) -> Option<&str> {
+        match self.assets.get(url) {
+            Some(AssetState::Ready(content)) => Some(content),
+            _ => None,
+        }
+    }
+
+    /// Checks whether an asset is being fetched.
+    pub fn is_loading(&self, url: &str) -> bool {
+        self.assets.get(url) == Some(&AssetState::Loading)
+    }
+
+    /// Marks an asset as being fetched, unless it's already being fetched or was fetched (the
+    /// assets that failed are fetched again).
+    ///
+    /// # Returns
+    ///
+    /// `true` if the asset should be fetched.
+    pub fn begin(&mut self, url: &str) -> bool {
+        match self.assets.get(url) {
+            Some(AssetState::Loading | AssetState::Ready(_)) => false,
+            _ => {
+                self.assets.insert(url.to_string(), AssetState::Loading);
+                true
+            }
+        }
+    }
+
+    /// Stores the result of fetching an asset.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The URL of the asset.
+    /// * `result` - The content of the asset, or the reason it couldn't be fetched.
+    pub fn finish(&mut self, url: &str, result: Result<String, String>) {
+        let state = match result {
+            Ok(content) => AssetState::Ready(content),
+            Err(reason) => AssetState::Failed(reason),
+        };
+        self.assets.insert(url.to_string(), state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that each asset is fetched once, and fetched again after failing.
+    #[test]
+    fn assets_are_fetched_once() {
+        let mut cache = AssetCache::default();
+        assert_eq!(cache.state("/es-MX.ftl"), AssetState::Idle);
+        assert!(cache.begin("/es-MX.ftl"));
+        assert!(cache.is_loading("/es-MX.ftl"));
+        assert!(!cache.begin("/es-MX.ftl"));
+
+        cache.finish("/es-MX.ftl", Err(String::from("Not Found")));
+        assert_eq!(
+            cache.state("/es-MX.ftl"),
+            AssetState::Failed(String::from("Not Found"))
+        );
+        assert!(cache.begin("/es-MX.ftl"));
+        cache.finish("/es-MX.ftl", Ok(String::from("lang = Idioma")));
+        assert_eq!(cache.content("/es-MX.ftl"), Some("lang = Idioma"));
+        assert!(!cache.begin("/es-MX.ftl"));
+        assert_eq!(cache.content("/en-US.ftl"), None);
+    }
+}
@@ -0,0 +1,268 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the Nonogram file of the challenge puzzles.
+use super::definitions::NonogramFile;
+
+// Import the generator of reproducible puzzles, drawn from the seed of each week.
+use super::generator::seeded_puzzle;
+
+// Import the assist levels, recorded in the verification tokens.
+use super::settings::AssistLevel;
+
+// Import the formatting of the week's first day.
+use super::timer::format_date;
+
+// Import the formatting and parsing of the verification tokens.
+use std::{fmt, str::FromStr};
+
+/// The published seed every weekly challenge is derived from.
+pub const CHALLENGE_SEED: u64 = 0x6e67_7261_6d2d_7765;
+
+/// The size of the weekly challenge puzzles.
+pub const CHALLENGE_SIZE: usize = 15;
+
+/// The number of colors (besides the background) of the weekly challenge puzzles.
+pub const CHALLENGE_COLORS: usize = 2;
+
+/// The seconds in a week.
+const WEEK_SECONDS: i64 = 7 * 86_400;
+
+/// The seconds from the Monday before the Unix epoch (a Thursday) to the epoch.
+const MONDAY_OFFSET: i64 = 3 * 86_400;
+
+/// Hashes a sequence of values with FNV-1a, stable across platforms and versions.
+fn fnv1a(values: impl IntoIterator<Item = u64>) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for value in values {
+        for byte in value.to_le_bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+    }
+    hash
+}
+
+/// The puzzle of a week, the same for every player since it's generated from a published seed.
+///
+/// The weeks start on Monday at 00:00 UTC, and are numbered from the week of the Unix epoch.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WeeklyChallenge {
+    /// The number of the week.
+    pub week: i64,
+}
+
+impl WeeklyChallenge {
+    /// Returns the challenge of the week containing a timestamp, in seconds since the Unix epoch.
+    pub fn at(timestamp: i64) -> Self {
+        Self {
+            week: (timestamp + MONDAY_OFFSET).div_euclid(WEEK_SECONDS),
+        }
+    }
+
+    /// Returns the timestamp when the week starts, in seconds since the Unix epoch.
+    pub fn starts_at(&self) -> i64 {
+        self.week * WEEK_SECONDS - MONDAY_OFFSET
+    }
+
+    /// Returns the first day of the week, as `YYYY-MM-DD`.
+    pub fn start_date(&self) -> String {
+        format_date(self.starts_at())[..10].to_string()
+    }
+
+    /// Returns the seed of the week's puzzle, derived from the published seed.
+    pub fn seed(&self) -> u64 {
+        fnv1a([CHALLENGE_SEED, self.week as u64])
+    }
+
+    /// Returns the name of the week's puzzle, identifying its completions.
+    pub fn filename(&self) -> String {
+        format!("weekly-{}.ngram", self.start_date())
+    }
+
+    /// Generates the week's puzzle.
+    pub fn file(&self) -> NonogramFile {
+        seeded_puzzle(
+            CHALLENGE_SIZE,
+            CHALLENGE_SIZE,
+            CHALLENGE_COLORS,
+            self.seed(),
+        )
+    }
+
+    /// Creates the verification token of a solve of the week's puzzle.
+    pub fn verify(&self, stats: SolveStats) -> Verification {
+        Verification {
+            week: self.week,
+            stats,
+            checksum: self.checksum(&stats),
+        }
+    }
+
+    /// Hashes the seed of the week with the stats of a solve.
+    fn checksum(&self, stats: &SolveStats) -> u32 {
+        let hash = fnv1a([
+            self.seed(),
+            stats.seconds,
+            stats.actions as u64,
+            stats.assist_level as u64,
+        ]);
+        (hash ^ hash >> 32) as u32
+    }
+}
+
+/// The stats of a solve of a weekly challenge.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SolveStats {
+    /// The seconds spent actively solving the puzzle.
+    pub seconds: u64,
+    /// The number of actions performed on the grid.
+    pub actions: usize,
+    /// The loosest assist level used while solving it.
+    pub assist_level: AssistLevel,
+}
+
+/// A token verifying a solve of a weekly challenge, to share and compare the solves.
+///
+/// The token is written as `ngram-w<week>-<seconds>-<actions>-<level>-<checksum>`, where the
+/// checksum hashes the seed of the week with the stats. It's computed locally, so it detects
+/// mistyped or edited tokens but can't prove the puzzle was solved honestly.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Verification {
+    /// The number of the week of the challenge.
+    pub week: i64,
+    /// The stats of the solve.
+    pub stats: SolveStats,
+    /// The checksum of the seed and the stats.
+    pub checksum: u32,
+}
+
+impl Verification {
+    /// Returns the challenge the token was created for.
+    pub fn challenge(&self) -> WeeklyChallenge {
+        WeeklyChallenge { week: self.week }
+    }
+
+    /// Checks that the checksum matches the week and the stats of the token.
+    pub fn is_valid(&self) -> bool {
+        self.challenge().checksum(&self.stats) == self.checksum
+    }
+}
+
+impl fmt::Display for Verification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self.stats.assist_level {
+            AssistLevel::Purist => 'p',
+            AssistLevel::Casual => 'c',
+            AssistLevel::Relaxed => 'r',
+        };
+        write!(
+            f,
+            "ngram-w{}-{}-{}-{level}-{:08x}",
+            self.week, self.stats.seconds, self.stats.actions, self.checksum
+        )
+    }
+}
+
+/// The error of a malformed verification token.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InvalidToken;
+
+impl FromStr for Verification {
+    type Err = InvalidToken;
+
+    fn from_str(token: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = token.trim().split('-').collect();
+        let [prefix, week, seconds, actions, level, checksum] = fields.as_slice() else {
+            return Err(InvalidToken);
+        };
+        if !prefix.eq_ignore_ascii_case("ngram") {
+            return Err(InvalidToken);
+        }
+        let week = week
+            .strip_prefix('w')
+            .and_then(|week| week.parse().ok())
+            .ok_or(InvalidToken)?;
+        let assist_level = match *level {
+            "p" => AssistLevel::Purist,
+            "c" => AssistLevel::Casual,
+            "r" => AssistLevel::Relaxed,
+            _ => return Err(InvalidToken),
+        };
+        Ok(Self {
+            week,
+            stats: SolveStats {
+                seconds: seconds.parse().map_err(|_| InvalidToken)?,
+                actions: actions.parse().map_err(|_| InvalidToken)?,
+                assist_level,
+            },
+            checksum: u32::from_str_radix(checksum, 16).map_err(|_| InvalidToken)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the weeks start on Monday, and that their puzzles are reproducible.
+    #[test]
+    fn weekly_challenges() {
+        // Monday, 2026-10-12 00:00 UTC.
+        let monday = 1_791_763_200;
+        let challenge = WeeklyChallenge::at(monday);
+        assert_eq!(challenge.starts_at(), monday);
+        assert_eq!(challenge.start_date(), "2026-10-12");
+        assert_eq!(WeeklyChallenge::at(monday + WEEK_SECONDS - 1), challenge);
+        assert_eq!(WeeklyChallenge::at(monday - 1).week, challenge.week - 1);
+        assert_eq!(WeeklyChallenge::at(0).start_date(), "1969-12-29");
+        assert_eq!(challenge.file().solution, challenge.file().solution);
+        assert_ne!(
+            challenge.seed(),
+            WeeklyChallenge::at(monday + WEEK_SECONDS).seed()
+        );
+    }
+
+    // Test that the verification tokens are parsed back, and that edited tokens are detected.
+    #[test]
+    fn verification_tokens() {
+        let challenge = WeeklyChallenge::at(1_791_763_200);
+        let verification = challenge.verify(SolveStats {
+            seconds: 421,
+            actions: 187,
+            assist_level: AssistLevel::Purist,
+        });
+        let token = verification.to_string();
+        assert!(token.starts_with("ngram-w2963-421-187-p-"));
+        let parsed: Verification = token.parse().unwrap();
+        assert_eq!(parsed, verification);
+        assert!(parsed.is_valid());
+
+        let edited: Verification = token.replace("-421-", "-321-").parse().unwrap();
+        assert!(!edited.is_valid());
+        assert_eq!("ngram-w2963-421".parse::<Verification>(), Err(InvalidToken));
+        assert_eq!(
+            token.replace("-p-", "-x-").parse::<Verification>(),
+            Err(InvalidToken)
+        );
+    }
+}
@@ -0,0 +1,260 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Contents copied to the clipboard: regions of a solution grid and palettes.
+//!
+//! The contents are exchanged as tagged JSON text, so they can be pasted in other instances of
+//! the application (or after a restart), and text copied by other applications is ignored. The
+//! last content copied is also stored, for the platforms whose clipboard is cleared on exit.
+
+// Import the grids and palettes copied.
+use super::definitions::{Area, NonogramPalette, NonogramPaletteFile, NonogramSolution};
+
+// Import the storage of the last content copied.
+use super::storage;
+
+// Import serialization support for the text exchanged through the clipboard.
+use serde::{Deserialize, Serialize};
+
+/// The storage key of the last content copied.
+const CLIPBOARD_KEY: &str = "ngram-clipboard.json";
+
+/// A rectangular region copied from a solution grid.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Region {
+    /// The color indexes of the cells of the region, by rows.
+    pub cells: Vec<Vec<usize>>,
+    /// The palette the color indexes refer to.
+    pub palette: NonogramPalette,
+}
+
+/// A content of the clipboard.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(tag = "ngram", rename_all = "snake_case")]
+pub enum ClipboardContent {
+    /// A region of a solution grid.
+    Region(Region),
+    /// A palette, with its name and author.
+    Palette(NonogramPaletteFile),
+}
+
+impl Region {
+    /// Copies a region of a solution grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `solution` - The grid copied.
+    /// * `palette` - The palette of the grid.
+    /// * `area` - The area copied, clipped to the grid.
+    ///
+    /// # Returns
+    ///
+    /// The copied region, or `None` if the area is outside of the grid.
+    pub fn copy(
+        solution: &NonogramSolution,
+        palette: &NonogramPalette,
+        area: Area,
+    ) -> Option<Self> {
+        let top = area.start.0.min(area.end.0);
+        let left = area.start.1.min(area.end.1);
+        let bottom = area.start.0.max(area.end.0);
+        let right = area.start.1.max(area.end.1);
+        let cells: Vec<Vec<usize>> = solution
+            .solution_grid
+            .iter()
+            .take(bottom + 1)
+            .skip(top)
+            .map(|row| row.iter().take(right + 1).skip(left).copied().collect())
+            .collect();
+        if cells.first().is_none_or(|row| row.is_empty()) {
+            return None;
+        }
+        let mut palette = palette.clone();
+        palette.brush = 0;
+        Some(Self { cells, palette })
+    }
+
+    /// Pastes the region over a solution grid, mapping each of its colors to the perceptually
+    /// closest color of the grid palette. The cells falling outside of the grid are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `solution` - The grid pasted over.
+    /// * `palette` - The palette of the grid.
+    /// * `at` - The coordinate `(row, column)` of the top-left cell of the pasted region.
+    ///
+    /// # Returns
+    ///
+    /// The number of cells whose color changed.
+    pub fn paste(
+        &self,
+        solution: &mut NonogramSolution,
+        palette: &NonogramPalette,
+        at: (usize, usize),
+    ) -> usize {
+        let mapping = self.palette.closest_mapping(palette);
+        let mut changed = 0;
+        for (row, cells) in solution
+            .solution_grid
+            .iter_mut()
+            .skip(at.0)
+            .zip(&self.cells)
+        {
+            for (cell, &color) in row.iter_mut().skip(at.1).zip(cells) {
+                let color = mapping.get(color).copied().unwrap_or_default();
+                if *cell != color {
+                    *cell = color;
+                    changed += 1;
+                }
+            }
+        }
+        changed
+    }
+}
+
+impl ClipboardContent {
+    /// Serializes the content as the text placed in the clipboard.
+    pub fn to_text(&self) -> Option<String> {
+        serde_json::to_string(self).ok()
+    }
+
+    /// Parses a content from the text of the clipboard.
+    ///
+    /// # Returns
+    ///
+    /// The content, or `None` if the text wasn't copied by this application.
+    pub fn from_text(text: &str) -> Option<Self> {
+        serde_json::from_str(text.trim()).ok()
+    }
+
+    /// Stores the content as the last one copied, so it can be pasted after a restart.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the content could be stored.
+    pub fn remember(&self) -> bool {
+        self.to_text()
+            .is_some_and(|text| storage::write(CLIPBOARD_KEY, &text))
+    }
+
+    /// Returns the last content copied, stored by this or a previous session.
+    pub fn remembered() -> Option<Self> {
+        Self::from_text(&storage::read(CLIPBOARD_KEY)?)
+    }
+}
+
+/// Reads the text of the system clipboard natively.
+///
+/// # Returns
+///
+/// The text, or `None` if the clipboard is unavailable or doesn't hold text.
+#[cfg(feature = "clipboard")]
+pub fn read_system() -> Option<String> {
+    arboard::Clipboard::new().ok()?.get_text().ok()
+}
+
+/// Writes text to the system clipboard natively.
+///
+/// The clipboard is kept open after the first write, since on some platforms (such as X11) the
+/// text is only served while its owner is alive.
+///
+/// # Returns
+///
+/// `true` if the text could be written.
+#[cfg(feature = "clipboard")]
+pub fn write_system(text: &str) -> bool {
+    // Import the lock of the clipboard kept open.
+    use std::sync::Mutex;
+
+    static SYSTEM: Mutex<Option<arboard::Clipboard>> = Mutex::new(None);
+    let Ok(mut system) = SYSTEM.lock() else {
+        return false;
+    };
+    if system.is_none() {
+        *system = arboard::Clipboard::new().ok();
+    }
+    system
+        .as_mut()
+        .is_some_and(|clipboard| clipboard.set_text(text).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that a region is copied clipped to the grid and pasted with the colors remapped.
+    #[test]
+    fn region_copy_and_paste() {
+        let solution = NonogramSolution {
+            solution_grid: vec![vec![0, 1, 2], vec![2, 1, 0], vec![1, 1, 1]],
+        };
+        let palette = NonogramPalette {
+            color_palette: vec!["#ffffff".into(), "#000000".into(), "#ff0000".into()],
+            brush: 2,
+        };
+        let area = Area {
+            start: (1, 5),
+            end: (0, 1),
+        };
+        let region = Region::copy(&solution, &palette, area).unwrap();
+        assert_eq!(region.cells, vec![vec![1, 2], vec![1, 0]]);
+        assert!(Region::copy(
+            &solution,
+            &palette,
+            Area {
+                start: (3, 0),
+                end: (4, 0)
+            }
+        )
+        .is_none());
+        let target = NonogramPalette {
+            color_palette: vec!["#ffffff".into(), "#fe0000".into(), "#000000".into()],
+            brush: 0,
+        };
+        let mut grid = NonogramSolution {
+            solution_grid: vec![vec![0; 3]; 2],
+        };
+        assert_eq!(region.paste(&mut grid, &target, (1, 2)), 1);
+        assert_eq!(grid.solution_grid, vec![vec![0, 0, 0], vec![0, 0, 2]]);
+    }
+
+    // Test that only the text copied by the application is parsed back.
+    #[test]
+    fn content_round_trip() {
+        let content = ClipboardContent::Palette(NonogramPaletteFile {
+            name: String::from("mono"),
+            author: String::new(),
+            palette: NonogramPalette {
+                color_palette: vec!["#ffffff".into(), "#000000".into()],
+                brush: 0,
+            },
+        });
+        let text = content.to_text().unwrap();
+        let Some(ClipboardContent::Palette(file)) = ClipboardContent::from_text(&text) else {
+            panic!("The palette wasn't parsed back");
+        };
+        assert_eq!(file.name, "mono");
+        assert_eq!(file.palette.color_palette, vec!["#ffffff", "#000000"]);
+        assert!(ClipboardContent::from_text("hello").is_none());
+        assert!(ClipboardContent::from_text(r#"{"name": "mono"}"#).is_none());
+    }
+}
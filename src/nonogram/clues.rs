@@ -0,0 +1,778 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Nonogram structures for palettes, segments and puzzles.
+use super::definitions::{NonogramPalette, NonogramPuzzle, NonogramSegment, DEFAULT_PALETTE};
+
+// Import the line slack, used to check that the segments of each line fit in the grid.
+use super::logic::line_slack;
+
+// Import the ordered set of the colors used by the clues.
+use std::collections::BTreeSet;
+
+// Import formatting traits for displaying the parsing errors.
+use std::fmt;
+
+/// The formats the clues of a puzzle can be exported to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ClueFormat {
+    /// Plain text, in the notation read by `parse_clues`.
+    PlainText,
+    /// Markdown tables, for posting puzzles in forums or documents.
+    Markdown,
+}
+
+impl ClueFormat {
+    /// Returns the file extension of the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ClueFormat::PlainText => "txt",
+            ClueFormat::Markdown => "md",
+        }
+    }
+}
+
+/// The number of colors a clue can use, including the background.
+pub const MAX_CLUE_COLORS: usize = 64;
+
+/// An error found while parsing the clues of a puzzle.
+///
+/// The lines are numbered from 1, as displayed in a text editor.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ClueError {
+    /// The text has no row clues or no column clues.
+    MissingLines,
+    /// A clue isn't a length, optionally followed by `:` and a color index.
+    InvalidClue { line: usize, clue: String },
+    /// The segments of a line don't fit in the grid.
+    LineTooLong { line: usize },
+    /// The rows and the columns paint a different number of cells of a color.
+    ColorMismatch { color: usize },
+}
+
+impl fmt::Display for ClueError {
+    /// Formats the error as a message for the logs.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClueError::MissingLines => write!(f, "the clues need rows and columns"),
+            ClueError::InvalidClue { line, clue } => {
+                write!(f, "invalid clue '{clue}' in line {line}")
+            }
+            ClueError::LineTooLong { line } => {
+                write!(f, "the segments of line {line} don't fit in the grid")
+            }
+            ClueError::ColorMismatch { color } => write!(
+                f,
+                "the rows and the columns paint a different number of cells of color {color}"
+            ),
+        }
+    }
+}
+
+/// The section of the clue text being parsed.
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    Rows,
+    Columns,
+}
+
+/// Recognizes the header of a section, such as `Rows:` or `columns`.
+fn section_header(line: &str) -> Option<Section> {
+    match line.trim_end_matches(':').trim().to_lowercase().as_str() {
+        "rows" | "row" | "filas" => Some(Section::Rows),
+        "columns" | "column" | "cols" | "columnas" => Some(Section::Columns),
+        _ => None,
+    }
+}
+
+/// Parses the segments of a line, such as `3 1:2, 2`.
+///
+/// # Arguments
+///
+/// * `text` - The clues of the line, separated by commas or spaces.
+/// * `line` - The number of the line in the text, for the errors.
+///
+/// # Returns
+///
+/// The segments of the line, empty for `0` or `-`.
+fn parse_line(text: &str, line: usize) -> Result<Vec<NonogramSegment>, ClueError> {
+    let mut segments = Vec::new();
+    for clue in text
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|clue| !clue.is_empty())
+    {
+        let invalid = || ClueError::InvalidClue {
+            line,
+            clue: clue.to_string(),
+        };
+        let (length, color) = match clue.split_once(':') {
+            Some((length, color)) => (length, color.parse::<usize>().map_err(|_| invalid())?),
+            None => (clue, 1),
+        };
+        if length == "-" || length == "0" {
+            continue;
+        }
+        let length = length.parse::<usize>().map_err(|_| invalid())?;
+        if color == 0 || color >= MAX_CLUE_COLORS {
+            return Err(invalid());
+        }
+        segments.push(NonogramSegment { color, length });
+    }
+    Ok(segments)
+}
+
+/// Parses a puzzle from the plain-text clue notation.
+///
+/// Each line holds the segments of a row or a column, as lengths separated by commas or spaces,
+/// with `0` or `-` for an empty line. A length can be followed by `:` and a color index (`3:2`),
+/// otherwise it takes the first color. The rows come first and the columns after, either under
+/// `Rows:` and `Columns:` headers or separated by a blank line. Lines starting with `#` are
+/// ignored.
+///
+/// # Arguments
+///
+/// * `text` - The clue text.
+///
+/// # Returns
+///
+/// The puzzle described by the clues, or the first error found.
+pub fn parse_clues(text: &str) -> Result<NonogramPuzzle, ClueError> {
+    let mut rows = Vec::new();
+    let mut cols = Vec::new();
+    let mut section = Section::Rows;
+    let mut headers = false;
+    for (index, line) in text.lines().enumerate() {
+        let number = index + 1;
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        if let Some(header) = section_header(line) {
+            section = header;
+            headers = true;
+            continue;
+        }
+        if line.is_empty() {
+            // Without headers, a blank line after the rows starts the columns.
+            if !headers && section == Section::Rows && !rows.is_empty() {
+                section = Section::Columns;
+            }
+            continue;
+        }
+        let segments = parse_line(line, number)?;
+        match section {
+            Section::Rows => rows.push((number, segments)),
+            Section::Columns => cols.push((number, segments)),
+        }
+    }
+    build_puzzle(rows, cols)
+}
+
+/// Finds a segment painted with the background or with a color beyond the `MAX_CLUE_COLORS`
+/// a clue can use, which the solvers can't represent.
+///
+/// # Arguments
+///
+/// * `segments` - The segments of a line.
+///
+/// # Returns
+///
+/// The first segment with an invalid color, if any.
+pub fn invalid_color_segment(segments: &[NonogramSegment]) -> Option<&NonogramSegment> {
+    segments
+        .iter()
+        .find(|segment| segment.color == 0 || segment.color >= MAX_CLUE_COLORS)
+}
+
+/// Builds a puzzle from the segments of its lines, checking that their colors are valid, that
+/// they fit in the grid and that the rows and the columns paint the same cells of each color.
+///
+/// # Arguments
+///
+/// * `rows` - The number of each row line, for the errors, and its segments.
+/// * `cols` - The number of each column line, for the errors, and its segments.
+///
+/// # Returns
+///
+/// The puzzle described by the lines, or the first error found.
+fn build_puzzle(
+    rows: Vec<(usize, Vec<NonogramSegment>)>,
+    cols: Vec<(usize, Vec<NonogramSegment>)>,
+) -> Result<NonogramPuzzle, ClueError> {
+    if rows.is_empty() || cols.is_empty() {
+        return Err(ClueError::MissingLines);
+    }
+    for (lines, length) in [(&rows, cols.len()), (&cols, rows.len())] {
+        for (line, segments) in lines {
+            if let Some(segment) = invalid_color_segment(segments) {
+                return Err(ClueError::InvalidClue {
+                    line: *line,
+                    clue: format!("{}:{}", segment.length, segment.color),
+                });
+            }
+            if line_slack(segments, length).is_none() {
+                return Err(ClueError::LineTooLong { line: *line });
+            }
+        }
+    }
+    let painted = |lines: &[(usize, Vec<NonogramSegment>)]| {
+        let mut counts = vec![0; MAX_CLUE_COLORS];
+        for segment in lines.iter().flat_map(|(_, segments)| segments) {
+            counts[segment.color] += segment.length;
+        }
+        counts
+    };
+    let (row_counts, col_counts) = (painted(&rows), painted(&cols));
+    if let Some(color) = (0..MAX_CLUE_COLORS).find(|&color| row_counts[color] != col_counts[color])
+    {
+        return Err(ClueError::ColorMismatch { color });
+    }
+    Ok(NonogramPuzzle {
+        rows: rows.len(),
+        cols: cols.len(),
+        row_constraints: rows.into_iter().map(|(_, segments)| segments).collect(),
+        col_constraints: cols.into_iter().map(|(_, segments)| segments).collect(),
+    })
+}
+
+/// A line of a puzzle, such as one whose clues are edited.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ClueLine {
+    /// A row, by its index.
+    Row(usize),
+    /// A column, by its index.
+    Column(usize),
+}
+
+impl ClueLine {
+    /// Returns the clues of the line in a puzzle, or `None` if the line is outside of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle with the line.
+    pub fn clues(self, puzzle: &NonogramPuzzle) -> Option<&Vec<NonogramSegment>> {
+        match self {
+            ClueLine::Row(row) => puzzle.row_constraints.get(row),
+            ClueLine::Column(col) => puzzle.col_constraints.get(col),
+        }
+    }
+}
+
+/// Replaces the clues of a line of a puzzle, validating that they fit in the line.
+///
+/// The clues of the crossing lines aren't checked against each other, so a puzzle can be fixed
+/// one line at a time.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle whose clues are edited.
+/// * `line` - The edited line.
+/// * `segments` - The new clues of the line.
+///
+/// # Returns
+///
+/// An error if a clue has an invalid color or the clues don't fit in the line, numbered from 1.
+pub fn set_line_clues(
+    puzzle: &mut NonogramPuzzle,
+    line: ClueLine,
+    segments: Vec<NonogramSegment>,
+) -> Result<(), ClueError> {
+    let (index, length) = match line {
+        ClueLine::Row(row) => (row, puzzle.cols),
+        ClueLine::Column(col) => (col, puzzle.rows),
+    };
+    if let Some(segment) = invalid_color_segment(&segments) {
+        return Err(ClueError::InvalidClue {
+            line: index + 1,
+            clue: format!("{}:{}", segment.length, segment.color),
+        });
+    }
+    if line_slack(&segments, length).is_none() {
+        return Err(ClueError::LineTooLong { line: index + 1 });
+    }
+    let lines = match line {
+        ClueLine::Row(_) => &mut puzzle.row_constraints,
+        ClueLine::Column(_) => &mut puzzle.col_constraints,
+    };
+    let Some(clues) = lines.get_mut(index) else {
+        return Err(ClueError::MissingLines);
+    };
+    *clues = segments
+        .into_iter()
+        .filter(|segment| segment.length > 0)
+        .collect();
+    Ok(())
+}
+
+/// A clue cell of a nonograms.org table: its text and background color, if any.
+type HtmlCell = (String, Option<String>);
+
+/// Returns the value of an attribute in the opening tag of an element.
+fn html_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let lower = tag.to_ascii_lowercase();
+    let start = lower.find(&format!("{name}="))? + name.len() + 1;
+    let rest = &tag[start..];
+    let quote = rest.chars().next().filter(|&c| c == '"' || c == '\'')?;
+    let rest = &rest[1..];
+    rest.find(quote).map(|end| &rest[..end])
+}
+
+/// Returns the text of an element's contents, without its tags and whitespace.
+fn html_text(contents: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in contents.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&nbsp;", " ").trim().to_string()
+}
+
+/// Splits the elements of a tag in a fragment of HTML, as pairs of their opening tag and contents.
+///
+/// The contents of nested elements of the same tag end at the first closing tag.
+fn html_elements<'a>(html: &'a str, tag: &str) -> Vec<(&'a str, &'a str)> {
+    let lower = html.to_ascii_lowercase();
+    let (open, close) = (format!("<{tag}"), format!("</{tag}>"));
+    let mut elements = Vec::new();
+    let mut position = 0;
+    while let Some(start) = lower[position..].find(&open).map(|start| start + position) {
+        let Some(tag_end) = lower[start..].find('>').map(|end| end + start + 1) else {
+            break;
+        };
+        let end = lower[tag_end..]
+            .find(&close)
+            .map_or(html.len(), |end| end + tag_end);
+        elements.push((&html[start..tag_end], &html[tag_end..end]));
+        // Continue inside the element, so nested elements are found too.
+        position = tag_end;
+    }
+    elements
+}
+
+/// Extracts the cells of the clue table with a class in a nonograms.org page, row by row.
+fn html_clue_table(html: &str, class: &str) -> Option<Vec<Vec<HtmlCell>>> {
+    let (_, table) = html_elements(html, "table").into_iter().find(|(tag, _)| {
+        html_attribute(tag, "class")
+            .is_some_and(|classes| classes.split_whitespace().any(|name| name == class))
+    })?;
+    let rows = html_elements(table, "tr")
+        .into_iter()
+        .map(|(_, row)| {
+            html_elements(row, "td")
+                .into_iter()
+                .map(|(tag, contents)| {
+                    let color = html_attribute(tag, "style").and_then(|style| {
+                        style.split(';').find_map(|declaration| {
+                            let (property, value) = declaration.split_once(':')?;
+                            (property.trim().eq_ignore_ascii_case("background-color"))
+                                .then(|| value.trim().to_ascii_lowercase())
+                        })
+                    });
+                    (html_text(contents), color)
+                })
+                .collect()
+        })
+        .collect();
+    Some(rows)
+}
+
+/// Parses a puzzle from a page of nonograms.org, saved as HTML.
+///
+/// The clues are read from the tables of the page: the column clues from the `nmtt` table, where
+/// each row holds one level of the clues stacked over the columns, and the row clues from the
+/// `nmtl` table. Cells without a number are padding. The colors of the clues come from the
+/// background of their cells, black if they have none, so the puzzle keeps its palette.
+///
+/// # Arguments
+///
+/// * `html` - The HTML of the page.
+///
+/// # Returns
+///
+/// The puzzle and its palette, or the first error found. The lines are numbered as in the plain
+/// text notation: the rows first and the columns after them.
+pub fn parse_nonograms_org(html: &str) -> Result<(NonogramPuzzle, NonogramPalette), ClueError> {
+    let (Some(top), Some(left)) = (html_clue_table(html, "nmtt"), html_clue_table(html, "nmtl"))
+    else {
+        return Err(ClueError::MissingLines);
+    };
+    let mut color_palette = vec![String::from("#ffffff")];
+    let mut segment = |line: usize, (text, color): &HtmlCell| {
+        if text.is_empty() {
+            return Ok(None);
+        }
+        let length = text.parse::<usize>().map_err(|_| ClueError::InvalidClue {
+            line,
+            clue: text.clone(),
+        })?;
+        let color = color.clone().unwrap_or_else(|| String::from("#000000"));
+        let index = match color_palette.iter().position(|known| *known == color) {
+            Some(index) => index,
+            None => {
+                color_palette.push(color);
+                color_palette.len() - 1
+            }
+        };
+        if index >= MAX_CLUE_COLORS {
+            return Err(ClueError::InvalidClue {
+                line,
+                clue: text.clone(),
+            });
+        }
+        Ok(Some(NonogramSegment {
+            color: index,
+            length,
+        }))
+    };
+    let mut rows = Vec::new();
+    for (index, cells) in left.iter().enumerate() {
+        let mut segments = Vec::new();
+        for cell in cells {
+            segments.extend(segment(index + 1, cell)?);
+        }
+        rows.push((index + 1, segments));
+    }
+    let width = top.iter().map(Vec::len).max().unwrap_or(0);
+    let mut cols = Vec::new();
+    for col in 0..width {
+        let line = rows.len() + col + 1;
+        let mut segments = Vec::new();
+        for cell in top.iter().filter_map(|level| level.get(col)) {
+            segments.extend(segment(line, cell)?);
+        }
+        cols.push((line, segments));
+    }
+    let puzzle = build_puzzle(rows, cols)?;
+    Ok((
+        puzzle,
+        NonogramPalette {
+            color_palette,
+            brush: 1,
+        },
+    ))
+}
+
+/// Creates a palette for a puzzle parsed from clues, which carry no colors.
+///
+/// The colors of the default palette are used first, followed by gray shades.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle parsed from the clues.
+///
+/// # Returns
+///
+/// A palette with a color for the background and for each color index of the clues.
+pub fn clue_palette(puzzle: &NonogramPuzzle) -> NonogramPalette {
+    let colors = puzzle
+        .row_constraints
+        .iter()
+        .flatten()
+        .map(|segment| segment.color + 1)
+        .max()
+        .unwrap_or(0)
+        .max(2);
+    let default_palette = DEFAULT_PALETTE;
+    let color_palette = (0..colors)
+        .map(|index| match default_palette.color_palette.get(index) {
+            Some(color) => color.clone(),
+            None => {
+                let level = 32 + (index * 37) % 192;
+                format!("#{level:02x}{level:02x}{level:02x}")
+            }
+        })
+        .collect();
+    NonogramPalette {
+        color_palette,
+        brush: 1,
+    }
+}
+
+/// Formats the segments of a line, marking their colors in multicolor puzzles.
+fn format_line(segments: &[NonogramSegment], multicolor: bool) -> String {
+    if segments.is_empty() {
+        return String::from("0");
+    }
+    segments
+        .iter()
+        .map(|segment| {
+            if multicolor {
+                format!("{}:{}", segment.length, segment.color)
+            } else {
+                segment.length.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Formats the clues of a puzzle as plain text or Markdown.
+///
+/// The clues of multicolor puzzles are written as `length:color`, with a legend of the colors
+/// of the palette. The plain text can be imported back with `parse_clues`.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle whose clues are exported.
+/// * `palette` - The palette of the puzzle, for the legend of the colors.
+/// * `format` - The format of the text.
+///
+/// # Returns
+///
+/// The clues of the rows followed by the clues of the columns.
+pub fn format_clues(
+    puzzle: &NonogramPuzzle,
+    palette: &NonogramPalette,
+    format: ClueFormat,
+) -> String {
+    let lines = [&puzzle.row_constraints, &puzzle.col_constraints];
+    let multicolor = lines
+        .iter()
+        .flat_map(|lines| lines.iter().flatten())
+        .any(|segment| segment.color != 1);
+    let colors = lines
+        .iter()
+        .flat_map(|lines| lines.iter().flatten())
+        .map(|segment| segment.color)
+        .collect::<BTreeSet<_>>();
+    let color = |index: usize| {
+        palette
+            .color_palette
+            .get(index)
+            .cloned()
+            .unwrap_or_default()
+    };
+    let mut text = String::new();
+    match format {
+        ClueFormat::PlainText => {
+            if multicolor {
+                for &index in &colors {
+                    text += &format!("# {index}: {}\n", color(index));
+                }
+                text += "\n";
+            }
+            for (header, lines) in [("Rows:", lines[0]), ("Columns:", lines[1])] {
+                text += header;
+                text += "\n";
+                for segments in lines {
+                    text += &format_line(segments, multicolor);
+                    text += "\n";
+                }
+                text += "\n";
+            }
+            text.pop();
+        }
+        ClueFormat::Markdown => {
+            text += &format!("{} × {}\n\n", puzzle.cols, puzzle.rows);
+            if multicolor {
+                text += "| Color | Hex |\n|---:|---|\n";
+                for &index in &colors {
+                    text += &format!("| {index} | `{}` |\n", color(index));
+                }
+                text += "\n";
+            }
+            for (header, lines) in [("Row", lines[0]), ("Column", lines[1])] {
+                text += &format!("| {header} | Clues |\n|---:|---|\n");
+                for (index, segments) in lines.iter().enumerate() {
+                    text += &format!(
+                        "| {} | {} |\n",
+                        index + 1,
+                        format_line(segments, multicolor)
+                    );
+                }
+                text += "\n";
+            }
+            text.pop();
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::puzzles::{tree_nonogram_palette, tree_nonogram_puzzle};
+
+    use super::*;
+
+    // Test that editing the clues of a line validates that they fit.
+    #[test]
+    fn edit_line_clues() {
+        let mut puzzle = tree_nonogram_puzzle();
+        let segment = |length, color| NonogramSegment { length, color };
+        assert_eq!(
+            set_line_clues(
+                &mut puzzle,
+                ClueLine::Row(0),
+                vec![segment(2, 1), segment(2, 1)]
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            puzzle.row_constraints[0],
+            vec![segment(2, 1), segment(2, 1)]
+        );
+        assert_eq!(
+            set_line_clues(
+                &mut puzzle,
+                ClueLine::Column(1),
+                vec![segment(3, 1), segment(3, 1)]
+            ),
+            Err(ClueError::LineTooLong { line: 2 })
+        );
+        assert_eq!(
+            set_line_clues(&mut puzzle, ClueLine::Column(1), vec![segment(1, 0)]),
+            Err(ClueError::InvalidClue {
+                line: 2,
+                clue: String::from("1:0")
+            })
+        );
+        assert_eq!(
+            puzzle.col_constraints[1],
+            tree_nonogram_puzzle().col_constraints[1]
+        );
+    }
+
+    // Test parsing the tree puzzle with headers and without them.
+    #[test]
+    fn parse_tree_clues() {
+        let with_headers = "Rows:\n3\n5\n2, 1:2, 2\n1:2\n1:2\n\nColumns:\n2\n3\n2 3:2\n3\n2\n";
+        assert_eq!(parse_clues(with_headers), Ok(tree_nonogram_puzzle()));
+
+        let blocks = "# tree\n3\n5\n2 1:2 2\n1:2\n1:2\n\n2\n3\n2 3:2\n3\n2";
+        let puzzle = parse_clues(blocks).unwrap();
+        assert_eq!(puzzle, tree_nonogram_puzzle());
+        assert_eq!(clue_palette(&puzzle).color_palette.len(), 3);
+
+        let empty = parse_clues("rows\n1\n0\ncols\n1\n-").unwrap();
+        assert!(empty.row_constraints[1].is_empty());
+        assert!(empty.col_constraints[1].is_empty());
+    }
+
+    // Test exporting the clues of the tree puzzle, and importing them back.
+    #[test]
+    fn export_tree_clues() {
+        let (puzzle, palette) = (tree_nonogram_puzzle(), tree_nonogram_palette());
+        let text = format_clues(&puzzle, &palette, ClueFormat::PlainText);
+        assert!(text.starts_with("# 1: #"));
+        assert!(text.contains("2:1 1:2 2:1\n"));
+        assert_eq!(parse_clues(&text), Ok(puzzle.clone()));
+
+        let markdown = format_clues(&puzzle, &palette, ClueFormat::Markdown);
+        assert!(markdown.starts_with("5 × 5\n"));
+        assert!(markdown.contains("| 3 | 2:1 1:2 2:1 |\n"));
+        assert!(markdown.contains("| Column | Clues |\n|---:|---|\n| 1 | 2:1 |"));
+    }
+
+    // Test importing the tree puzzle from the clue tables of a nonograms.org page.
+    #[test]
+    fn parse_nonograms_org_tables() {
+        let cell = |clue: &str, color: &str| {
+            match clue {
+            "" => String::from("<td class=\"num_empty\"></td>"),
+            clue => format!(
+                "<td class=\"num\" style=\"background-color:{color};color:#fff\"><div>{clue}</div></td>"
+            ),
+        }
+        };
+        let (g, r) = ("#008000", "#8B4513");
+        let table = |lines: &[&[(&str, &str)]]| {
+            lines
+                .iter()
+                .map(|line| {
+                    let cells: String = line
+                        .iter()
+                        .map(|&(clue, color)| cell(clue, color))
+                        .collect();
+                    format!("<tr>{cells}</tr>")
+                })
+                .collect::<String>()
+        };
+        let top = table(&[
+            &[("", ""), ("", ""), ("2", g), ("", ""), ("", "")],
+            &[("2", g), ("3", g), ("3", r), ("3", g), ("2", g)],
+        ]);
+        let left = table(&[
+            &[("", ""), ("", ""), ("3", g)],
+            &[("", ""), ("", ""), ("5", g)],
+            &[("2", g), ("1", r), ("2", g)],
+            &[("", ""), ("", ""), ("1", r)],
+            &[("", ""), ("", ""), ("1", r)],
+        ]);
+        let html = format!(
+            "<html><body><table class=\"nonogram_table\"><tr><td>\
+             <TABLE class='nmtt'>{top}</TABLE><table class=\"nmtl\">{left}</table>\
+             </td></tr></table></body></html>"
+        );
+        let (puzzle, palette) = parse_nonograms_org(&html).unwrap();
+        assert_eq!(puzzle, tree_nonogram_puzzle());
+        assert_eq!(palette.color_palette, ["#ffffff", "#008000", "#8b4513"]);
+
+        assert_eq!(
+            parse_nonograms_org("<table class=\"nmtt\"></table>").map(|(puzzle, _)| puzzle),
+            Err(ClueError::MissingLines)
+        );
+    }
+
+    // Test the errors of invalid clue texts.
+    #[test]
+    fn reject_invalid_clues() {
+        assert_eq!(parse_clues("1 1\n"), Err(ClueError::MissingLines));
+        assert_eq!(
+            parse_clues("1\nx\n\n1\n1"),
+            Err(ClueError::InvalidClue {
+                line: 2,
+                clue: String::from("x")
+            })
+        );
+        assert_eq!(
+            parse_clues("1 1\n1\n\n1\n1"),
+            Err(ClueError::LineTooLong { line: 1 })
+        );
+        assert_eq!(
+            parse_clues("2\n1\n\n1\n1"),
+            Err(ClueError::ColorMismatch { color: 1 })
+        );
+        let segment = |color| NonogramSegment { color, length: 1 };
+        assert_eq!(
+            build_puzzle(
+                vec![(1, vec![segment(MAX_CLUE_COLORS)])],
+                vec![(3, vec![segment(MAX_CLUE_COLORS)])]
+            ),
+            Err(ClueError::InvalidClue {
+                line: 1,
+                clue: format!("1:{MAX_CLUE_COLORS}")
+            })
+        );
+        assert_eq!(
+            build_puzzle(vec![(1, vec![segment(1)])], vec![(3, vec![segment(0)])]),
+            Err(ClueError::InvalidClue {
+                line: 3,
+                clue: String::from("1:0")
+            })
+        );
+    }
+}
@@ -0,0 +1,444 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the definitions of the solution grid modified by the commands.
+use super::definitions::{Area, NonogramSolution, ResizeMode};
+
+// Import the cleanup filters applied by the commands.
+use super::filters::Filter;
+
+// Import the action journal where the result of each command is recorded.
+use super::journal::{Action, ActionLog, Tool};
+
+// Import serialization support so commands can be stored (e.g. in macros) or sent to other instances.
+use serde::{Deserialize, Serialize};
+
+/// A command modifying the solution grid.
+///
+/// Components don't modify the solution grid directly: they build a command describing the
+/// interaction and dispatch it, so every modification goes through the same reducer and
+/// is recorded in the action log.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum GridCommand {
+    /// Paints a single cell with a color.
+    Paint {
+        /// The row of the cell.
+        row: usize,
+        /// The column of the cell.
+        col: usize,
+        /// The color index used to paint.
+        color: usize,
+    },
+    /// Draws a horizontal or vertical line with a color (see `NonogramSolution::draw_line`).
+    DrawLine {
+        /// The starting coordinate `(row, column)` of the line.
+        start: (usize, usize),
+        /// The ending coordinate `(row, column)` of the line.
+        end: (usize, usize),
+        /// The color index used to draw.
+        color: usize,
+    },
+    /// Clears the whole grid with the background color.
+    Clear,
+    /// Crops the grid to an area.
+    Crop {
+        /// The area of the grid to keep.
+        area: Area,
+    },
+    /// Slides the whole grid horizontally and vertically.
+    Slide {
+        /// The amount to shift horizontally.
+        dx: isize,
+        /// The amount to shift vertically.
+        dy: isize,
+    },
+    /// Resizes the grid, cropping or padding the drawing, or rescaling it.
+    Resize {
+        /// The target number of rows.
+        rows: usize,
+        /// The target number of columns.
+        cols: usize,
+        /// How the drawing is adapted to the new dimensions.
+        #[serde(default)]
+        mode: ResizeMode,
+    },
+    /// Cleans the grid with a filter.
+    Filter {
+        /// The filter to apply.
+        filter: Filter,
+    },
+    /// Remaps the colors of the grid (see `NonogramSolution::remap`).
+    Remap {
+        /// The new color of each color index.
+        mapping: Vec<usize>,
+        /// The area of the grid to remap, or `None` to remap the whole grid.
+        area: Option<Area>,
+    },
+    /// Replaces the whole grid, like when loading a file or writing a solver result.
+    Replace {
+        /// The tool responsible for the replacement.
+        tool: Tool,
+        /// The new solution grid.
+        solution: NonogramSolution,
+    },
+}
+
+impl GridCommand {
+    /// Builds a command replacing a color with another one.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The color index to replace.
+    /// * `to` - The color index replacing it.
+    /// * `area` - The area of the grid to modify, or `None` to modify the whole grid.
+    pub fn replace_color(from: usize, to: usize, area: Option<Area>) -> Self {
+        let mut mapping: Vec<usize> = (0..=from).collect();
+        mapping[from] = to;
+        GridCommand::Remap { mapping, area }
+    }
+
+    /// Returns the tool responsible for the command, used to tag it in the action log.
+    pub fn tool(&self) -> Tool {
+        match self {
+            GridCommand::Paint { .. } => Tool::Brush,
+            GridCommand::DrawLine { .. } => Tool::Line,
+            GridCommand::Clear => Tool::Clear,
+            GridCommand::Crop { .. } => Tool::Resize,
+            GridCommand::Slide { .. } => Tool::Slide,
+            GridCommand::Resize { .. } => Tool::Resize,
+            GridCommand::Filter { .. } => Tool::Filter,
+            GridCommand::Remap { .. } => Tool::Remap,
+            GridCommand::Replace { tool, .. } => *tool,
+        }
+    }
+
+    /// Reduces the command on a solution grid, applying its modification.
+    ///
+    /// Commands referencing cells outside of the grid are ignored, so commands recorded on a
+    /// grid can be safely replayed on another one with different dimensions.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the command could be applied to the grid, `false` if it was ignored.
+    pub fn reduce(&self, solution: &mut NonogramSolution) -> bool {
+        let contains = |(row, col): (usize, usize)| row < solution.rows() && col < solution.cols();
+        match self {
+            GridCommand::Paint { row, col, color } => {
+                if !contains((*row, *col)) {
+                    return false;
+                }
+                solution.solution_grid[*row][*col] = *color;
+            }
+            GridCommand::DrawLine { start, end, color } => {
+                if !contains(*start) || !contains(*end) {
+                    return false;
+                }
+                solution.draw_line(*start, *end, *color);
+            }
+            GridCommand::Clear => solution.clear(),
+            GridCommand::Crop { area } => {
+                if !contains(area.start) || !contains(area.end) {
+                    return false;
+                }
+                solution.crop(*area);
+            }
+            GridCommand::Slide { dx, dy } => solution.slide(*dx, *dy),
+            GridCommand::Resize { rows, cols, mode } => solution.resize(*rows, *cols, *mode),
+            GridCommand::Filter { filter } => *solution = filter.apply(solution),
+            GridCommand::Remap { mapping, area } => solution.remap(mapping, *area),
+            GridCommand::Replace { solution: grid, .. } => *solution = grid.clone(),
+        }
+        true
+    }
+
+    /// Dispatches the command: reduces it on the solution grid and records the resulting
+    /// modification in the action log.
+    ///
+    /// # Returns
+    ///
+    /// The index of the recorded entry, or `None` if the command didn't modify the grid.
+    pub fn dispatch(&self, solution: &mut NonogramSolution, log: &mut ActionLog) -> Option<usize> {
+        let previous = solution.clone();
+        if !self.reduce(solution) {
+            return None;
+        }
+        Action::edit(self.tool(), &previous, solution).map(|action| log.record(action))
+    }
+}
+
+/// A named sequence of commands that can be replayed on other grids.
+///
+/// Macros are recorded from the editor interactions (see `MacroRecorder`) and stored in the
+/// settings, e.g. a macro drawing a border with a color can be replayed on every new puzzle.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Macro {
+    /// The name of the macro shown to the user.
+    pub name: String,
+    /// The recorded commands, in the order they were dispatched.
+    pub commands: Vec<GridCommand>,
+}
+
+impl Macro {
+    /// Replays the macro on a solution grid, dispatching each of its commands.
+    ///
+    /// Commands that don't fit in the grid (e.g. painting outside of a smaller grid) are skipped.
+    ///
+    /// # Returns
+    ///
+    /// The number of commands that modified the grid.
+    pub fn replay(&self, solution: &mut NonogramSolution, log: &mut ActionLog) -> usize {
+        self.commands
+            .iter()
+            .filter_map(|command| command.dispatch(solution, log))
+            .count()
+    }
+}
+
+/// Records the dispatched commands into a macro.
+#[derive(Clone, Default, Debug)]
+pub struct MacroRecorder {
+    /// The commands recorded so far, `None` when the recorder is stopped.
+    recording: Option<Vec<GridCommand>>,
+}
+
+impl MacroRecorder {
+    /// Returns `true` if the recorder is currently recording commands.
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Starts a new recording, discarding any unfinished one.
+    pub fn start(&mut self) {
+        self.recording = Some(Vec::new());
+    }
+
+    /// Records a command if the recorder is currently recording.
+    pub fn record(&mut self, command: &GridCommand) {
+        if let Some(commands) = &mut self.recording {
+            commands.push(command.clone());
+        }
+    }
+
+    /// Stops the recording and names the recorded macro.
+    ///
+    /// # Returns
+    ///
+    /// The recorded macro, or `None` if the recorder wasn't recording or no command was recorded.
+    pub fn stop(&mut self, name: String) -> Option<Macro> {
+        self.recording
+            .take()
+            .filter(|commands| !commands.is_empty())
+            .map(|commands| Macro { name, commands })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::puzzles::{tree_empty_nonogram_solution, tree_nonogram_file, LEAVES};
+
+    use super::*;
+
+    // Test that dispatching commands records only the ones modifying the grid, and that the
+    // recorded actions can be reverted to the initial grid.
+    #[test]
+    fn dispatch_records_modifications() {
+        let mut solution = tree_empty_nonogram_solution();
+        let mut log = ActionLog::default();
+        let commands = [
+            GridCommand::DrawLine {
+                start: (1, 0),
+                end: (1, 4),
+                color: 1,
+            },
+            GridCommand::Paint {
+                row: 1,
+                col: 2,
+                color: 1,
+            },
+            GridCommand::Slide { dx: 0, dy: 1 },
+            GridCommand::Resize {
+                rows: 6,
+                cols: 5,
+                mode: ResizeMode::Crop,
+            },
+        ];
+        let recorded: Vec<_> = commands
+            .iter()
+            .map(|command| command.dispatch(&mut solution, &mut log))
+            .collect();
+        assert_eq!(recorded, vec![Some(0), None, Some(1), Some(2)]);
+        assert_eq!(solution.rows(), 6);
+        assert_eq!(solution.solution_grid[2], vec![1; 5]);
+
+        for entry in log.entries().iter().rev() {
+            entry.action.revert(&mut solution);
+        }
+        assert_eq!(solution, tree_empty_nonogram_solution());
+    }
+
+    // Test that commands referencing cells outside of the grid are ignored.
+    #[test]
+    fn out_of_bounds_commands_are_ignored() {
+        let mut solution = tree_nonogram_file().solution;
+        let mut log = ActionLog::default();
+        let paint = GridCommand::Paint {
+            row: 5,
+            col: 0,
+            color: 2,
+        };
+        let line = GridCommand::DrawLine {
+            start: (0, 0),
+            end: (0, 9),
+            color: 2,
+        };
+        assert_eq!(paint.dispatch(&mut solution, &mut log), None);
+        assert_eq!(line.dispatch(&mut solution, &mut log), None);
+        assert_eq!(solution, tree_nonogram_file().solution);
+        assert!(log.is_empty());
+    }
+
+    // Test that the drawing can be rescaled instead of cropped when resizing the grid.
+    #[test]
+    fn resize_rescales_drawing() {
+        let sketch = NonogramSolution {
+            solution_grid: vec![vec![1, 0], vec![0, 2]],
+        };
+        let mut solution = sketch;
+        let mut log = ActionLog::default();
+        let upscale = GridCommand::Resize {
+            rows: 4,
+            cols: 4,
+            mode: ResizeMode::Nearest,
+        };
+        upscale.dispatch(&mut solution, &mut log);
+        assert_eq!(
+            solution.solution_grid,
+            vec![
+                vec![1, 1, 0, 0],
+                vec![1, 1, 0, 0],
+                vec![0, 0, 2, 2],
+                vec![0, 0, 2, 2],
+            ]
+        );
+
+        solution.solution_grid[0][3] = 1;
+        solution.solution_grid[1][2] = 1;
+        solution.solution_grid[1][3] = 1;
+        let downscale = GridCommand::Resize {
+            rows: 2,
+            cols: 2,
+            mode: ResizeMode::Majority,
+        };
+        downscale.dispatch(&mut solution, &mut log);
+        assert_eq!(solution.solution_grid, vec![vec![1, 1], vec![0, 2]]);
+        assert_eq!(log.len(), 2);
+    }
+
+    // Test that colors can be remapped in the whole grid or inside an area.
+    #[test]
+    fn remap_colors() {
+        let mut solution = tree_nonogram_file().solution;
+        let counts = solution.color_counts(8);
+        let mut log = ActionLog::default();
+        let area = Area {
+            start: (4, 4),
+            end: (0, 3),
+        };
+        let inside = GridCommand::replace_color(LEAVES, 3, Some(area));
+        inside.dispatch(&mut solution, &mut log);
+        assert!(solution
+            .solution_grid
+            .iter()
+            .all(|row| !row[3..].contains(&LEAVES)));
+        assert_eq!(solution.solution_grid[1][..3], [LEAVES; 3]);
+        let whole = GridCommand::Remap {
+            mapping: vec![0, 3],
+            area: None,
+        };
+        whole.dispatch(&mut solution, &mut log);
+        let remapped = solution.color_counts(8);
+        assert_eq!(remapped[1], 0);
+        assert_eq!(remapped[3], counts[1] + counts[3]);
+        assert_eq!(remapped.iter().sum::<usize>(), counts.iter().sum::<usize>());
+    }
+
+    // Test that the palette reduction merges the perceptually closest colors.
+    #[test]
+    fn reduce_palette_colors() {
+        let mut file = tree_nonogram_file();
+        file.palette.color_palette = ["#b7e1f9", "#2b711f", "#8b4513", "#2e7522", "#8a4412"]
+            .map(String::from)
+            .to_vec();
+        file.solution.solution_grid[0][0] = 3;
+        file.solution.solution_grid[4][4] = 4;
+        let counts = file.solution.color_counts(file.palette.len());
+        assert_eq!(
+            file.palette.reduction_mapping(&counts, 5),
+            vec![0, 1, 2, 3, 4]
+        );
+        let mapping = file.palette.reduction_mapping(&counts, 3);
+        assert_eq!(mapping, vec![0, 1, 2, 1, 2]);
+
+        let mut log = ActionLog::default();
+        let reduce = GridCommand::Remap {
+            mapping,
+            area: None,
+        };
+        reduce.dispatch(&mut file.solution, &mut log);
+        let reduced = file.solution.color_counts(file.palette.len());
+        assert_eq!(reduced.iter().filter(|&&count| count > 0).count(), 3);
+    }
+
+    // Test that a recorded macro can be replayed on a grid with different dimensions.
+    #[test]
+    fn recorded_macro_replays_on_other_grids() {
+        let mut recorder = MacroRecorder::default();
+        recorder.record(&GridCommand::Clear);
+        assert!(recorder.stop(String::from("empty")).is_none());
+
+        recorder.start();
+        let commands = [
+            GridCommand::DrawLine {
+                start: (0, 0),
+                end: (0, 4),
+                color: 2,
+            },
+            GridCommand::Paint {
+                row: 3,
+                col: 9,
+                color: 2,
+            },
+        ];
+        for command in &commands {
+            recorder.record(command);
+        }
+        let border = recorder.stop(String::from("border")).unwrap();
+        assert!(!recorder.is_recording());
+        assert_eq!(border.commands, commands);
+
+        let mut solution = tree_empty_nonogram_solution();
+        let mut log = ActionLog::default();
+        assert_eq!(border.replay(&mut solution, &mut log), 1);
+        assert_eq!(solution.solution_grid[0], vec![2; 5]);
+        assert_eq!(log.len(), 1);
+    }
+}
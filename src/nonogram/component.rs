@@ -20,17 +20,137 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+// Import the zip archives the library is exported to.
+use super::archive::Archive;
+
+// Import the cache of the assets fetched at runtime, such as the locales of the languages not in use.
+use super::assets::AssetCache;
+
+// Import the annotation layer drawn over the grid, for explaining solving techniques.
+use super::annotation::{annotated_svg, annotations_svg, Annotation, AnnotationTool, Annotations};
+
 // Import necessary definitions for working with Nonogram puzzles and solutions.
-use super::definitions::{NonogramFile, NonogramPuzzle, NonogramSolution, DEFAULT_PALETTE};
+use super::definitions::{
+    Area, AuthorNotes, CellDiff, CheckMode, ClueSelection, FullscreenMode, Heatmap, Layers,
+    NewPuzzleDialog, NonogramFile, NonogramPaletteFile, NonogramPuzzle, NonogramSegment,
+    NonogramSolution, PaletteLoadMode, PaletteSelection, PendingResize, PinnedCells,
+    PopulationOverlay, ReferenceImage, ResizeMode, Signature, SolutionDiff, SolverSeed,
+    StrictUnlock, BACKGROUND, DEFAULT_PALETTE,
+};
 
 // Import the `History` structure from the `evolutive` module for tracking evolution-related data.
 use super::evolutive::History;
 
+// Import the weekly challenges, generated from a published seed.
+use super::challenge::{SolveStats, Verification, WeeklyChallenge};
+
+// Import the generator of the seeded puzzles of the benchmarks.
+use super::generator::seeded_puzzle;
+
+// Import the parser of the plain-text clue notation, for importing puzzles without a solution.
+use super::clues::{
+    clue_palette, format_clues, parse_clues, parse_nonograms_org, set_line_clues, ClueError,
+    ClueFormat, ClueLine, MAX_CLUE_COLORS,
+};
+
+// Import the commands modifying the solution grid, dispatched by the interactive components.
+use super::command::{GridCommand, MacroRecorder};
+
+// Import the feedback events, played as sound effects and vibrations.
+use super::feedback::Feedback;
+
+// Import the cleanup filters removing noise from the solution grid.
+use super::filters::Filter;
+
+// Import the action journal where every modification of the solution grid is recorded.
+use super::journal::{Action, ActionLog, Timeline, Tool};
+
+// Import the local library of the solutions found for the puzzles imported from their clues.
+use super::library::{SolutionLibrary, SILHOUETTE_SIZE};
+
+// Import the markdown-lite notes about the puzzles, rendered in the notes drawers.
+use super::notes::{parse_notes, NoteBlock, NoteSpan};
+
+// Import the regions and palettes exchanged through the clipboard.
+use super::clipboard::{ClipboardContent, Region};
+
+// Import the native access to the system clipboard, on desktop platforms.
+#[cfg(feature = "clipboard")]
+use super::clipboard::{read_system, write_system};
+
+// Import the conversion of photos into solution grids
+use super::import::{ImageImport, IMPORT_SAMPLES};
+
+// Import the thumbnails previewing the grids, updated incrementally while they're edited
+use super::thumbnail::Thumbnail;
+
+// Import the logic solver, used to solve puzzles deterministically and to find the cells that
+// can't be deduced without guessing.
+use super::logic::{
+    crossed_cells, hint, puzzle_slack, search_depths, solve_logic, trivial_cells, LineSlack,
+    Tightness,
+};
+
+// Import the PNG pictures embedding the Nonogram file, exported and imported by the editor.
+use super::picture::{embed_metadata, encode_png, read_metadata};
+
+// Import the printable sheets of the puzzle.
+use super::print::{print_sheet, PrintOptions};
+
+// Import the names of the Nonogram files, and the file the application was launched with.
+use super::filetype::{
+    has_extension, is_ngram, ngram_filename, ngram_stem, strip_extension, LaunchFile,
+};
+
+// Import the pointer gestures on the solution grid.
+use super::pointer::GridPointer;
+
+// Import the local profiles, chosen at startup.
+use super::profile::{ActiveProfile, Profile, Profiles};
+
+// Import the random number generator service of the session.
+use super::rng::SessionRng;
+
+// Import the snapshots of the page state, exposed to automated UI tests.
+#[cfg(feature = "debug-state")]
+use super::snapshot::AppSnapshot;
+
+// Import the history of the puzzles touched during the session, listed in the header.
+use super::session::{SessionHistory, SessionPage, SessionPuzzle};
+
+// Import the database of solver runs, recorded on desktop platforms.
+#[cfg(feature = "telemetry")]
+use super::telemetry::{ResultsMatrix, RunFilter, RunStore, SolverRun};
+
+// Import the timer measuring the time spent actively solving a puzzle.
+use super::timer::{PauseReason, SolveTimer};
+
+// Import the conventions of the language of the interface for displaying numbers, durations and
+// dates.
+use super::locale::LocaleFormat;
+
+// Import the user settings, where the recorded macros are stored.
+use super::settings::{
+    Accessibility, AssistLevel, Autosave, ClueAlignment, Completion, DuplicateLoad, PowerMode,
+    Settings, TapAction, Theme, ThemeError,
+};
+
 // Import specific definitions from the Nonogram module to manage Nonogram data and palettes.
 use crate::nonogram::definitions::{NonogramData, NonogramPalette};
 
 // Import functions from the Nonogram evolutive module for solving puzzles and statistical analysis.
-use crate::nonogram::evolutive::{anova, solve_nonogram};
+use crate::nonogram::evolutive::{anova, CancelToken, Progress, SearchEvent, WarmStart};
+
+// Import the solver run in a background thread, on platforms with threads.
+#[cfg(not(feature = "web"))]
+use crate::nonogram::evolutive::solve_nonogram_sampled;
+
+// Import the search advanced a few generations at a time, on platforms without threads.
+#[cfg(feature = "web")]
+use crate::nonogram::evolutive::EvolutiveSearch;
+
+// Import the parameters of the solver, tuned in the toolbar and recorded with each run.
+use crate::nonogram::evolutive::{Adaptation, Replacement, SolverConfig};
 
 // Import predefined puzzles from the Nonogram puzzles module for creating or managing puzzles.
 use crate::nonogram::puzzles::*;
@@ -41,6 +161,18 @@ use dioxus::{
     prelude::*,
 };
 
+// Import the file engine of the file inputs, to read the loaded files.
+use dioxus::html::FileEngine;
+
+// Import the shared pointer holding the file engine.
+use std::sync::Arc;
+
+// Import the mounted element data and its bounds, to locate the pointer over the annotation layer.
+use dioxus::html::{geometry::PixelsRect, MountedData};
+
+// Import the shared pointer holding the mounted element.
+use std::rc::Rc;
+
 // Import mouse button data from Dioxus elements to handle input events.
 use dioxus_elements::input_data::MouseButton;
 
@@ -53,10 +185,10 @@ use dioxus_free_icons::icons::fa_solid_icons::{
 use dioxus_free_icons::Icon;
 
 // Import internationalization support from `dioxus_i18n` to handle translations within the UI.
-use dioxus_i18n::t;
+use dioxus_i18n::{prelude::i18n, t};
 
 // Import random number generation utilities from the `rand` crate to provide randomness in solving Nonograms.
-use rand::{rngs::StdRng, Rng, SeedableRng};
+use rand::Rng;
 
 /// The main component for the Nonogram Solver page.
 ///
@@ -70,8 +202,15 @@ use rand::{rngs::StdRng, Rng, SeedableRng};
 /// - `tree_empty_nonogram_solution()`: Initializes an empty Nonogram solution grid.
 /// - `tree_nonogram_file()`: Initializes a preview Nonogram file.
 /// - `tree_nonogram_puzzle().score(&tree_nonogram_file().solution)`: Sets up the Nonogram score state.
-/// - `History::new(&tree_nonogram_puzzle(), &mut StdRng::from_entropy())`: Initializes Nonogram history with a random number generator.
+/// - `History::new(&tree_nonogram_puzzle(), &mut rng)`: Initializes Nonogram history with a generator drawn from the `SessionRng`.
+/// - `ActionLog`: Records every modification of the solution grid and solver run.
+/// - `Annotations`: The marks drawn over the grid, independent from the puzzle.
+/// - `ClueSelection`: The clue edited inline, in puzzles imported from their clues.
 /// - `NonogramData`: Stores Nonogram editor data such as filename and block size.
+/// - `SolutionLibrary`: The solutions cached for the puzzles imported from their clues.
+/// - `Option<Progress>`: The progress of the running solver.
+/// - `SolverConfig`: The parameters of the genetic algorithm, tuned in the toolbar.
+/// - `SolverSeed`: The seed of the genetic algorithm, chosen in the toolbar.
 ///
 /// # UI Rendering:
 /// - The component returns a structured layout with various UI elements including a toolbar, nonogram display,
@@ -81,6 +220,7 @@ pub fn Solver() -> Element {
     std::panic::set_hook(Box::new(|info| {
         error!("Panic: {}", info);
     }));
+    let mut use_rng = use_context::<Signal<SessionRng>>();
     use_context_provider(|| {
         info!("Initializing nonogram puzzle");
         Signal::new(tree_nonogram_puzzle())
@@ -103,24 +243,102 @@ pub fn Solver() -> Element {
     });
     use_context_provider(|| {
         info!("Initializing nonogram history");
-        let mut rng = StdRng::from_entropy();
+        let mut rng = use_rng.write().next_rng();
         Signal::new(History::new(&tree_nonogram_puzzle(), &mut rng))
     });
+    use_context_provider(|| {
+        info!("Initializing nonogram action log");
+        Signal::new(ActionLog::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing heat overlay");
+        Signal::new(None::<Heatmap>)
+    });
+    use_context_provider(|| {
+        info!("Initializing population overlay");
+        Signal::new(PopulationOverlay::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing annotation layer");
+        Signal::new(Annotations::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing clue selection");
+        Signal::new(None::<ClueSelection>)
+    });
+    use_context_provider(|| {
+        info!("Initializing solver progress");
+        Signal::new(None::<Progress>)
+    });
+    use_context_provider(|| {
+        info!("Initializing solver parameters");
+        Signal::new(SolverConfig::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing solver seed");
+        Signal::new(SolverSeed::default())
+    });
     use_context_provider(|| {
         info!("Initializing nonogram editor state");
         Signal::new(NonogramData {
             filename: String::from("tree.ngram"),
             block_size: 30,
+            auto_block_size: true,
             completed: false,
+            blind: false,
+            solution_cached: false,
         })
     });
+    use_context_provider(|| {
+        info!("Initializing strict mode unlock");
+        Signal::new(StrictUnlock::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing pinned cells");
+        Signal::new(PinnedCells::default())
+    });
+
+    use_session_tracking(SessionPage::Solver);
+    #[cfg(feature = "debug-state")]
+    use_state_snapshot(SessionPage::Solver);
+    use_auto_block_size(SessionPage::Solver);
+    use_feedback(SessionPage::Solver);
+    use_solve_timer();
+    use_assists();
+    use_strict_mode();
+    use_pinned_cells();
+    use_solution_library();
+    let mut use_launch_error = use_launch_file();
+
+    let fullscreen = try_use_context::<Signal<FullscreenMode>>()
+        .is_some_and(|use_fullscreen| use_fullscreen().enabled);
 
     rsx! {
-        main { class: "flex flex-col gap-10 items-center min-h-screen mb-20",
-            h1 { class: "text-4xl font-bold my-10 text-center", {t!("title_nonogram_solver")} }
-            SolverToolbar {}
-            SolverNonogram {}
-            ConvergeGraphic {}
+        if fullscreen {
+            main { class: "flex flex-col items-center min-h-screen pt-20",
+                FullscreenToolbar {}
+                SolverNonogram {}
+            }
+        } else {
+            main { class: "flex flex-col gap-10 items-center min-h-screen mb-20",
+                h1 { class: "text-4xl font-bold my-10 text-center", {t!("title_nonogram_solver")} }
+                SolverToolbar {}
+                SolverNonogram {}
+                ConvergeGraphic {}
+            }
+        }
+        if let Some(message) = use_launch_error() {
+            div {
+                class: "fixed bottom-4 left-4 z-50 flex flex-col gap-3 p-4 max-w-sm rounded-lg border border-red-700 bg-gray-800 text-white shadow-lg",
+                role: "alert",
+                span { class: "font-semibold text-red-400", {t!("title_launch_file_error")} }
+                span { class: "text-sm break-words", "{message}" }
+                button {
+                    class: "self-end px-4 py-1 rounded border border-gray-500 bg-gray-700 text-white hover:bg-gray-600",
+                    onclick: move |_| *use_launch_error.write() = None,
+                    {t!("button_dismiss")}
+                }
+            }
         }
     }
 }
@@ -134,33 +352,109 @@ pub fn Solver() -> Element {
 /// - `RowsInput`: Read-only input for row configuration.
 /// - `ColumnsInput`: Read-only input for column configuration.
 /// - `BlockSizeInput`: Input for adjusting the size of blocks in the Nonogram.
+/// - `FullscreenButton`: Button to maximize the grid space.
 /// - `FileLoadInput`: Input for loading Nonogram puzzle files.
 /// - `SolveButton`: Button to solve the Nonogram puzzle.
+/// - `SolverSeedInput`: Input fixing the seed of the solver, or drawing a random one.
+/// - `SolverProgressBar`: Shows the generations evolved and the best score while the solver runs.
+/// - `SolverConfigPanel`: Tunes the parameters of the genetic algorithm.
+/// - `ParetoFrontPanel`: Shows the trade-off candidates of the last multi-objective search.
+/// - `LogicSolveButton`: Button to solve the Nonogram puzzle deterministically by logic.
+/// - `ExactSolveButton`: Button to solve the Nonogram puzzle by exhaustive search.
+/// - `PopulationOverlayToggle`: Shows the cells the population disagrees on while solving.
+/// - `LowPowerToggle`: Saves battery with fewer effects and live updates.
+/// - `AutosaveSelect`: Selects where the solutions found are saved automatically.
+/// - `CompletionSelect`: Selects whether completing means satisfying the clues or the exact image.
+/// - `DuplicateLoadSelect`: Selects whether loading the same file again keeps the progress.
+/// - `AssistSelect`: Selects the assist level: purist, casual or relaxed.
+/// - `StrictModeToggle`: Locks the solve buttons and hints, so the completions count as unassisted.
+/// - `StrictUnlockButton`: Unlocks them with a long press and a confirmation, in the strict mode.
+/// - `HintButton`: Button to paint a cell deduced by logic (relaxed assist level only).
 /// - `AnovaButton`: Button to perform Anova analysis on the puzzle.
 /// - `ClearSolutionButton`: Button to clear the current solution.
 /// - `SlideSolutionButtons`: Buttons to navigate through possible solutions.
+/// - `HistoryScrubber`: Slider scrubbing through the history of the solution grid.
 /// - `ColorPalette`: Displays the color palette used in the Nonogram.
+/// - `PuzzleInfoPanel`: Shows the size, the colors and the signature of the puzzle.
+/// - `NotesPanel`: Shows the notes of the author, and keeps the notes of the user.
+/// - `LibraryExportPanel`: Exports every puzzle of the library, and optionally the progress, as a zip.
+/// - `LibrarySearchPanel`: Finds puzzles of the library by sketching their shape.
+/// - `WeeklyChallengePanel`: Plays the puzzle of the week, and verifies the solves shared.
+/// - `ClueTextPanel`: Imports a puzzle from its clues, for solving it blind.
+/// - `AnnotationPanel`: Draws arrows, circles and labels over the grid, for explanations.
+/// - `PrintPanel`: Prints the puzzle, optionally saving ink.
+/// - `ReplayExportPanel`: Exports an animated GIF replaying the solve (desktop only).
+/// - `DiffPanel`: Compares the current solution against the puzzle's solution or another file.
+/// - `InteractionPanel`: Sets what a tap does, stylus-only drawing and pressure-chosen colors.
+/// - `AccessibilityPanel`: Sets the font scale, the reduced-motion mode and the clue alignment.
+/// - `ThemePanel`: Loads a custom theme replacing the colors of the interface.
+/// - `FeedbackPanel`: Sets the volume of the sound effects and the vibration.
+///
+/// # Context:
+/// - `Signal<Settings>`: Whether the strict mode locks the solve buttons and hints.
+/// - `Signal<StrictUnlock>`: Whether they were unlocked for the puzzle being solved.
 #[component]
 fn SolverToolbar() -> Element {
+    let use_settings = use_context::<Signal<Settings>>();
+    let use_unlock = use_context::<Signal<StrictUnlock>>();
+    let locked = use_settings().strict_mode && !use_unlock().unlocked;
     rsx! {
         section { class: "container flex flex-col space-y-6 p-6 rounded-lg shadow-lg bg-gray-900",
             div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6",
                 RowsInput { readonly: true }
                 ColumnsInput { readonly: true }
                 BlockSizeInput {}
+                FullscreenButton {}
             }
             div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6",
                 FileLoadInput {}
-                SolveButton {}
+                if !locked {
+                    SolveButton {}
+                }
+                SolverSeedInput {}
+                if !locked {
+                    LogicSolveButton {}
+                    ExactSolveButton {}
+                }
+                PopulationOverlayToggle {}
+                LowPowerToggle {}
+                AutosaveSelect {}
+                CompletionSelect {}
+                DuplicateLoadSelect {}
+                AssistSelect {}
+                StrictModeToggle {}
+                if locked {
+                    StrictUnlockButton {}
+                } else {
+                    HintButton {}
+                }
                 AnovaButton {}
             }
+            SolverProgressBar {}
+            SolverConfigPanel {}
+            ParetoFrontPanel {}
             div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6",
                 ClearSolutionButton {}
                 SlideSolutionButtons {}
+                HistoryScrubber {}
             }
             div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6",
                 ColorPalette { readonly: true }
             }
+            PuzzleInfoPanel {}
+            NotesPanel {}
+            LibraryExportPanel {}
+            LibrarySearchPanel {}
+            WeeklyChallengePanel {}
+            ClueTextPanel {}
+            AnnotationPanel {}
+            PrintPanel {}
+            ReplayExportPanel {}
+            DiffPanel {}
+            InteractionPanel {}
+            AccessibilityPanel {}
+            ThemePanel {}
+            FeedbackPanel {}
         }
     }
 }
@@ -174,25 +468,95 @@ fn SolverToolbar() -> Element {
 /// - `Signal<NonogramPuzzle>`: Provides the current state of the puzzle.
 /// - `Signal<NonogramSolution>`: Provides the current state of the solution.
 /// - `Signal<NonogramData>`: Manages Nonogram-related data including completion state.
+/// - `Signal<NonogramFile>`: The stored solution, matched by the exact image criterion.
+/// - `Signal<Settings>`: The completion criterion, and the assist level crossing out completed lines.
+/// - `Signal<SessionHistory>`: Where the puzzles with alternate solutions are flagged.
 ///
 /// # UI Elements:
 /// - `RowsConstraints`: Displays row constraints of the puzzle.
 /// - `ColumnsConstraints`: Displays column constraints of the puzzle.
 /// - `SolutionPreview`: Shows a preview of the solution.
 /// - `Solution`: Displays the solution grid.
+/// - `AnnotationOverlay`: Displays the marks drawn over the grid.
+/// - `SignatureRow`: Displays the visible signature of the author below the grid.
 #[component]
 fn SolverNonogram() -> Element {
     let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
     let use_solution = use_context::<Signal<NonogramSolution>>();
     let mut use_data = use_context::<Signal<NonogramData>>();
+    let use_file = use_context::<Signal<NonogramFile>>();
+    let use_settings = try_use_context::<Signal<Settings>>();
+    let criterion = move || {
+        use_settings
+            .map(|use_settings| use_settings().completion)
+            .unwrap_or_default()
+            .applied(use_data.peek().blind)
+    };
+    let use_session = try_use_context::<Signal<SessionHistory>>();
+    let mut use_compare = use_signal(|| false);
+    let auto_cross =
+        use_settings.is_some_and(|use_settings| use_settings().assist_level.assists().auto_cross);
     use_effect(move || {
-        let current_puzzle = NonogramPuzzle::from_solution(&use_solution());
-        use_data.write().completed = use_puzzle() == current_puzzle;
+        let completed = criterion().is_met(&use_puzzle(), &use_file().solution, &use_solution());
+        use_data.write().completed = completed;
+    });
+    // Completed by the clues with a grid different from the stored one.
+    let alternate = use_memo(move || {
+        use_data().completed
+            && (!use_data().blind || use_data().solution_cached)
+            && use_file().solution.solution_grid != use_solution().solution_grid
+    });
+    use_effect(move || {
+        if !alternate() {
+            use_compare.set(false);
+            return;
+        }
+        let filename = use_data.peek().filename.clone();
+        if let Some(mut use_session) = use_session {
+            if use_session.write().flag_ambiguous(&filename) {
+                info!("Alternate solution found for '{filename}'");
+            }
+        }
     });
     rsx! {
         section { class: "mb-20",
             if use_data().completed {
-                h2 { class: "text-6xl font-bold my-10 text-center", {t!("completed")} }
+                h2 { class: "text-6xl font-bold mt-10 text-center", {t!("completed")} }
+                p { class: "mb-10 text-center text-gray-300",
+                    match criterion() {
+                        Completion::Constraints => t!("completed_by_constraints"),
+                        Completion::ExactImage => t!("completed_by_image"),
+                    }
+                }
+                if alternate() {
+                    div { class: "flex flex-col items-center gap-3 mb-10",
+                        p { class: "text-xl font-semibold text-center text-amber-400",
+                            {t!("completed_alternate")}
+                        }
+                        button {
+                            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                            onclick: move |_| use_compare.set(!use_compare()),
+                            if use_compare() {
+                                {t!("button_hide_official")}
+                            } else {
+                                {t!("button_compare_official")}
+                            }
+                        }
+                        if use_compare() {
+                            div { class: "flex flex-row flex-wrap justify-center items-start gap-6",
+                                GridPreview { solution: use_file().solution }
+                                DiffGrid { diff: use_solution().diff(&use_file().solution) }
+                            }
+                        }
+                    }
+                }
+            }
+            SolveTimerDisplay {}
+            if !use_data().completed {
+                SolveProgress { criterion: criterion() }
+            }
+            if use_data().blind {
+                ClueEditForm {}
             }
             table { class: "border-separate border-spacing-4",
                 thead {
@@ -208,7 +572,18 @@ fn SolverNonogram() -> Element {
                         th { class: "flex justify-end",
                             RowsConstraints { puzzle: use_puzzle() }
                         }
-                        td { Solution {} }
+                        td {
+                            div { position: "relative",
+                                Solution { auto_cross }
+                                AnnotationOverlay {}
+                            }
+                        }
+                    }
+                    tr {
+                        th {}
+                        td {
+                            SignatureRow { signature: use_file().signature, cols: use_puzzle().cols }
+                        }
                     }
                 }
             }
@@ -227,6 +602,16 @@ fn SolverNonogram() -> Element {
 /// - `tree_nonogram_palette()`: Initializes the color palette for editing the Nonogram.
 /// - `tree_empty_nonogram_solution()`: Initializes an empty Nonogram solution for editing.
 /// - `tree_nonogram_puzzle()`: Sets up the Nonogram puzzle.
+/// - `ActionLog`: Records every modification of the solution grid.
+/// - `MacroRecorder`: Records the editor commands into macros.
+/// - `ResizeMode`: How the drawing is adapted when the grid is resized.
+/// - `PendingResize`: A resize deleting colored cells, held for confirmation.
+/// - `Layers`: The visibility of each color layer of the solution grid.
+/// - `ReferenceImage`: The reference image displayed beneath the solution grid.
+/// - `Annotations`: The marks drawn over the grid, independent from the puzzle.
+/// - `NewPuzzleDialog`: The dialog creating a new puzzle, opened on startup.
+/// - `Option<Signature>`: The signature of the author, saved in the file.
+/// - `AuthorNotes`: The notes of the author, saved in the file.
 /// - `NonogramData`: Manages the state of the Nonogram editor including filename, block size, and completion status.
 ///
 /// # UI Rendering:
@@ -258,20 +643,380 @@ pub fn Editor() -> Element {
         // Unused in Editor
         Signal::new(tree_nonogram_puzzle())
     });
+    use_context_provider(|| {
+        info!("Initializing nonogram action log");
+        Signal::new(ActionLog::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing macro recorder");
+        Signal::new(MacroRecorder::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing resize mode");
+        Signal::new(ResizeMode::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing resize confirmation");
+        Signal::new(None::<PendingResize>)
+    });
+    use_context_provider(|| {
+        info!("Initializing color layers");
+        Signal::new(Layers::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing reference image");
+        Signal::new(ReferenceImage::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing heat overlay");
+        Signal::new(None::<Heatmap>)
+    });
+    use_context_provider(|| {
+        info!("Initializing check mode");
+        Signal::new(CheckMode::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing palette selection");
+        Signal::new(PaletteSelection::default())
+    });
+    // The dialog isn't opened when a puzzle of the session is being reopened.
+    let reopening = try_use_context::<Signal<SessionHistory>>()
+        .is_some_and(|use_session| use_session.peek().has_jump(SessionPage::Editor));
+    use_context_provider(|| {
+        info!("Initializing new puzzle dialog");
+        Signal::new(NewPuzzleDialog { open: !reopening })
+    });
+    use_context_provider(|| {
+        info!("Initializing annotation layer");
+        Signal::new(Annotations::default())
+    });
+    use_context_provider(|| {
+        info!("Initializing author signature");
+        Signal::new(None::<Signature>)
+    });
+    use_context_provider(|| {
+        info!("Initializing author notes");
+        Signal::new(AuthorNotes::default())
+    });
     use_context_provider(|| {
         info!("Initializing nonogram editor state");
         Signal::new(NonogramData {
             filename: String::new(),
             block_size: 30,
+            auto_block_size: true,
             completed: false,
+            blind: false,
+            solution_cached: false,
         })
     });
 
+    use_session_tracking(SessionPage::Editor);
+    #[cfg(feature = "debug-state")]
+    use_state_snapshot(SessionPage::Editor);
+    use_auto_block_size(SessionPage::Editor);
+    use_feedback(SessionPage::Editor);
+
+    let fullscreen = try_use_context::<Signal<FullscreenMode>>()
+        .is_some_and(|use_fullscreen| use_fullscreen().enabled);
+
+    rsx! {
+        if fullscreen {
+            main { class: "flex flex-col items-center min-h-screen pt-20",
+                FullscreenToolbar {}
+                EditorNonogram {}
+            }
+        } else {
+            main { class: "flex flex-col gap-10 items-center min-h-screen mb-20",
+                h1 { class: "text-4xl font-bold my-10 text-center", {t!("title_nonogram_editor")} }
+                EditorToolbar {}
+                EditorNonogram {}
+            }
+        }
+        NewPuzzleForm {}
+    }
+}
+
+/// The page benchmarking the solver: it generates the seeded puzzles of the benchmarks, and browses
+/// the solver runs recorded on this device.
+///
+/// The runs can be filtered by puzzle and result, and deleted one by one or all the listed
+/// ones at once. The listed runs can also be exported as a matrix of the results of each solver
+/// configuration on each puzzle, in Markdown or JSON. Runs are only recorded on desktop platforms.
+///
+/// # Context:
+/// - `Signal<ActiveProfile>`: The profile whose runs are listed.
+/// - `Signal<Option<LaunchFile>>`: Replaced by the generated puzzles, opened in the solver.
+///
+/// # Example
+/// ```rust
+/// SolverRuns {}
+/// ```
+#[component]
+pub fn SolverRuns() -> Element {
     rsx! {
         main { class: "flex flex-col gap-10 items-center min-h-screen mb-20",
-            h1 { class: "text-4xl font-bold my-10 text-center", {t!("title_nonogram_editor")} }
-            EditorToolbar {}
-            EditorNonogram {}
+            h1 { class: "text-4xl font-bold my-10 text-center", {t!("title_solver_runs")} }
+            BenchPuzzlePanel {}
+            RunsBrowser {}
+        }
+    }
+}
+
+/// A panel generating the puzzles of the benchmarks (see `seeded_puzzle`), opened in the solver
+/// so their runs are recorded and compared in the results matrix.
+///
+/// The same size, colors and seed always generate the same puzzle, with a single solution, so
+/// the benchmarks can be reproduced on other devices.
+///
+/// # Context:
+/// - `Signal<Option<LaunchFile>>`: Replaced by the generated puzzle, opened once the solver is
+///   shown (see `use_launch_file`).
+#[component]
+fn BenchPuzzlePanel() -> Element {
+    let mut use_launch = use_context::<Signal<Option<LaunchFile>>>();
+    let mut use_rows = use_signal(|| 15usize);
+    let mut use_cols = use_signal(|| 15usize);
+    let mut use_colors = use_signal(|| 2usize);
+    let mut use_seed = use_signal(|| 1usize);
+    let open_onclick = move |_| {
+        let (rows, cols, colors, seed) = (use_rows(), use_cols(), use_colors(), use_seed());
+        let file = seeded_puzzle(rows, cols, colors, seed as u64);
+        *use_launch.write() = Some(LaunchFile {
+            filename: format!("bench-{rows}x{cols}-{colors}-{seed}"),
+            json: serde_json::to_string(&file).map_err(|err| err.to_string()),
+        });
+        info!("Generated the {rows}x{cols} benchmark puzzle of seed {seed}");
+        navigator().push("/");
+    };
+    rsx! {
+        section { class: "flex flex-col gap-4 items-center p-6 rounded-lg shadow-lg bg-gray-900",
+            h2 { class: "text-2xl font-bold text-center", {t!("title_bench_puzzle")} }
+            div { class: "flex flex-row flex-wrap justify-center items-center gap-6",
+                NumberInput {
+                    id: "bench-rows-input",
+                    label: t!("label_rows"),
+                    value: use_rows(),
+                    min: 2,
+                    max: 40,
+                    onchange: move |rows| use_rows.set(rows),
+                }
+                NumberInput {
+                    id: "bench-columns-input",
+                    label: t!("label_columns"),
+                    value: use_cols(),
+                    min: 2,
+                    max: 40,
+                    onchange: move |cols| use_cols.set(cols),
+                }
+                NumberInput {
+                    id: "bench-colors-input",
+                    label: t!("label_colors"),
+                    value: use_colors(),
+                    min: 1,
+                    max: 16,
+                    onchange: move |colors| use_colors.set(colors),
+                }
+                NumberInput {
+                    id: "bench-seed-input",
+                    label: t!("label_bench_seed"),
+                    value: use_seed(),
+                    min: 0,
+                    max: 999_999,
+                    onchange: move |seed| use_seed.set(seed),
+                }
+            }
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                title: t!("hint_bench_puzzle"),
+                onclick: open_onclick,
+                {t!("button_open_bench_puzzle")}
+            }
+        }
+    }
+}
+
+/// The browser of the recorded solver runs, unavailable without the database.
+#[cfg(not(feature = "telemetry"))]
+#[component]
+fn RunsBrowser() -> Element {
+    rsx! {
+        p { class: "text-gray-400 select-none", {t!("runs_unavailable")} }
+    }
+}
+
+/// The browser of the recorded solver runs, with the filters and a table of the matching runs,
+/// exported as a results matrix (see `ResultsMatrix`).
+///
+/// # Context:
+/// - `Signal<ActiveProfile>`: The profile whose runs are listed.
+/// - `I18n`: The language formatting the dates, the numbers and the durations of the runs.
+#[cfg(feature = "telemetry")]
+#[component]
+fn RunsBrowser() -> Element {
+    let locale = use_locale_format();
+    let use_active = try_use_context::<Signal<ActiveProfile>>();
+    let mut use_hash = use_signal(String::new);
+    let mut use_solved = use_signal(|| None::<bool>);
+    // Bumped after deleting runs, to list them again.
+    let mut use_revision = use_signal(|| 0usize);
+    let open_store = move || {
+        let profile = use_active.and_then(|use_active| use_active().profile);
+        RunStore::open_default(profile.as_ref())
+    };
+    let filter = use_memo(move || RunFilter {
+        puzzle_hash: Some(use_hash().trim().to_string()).filter(|hash| !hash.is_empty()),
+        solved: use_solved(),
+    });
+    let runs = use_memo(move || {
+        use_revision();
+        open_store()
+            .and_then(|store| store.runs(&filter()).ok())
+            .unwrap_or_default()
+    });
+    let mut delete = move |id: Option<i64>| {
+        let deleted = open_store().map(|store| match id {
+            Some(id) => store.delete(id).map(|_| 1),
+            None => store.delete_matching(&filter()),
+        });
+        match deleted {
+            Some(Ok(count)) => info!("Deleted {count} solver runs"),
+            Some(Err(err)) => error!("Failed to delete solver runs: {err}"),
+            None => error!("Failed to open the database of solver runs"),
+        }
+        *use_revision.write() += 1;
+    };
+    let export = move |markdown: bool| {
+        let matrix = ResultsMatrix::from_runs(&runs());
+        if markdown {
+            save_file(
+                matrix.to_markdown(),
+                String::from("ngram-results.md"),
+                "text/markdown",
+            );
+        } else {
+            save_file(
+                matrix.to_json(),
+                String::from("ngram-results.json"),
+                "application/json",
+            );
+        }
+        info!("Results matrix prepared for download!");
+    };
+    rsx! {
+        div { class: "flex flex-row flex-wrap gap-4 items-center justify-center",
+            label { class: "flex flex-col",
+                span { class: "text-sm text-gray-400", {t!("label_puzzle_hash")} }
+                input {
+                    class: "bg-gray-700 text-white border border-gray-600 rounded-md p-2 font-mono",
+                    r#type: "text",
+                    value: "{use_hash}",
+                    oninput: move |event| *use_hash.write() = event.value(),
+                }
+            }
+            label { class: "flex flex-col",
+                span { class: "text-sm text-gray-400", {t!("label_run_result")} }
+                select {
+                    class: "appearance-none bg-gray-700 text-white border border-gray-600 rounded-md p-2",
+                    onchange: move |event| {
+                        *use_solved.write() = match event.value().as_str() {
+                            "solved" => Some(true),
+                            "unsolved" => Some(false),
+                            _ => None,
+                        };
+                    },
+                    option { value: "all", {t!("option_runs_all")} }
+                    option { value: "solved", {t!("option_runs_solved")} }
+                    option { value: "unsolved", {t!("option_runs_unsolved")} }
+                }
+            }
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-red-800 disabled:opacity-50",
+                disabled: runs().is_empty(),
+                onclick: move |_| delete(None),
+                {t!("button_delete_listed_runs")}
+            }
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 disabled:opacity-50",
+                disabled: runs().is_empty(),
+                title: t!("hint_export_results"),
+                onclick: move |_| export(true),
+                {t!("button_export_results_markdown")}
+            }
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 disabled:opacity-50",
+                disabled: runs().is_empty(),
+                title: t!("hint_export_results"),
+                onclick: move |_| export(false),
+                {t!("button_export_results_json")}
+            }
+        }
+        if runs().is_empty() {
+            p { class: "text-gray-400 select-none", {t!("runs_empty")} }
+        } else {
+            table { class: "text-sm text-left border-collapse",
+                thead {
+                    tr { class: "border-b border-gray-600",
+                        th { class: "p-2", {t!("column_run_date")} }
+                        th { class: "p-2", {t!("label_puzzle_hash")} }
+                        th { class: "p-2", {t!("column_run_size")} }
+                        th { class: "p-2", {t!("column_run_params")} }
+                        th { class: "p-2", {t!("label_run_result")} }
+                        th { class: "p-2", {t!("column_run_iterations")} }
+                        th { class: "p-2", {t!("column_run_duration")} }
+                        th { class: "p-2" }
+                    }
+                }
+                tbody {
+                    for run in runs() {
+                        tr { key: "run-{run.id}", class: "border-b border-gray-700",
+                            td { class: "p-2 whitespace-nowrap", "{locale.date_time(run.recorded_at)}" }
+                            td { class: "p-2 font-mono",
+                                button {
+                                    class: "hover:underline",
+                                    title: t!("title_filter_puzzle"),
+                                    onclick: {
+                                        let hash = run.puzzle_hash.clone();
+                                        move |_| *use_hash.write() = hash.clone()
+                                    },
+                                    "{run.puzzle_hash}"
+                                }
+                            }
+                            td { class: "p-2", "{run.rows}×{run.cols}" }
+                            td { class: "p-2 text-gray-400",
+                                "n={locale.count(run.population_size)} pc={run.cross_probability} pm={run.mutation_probability} k={run.tournament_size} s={run.slide_tries} {run.replacement} seed={run.seed}"
+                            }
+                            td { class: "p-2",
+                                if run.solved {
+                                    {t!("option_runs_solved")}
+                                } else {
+                                    {t!("label_run_score", score : locale.count(run.score))}
+                                }
+                            }
+                            td { class: "p-2", "{locale.count(run.iterations)}/{locale.count(run.max_iterations)}" }
+                            td { class: "p-2", "{locale.duration(run.duration_ms as f64)}" }
+                            td { class: "p-2 whitespace-nowrap",
+                                if let Some(json) = run.solution.clone() {
+                                    button {
+                                        class: "px-2 rounded hover:bg-blue-800",
+                                        title: t!("button_save_run_solution"),
+                                        onclick: {
+                                            let filename = format!("{}.solved.ngram", run.puzzle_hash);
+                                            move |_| save_nonogram(json.clone(), filename.clone())
+                                        },
+                                        "⤓"
+                                    }
+                                }
+                                button {
+                                    class: "px-2 rounded text-red-400 hover:bg-red-900",
+                                    title: t!("button_delete_run"),
+                                    onclick: move |_| delete(Some(run.id)),
+                                    "✕"
+                                }
+                            }
+                        }
+                    }
+                }
+            }
         }
     }
 }
@@ -285,14 +1030,30 @@ pub fn Editor() -> Element {
 /// # UI Elements:
 /// - `RowsInput`: Input for row configuration with editing capabilities.
 /// - `ColumnsInput`: Input for column configuration with editing capabilities.
+/// - `ResizeModeSelect`: Selects whether resizing crops or rescales the drawing.
 /// - `BlockSizeInput`: Input for adjusting the block size.
 /// - `FileInput`: Input for loading Nonogram files.
 /// - `FileSaveButton`: Button for saving the current Nonogram.
+/// - `PictureSaveButton`: Button for saving the current Nonogram as a PNG picture embedding the file.
+/// - `ClueExportButtons`: Buttons for exporting the clues as plain text or Markdown.
 /// - `FileLoadEditInput`: Input for editing the Nonogram by loading from a file.
 /// - `ClearSolutionButton`: Button to clear the current solution.
 /// - `SlideSolutionButtons`: Buttons for navigating through solutions.
+/// - `HistoryScrubber`: Slider scrubbing through the history of the solution grid.
 /// - `NewColorButton`: Button to add new colors to the palette.
 /// - `ColorPalette`: Displays and allows modification of the color palette.
+/// - `LayersPanel`: Shows, hides and solos the color layers of the solution grid.
+/// - `ReferenceImagePanel`: Loads and adjusts a reference image beneath the solution grid.
+/// - `ImageImportPanel`: Imports a photo into the solution grid, in the background.
+/// - `ColorUsagePanel`: Shows the color usage and replaces colors in the solution grid.
+/// - `FilterPanel`: Previews and applies cleanup filters to the solution grid.
+/// - `ClipboardPanel`: Copies a region of the solution grid and pastes it, even from other instances.
+/// - `DiffPanel`: Compares the current solution against another file.
+/// - `SignaturePanel`: Signs the drawing with the profile in use.
+/// - `AuthorNotesPanel`: Writes the notes of the author, saved in the file.
+/// - `PrintPanel`: Prints the puzzle of the drawing, optionally saving ink.
+/// - `ReplayExportPanel`: Exports an animated GIF replaying the drawing (desktop only).
+/// - `MacroPanel`: Records and replays macros of editor commands.
 ///
 /// # Example
 /// ```rust
@@ -305,11 +1066,18 @@ fn EditorToolbar() -> Element {
             div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6",
                 RowsInput { readonly: false }
                 ColumnsInput { readonly: false }
+                ResizeModeSelect {}
                 BlockSizeInput {}
+                CheckerboardToggle {}
+                FullscreenButton {}
             }
+            ResizeWarning {}
             div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6",
+                NewPuzzleButton {}
                 FileInput { readonly: false }
                 FileSaveButton {}
+                PictureSaveButton {}
+                ClueExportButtons {}
             }
             div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6",
                 FileLoadEditInput {}
@@ -318,45 +1086,235 @@ fn EditorToolbar() -> Element {
                 ClearSolutionButton {}
                 SlideSolutionButtons {}
                 NewColorButton {}
+                HistoryScrubber {}
             }
             div { class: "flex flex-wrap justify-items-center justify-center items-center gap-6",
                 ColorPalette { readonly: false }
             }
+            PaletteSelectionPanel {}
+            PaletteFilePanel {}
+            LayersPanel {}
+            ReferenceImagePanel {}
+            ImageImportPanel {}
+            ColorUsagePanel {}
+            FilterPanel {}
+            ClipboardPanel {}
+            GuessingLintPanel {}
+            DiffPanel {}
+            AnnotationPanel {}
+            SignaturePanel {}
+            AuthorNotesPanel {}
+            PrintPanel {}
+            ReplayExportPanel {}
+            MacroPanel {}
+            InteractionPanel {}
+            AccessibilityPanel {}
+            ThemePanel {}
+            FeedbackPanel {}
         }
     }
 }
 
-/// Allows editing the Nonogram puzzle solution.
+/// A button opening the dialog that creates a new puzzle.
 ///
-/// This component manages the display and interaction for editing a Nonogram puzzle.
-/// It provides a grid layout where users can directly edit the solution, including colors
-/// and constraints for rows and columns.
-///
-/// # Contexts Used:
-/// - `Signal<NonogramSolution>`: Provides the current state of the Nonogram solution for editing.
-///
-/// # UI Elements:
-/// - `ColorInput`: Allows users to edit the color used in the Nonogram.
-/// - `ColumnsConstraints`: Displays column constraints for the puzzle.
-/// - `RowsConstraints`: Displays row constraints for the puzzle.
-/// - `Solution`: Provides the solution grid for direct editing.
+/// # Context:
+/// - `Signal<NewPuzzleDialog>`: Whether the dialog is open.
+#[component]
+fn NewPuzzleButton() -> Element {
+    let mut use_dialog = use_context::<Signal<NewPuzzleDialog>>();
+    rsx! {
+        button {
+            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+            onclick: move |_| use_dialog.write().open = true,
+            {t!("button_new_puzzle")}
+        }
+    }
+}
+
+/// A dialog creating a new puzzle in the editor: its size, from the presets or custom, its number
+/// of colors and the drawing it starts with.
 ///
-/// # Example
-/// ```rust
-/// EditorNonogram {}
-/// ```
+/// # Context:
+/// - `Signal<NewPuzzleDialog>`: Whether the dialog is open.
+/// - `Signal<NonogramSolution>`: Replaced by the drawing of the new puzzle.
+/// - `Signal<NonogramPalette>`: Replaced by the palette of the new puzzle.
+/// - `Signal<NonogramData>`: The new puzzle is untitled.
+/// - `Signal<Option<Signature>>`: Cleared, since the new puzzle isn't signed yet.
+/// - `Signal<AuthorNotes>`: Cleared with the previous puzzle.
+/// - `Signal<SessionRng>`: Draws the random colors and patterns.
 #[component]
-fn EditorNonogram() -> Element {
-    let use_solution = use_context::<Signal<NonogramSolution>>();
-    let current_puzzle = NonogramPuzzle::from_solution(&use_solution());
+fn NewPuzzleForm() -> Element {
+    let mut use_dialog = use_context::<Signal<NewPuzzleDialog>>();
+    let dispatcher = use_dispatcher();
+    let mut use_palette = use_context::<Signal<NonogramPalette>>();
+    let mut use_data = use_context::<Signal<NonogramData>>();
+    let mut use_signature = use_context::<Signal<Option<Signature>>>();
+    let mut use_notes = use_context::<Signal<AuthorNotes>>();
+    let mut use_rng = use_context::<Signal<SessionRng>>();
+    let mut use_preset = use_signal(|| Some(SIZE_PRESETS[1]));
+    let mut use_rows = use_signal(|| SIZE_PRESETS[1]);
+    let mut use_cols = use_signal(|| SIZE_PRESETS[1]);
+    let mut use_colors = use_signal(|| 2usize);
+    let mut use_template = use_signal(GridTemplate::default);
+    let create_onclick = move |_| {
+        let (rows, cols) = match use_preset() {
+            Some(side) => (side, side),
+            None => (use_rows(), use_cols()),
+        };
+        let mut rng = use_rng.write().next_rng();
+        let file = new_nonogram_file(rows, cols, use_colors(), use_template(), &mut rng);
+        dispatcher.dispatch(GridCommand::Replace {
+            tool: Tool::Load,
+            solution: file.solution,
+        });
+        *use_palette.write() = file.palette;
+        *use_signature.write() = None;
+        *use_notes.write() = AuthorNotes::default();
+        use_data.write().filename = String::new();
+        use_data.write().completed = false;
+        use_dialog.write().open = false;
+        info!("New {rows}x{cols} nonogram created");
+    };
+    if !use_dialog().open {
+        return rsx! {};
+    }
     rsx! {
-        section { class: "mb-20",
-            table { class: "border-separate border-spacing-4",
-                thead {
-                    tr {
-                        th { class: "align-bottom flex justify-end", ColorInput {} }
-                        th { class: "align-bottom",
-                            ColumnsConstraints { puzzle: current_puzzle.clone() }
+        div { class: "fixed inset-0 z-50 flex justify-center items-center bg-black bg-opacity-60",
+            div { class: "flex flex-col gap-6 p-6 max-w-lg rounded-lg border border-gray-600 bg-gray-900 text-white shadow-lg",
+                h2 { class: "text-2xl font-bold text-center", {t!("title_new_puzzle")} }
+                div { class: "flex flex-row flex-wrap justify-center items-center gap-3",
+                    for side in SIZE_PRESETS {
+                        button {
+                            key: "preset-{side}",
+                            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                            background_color: if use_preset() == Some(side) { "#1e40af" },
+                            onclick: move |_| use_preset.set(Some(side)),
+                            "{side} × {side}"
+                        }
+                    }
+                    button {
+                        class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                        background_color: if use_preset().is_none() { "#1e40af" },
+                        onclick: move |_| use_preset.set(None),
+                        {t!("size_custom")}
+                    }
+                }
+                if use_preset().is_none() {
+                    div { class: "flex flex-row flex-wrap justify-center items-center gap-6",
+                        NumberInput {
+                            id: "new-rows-input",
+                            label: t!("label_rows"),
+                            value: use_rows(),
+                            min: 2,
+                            max: 40,
+                            onchange: move |rows| use_rows.set(rows),
+                        }
+                        NumberInput {
+                            id: "new-columns-input",
+                            label: t!("label_columns"),
+                            value: use_cols(),
+                            min: 2,
+                            max: 40,
+                            onchange: move |cols| use_cols.set(cols),
+                        }
+                    }
+                }
+                div { class: "flex flex-row flex-wrap justify-center items-center gap-6",
+                    NumberInput {
+                        id: "new-colors-input",
+                        label: t!("label_colors"),
+                        value: use_colors(),
+                        min: 1,
+                        max: 16,
+                        onchange: move |colors| use_colors.set(colors),
+                    }
+                    div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                        label {
+                            r#for: "template-select",
+                            class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                            {t!("label_template")}
+                            ":"
+                        }
+                        select {
+                            id: "template-select",
+                            class: "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 focus:ring focus:ring-blue-500 focus:outline-none cursor-pointer",
+                            onchange: move |event: FormEvent| {
+                                use_template.set(match event.value().as_str() {
+                                    "border" => GridTemplate::Border,
+                                    "symmetric" => GridTemplate::SymmetricMask,
+                                    _ => GridTemplate::Empty,
+                                });
+                            },
+                            option {
+                                value: "empty",
+                                selected: use_template() == GridTemplate::Empty,
+                                {t!("template_empty")}
+                            }
+                            option {
+                                value: "border",
+                                selected: use_template() == GridTemplate::Border,
+                                {t!("template_border")}
+                            }
+                            option {
+                                value: "symmetric",
+                                selected: use_template() == GridTemplate::SymmetricMask,
+                                {t!("template_symmetric_mask")}
+                            }
+                        }
+                    }
+                }
+                div { class: "flex flex-row justify-center items-center gap-3",
+                    button {
+                        class: "px-4 py-1 font-bold rounded border border-gray-500 bg-blue-800 text-white hover:bg-blue-700 hover:scale-110 active:scale-125 transition-transform transform",
+                        onclick: create_onclick,
+                        {t!("button_create_puzzle")}
+                    }
+                    button {
+                        class: "px-4 py-1 rounded border border-gray-500 bg-gray-700 text-white hover:bg-gray-600",
+                        onclick: move |_| use_dialog.write().open = false,
+                        {t!("button_cancel")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Allows editing the Nonogram puzzle solution.
+///
+/// This component manages the display and interaction for editing a Nonogram puzzle.
+/// It provides a grid layout where users can directly edit the solution, including colors
+/// and constraints for rows and columns.
+///
+/// # Contexts Used:
+/// - `Signal<NonogramSolution>`: Provides the current state of the Nonogram solution for editing.
+/// - `Signal<Option<Signature>>`: The signature of the author, drawn below the grid if visible.
+///
+/// # UI Elements:
+/// - `ColorInput`: Allows users to edit the color used in the Nonogram.
+/// - `ColumnsConstraints`: Displays column constraints for the puzzle.
+/// - `RowsConstraints`: Displays row constraints for the puzzle.
+/// - `Solution`: Provides the solution grid for direct editing.
+/// - `AnnotationOverlay`: Displays the marks drawn over the grid.
+/// - `SignatureRow`: Displays the visible signature of the author below the grid.
+///
+/// # Example
+/// ```rust
+/// EditorNonogram {}
+/// ```
+#[component]
+fn EditorNonogram() -> Element {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_signature = use_context::<Signal<Option<Signature>>>();
+    let current_puzzle = NonogramPuzzle::from_solution(&use_solution());
+    rsx! {
+        section { class: "mb-20",
+            table { class: "border-separate border-spacing-4",
+                thead {
+                    tr {
+                        th { class: "align-bottom flex justify-end", ColorInput {} }
+                        th { class: "align-bottom",
+                            ColumnsConstraints { puzzle: current_puzzle.clone() }
                         }
                     }
                 }
@@ -365,7 +1323,5218 @@ fn EditorNonogram() -> Element {
                         th { class: "flex justify-end",
                             RowsConstraints { puzzle: current_puzzle }
                         }
-                        td { Solution {} }
+                        td {
+                            div { position: "relative", z_index: "0",
+                                ReferenceUnderlay {}
+                                Solution {}
+                                AnnotationOverlay {}
+                            }
+                        }
+                    }
+                    tr {
+                        th {}
+                        td {
+                            SignatureRow { signature: use_signature(), cols: use_solution().cols() }
+                        }
+                    }
+                }
+            }
+            ZoomPreview {}
+        }
+    }
+}
+
+/// The longest side, in pixels, of the thumbnail shown while zoomed in.
+const ZOOM_PREVIEW_SIDE: usize = 64;
+
+/// The milliseconds between the updates of the thumbnail shown while zoomed in.
+const ZOOM_PREVIEW_THROTTLE_MILLIS: u32 = 150;
+
+/// A corner thumbnail of the whole grid, shown while it doesn't fit in the viewport.
+///
+/// When the block size zooms the grid beyond the viewport, the thumbnail keeps the whole picture
+/// in sight while the details are drawn. The edits are gathered for `ZOOM_PREVIEW_THROTTLE_MILLIS`
+/// milliseconds, and only the pixels covering the changed cells are rendered again (see
+/// `Thumbnail::update`).
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: The solution grid previewed.
+/// - `Signal<NonogramPalette>`: Defines the color palette used.
+/// - `Signal<NonogramData>`: The block size zooming the grid.
+#[component]
+fn ZoomPreview() -> Element {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let use_viewport = use_viewport();
+    let mut use_thumbnail = use_signal(|| Thumbnail::new(&use_solution.peek(), ZOOM_PREVIEW_SIDE));
+    let mut use_rendered = use_signal(|| use_solution.peek().clone());
+    let mut use_pending = use_signal(|| false);
+    use_effect(move || {
+        use_solution.read();
+        if *use_pending.peek() {
+            return;
+        }
+        use_pending.set(true);
+        spawn(async move {
+            sleep(ZOOM_PREVIEW_THROTTLE_MILLIS).await;
+            let solution = use_solution.peek().clone();
+            let changed = solution.changed_area(&use_rendered.peek());
+            if let Some(area) = changed {
+                use_thumbnail.write().update(&solution, area);
+                use_rendered.set(solution);
+            }
+            use_pending.set(false);
+        });
+    });
+    let zoomed = use_memo(move || {
+        let Some((width, height)) = use_viewport() else {
+            return false;
+        };
+        let block_size = use_data().block_size as f64;
+        let solution = use_solution.read();
+        solution.cols() as f64 * block_size > width * 0.9
+            || solution.rows() as f64 * block_size > height * 0.8
+    });
+    rsx! {
+        div {
+            class: "fixed bottom-4 left-4 z-40 p-1 rounded border border-gray-600 bg-gray-800 shadow-lg pointer-events-none",
+            hidden: !zoomed(),
+            aria_label: t!("label_zoom_preview"),
+            table { draggable: false,
+                tbody {
+                    for (i , row_data) in use_thumbnail().pixels.iter().enumerate() {
+                        tr { key: "zoom-row-{i}",
+                            for (j , pixel) in row_data.iter().enumerate() {
+                                td {
+                                    key: "zoom-cell-{i}-{j}",
+                                    class: "select-none",
+                                    style: "background-color: {use_palette().color_palette.get(*pixel).cloned().unwrap_or_default()}; width: 3px; height: 3px; padding: 0;",
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Displays the reference image beneath the solution grid, if any is loaded.
+///
+/// The grid cells become translucent (see `ReferenceImage::cell_alpha`), so the image can
+/// be seen through them and traced.
+///
+/// # Context:
+/// - `Signal<ReferenceImage>`: The reference image, its opacity and offset.
+#[component]
+fn ReferenceUnderlay() -> Element {
+    let use_reference = use_context::<Signal<ReferenceImage>>();
+    let ReferenceImage {
+        source,
+        offset: (x, y),
+        ..
+    } = use_reference();
+    rsx! {
+        if let Some(source) = source {
+            img {
+                src: source,
+                draggable: false,
+                style: "position: absolute; inset: 0; z-index: -1; width: 100%; height: 100%; object-fit: fill; pointer-events: none; transform: translate({x}px, {y}px);",
+            }
+        }
+    }
+}
+
+/// Displays the visible signature of the author as a row of cells below the grid, outside of
+/// the puzzle, with the pattern derived from the signature (see `Signature::pattern`).
+///
+/// # Arguments:
+/// - `signature`: The signature of the author, displayed only if it's visible.
+/// - `cols`: The number of columns of the grid.
+///
+/// # Context:
+/// - `Signal<NonogramData>`: The block size of the cells.
+#[component]
+fn SignatureRow(signature: Option<Signature>, cols: usize) -> Element {
+    let use_data = use_context::<Signal<NonogramData>>();
+    let Some(signature) = signature.filter(|signature| signature.visible) else {
+        return rsx! {};
+    };
+    rsx! {
+        table {
+            class: "mt-2 border-2 border-gray-500",
+            title: t!("signature_by", author : signature.author.clone()),
+            tbody {
+                tr {
+                    for (col , filled) in signature.pattern(cols).into_iter().enumerate() {
+                        td {
+                            key: "signature-{col}",
+                            class: if filled { "bg-gray-400" } else { "bg-gray-800" },
+                            style: "min-width: {use_data().block_size}px; height: {use_data().block_size / 2}px; padding: 0;",
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Displays the annotation layer over the solution grid, and draws new marks with the selected
+/// tool.
+///
+/// The layer only captures the pointer while a tool is selected, otherwise the grid beneath it
+/// keeps working as usual. Positions are measured in cells, so the marks follow the block size.
+///
+/// # Context:
+/// - `Signal<Annotations>`: The marks drawn, their visibility and the selected tool.
+/// - `Signal<NonogramSolution>`: The grid whose dimensions give the cell units.
+#[component]
+fn AnnotationOverlay() -> Element {
+    let mut use_annotations = use_context::<Signal<Annotations>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let mut use_mounted = use_signal(|| None::<Rc<MountedData>>);
+    let mut use_bounds = use_signal(|| None::<PixelsRect>);
+    let mut use_draft = use_signal(|| None::<Annotation>);
+    let rows = use_solution().solution_grid.len();
+    let cols = use_solution().solution_grid.first().map_or(0, Vec::len);
+    // Converts the pointer position to cell units, using the bounds measured when pressed.
+    let cell_position = move |event: &PointerEvent| {
+        let bounds = use_bounds()?;
+        let point = event.client_coordinates();
+        Some((
+            ((point.x - bounds.origin.x) / bounds.size.width * cols as f64) as f32,
+            ((point.y - bounds.origin.y) / bounds.size.height * rows as f64) as f32,
+        ))
+    };
+    let mut finish = move || {
+        if let Some(annotation) = use_draft.write().take() {
+            if !annotation.is_degenerate() {
+                info!("Annotation drawn: {:?}", annotation);
+                use_annotations.write().items.push(annotation);
+            }
+        }
+    };
+    let annotations = use_annotations();
+    let markup = annotations_svg(annotations.items.iter().chain(use_draft().as_ref()));
+    rsx! {
+        if annotations.visible {
+            svg {
+                view_box: "0 0 {cols} {rows}",
+                preserve_aspect_ratio: "none",
+                style: "position: absolute; inset: 0; z-index: 1; width: 100%; height: 100%; touch-action: none;",
+                pointer_events: if annotations.drawing() { "auto" } else { "none" },
+                cursor: if annotations.drawing() { "crosshair" },
+                onmounted: move |event: MountedEvent| use_mounted.set(Some(event.data())),
+                onpointerdown: move |event: PointerEvent| async move {
+                    let Some(mounted) = use_mounted() else {
+                        return;
+                    };
+                    use_bounds.set(mounted.get_client_rect().await.ok());
+                    let Some(position) = cell_position(&event) else {
+                        return;
+                    };
+                    use_draft.set(use_annotations().start(position));
+                    if use_annotations().tool == Some(AnnotationTool::Label) {
+                        finish();
+                    }
+                },
+                onpointermove: move |event: PointerEvent| {
+                    if let Some(position) = cell_position(&event) {
+                        if let Some(draft) = use_draft.write().as_mut() {
+                            draft.drag(position);
+                        }
+                    }
+                },
+                onpointerup: move |_| finish(),
+                onpointerleave: move |_| finish(),
+                dangerous_inner_html: markup,
+            }
+        }
+    }
+}
+
+/// The dispatcher of the commands modifying the Nonogram solution grid.
+///
+/// Components don't write the solution grid directly: they describe the interaction with a
+/// `GridCommand` and dispatch it, so every change goes through the same reducer and is recorded
+/// in the action log with the tool that produced it.
+#[derive(Clone, Copy)]
+struct Dispatcher {
+    /// The signal of the solution grid modified by the commands.
+    solution: Signal<NonogramSolution>,
+    /// The signal of the action log where the modifications are recorded.
+    log: Signal<ActionLog>,
+    /// The signal of the macro recorder, only available in the editor.
+    recorder: Option<Signal<MacroRecorder>>,
+}
+
+impl Dispatcher {
+    /// Reduces a command on the solution grid and records the resulting modification.
+    ///
+    /// # Arguments:
+    /// - `command`: The command to apply to the solution grid.
+    fn dispatch(mut self, command: GridCommand) {
+        if let Some(mut recorder) = self.recorder {
+            recorder.write().record(&command);
+        }
+        command.dispatch(&mut self.solution.write(), &mut self.log.write());
+    }
+}
+
+/// Builds the command dispatcher from the solution grid and action log contexts.
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: The solution grid modified by the commands.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+/// - `Signal<MacroRecorder>`: Records the commands into a macro (optional).
+fn use_dispatcher() -> Dispatcher {
+    Dispatcher {
+        solution: use_context::<Signal<NonogramSolution>>(),
+        log: use_context::<Signal<ActionLog>>(),
+        recorder: try_use_context::<Signal<MacroRecorder>>(),
+    }
+}
+
+/// Returns the style of the clue cells of a line, colored by how constrained the line is.
+///
+/// Tight lines are green, lines deduced by overlapping are amber, and slack lines are red.
+fn tightness_style(slack: Option<LineSlack>) -> &'static str {
+    match slack.map(|slack| slack.tightness) {
+        Some(Tightness::Tight) => "outline: 3px solid #22c55e; outline-offset: -3px;",
+        Some(Tightness::Overlapping) => "outline: 3px solid #f59e0b; outline-offset: -3px;",
+        Some(Tightness::Slack) => "outline: 3px solid #ef4444; outline-offset: -3px;",
+        None => "",
+    }
+}
+
+/// Returns the style of the clue cells of a line, tinted red if it can no longer be completed.
+fn contradiction_style(contradicted: bool) -> &'static str {
+    if contradicted {
+        "box-shadow: inset 0 0 0 100vmax rgba(220, 38, 38, 0.6);"
+    } else {
+        ""
+    }
+}
+
+/// Finds the lines of the puzzle being solved that can no longer be completed with the painted
+/// cells (see `NonogramPuzzle::find_contradictions`), if the assist level highlights them. Only
+/// the grid of the solver is checked.
+///
+/// # Arguments:
+/// - `puzzle`: The puzzle whose clues are displayed.
+///
+/// # Context:
+/// - `Signal<NonogramFile>`: Only provided in the solver.
+/// - `Signal<NonogramSolution>`: The grid being solved.
+/// - `Signal<Settings>`: Whether the assist level highlights the contradictions (optional).
+fn use_contradictions(puzzle: &NonogramPuzzle) -> Vec<ClueLine> {
+    let use_file = try_use_context::<Signal<NonogramFile>>();
+    let use_solution = try_use_context::<Signal<NonogramSolution>>();
+    let use_settings = try_use_context::<Signal<Settings>>();
+    let enabled = use_file.is_some()
+        && use_settings
+            .is_some_and(|use_settings| use_settings().assist_level.assists().contradictions);
+    match use_solution.filter(|_| enabled) {
+        Some(use_solution) => puzzle.find_contradictions(&use_solution()),
+        None => Vec::new(),
+    }
+}
+
+/// Keeps the puzzle of a page in the session history, and reopens the puzzles requested from it.
+///
+/// Every action logged in the page's journal records the current state of the puzzle in the
+/// history, so only the puzzles actually touched are listed. In the low-power mode, the state is
+/// only recorded once the grid stays untouched for a while.
+///
+/// # Arguments:
+/// - `page`: The page tracked.
+///
+/// # Context:
+/// - `Signal<SessionHistory>`: The puzzles touched during the session (optional, nothing is tracked without it).
+/// - `Signal<ActionLog>`: Notifies every modification of the solution grid.
+/// - `Signal<NonogramSolution>`: The current solution grid.
+/// - `Signal<NonogramPalette>`: The current color palette.
+/// - `Signal<NonogramData>`: The filename of the puzzle, and whether it's blind (blind puzzles aren't tracked).
+/// - `Signal<NonogramFile>`: The puzzle being solved (in the solver).
+/// - `Signal<NonogramPuzzle>`: The constraints of the puzzle being solved (in the solver).
+/// - `Signal<Option<Signature>>`: The signature of the drawing (in the editor).
+/// - `Signal<AuthorNotes>`: The notes of the author of the drawing (in the editor).
+/// - `Signal<Settings>`: The power mode, delaying the records.
+fn use_session_tracking(page: SessionPage) {
+    let use_session = try_use_context::<Signal<SessionHistory>>();
+    let mut use_log = use_context::<Signal<ActionLog>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let mut use_palette = use_context::<Signal<NonogramPalette>>();
+    let mut use_data = use_context::<Signal<NonogramData>>();
+    let use_file =
+        try_use_context::<Signal<NonogramFile>>().filter(|_| page == SessionPage::Solver);
+    let mut use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let use_signature = try_use_context::<Signal<Option<Signature>>>();
+    let use_notes = try_use_context::<Signal<AuthorNotes>>();
+    let use_settings = try_use_context::<Signal<Settings>>();
+    let dispatcher = use_dispatcher();
+    let mut use_revision = use_signal(|| 0usize);
+    use_hook(move || {
+        use_log
+            .write()
+            .subscribe(move |_| *use_revision.write() += 1);
+    });
+    let record = move || {
+        let Some(mut use_session) = use_session else {
+            return;
+        };
+        let filename = use_data.peek().filename.clone();
+        let name = if filename.is_empty() {
+            t!("untitled")
+        } else {
+            filename
+        };
+        let grid = use_solution.peek().clone();
+        let file = match use_file {
+            Some(use_file) => use_file.peek().clone(),
+            None => NonogramFile {
+                solution: grid.clone(),
+                palette: use_palette.peek().clone(),
+                signature: use_signature.and_then(|use_signature| use_signature.peek().clone()),
+                notes: use_notes
+                    .map(|use_notes| use_notes.peek().text.clone())
+                    .unwrap_or_default(),
+            },
+        };
+        use_session.write().touch(SessionPuzzle {
+            name,
+            page,
+            file,
+            grid,
+        });
+    };
+    use_effect(move || {
+        let revision = use_revision();
+        if revision == 0 || use_session.is_none() || use_data.peek().blind {
+            return;
+        }
+        let delay = use_settings
+            .map(|use_settings| use_settings.peek().power_mode)
+            .unwrap_or_default()
+            .snapshot_delay_millis();
+        if delay == 0 {
+            record();
+            return;
+        }
+        spawn(async move {
+            if sleep(delay).await.is_some() && *use_revision.peek() == revision {
+                record();
+            }
+        });
+    });
+    use_effect(move || {
+        let Some(mut use_session) = use_session else {
+            return;
+        };
+        if !use_session().has_jump(page) {
+            return;
+        }
+        let Some(puzzle) = use_session.write().take_jump(page) else {
+            return;
+        };
+        info!("Reopening '{}' from the session history", puzzle.name);
+        if let Some(mut use_file) = use_file {
+            *use_puzzle.write() = NonogramPuzzle::from_solution(&puzzle.file.solution);
+            *use_file.write() = puzzle.file.clone();
+        }
+        if let Some(mut use_signature) = use_signature {
+            *use_signature.write() = puzzle.file.signature;
+        }
+        if let Some(mut use_notes) = use_notes {
+            use_notes.write().text = puzzle.file.notes;
+        }
+        *use_palette.write() = puzzle.file.palette;
+        use_data.write().filename = puzzle.name;
+        use_data.write().completed = false;
+        use_data.write().blind = false;
+        dispatcher.dispatch(GridCommand::Replace {
+            tool: Tool::Load,
+            solution: puzzle.grid,
+        });
+    });
+}
+
+/// Opens the Nonogram file the application was launched with in the solver, such as a file
+/// opened from the file manager, only the first time the solver is shown. The benchmark puzzles
+/// are opened the same way (see `BenchPuzzlePanel`).
+///
+/// # Returns:
+/// The message explaining why the file couldn't be opened, if it couldn't, until dismissed.
+///
+/// # Context:
+/// - `Signal<Option<LaunchFile>>`: The file the application was launched with, or the benchmark
+///   puzzle generated, taken once opened (optional).
+/// - `Signal<NonogramFile>`: Replaced by the file.
+/// - `Signal<NonogramPuzzle>`: Replaced by the puzzle of the file.
+/// - `Signal<NonogramPalette>`: Replaced by the palette of the file.
+/// - `Signal<NonogramData>`: The filename of the puzzle.
+/// - `Signal<NonogramSolution>`: Cleared for solving the puzzle.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+fn use_launch_file() -> Signal<Option<String>> {
+    let mut use_launch_error = use_signal(|| None::<String>);
+    let use_launch = try_use_context::<Signal<Option<LaunchFile>>>();
+    let mut use_file = use_context::<Signal<NonogramFile>>();
+    let mut use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let mut use_palette = use_context::<Signal<NonogramPalette>>();
+    let mut use_data = use_context::<Signal<NonogramData>>();
+    let dispatcher = use_dispatcher();
+    use_effect(move || {
+        let Some(launch) = use_launch.and_then(|mut use_launch| use_launch.write().take()) else {
+            return;
+        };
+        let parsed = launch.json.clone().and_then(|json| {
+            serde_json::from_str::<NonogramFile>(&json).map_err(|err| err.to_string())
+        });
+        match parsed {
+            Ok(nonogram_file) => {
+                let puzzle = NonogramPuzzle::from_solution(&nonogram_file.solution);
+                *use_palette.write() = nonogram_file.palette.clone();
+                *use_file.write() = nonogram_file;
+                use_data.write().filename = ngram_filename(&launch.filename);
+                use_data.write().completed = false;
+                use_data.write().blind = false;
+                dispatcher.dispatch(GridCommand::Replace {
+                    tool: Tool::Load,
+                    solution: NonogramSolution {
+                        solution_grid: vec![vec![BACKGROUND; puzzle.cols]; puzzle.rows],
+                    },
+                });
+                *use_puzzle.write() = puzzle;
+                info!("Opened '{}' in the solver", launch.filename);
+            }
+            Err(err) => {
+                error!("Couldn't open file '{}': {err}", launch.filename);
+                *use_launch_error.write() = Some(
+                    t!("launch_file_error", file : launch.filename.clone(), error : err)
+                        .to_string(),
+                );
+            }
+        }
+    });
+    use_launch_error
+}
+
+/// The script exposing the snapshots of a page as `window.ngramDebug`, for automated UI tests:
+/// `snapshot()` resolves to the state of the page, `restore(snapshot)` replaces it, and
+/// `dispatch(command)` modifies its grid with a `GridCommand`, as its tools do.
+#[cfg(feature = "debug-state")]
+const SNAPSHOT_SCRIPT: &str = r#"
+const pending = [];
+window.ngramDebug = {
+    snapshot: () => new Promise((resolve) => {
+        pending.push(resolve);
+        dioxus.send(["snapshot", null]);
+    }),
+    restore: (snapshot) => dioxus.send(["restore", JSON.stringify(snapshot)]),
+    dispatch: (command) => dioxus.send(["dispatch", JSON.stringify(command)]),
+};
+while (true) {
+    const json = await dioxus.recv();
+    pending.shift()?.(JSON.parse(json));
+}
+"#;
+
+/// Exposes the state of a page to the document (see `SNAPSHOT_SCRIPT`), so automated UI tests can
+/// inspect it after driving the interface, restore a state before a flow, or dispatch the commands
+/// of the tools through the dispatcher of the page.
+///
+/// # Arguments:
+/// - `page`: The page exposed.
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: The current solution grid.
+/// - `Signal<NonogramPalette>`: The current color palette.
+/// - `Signal<NonogramData>`: The filename of the puzzle, and whether it's completed.
+/// - `Signal<NonogramFile>`: The puzzle being solved (in the solver).
+/// - `Signal<NonogramPuzzle>`: The constraints of the puzzle being solved (in the solver).
+/// - `Signal<ActionLog>`: The journal of the page, whose actions are counted.
+/// - `Signal<Settings>`: The user settings (optional).
+#[cfg(feature = "debug-state")]
+fn use_state_snapshot(page: SessionPage) {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let mut use_palette = use_context::<Signal<NonogramPalette>>();
+    let mut use_data = use_context::<Signal<NonogramData>>();
+    let use_file =
+        try_use_context::<Signal<NonogramFile>>().filter(|_| page == SessionPage::Solver);
+    let mut use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let use_log = use_context::<Signal<ActionLog>>();
+    let use_settings = try_use_context::<Signal<Settings>>();
+    let dispatcher = use_dispatcher();
+    let snapshot = move || {
+        let data = use_data.peek();
+        let grid = use_solution.peek().clone();
+        let palette = use_palette.peek().clone();
+        AppSnapshot {
+            page,
+            filename: data.filename.clone(),
+            file: match use_file {
+                Some(use_file) => use_file.peek().clone(),
+                None => NonogramFile {
+                    solution: grid.clone(),
+                    palette: palette.clone(),
+                    signature: None,
+                    notes: String::new(),
+                },
+            },
+            grid,
+            palette,
+            completed: data.completed,
+            actions: use_log.peek().len(),
+            settings: use_settings
+                .map(|use_settings| use_settings.peek().clone())
+                .unwrap_or_default(),
+        }
+    };
+    let mut restore = move |snapshot: AppSnapshot| {
+        if let Some(mut use_file) = use_file {
+            *use_puzzle.write() = snapshot.puzzle();
+            *use_file.write() = snapshot.file;
+        }
+        if let Some(mut use_settings) = use_settings {
+            use_settings.write().restore(snapshot.settings);
+        }
+        *use_palette.write() = snapshot.palette;
+        use_data.write().filename = snapshot.filename;
+        use_data.write().completed = snapshot.completed;
+        dispatcher.dispatch(GridCommand::Replace {
+            tool: Tool::Load,
+            solution: snapshot.grid,
+        });
+    };
+    use_future(move || async move {
+        let mut requests = document::eval(SNAPSHOT_SCRIPT);
+        while let Ok((request, json)) = requests.recv::<(String, Option<String>)>().await {
+            match (request.as_str(), json) {
+                ("snapshot", _) => {
+                    let json = snapshot().to_json().unwrap_or_else(|| String::from("null"));
+                    if requests.send(json).is_err() {
+                        error!("Couldn't send the snapshot of the {page:?} page");
+                    }
+                }
+                ("restore", Some(json)) => match AppSnapshot::from_json(&json) {
+                    Some(snapshot) => {
+                        info!("Restoring a snapshot of the {:?} page", snapshot.page);
+                        restore(snapshot);
+                    }
+                    None => error!("Couldn't parse the snapshot to restore"),
+                },
+                ("dispatch", Some(json)) => match serde_json::from_str::<GridCommand>(&json) {
+                    Ok(command) => dispatcher.dispatch(command),
+                    Err(err) => error!("Couldn't parse the command to dispatch: {err}"),
+                },
+                _ => error!("Unknown snapshot request '{request}'"),
+            }
+        }
+    });
+}
+
+/// Provides the fullscreen mode, toggled with F11 and synchronized with the window fullscreen.
+///
+/// Entering the fullscreen mode also requests the window to go fullscreen, and leaving the window
+/// fullscreen (for instance with Escape) leaves the fullscreen mode.
+///
+/// # Returns
+///
+/// The fullscreen mode, also provided as a `Signal<FullscreenMode>` context.
+pub fn use_fullscreen_mode() -> Signal<FullscreenMode> {
+    let mut use_fullscreen = use_context_provider(|| Signal::new(FullscreenMode::default()));
+    use_future(move || async move {
+        let mut shortcuts = document::eval(
+            r#"
+            document.addEventListener("keydown", (event) => {
+                if (event.key === "F11") {
+                    event.preventDefault();
+                    dioxus.send("toggle");
+                }
+            });
+            document.addEventListener("fullscreenchange", () => {
+                if (!document.fullscreenElement) {
+                    dioxus.send("exit");
+                }
+            });
+            "#,
+        );
+        while let Ok(shortcut) = shortcuts.recv::<String>().await {
+            let enabled = shortcut == "toggle" && !use_fullscreen().enabled;
+            use_fullscreen.write().enabled = enabled;
+        }
+    });
+    use_effect(move || {
+        let script = if use_fullscreen().enabled {
+            "if (!document.fullscreenElement) { document.documentElement.requestFullscreen?.().catch(() => {}); }"
+        } else {
+            "if (document.fullscreenElement) { document.exitFullscreen?.().catch(() => {}); }"
+        };
+        document::eval(script);
+    });
+    use_fullscreen
+}
+
+/// Applies the accessibility settings to the document root, whenever they change.
+///
+/// # Context:
+/// - `Signal<Settings>`: The font scale and the reduced-motion mode.
+pub fn use_accessibility() {
+    let use_settings = use_context::<Signal<Settings>>();
+    use_effect(move || {
+        document::eval(&use_settings().accessibility.script());
+    });
+}
+
+/// Applies the custom theme of the settings to the document root, whenever it changes.
+///
+/// # Context:
+/// - `Signal<Settings>`: The custom theme.
+pub fn use_theme() {
+    let use_settings = use_context::<Signal<Settings>>();
+    use_effect(move || {
+        document::eval(&use_settings().theme.script());
+    });
+}
+
+/// Applies the power mode of the settings to the document root, whenever it changes.
+///
+/// # Context:
+/// - `Signal<Settings>`: The power mode.
+pub fn use_power_mode() {
+    let use_settings = use_context::<Signal<Settings>>();
+    use_effect(move || {
+        document::eval(&use_settings().power_mode.script());
+    });
+}
+
+/// Provides the cache of the assets fetched at runtime, and prefetches some of them once the
+/// browser is idle, so they're ready when they're needed without being bundled in the binary.
+///
+/// # Arguments:
+/// - `urls`: The URLs of the assets to prefetch.
+///
+/// # Returns:
+/// - `Signal<AssetCache>`: The cache of the assets.
+pub fn use_asset_cache(urls: Vec<String>) -> Signal<AssetCache> {
+    let use_cache = use_context_provider(|| Signal::new(AssetCache::default()));
+    use_future(move || {
+        let urls = urls.clone();
+        async move {
+            idle().await;
+            for url in urls {
+                load_asset(use_cache, url).await;
+            }
+        }
+    });
+    use_cache
+}
+
+/// Fetches an asset into the cache, unless it's already being fetched or was fetched.
+///
+/// # Arguments:
+/// - `use_cache`: The cache of the assets.
+/// - `url`: The URL of the asset.
+pub async fn load_asset(mut use_cache: Signal<AssetCache>, url: String) {
+    if !use_cache.write().begin(&url) {
+        return;
+    }
+    info!("Fetching asset {url}");
+    let eval = document::eval(
+        r#"
+        const url = await dioxus.recv();
+        try {
+            const response = await fetch(url);
+            if (!response.ok) {
+                return { Err: `${response.status} ${response.statusText}` };
+            }
+            return { Ok: await response.text() };
+        } catch (error) {
+            return { Err: String(error) };
+        }
+        "#,
+    );
+    let result = match eval.send(url.clone()) {
+        Ok(()) => eval
+            .join::<Result<String, String>>()
+            .await
+            .unwrap_or_else(|error| Err(error.to_string())),
+        Err(error) => Err(error.to_string()),
+    };
+    if let Err(reason) = &result {
+        error!("Failed to fetch asset {url}: {reason}");
+    }
+    use_cache.write().finish(&url, result);
+}
+
+/// Waits until the browser is idle, or a short while where it can't tell.
+async fn idle() -> Option<()> {
+    document::eval(
+        "await new Promise((resolve) => (window.requestIdleCallback ?? ((callback) => setTimeout(callback, 200)))(resolve)); return true;",
+    )
+    .join::<bool>()
+    .await
+    .ok()
+    .map(|_| ())
+}
+
+/// A button entering the fullscreen mode (see `use_fullscreen_mode`).
+///
+/// # Context:
+/// - `Signal<FullscreenMode>`: The fullscreen mode.
+#[component]
+fn FullscreenButton() -> Element {
+    let mut use_fullscreen = use_context::<Signal<FullscreenMode>>();
+    rsx! {
+        button {
+            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+            title: "F11",
+            onclick: move |_| use_fullscreen.write().enabled = true,
+            {t!("button_fullscreen")}
+        }
+    }
+}
+
+/// An overlay mini-toolbar shown in fullscreen mode, to select the brush and leave the mode.
+///
+/// # Context:
+/// - `Signal<FullscreenMode>`: The fullscreen mode.
+/// - `Signal<NonogramPalette>`: The palette where the brush is selected.
+#[component]
+fn FullscreenToolbar() -> Element {
+    let mut use_fullscreen = use_context::<Signal<FullscreenMode>>();
+    rsx! {
+        div { class: "fixed top-2 left-1/2 -translate-x-1/2 z-50 flex flex-row flex-wrap justify-center items-center gap-3 px-4 py-2 rounded-lg shadow-lg bg-gray-900 bg-opacity-90",
+            ColorPalette { readonly: true }
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                title: "F11",
+                onclick: move |_| use_fullscreen.write().enabled = false,
+                {t!("button_exit_fullscreen")}
+            }
+        }
+    }
+}
+
+/// Plays a feedback event as a sound effect and a vibration, according to the settings.
+///
+/// The sound effects are synthesized with the Web Audio API and the vibration uses the Vibration
+/// API, so they work in the browser and in the desktop and mobile webviews (where supported).
+///
+/// # Arguments:
+/// - `feedback`: The feedback event to play.
+/// - `settings`: The volume and whether the device vibrates.
+fn play_feedback(feedback: Feedback, settings: &Settings) {
+    let volume = settings.volume.clamp(0.0, 1.0);
+    if volume > 0.0 {
+        let tones = feedback
+            .tones()
+            .iter()
+            .map(|(frequency, duration)| format!("[{frequency}, {duration}]"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        document::eval(&format!(
+            r#"
+            const AudioContext = window.AudioContext || window.webkitAudioContext;
+            if (AudioContext) {{
+                const context = window.ngramAudio = window.ngramAudio || new AudioContext();
+                let time = context.currentTime;
+                for (const [frequency, duration] of [{tones}]) {{
+                    const oscillator = context.createOscillator();
+                    const gain = context.createGain();
+                    oscillator.frequency.value = frequency;
+                    gain.gain.setValueAtTime({volume}, time);
+                    gain.gain.exponentialRampToValueAtTime(0.001, time + duration);
+                    oscillator.connect(gain).connect(context.destination);
+                    oscillator.start(time);
+                    oscillator.stop(time + duration);
+                    time += duration;
+                }}
+            }}
+            "#
+        ));
+    }
+    if settings.vibration {
+        let pattern = feedback
+            .vibration()
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        document::eval(&format!("navigator.vibrate?.([{pattern}]);"));
+    }
+}
+
+/// Plays the feedback of the actions recorded in a page's journal, and of completing the puzzle.
+///
+/// In the solver, painting cells that don't match the solution of the puzzle is a mistake, played
+/// and flashed on the grid unless the assist level hides mistakes.
+///
+/// # Arguments:
+/// - `page`: The page whose actions are played.
+///
+/// # Context:
+/// - `Signal<Settings>`: The volume, whether the device vibrates and the assist level (optional,
+///   nothing is played without it).
+/// - `Signal<ActionLog>`: Notifies every modification of the solution grid.
+/// - `Signal<NonogramFile>`: The puzzle being solved, to detect mistakes (in the solver).
+/// - `Signal<NonogramData>`: Whether the puzzle is completed, and whether it's blind (without mistakes).
+fn use_feedback(page: SessionPage) {
+    let use_settings = try_use_context::<Signal<Settings>>();
+    let mut use_log = use_context::<Signal<ActionLog>>();
+    let use_file =
+        try_use_context::<Signal<NonogramFile>>().filter(|_| page == SessionPage::Solver);
+    let use_data = use_context::<Signal<NonogramData>>();
+    use_hook(move || {
+        let Some(use_settings) = use_settings else {
+            return;
+        };
+        use_log.write().subscribe(move |entry| {
+            let reference = use_file
+                .filter(|_| !use_data.peek().blind)
+                .filter(|_| use_settings.peek().assist_level.assists().mistakes)
+                .map(|use_file| use_file.peek().solution.clone());
+            if let Some(feedback) = Feedback::of_action(&entry.action, reference.as_ref()) {
+                if feedback == Feedback::Mistake {
+                    document::eval(
+                        "document.getElementById('solution-grid')?.closest('table')?.animate([{ outlineColor: 'red' }, { outlineColor: 'transparent' }], { duration: 400 });",
+                    );
+                }
+                play_feedback(feedback, &use_settings.peek());
+            }
+        });
+    });
+    let mut use_completed = use_signal(|| false);
+    use_effect(move || {
+        let completed = use_data().completed;
+        if completed && !*use_completed.peek() {
+            if let Some(use_settings) = use_settings {
+                play_feedback(Feedback::Completion, &use_settings.peek());
+            }
+        }
+        *use_completed.write() = completed;
+    });
+}
+
+/// Runs the solve timer of the solver, pausing it when the window loses the focus or no input
+/// occurs for the minutes set in the settings.
+///
+/// The timer restarts when a puzzle is loaded, and stops when the puzzle is completed. The
+/// window events and timestamps come from the webview, so the timer works on every platform.
+/// The timer ticks every second, or less often in the low-power mode.
+///
+/// # Context:
+/// - `Signal<Settings>`: The minutes without input pausing the timer (optional, 2 minutes by
+///   default), and the power mode.
+/// - `Signal<ActionLog>`: Notifies the loaded puzzles and the modifications of the solution grid.
+/// - `Signal<NonogramData>`: Whether the puzzle is completed.
+///
+/// # Returns
+///
+/// The solve timer and the current timestamp, also provided as contexts.
+fn use_solve_timer() -> (Signal<Option<SolveTimer>>, Signal<f64>) {
+    let use_settings = try_use_context::<Signal<Settings>>();
+    let mut use_log = use_context::<Signal<ActionLog>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let mut use_timer = use_context_provider(|| Signal::new(None::<SolveTimer>));
+    let mut use_now = use_context_provider(|| Signal::new(0.0f64));
+    use_hook(move || {
+        use_log.write().subscribe(move |entry| {
+            if entry.action.tool() == Some(Tool::Load) {
+                *use_timer.write() = None;
+            } else if let Some(timer) = use_timer.write().as_mut() {
+                timer.input(*use_now.peek());
+            }
+        });
+    });
+    use_future(move || async move {
+        let mut events = document::eval(
+            r#"
+            const send = (event) => dioxus.send([event, Date.now()]);
+            window.addEventListener("blur", () => send("blur"));
+            window.addEventListener("focus", () => send("focus"));
+            for (const name of ["pointerdown", "keydown", "wheel", "touchstart"]) {
+                document.addEventListener(name, () => send("input"), { passive: true });
+            }
+            let ticker = setInterval(() => send("tick"), 1000);
+            send("tick");
+            while (true) {
+                const millis = await dioxus.recv();
+                clearInterval(ticker);
+                ticker = setInterval(() => send("tick"), millis);
+            }
+            "#,
+        );
+        let mut tick_millis = PowerMode::Normal.tick_millis();
+        while let Ok((event, now)) = events.recv::<(String, f64)>().await {
+            *use_now.write() = now;
+            let power_mode = use_settings
+                .map(|use_settings| use_settings.peek().power_mode)
+                .unwrap_or_default();
+            if power_mode.tick_millis() != tick_millis {
+                tick_millis = power_mode.tick_millis();
+                let _ = events.send(tick_millis);
+            }
+            let idle_minutes = use_settings
+                .map(|use_settings| use_settings.peek().idle_minutes)
+                .unwrap_or(2)
+                .max(1);
+            let mut timer = use_timer.write();
+            let timer = timer.get_or_insert_with(|| SolveTimer::start(now));
+            match event.as_str() {
+                "blur" => timer.blur(now),
+                "focus" => timer.focus(now),
+                "input" => timer.input(now),
+                _ => timer.tick(now, idle_minutes as f64 * 60_000.0),
+            }
+            if use_data.peek().completed {
+                timer.complete(now);
+            }
+        }
+    });
+    (use_timer, use_now)
+}
+
+/// Applies the assists of the assist level chosen for the solver, and records the completions of
+/// the session with the loosest level used since the puzzle was loaded, so purist solves are
+/// distinguishable.
+///
+/// With auto-fill, the trivial cells of every loaded puzzle are painted when they're still
+/// background.
+///
+/// # Context:
+/// - `Signal<Settings>`: The assist level (optional, casual by default).
+/// - `Signal<SessionHistory>`: Where the completions are recorded (optional).
+/// - `Signal<ActionLog>`: Notifies the loaded puzzles.
+/// - `Signal<NonogramData>`: The name of the puzzle, whether it's completed and whether it's blind.
+/// - `Signal<NonogramPuzzle>`: The puzzle being solved.
+/// - `Signal<NonogramSolution>`: The grid auto-filled.
+fn use_assists() {
+    let use_settings = try_use_context::<Signal<Settings>>();
+    let use_session = try_use_context::<Signal<SessionHistory>>();
+    let mut use_log = use_context::<Signal<ActionLog>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let dispatcher = use_dispatcher();
+    let level = move || {
+        use_settings
+            .map(|use_settings| use_settings().assist_level)
+            .unwrap_or_default()
+    };
+    let mut use_loosest = use_signal(level);
+    use_hook(move || {
+        use_log.write().subscribe(move |entry| {
+            if entry.action.tool() == Some(Tool::Load) {
+                let level = use_settings
+                    .map(|use_settings| use_settings.peek().assist_level)
+                    .unwrap_or_default();
+                *use_loosest.write() = level;
+            }
+        });
+    });
+    use_effect(move || {
+        let level = level();
+        if level > *use_loosest.peek() {
+            *use_loosest.write() = level;
+        }
+    });
+    use_effect(move || {
+        let Some(mut use_session) = use_session else {
+            return;
+        };
+        let data = use_data();
+        if data.completed && !data.blind {
+            let name = if data.filename.is_empty() {
+                t!("untitled")
+            } else {
+                data.filename
+            };
+            let level = *use_loosest.peek();
+            info!("Completed {name} with the {level:?} assist level");
+            use_session.write().record_completion(&name, level);
+        }
+    });
+    use_effect(move || {
+        let puzzle = use_puzzle();
+        if !level().assists().auto_fill {
+            return;
+        }
+        let mut solution = use_solution.peek().clone();
+        let mut filled = false;
+        for (i, row) in trivial_cells(&puzzle).into_iter().enumerate() {
+            for (j, color) in row.into_iter().enumerate() {
+                let Some(color) = color.filter(|&color| color != BACKGROUND) else {
+                    continue;
+                };
+                if let Some(cell) = solution
+                    .solution_grid
+                    .get_mut(i)
+                    .and_then(|row| row.get_mut(j))
+                {
+                    if *cell == BACKGROUND {
+                        *cell = color;
+                        filled = true;
+                    }
+                }
+            }
+        }
+        if filled {
+            info!("Auto-filled the trivial cells of the nonogram");
+            dispatcher.dispatch(GridCommand::Replace {
+                tool: Tool::Solver,
+                solution,
+            });
+        }
+    });
+}
+
+/// Locks the solve buttons and hints again, in the strict mode, every time a puzzle is loaded.
+///
+/// # Context:
+/// - `Signal<ActionLog>`: Notifies the loaded puzzles.
+/// - `Signal<StrictUnlock>`: The unlock reset.
+fn use_strict_mode() {
+    let mut use_log = use_context::<Signal<ActionLog>>();
+    let mut use_unlock = use_context::<Signal<StrictUnlock>>();
+    use_hook(move || {
+        use_log.write().subscribe(move |entry| {
+            if entry.action.tool() == Some(Tool::Load)
+                && *use_unlock.peek() != StrictUnlock::default()
+            {
+                use_unlock.set(StrictUnlock::default());
+            }
+        });
+    });
+}
+
+/// Unpins every cell of the grid every time a puzzle is loaded.
+///
+/// # Context:
+/// - `Signal<ActionLog>`: Notifies the loaded puzzles.
+/// - `Signal<PinnedCells>`: The cells unpinned.
+fn use_pinned_cells() {
+    let mut use_log = use_context::<Signal<ActionLog>>();
+    let mut use_pinned = use_context::<Signal<PinnedCells>>();
+    use_hook(move || {
+        use_log.write().subscribe(move |entry| {
+            if entry.action.tool() == Some(Tool::Load) && !use_pinned.peek().cells.is_empty() {
+                use_pinned.set(PinnedCells::default());
+            }
+        });
+    });
+}
+
+/// Looks up the puzzles imported from their clues in the local library, every time the clues
+/// change, so the solutions cached by the exact solver can be previewed and compared.
+///
+/// # Context:
+/// - `Signal<ActiveProfile>`: The profile whose library is loaded (optional, the guest library by default).
+/// - `Signal<NonogramPuzzle>`: The puzzle looked up.
+/// - `Signal<NonogramFile>`: Updated with the cached solution.
+/// - `Signal<NonogramData>`: Whether the puzzle is blind, and whether its solution is cached.
+///
+/// # Returns
+///
+/// The library of the profile, also provided as a `Signal<SolutionLibrary>` context.
+fn use_solution_library() -> Signal<SolutionLibrary> {
+    let use_active = try_use_context::<Signal<ActiveProfile>>();
+    let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let mut use_file = use_context::<Signal<NonogramFile>>();
+    let mut use_data = use_context::<Signal<NonogramData>>();
+    let use_library = use_context_provider(|| {
+        info!("Loading the library of solutions");
+        let profile = use_active.and_then(|use_active| use_active.peek().profile.clone());
+        Signal::new(SolutionLibrary::load_profile(profile.as_ref()))
+    });
+    use_effect(move || {
+        let puzzle = use_puzzle();
+        if !use_data.peek().blind {
+            return;
+        }
+        let cached = use_library.peek().get(&puzzle).cloned();
+        use_data.write().solution_cached = cached.is_some();
+        if let Some(solution) = cached {
+            info!("Found the solution of the imported clues in the library");
+            use_file.write().solution = solution;
+        }
+    });
+    use_library
+}
+
+/// Displays a progress bar estimating how close the grid is to completing the puzzle, updated
+/// live while it's painted.
+///
+/// # Arguments:
+/// - `criterion`: The completion criterion applied, which decides whether lines or cells are counted.
+///
+/// # Context:
+/// - `Signal<NonogramPuzzle>`: The puzzle being solved.
+/// - `Signal<NonogramSolution>`: The grid painted.
+/// - `Signal<NonogramFile>`: The solution stored in the puzzle file.
+#[component]
+fn SolveProgress(criterion: Completion) -> Element {
+    let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_file = use_context::<Signal<NonogramFile>>();
+    let progress = use_memo(use_reactive!(|criterion| {
+        criterion.progress(&use_puzzle(), &use_file().solution, &use_solution())
+    }));
+    let percent = (progress() * 100.0).floor();
+    rsx! {
+        div { class: "flex flex-row justify-center items-center gap-3 my-4",
+            span { class: "text-gray-200 font-semibold select-none",
+                match criterion {
+                    Completion::Constraints => t!("progress_lines"),
+                    Completion::ExactImage => t!("progress_cells"),
+                }
+                ":"
+            }
+            div {
+                class: "w-64 h-3 rounded-full bg-gray-700 overflow-hidden",
+                role: "progressbar",
+                aria_valuenow: "{percent}",
+                aria_valuemin: 0,
+                aria_valuemax: 100,
+                div {
+                    class: "h-full bg-green-600 transition-all",
+                    width: "{percent}%",
+                }
+            }
+            span { class: "w-12 font-mono text-gray-200", "{percent}%" }
+        }
+    }
+}
+
+/// Chooses the conventions for displaying numbers, durations and dates in the language of the
+/// interface, rendering the component again when the language changes.
+///
+/// # Context:
+/// - `I18n`: The language of the interface.
+fn use_locale_format() -> LocaleFormat {
+    LocaleFormat::new(&i18n().language())
+}
+
+/// Displays the solve timer, and why it's paused.
+///
+/// # Context:
+/// - `Signal<Option<SolveTimer>>`: The solve timer.
+/// - `Signal<f64>`: The current timestamp.
+/// - `Signal<Settings>`: The minutes without input pausing the timer.
+/// - `I18n`: The language formatting the hours of the timer.
+#[component]
+fn SolveTimerDisplay() -> Element {
+    let locale = use_locale_format();
+    let use_timer = use_context::<Signal<Option<SolveTimer>>>();
+    let use_now = use_context::<Signal<f64>>();
+    let mut use_settings = use_context::<Signal<Settings>>();
+    let Some(timer) = use_timer() else {
+        return rsx! {};
+    };
+    let paused = match timer.paused() {
+        Some(PauseReason::Idle) => Some(t!("timer_paused_idle")),
+        Some(PauseReason::Unfocused) => Some(t!("timer_paused_unfocused")),
+        Some(PauseReason::Completed) | None => None,
+    };
+    rsx! {
+        div { class: "flex flex-row flex-wrap justify-center items-center gap-6 my-4",
+            span { class: "text-3xl font-mono font-bold", "{locale.clock(timer.elapsed(use_now()))}" }
+            if let Some(paused) = paused {
+                span { class: "px-3 py-1 rounded bg-amber-600 text-white font-semibold select-none",
+                    {paused}
+                }
+            }
+            NumberInput {
+                id: "idle-input",
+                label: t!("label_idle_minutes"),
+                value: use_settings().idle_minutes as usize,
+                min: 1,
+                max: 60,
+                onchange: move |minutes: usize| {
+                    use_settings.write().idle_minutes = minutes as u32;
+                    if !use_settings().save() {
+                        error!("Couldn't store the settings");
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[cfg(not(feature = "update"))]
+/// Displays nothing on platforms without the update checker.
+#[component]
+pub fn UpdateToast() -> Element {
+    rsx! {}
+}
+
+#[cfg(feature = "update")]
+/// A toast notifying that a newer release was published on GitHub, with a link to download it.
+///
+/// The check runs once in the background when the component is mounted, unless it was disabled
+/// in the settings, which the toast also allows. Nothing is downloaded nor installed.
+///
+/// # Context:
+/// - `Signal<Settings>`: Whether the updates are checked.
+#[component]
+pub fn UpdateToast() -> Element {
+    use crate::nonogram::update::{check_for_update, Release, CURRENT_VERSION};
+
+    let mut use_settings = use_context::<Signal<Settings>>();
+    let mut use_release = use_signal(|| None::<Release>);
+    use_hook(move || {
+        if !use_settings.peek().check_updates {
+            return;
+        }
+        // The request blocks, so it's sent from another thread.
+        let (sender, receiver) = futures::channel::oneshot::channel();
+        std::thread::spawn(move || {
+            let _ = sender.send(check_for_update(CURRENT_VERSION));
+        });
+        spawn(async move {
+            match receiver.await {
+                Ok(Ok(Some(release))) => {
+                    info!("Version {} is available", release.version);
+                    *use_release.write() = Some(release);
+                }
+                Ok(Ok(None)) => info!("Version {CURRENT_VERSION} is up to date"),
+                Ok(Err(err)) => error!("Couldn't check for updates: {err}"),
+                Err(_) => error!("The update check was interrupted"),
+            }
+        });
+    });
+    let Some(release) = use_release() else {
+        return rsx! {};
+    };
+    rsx! {
+        div { class: "fixed bottom-4 right-4 z-50 flex flex-col gap-3 p-4 max-w-sm rounded-lg border border-gray-600 bg-gray-800 text-white shadow-lg",
+            span { class: "font-semibold",
+                {t!("update_available", version : release.version.to_string())}
+            }
+            div { class: "flex flex-row flex-wrap gap-3",
+                a {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-blue-800 text-white hover:bg-blue-700",
+                    href: "{release.url}",
+                    target: "_blank",
+                    {t!("button_download_update")}
+                }
+                button {
+                    class: "px-4 py-1 rounded border border-gray-500 bg-gray-700 text-white hover:bg-gray-600",
+                    onclick: move |_| *use_release.write() = None,
+                    {t!("button_dismiss")}
+                }
+                button {
+                    class: "px-4 py-1 rounded text-gray-400 hover:text-white",
+                    onclick: move |_| {
+                        *use_release.write() = None;
+                        use_settings.write().check_updates = false;
+                        if !use_settings().save() {
+                            error!("Couldn't store the settings");
+                        }
+                    },
+                    {t!("button_stop_update_checks")}
+                }
+            }
+        }
+    }
+}
+
+/// A panel for the settings of the pointer interaction with the grid: what a tap does, whether
+/// only a stylus draws, and whether its pressure chooses the color.
+///
+/// # Context:
+/// - `Signal<Settings>`: Stores the interaction settings.
+#[component]
+fn InteractionPanel() -> Element {
+    let mut use_settings = use_context::<Signal<Settings>>();
+    let save_settings = move || {
+        if !use_settings().save() {
+            error!("Couldn't store the settings");
+        }
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_interaction")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    label {
+                        r#for: "tap-select",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_tap")}
+                        ":"
+                    }
+                    select {
+                        id: "tap-select",
+                        class: "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform cursor-pointer",
+                        onchange: move |event: FormEvent| {
+                            use_settings.write().interaction.tap = match event.value().as_str() {
+                                "toggle" => TapAction::Toggle,
+                                _ => TapAction::Paint,
+                            };
+                            save_settings();
+                        },
+                        option {
+                            value: "paint",
+                            selected: use_settings().interaction.tap == TapAction::Paint,
+                            {t!("option_tap_paint")}
+                        }
+                        option {
+                            value: "toggle",
+                            selected: use_settings().interaction.tap == TapAction::Toggle,
+                            {t!("option_tap_toggle")}
+                        }
+                    }
+                }
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    input {
+                        id: "stylus-only-input",
+                        r#type: "checkbox",
+                        class: "w-5 h-5 cursor-pointer",
+                        checked: use_settings().interaction.stylus_only,
+                        onchange: move |event: FormEvent| {
+                            use_settings.write().interaction.stylus_only = event.checked();
+                            save_settings();
+                        },
+                    }
+                    label {
+                        r#for: "stylus-only-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_stylus_only")}
+                    }
+                }
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    input {
+                        id: "pressure-input",
+                        r#type: "checkbox",
+                        class: "w-5 h-5 cursor-pointer",
+                        checked: use_settings().interaction.pressure,
+                        onchange: move |event: FormEvent| {
+                            use_settings.write().interaction.pressure = event.checked();
+                            save_settings();
+                        },
+                    }
+                    label {
+                        r#for: "pressure-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_pressure")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A panel loading a custom theme from a JSON file of CSS colors (see `Theme`), which replaces the
+/// colors of the interface and is persisted in the settings.
+///
+/// # Context:
+/// - `Signal<Settings>`: Stores the custom theme.
+#[component]
+fn ThemePanel() -> Element {
+    let mut use_settings = use_context::<Signal<Settings>>();
+    let mut use_error = use_signal(|| None::<ThemeError>);
+    let mut set_theme = move |theme: Theme| {
+        use_settings.write().theme = theme;
+        if !use_settings().save() {
+            error!("Couldn't store the settings");
+        }
+    };
+    let load_theme_onchange = move |event: FormEvent| async move {
+        let Some(file_engine) = event.files() else {
+            error!("Event hadn't a file engine attached: {event:?}");
+            return;
+        };
+        let Some(file) = file_engine.files().into_iter().next() else {
+            error!("File engine had no attached files");
+            return;
+        };
+        let Some(json) = file_engine.read_file_to_string(&file).await else {
+            error!("Couldn't read file: '{file}'");
+            return;
+        };
+        match Theme::parse(&json) {
+            Ok(theme) => {
+                set_theme(theme);
+                use_error.set(None);
+                info!("Theme loaded from '{file}'");
+            }
+            Err(err) => {
+                error!("Couldn't load the theme '{file}': {err:?}");
+                use_error.set(Some(err));
+            }
+        }
+    };
+    let error_message = move || {
+        use_error().map(|err| match err {
+            ThemeError::Malformed => t!("theme_malformed"),
+            ThemeError::InvalidColor(color) => t!("theme_invalid_color", color : color),
+        })
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_theme")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                input {
+                    class: "appearance-none rounded border px-4 py-1 border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform cursor-pointer",
+                    r#type: "file",
+                    accept: ".json",
+                    multiple: false,
+                    onchange: load_theme_onchange,
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    disabled: use_settings().theme == Theme::default(),
+                    onclick: move |_| {
+                        set_theme(Theme::default());
+                        info!("Theme reset to the built-in look");
+                    },
+                    {t!("button_reset_theme")}
+                }
+            }
+            if let Some(message) = error_message() {
+                p { class: "text-red-400 text-center", "{message}" }
+            }
+        }
+    }
+}
+
+/// A panel for the accessibility settings: the font scale, the reduced-motion mode and the
+/// alignment of the clues.
+///
+/// # Context:
+/// - `Signal<Settings>`: Stores the accessibility settings.
+#[component]
+fn AccessibilityPanel() -> Element {
+    let mut use_settings = use_context::<Signal<Settings>>();
+    let save_settings = move || {
+        if !use_settings().save() {
+            error!("Couldn't store the settings");
+        }
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_accessibility")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    label {
+                        r#for: "font-scale-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_font_scale")}
+                        ":"
+                    }
+                    input {
+                        id: "font-scale-input",
+                        r#type: "range",
+                        class: "cursor-pointer",
+                        min: (Accessibility::MIN_FONT_SCALE * 100.0) as u32,
+                        max: (Accessibility::MAX_FONT_SCALE * 100.0) as u32,
+                        step: 5,
+                        value: "{(use_settings().accessibility.font_scale * 100.0).round()}",
+                        onchange: move |event: FormEvent| {
+                            if let Ok(percent) = event.value().parse::<f32>() {
+                                use_settings.write().accessibility.font_scale = (percent / 100.0)
+                                    .clamp(Accessibility::MIN_FONT_SCALE, Accessibility::MAX_FONT_SCALE);
+                                save_settings();
+                            }
+                        },
+                    }
+                    span { class: "w-12 font-mono text-gray-200",
+                        "{(use_settings().accessibility.font_scale * 100.0).round()}%"
+                    }
+                }
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    input {
+                        id: "reduced-motion-input",
+                        r#type: "checkbox",
+                        class: "w-5 h-5 cursor-pointer",
+                        checked: use_settings().accessibility.reduced_motion,
+                        onchange: move |event: FormEvent| {
+                            use_settings.write().accessibility.reduced_motion = event.checked();
+                            save_settings();
+                        },
+                    }
+                    label {
+                        r#for: "reduced-motion-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_reduced_motion")}
+                    }
+                }
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    label {
+                        r#for: "clue-alignment-select",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_clue_alignment")}
+                        ":"
+                    }
+                    select {
+                        id: "clue-alignment-select",
+                        class: "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform cursor-pointer",
+                        onchange: move |event: FormEvent| {
+                            use_settings.write().clue_alignment = match event.value().as_str() {
+                                "edge" => ClueAlignment::Edge,
+                                _ => ClueAlignment::Grid,
+                            };
+                            save_settings();
+                        },
+                        option {
+                            value: "grid",
+                            selected: use_settings().clue_alignment == ClueAlignment::Grid,
+                            {t!("option_clues_grid")}
+                        }
+                        option {
+                            value: "edge",
+                            selected: use_settings().clue_alignment == ClueAlignment::Edge,
+                            {t!("option_clues_edge")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A panel for the settings of the sound effects and the vibration.
+///
+/// # Context:
+/// - `Signal<Settings>`: Stores the volume and whether the device vibrates.
+#[component]
+fn FeedbackPanel() -> Element {
+    let mut use_settings = use_context::<Signal<Settings>>();
+    let save_settings = move || {
+        if !use_settings().save() {
+            error!("Couldn't store the settings");
+        }
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_feedback")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    label {
+                        r#for: "volume-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_volume")}
+                        ":"
+                    }
+                    input {
+                        id: "volume-input",
+                        r#type: "range",
+                        class: "cursor-pointer",
+                        min: 0,
+                        max: 100,
+                        value: "{(use_settings().volume * 100.0).round()}",
+                        onchange: move |event: FormEvent| {
+                            if let Ok(volume) = event.value().parse::<f32>() {
+                                use_settings.write().volume = (volume / 100.0).clamp(0.0, 1.0);
+                                save_settings();
+                                play_feedback(Feedback::Paint, &use_settings());
+                            }
+                        },
+                    }
+                }
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    input {
+                        id: "vibration-input",
+                        r#type: "checkbox",
+                        class: "w-5 h-5 cursor-pointer",
+                        checked: use_settings().vibration,
+                        onchange: move |event: FormEvent| {
+                            use_settings.write().vibration = event.checked();
+                            save_settings();
+                        },
+                    }
+                    label {
+                        r#for: "vibration-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_vibration")}
+                    }
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: move |_| play_feedback(Feedback::Completion, &use_settings()),
+                    {t!("button_test_feedback")}
+                }
+            }
+        }
+    }
+}
+
+/// Adapts the block size of a page to the viewport and puzzle dimensions.
+///
+/// The block size is computed again when the window is resized or the puzzle dimensions change,
+/// unless the user set it manually (see `BlockSizeInput`).
+///
+/// # Arguments:
+/// - `page`: The page whose puzzle is displayed: the loaded puzzle in the solver, or the puzzle
+///   of the solution grid in the editor.
+///
+/// # Context:
+/// - `Signal<NonogramData>`: The block size and whether it's computed automatically.
+/// - `Signal<NonogramPuzzle>`: The puzzle being solved (in the solver).
+/// - `Signal<NonogramSolution>`: The solution grid being edited (in the editor).
+fn use_auto_block_size(page: SessionPage) {
+    let mut use_data = use_context::<Signal<NonogramData>>();
+    let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_viewport = use_viewport();
+    use_effect(move || {
+        let Some((width, height)) = use_viewport() else {
+            return;
+        };
+        if !use_data().auto_block_size {
+            return;
+        }
+        let block_size = match page {
+            SessionPage::Solver => use_puzzle().auto_block_size(width, height),
+            SessionPage::Editor => {
+                NonogramPuzzle::from_solution(&use_solution()).auto_block_size(width, height)
+            }
+        };
+        if use_data.peek().block_size != block_size {
+            info!("Adapting the block size to {block_size}px");
+            use_data.write().block_size = block_size;
+        }
+    });
+}
+
+/// Tracks the size of the browser window, or of the desktop window.
+///
+/// # Returns:
+/// The width and height of the viewport in pixels, or `None` until they're first received.
+fn use_viewport() -> Signal<Option<(f64, f64)>> {
+    let mut use_viewport = use_signal(|| None::<(f64, f64)>);
+    use_future(move || async move {
+        let mut viewport = document::eval(
+            r#"
+            const send = () => dioxus.send([window.innerWidth, window.innerHeight]);
+            window.addEventListener("resize", send);
+            send();
+            "#,
+        );
+        while let Ok(size) = viewport.recv::<(f64, f64)>().await {
+            *use_viewport.write() = Some(size);
+        }
+    });
+    use_viewport
+}
+
+/// Displays the avatar of a profile: its initial over its color.
+///
+/// # Arguments:
+/// - `profile`: The profile, or `None` for the guest.
+#[component]
+fn ProfileAvatar(profile: Option<Profile>) -> Element {
+    let (initial, color) = match &profile {
+        Some(profile) => (profile.initial(), profile.color.clone()),
+        None => (String::from("?"), String::from("#6b7280")),
+    };
+    rsx! {
+        span {
+            class: "inline-flex justify-center items-center w-8 h-8 rounded-full font-bold text-white select-none",
+            style: "background-color: {color}",
+            "{initial}"
+        }
+    }
+}
+
+/// Switches to a profile, loading its settings and starting a new session.
+///
+/// # Arguments:
+/// - `profile`: The chosen profile, or `None` for the guest.
+fn choose_profile(
+    profile: Option<Profile>,
+    mut use_active: Signal<ActiveProfile>,
+    mut use_settings: Signal<Settings>,
+    mut use_session: Signal<SessionHistory>,
+) {
+    info!(
+        "Using the profile '{}'",
+        profile.as_ref().map_or("guest", |profile| &profile.name)
+    );
+    *use_settings.write() = Settings::load_profile(profile.as_ref());
+    *use_session.write() = SessionHistory::default();
+    *use_active.write() = ActiveProfile {
+        profile,
+        chosen: true,
+    };
+}
+
+/// The screen for choosing, creating and removing the local profiles, displayed at startup.
+///
+/// Each profile has its own settings and data, so several users can share the same device.
+///
+/// # Context:
+/// - `Signal<Profiles>`: The local profiles.
+/// - `Signal<ActiveProfile>`: The profile in use.
+/// - `Signal<Settings>`: The settings of the profile in use.
+/// - `Signal<SessionHistory>`: The puzzles touched during the session, reset on switching profile.
+#[component]
+pub fn ProfilePicker() -> Element {
+    let mut use_profiles = use_context::<Signal<Profiles>>();
+    let use_active = use_context::<Signal<ActiveProfile>>();
+    let use_settings = use_context::<Signal<Settings>>();
+    let use_session = use_context::<Signal<SessionHistory>>();
+    let mut use_name = use_signal(String::new);
+    let mut use_color = use_signal(|| String::from("#3b82f6"));
+    let save_profiles = move || {
+        if !use_profiles().save() {
+            error!("Couldn't store the profiles");
+        }
+    };
+    let create_onclick = move |_| {
+        let name = use_name().trim().to_string();
+        if name.is_empty() {
+            return;
+        }
+        let profile = use_profiles.write().add(&name, &use_color());
+        save_profiles();
+        use_name.write().clear();
+        choose_profile(Some(profile), use_active, use_settings, use_session);
+    };
+    rsx! {
+        main { class: "flex flex-col gap-10 items-center min-h-screen py-20",
+            h1 { class: "text-4xl font-bold text-center", {t!("title_profiles")} }
+            section { class: "container flex flex-col items-center gap-3 p-6 rounded-lg shadow-lg bg-gray-900",
+                for profile in use_profiles().profiles {
+                    div {
+                        key: "profile-{profile.id}",
+                        class: "flex flex-row items-center gap-3",
+                        button {
+                            class: "flex flex-row items-center gap-3 px-4 py-2 min-w-64 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800",
+                            onclick: {
+                                let profile = profile.clone();
+                                move |_| choose_profile(Some(profile.clone()), use_active, use_settings, use_session)
+                            },
+                            ProfileAvatar { profile: Some(profile.clone()) }
+                            span { class: "font-semibold", "{profile.name}" }
+                        }
+                        button {
+                            class: "px-3 py-2 rounded border border-gray-500 bg-gray-800 text-red-400 hover:bg-red-900",
+                            title: t!("button_remove_profile"),
+                            onclick: {
+                                let profile = profile.clone();
+                                move |_| {
+                                    use_profiles.write().remove(&profile.id);
+                                    if !Settings::remove_profile(&profile) {
+                                        error!("Couldn't remove the settings of '{}'", profile.name);
+                                    }
+                                    save_profiles();
+                                }
+                            },
+                            "✕"
+                        }
+                    }
+                }
+                button {
+                    class: "flex flex-row items-center gap-3 px-4 py-2 min-w-64 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800",
+                    onclick: move |_| choose_profile(None, use_active, use_settings, use_session),
+                    ProfileAvatar { profile: None }
+                    span { class: "font-semibold", {t!("button_guest")} }
+                }
+            }
+            section { class: "container flex flex-row flex-wrap justify-center items-center gap-3 p-6 rounded-lg shadow-lg bg-gray-900",
+                input {
+                    class: "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 focus:ring focus:ring-blue-500 focus:outline-none",
+                    r#type: "text",
+                    placeholder: t!("label_profile_name"),
+                    value: "{use_name}",
+                    oninput: move |event| *use_name.write() = event.value(),
+                }
+                input {
+                    r#type: "color",
+                    class: "appearance-none w-10 h-10 border outline-none cursor-pointer",
+                    value: "{use_color}",
+                    onchange: move |event| *use_color.write() = event.value(),
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: create_onclick,
+                    {t!("button_create_profile")}
+                }
+            }
+        }
+    }
+}
+
+/// A button in the header with the avatar of the profile in use, opening the profile picker.
+///
+/// # Context:
+/// - `Signal<ActiveProfile>`: The profile in use.
+#[component]
+pub fn ProfileMenu() -> Element {
+    let mut use_active = use_context::<Signal<ActiveProfile>>();
+    let profile = use_active().profile;
+    let name = profile
+        .as_ref()
+        .map_or_else(|| t!("button_guest"), |profile| profile.name.clone());
+    rsx! {
+        button {
+            class: "flex flex-row items-center gap-2 bg-gray-700 text-white border border-gray-600 rounded-md p-1 pr-3 hover:bg-gray-600 transition ease-in-out duration-200",
+            title: t!("button_switch_profile"),
+            onclick: move |_| use_active.write().chosen = false,
+            ProfileAvatar { profile }
+            span { "{name}" }
+        }
+    }
+}
+
+/// Displays the seed of the session in the header, and lets users enter another one to reproduce
+/// a previous session: the randomized actions repeat once the same seed is entered.
+///
+/// # Context:
+/// - `Signal<SessionRng>`: The random number generator service of the session.
+#[component]
+pub fn SessionSeed() -> Element {
+    let mut use_rng = use_context::<Signal<SessionRng>>();
+    let mut use_text = use_signal(|| use_rng.peek().seed().to_string());
+    let mut use_invalid = use_signal(|| false);
+    let mut apply = move || match use_text().trim().parse::<u64>() {
+        Ok(seed) => {
+            use_rng.write().reseed(seed);
+            *use_invalid.write() = false;
+            info!("Session seeded with {seed}");
+        }
+        Err(err) => {
+            error!("Invalid session seed: {err}");
+            *use_invalid.write() = true;
+        }
+    };
+    rsx! {
+        div {
+            class: "flex flex-row items-center gap-2 text-white",
+            title: t!("hint_session_seed", draws : use_rng().draws()),
+            label { r#for: "session-seed-input", class: "select-none", {t!("label_session_seed")} }
+            input {
+                id: "session-seed-input",
+                r#type: "text",
+                inputmode: "numeric",
+                class: "w-48 px-2 py-1 font-mono rounded-md border bg-gray-700 text-white",
+                border_color: if use_invalid() { "#ef4444" } else { "#4b5563" },
+                value: use_text(),
+                oninput: move |event: FormEvent| *use_text.write() = event.value(),
+                onchange: move |_| apply(),
+            }
+        }
+    }
+}
+
+/// A dropdown listing the puzzles touched during the session, with thumbnails.
+///
+/// Selecting a puzzle reopens it in its page, with the state it had when it was last touched, so
+/// switching among several puzzles doesn't require loading their files again.
+///
+/// # Arguments:
+/// - `onjump`: Called with the page where the selected puzzle is reopened, to navigate to it.
+///
+/// # Context:
+/// - `Signal<SessionHistory>`: The puzzles touched during the session, and the assist level of
+///   their completions.
+#[component]
+pub fn SessionMenu(onjump: EventHandler<SessionPage>) -> Element {
+    let mut use_session = use_context::<Signal<SessionHistory>>();
+    let mut use_open = use_signal(|| false);
+    rsx! {
+        div { class: "relative",
+            button {
+                class: "appearance-none bg-gray-700 text-white border border-gray-600 rounded-md p-2 hover:bg-gray-600 transition ease-in-out duration-200",
+                onclick: move |_| {
+                    let open = !use_open();
+                    *use_open.write() = open;
+                },
+                {t!("title_session")}
+                " ({use_session().puzzles().len()})"
+            }
+            if use_open() {
+                div { class: "absolute right-0 mt-2 z-50 flex flex-col gap-1 p-2 min-w-64 max-h-96 overflow-y-auto rounded-md border border-gray-600 bg-gray-800 shadow-lg",
+                    if use_session().puzzles().is_empty() {
+                        span { class: "p-2 text-gray-400 select-none", {t!("session_empty")} }
+                    }
+                    for (index , puzzle) in use_session().puzzles().iter().enumerate() {
+                        button {
+                            key: "session-{index}",
+                            class: "flex flex-row items-center gap-3 p-2 rounded text-left text-white hover:bg-blue-800",
+                            onclick: move |_| {
+                                *use_open.write() = false;
+                                if let Some(page) = use_session.write().request_jump(index) {
+                                    onjump.call(page);
+                                }
+                            },
+                            table { class: "pointer-events-none", draggable: false,
+                                tbody {
+                                    for (i , row_data) in puzzle.grid.solution_grid.iter().enumerate() {
+                                        tr { key: "session-{index}-{i}",
+                                            for (j , cell) in row_data.iter().enumerate() {
+                                                td {
+                                                    key: "session-{index}-{i}-{j}",
+                                                    style: "background-color: {puzzle.file.palette.color_palette.get(*cell).cloned().unwrap_or_default()}; width: 3px; height: 3px; padding: 0;",
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                            div { class: "flex flex-col",
+                                span { class: "font-semibold", "{puzzle.name}" }
+                                if use_session().is_ambiguous(&puzzle.name) {
+                                    span { class: "text-sm text-amber-400", {t!("session_ambiguous")} }
+                                }
+                                if let Some(level) = use_session().completion(&puzzle.name) {
+                                    span { class: "text-sm text-green-400",
+                                        {t!("session_completed", level : assist_level_name(level))}
+                                    }
+                                }
+                                if use_session().is_assisted(&puzzle.name) {
+                                    span { class: "text-sm text-amber-400", {t!("session_assisted")} }
+                                }
+                                span { class: "text-sm text-gray-400",
+                                    match puzzle.page {
+                                        SessionPage::Solver => t!("title_nonogram_solver"),
+                                        SessionPage::Editor => t!("title_nonogram_editor"),
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A panel signing the drawing with the profile in use, embedding the author and the date in the
+/// saved file, and optionally showing the signature as a row below the grid.
+///
+/// # Context:
+/// - `Signal<Option<Signature>>`: The signature of the drawing.
+/// - `Signal<ActiveProfile>`: The profile signing the drawing (guests can't sign).
+/// - `I18n`: The language formatting the date of the signature.
+#[component]
+fn SignaturePanel() -> Element {
+    let locale = use_locale_format();
+    let mut use_signature = use_context::<Signal<Option<Signature>>>();
+    let use_active = use_context::<Signal<ActiveProfile>>();
+    let sign_onclick = move |_| async move {
+        let Some(profile) = use_active().profile else {
+            return;
+        };
+        let now = document::eval("return Date.now();")
+            .join::<f64>()
+            .await
+            .unwrap_or_default();
+        let visible = use_signature().is_some_and(|signature| signature.visible);
+        let mut signature = Signature::new(&profile.id, &profile.name, (now / 1000.0) as i64);
+        signature.visible = visible;
+        info!("Signed the nonogram as {}", profile.name);
+        *use_signature.write() = Some(signature);
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_signature")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3 py-2",
+                match use_signature() {
+                    Some(signature) => rsx! {
+                        span { class: "text-gray-200",
+                            {t!("signature_signed", author : signature.author.clone(), date : locale.date_time(signature.signed_at))}
+                        }
+                        div { class: "flex flex-row items-center gap-2",
+                            input {
+                                id: "signature-visible-input",
+                                r#type: "checkbox",
+                                class: "w-5 h-5 cursor-pointer",
+                                checked: signature.visible,
+                                onchange: move |event: FormEvent| {
+                                    if let Some(signature) = use_signature.write().as_mut() {
+                                        signature.visible = event.checked();
+                                    }
+                                },
+                            }
+                            label {
+                                r#for: "signature-visible-input",
+                                class: "text-gray-200 cursor-pointer select-none",
+                                {t!("label_signature_visible")}
+                            }
+                        }
+                        button {
+                            class: "px-4 py-1 rounded border border-gray-500 bg-gray-800 text-red-400 hover:bg-red-900",
+                            onclick: move |_| use_signature.set(None),
+                            {t!("button_remove_signature")}
+                        }
+                    },
+                    None => rsx! {
+                        span { class: "text-gray-400", {t!("signature_none")} }
+                    },
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    disabled: use_active().profile.is_none(),
+                    title: if use_active().profile.is_none() { t!("signature_guest") },
+                    onclick: sign_onclick,
+                    {t!("button_sign")}
+                }
+            }
+        }
+    }
+}
+
+/// A panel showing the information of the puzzle being solved: its size, its colors and the
+/// signature of its author, so shared puzzles stay credited.
+///
+/// # Context:
+/// - `Signal<NonogramFile>`: The puzzle file, with the signature.
+/// - `Signal<NonogramPuzzle>`: The size of the puzzle.
+/// - `Signal<NonogramPalette>`: The colors of the puzzle.
+/// - `I18n`: The language formatting the date of the signature.
+#[component]
+fn PuzzleInfoPanel() -> Element {
+    let locale = use_locale_format();
+    let use_file = use_context::<Signal<NonogramFile>>();
+    let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_puzzle_info")}
+            }
+            div { class: "flex flex-col justify-items-center justify-center items-center gap-1 py-2 text-gray-200",
+                span {
+                    {t!("puzzle_info_size", rows : use_puzzle().rows, cols : use_puzzle().cols, colors : use_palette().color_palette.len().saturating_sub(1))}
+                }
+                match use_file().signature {
+                    Some(signature) => rsx! {
+                        span { {t!("signature_signed", author : signature.author.clone(), date : locale.date_time(signature.signed_at))} }
+                    },
+                    None => rsx! {
+                        span { class: "text-gray-400", {t!("signature_none")} }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// A drawer with the trade-off candidates of the last multi-objective search (see
+/// `Replacement::Pareto`), with their violations of the rows and of the columns. Choosing a
+/// candidate shows it in the grid. The drawer is hidden while there are no candidates.
+///
+/// # Context:
+/// - `Signal<History>`: The Pareto front of the last search.
+/// - `Signal<NonogramSolution>`: Replaced by the candidate chosen.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+/// - `I18n`: The language formatting the violations.
+#[component]
+fn ParetoFrontPanel() -> Element {
+    let locale = use_locale_format();
+    let use_history = use_context::<Signal<History>>();
+    let dispatcher = use_dispatcher();
+    let front = use_history.read().pareto_front.clone();
+    if front.is_empty() {
+        return rsx! {};
+    }
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_pareto_front")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3 py-2",
+                for (index , candidate) in front.into_iter().enumerate() {
+                    button {
+                        key: "{index}",
+                        class: "px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                        title: t!("hint_pareto_candidate"),
+                        onclick: move |_| {
+                            info!(
+                                "Showing the candidate with {} row and {} column violations",
+                                candidate.row_violations,
+                                candidate.column_violations
+                            );
+                            dispatcher
+                                .dispatch(GridCommand::Replace {
+                                    tool: Tool::Solver,
+                                    solution: candidate.solution.clone(),
+                                });
+                        },
+                        {
+                            t!(
+                                "pareto_candidate", rows : locale.count(candidate.row_violations), columns :
+                                locale.count(candidate.column_violations)
+                            )
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A drawer with the notes about the puzzle being solved: the notes of its author, saved in the
+/// file, and the notes of the user, such as solving strategies, kept in the local library.
+///
+/// # Context:
+/// - `Signal<NonogramFile>`: The puzzle file, with the notes of the author.
+/// - `Signal<NonogramPuzzle>`: The puzzle the notes of the user are kept for.
+/// - `Signal<SolutionLibrary>`: The library keeping the notes of the user.
+#[component]
+fn NotesPanel() -> Element {
+    let use_file = use_context::<Signal<NonogramFile>>();
+    let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let mut use_library = use_context::<Signal<SolutionLibrary>>();
+    let mut use_draft = use_signal(String::new);
+    let mut use_saved = use_signal(|| false);
+    use_effect(move || {
+        let puzzle = use_puzzle();
+        let notes = use_library
+            .peek()
+            .notes(&puzzle)
+            .unwrap_or_default()
+            .to_string();
+        use_draft.set(notes);
+        use_saved.set(false);
+    });
+    let save_onclick = move |_| {
+        use_library
+            .write()
+            .set_notes(&use_puzzle.peek(), &use_draft.peek());
+        let saved = use_library.peek().save();
+        if !saved {
+            error!("Failed to store the notes of the puzzle");
+        }
+        use_saved.set(saved);
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_notes")}
+            }
+            div { class: "flex flex-col items-center gap-3 py-2",
+                if !use_file().notes.is_empty() {
+                    span { class: "text-gray-200 font-semibold", {t!("label_author_notes")} }
+                    NotesView { text: use_file().notes }
+                }
+                label {
+                    r#for: "notes-input",
+                    class: "text-gray-200 font-semibold select-none",
+                    {t!("label_my_notes")}
+                }
+                textarea {
+                    id: "notes-input",
+                    class: "w-full max-w-xl h-32 px-4 py-2 rounded border border-gray-500 bg-gray-800 text-white focus:ring focus:ring-blue-500 focus:outline-none",
+                    placeholder: t!("placeholder_notes"),
+                    value: "{use_draft}",
+                    oninput: move |event| {
+                        use_draft.set(event.value());
+                        use_saved.set(false);
+                    },
+                }
+                NotesView { text: use_draft() }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: save_onclick,
+                    if use_saved() {
+                        {t!("button_notes_saved")}
+                    } else {
+                        {t!("button_save_notes")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A panel exporting every puzzle of the local library as a zip archive of `.ngram` files, with
+/// the notes of the user, and optionally the puzzles touched during the session with their
+/// progress.
+///
+/// # Context:
+/// - `Signal<SolutionLibrary>`: The library exported.
+/// - `Signal<SessionHistory>`: The puzzles of the session, exported with their progress (optional).
+/// - `I18n`: The language grouping the thousands of the size of the library.
+#[component]
+fn LibraryExportPanel() -> Element {
+    let locale = use_locale_format();
+    let use_library = use_context::<Signal<SolutionLibrary>>();
+    let use_session = try_use_context::<Signal<SessionHistory>>();
+    let mut use_progress = use_signal(|| false);
+    let export_onclick = move |_| {
+        let mut archive = Archive::default();
+        use_library.read().add_to_archive(&mut archive);
+        if let Some(use_session) = use_session.filter(|_| use_progress()) {
+            use_session.read().add_to_archive(&mut archive);
+        }
+        info!("Exporting {} files of the library", archive.len());
+        save_binary(
+            archive.to_zip(),
+            String::from("ngram-library.zip"),
+            "application/zip",
+        );
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_library")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3 py-2",
+                span { class: "text-gray-200",
+                    {t!("library_size", count : locale.count(use_library.read().len()))}
+                }
+                if use_session.is_some() {
+                    div { class: "flex flex-row items-center gap-2",
+                        input {
+                            id: "export-progress-input",
+                            r#type: "checkbox",
+                            class: "w-5 h-5 cursor-pointer",
+                            checked: use_progress(),
+                            onchange: move |event: FormEvent| use_progress.set(event.checked()),
+                        }
+                        label {
+                            r#for: "export-progress-input",
+                            class: "text-gray-200 cursor-pointer select-none",
+                            {t!("label_export_progress")}
+                        }
+                    }
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: export_onclick,
+                    {t!("button_export_all")}
+                }
+            }
+        }
+    }
+}
+
+/// A panel finding puzzles in the local library by drawing: the user sketches a rough shape on a
+/// small grid, and the puzzles are ranked by the similarity of their silhouette (see
+/// `SolutionLibrary::search_by_drawing`), so a half-remembered puzzle can be opened again.
+///
+/// # Context:
+/// - `Signal<SolutionLibrary>`: The library searched.
+/// - `Signal<NonogramFile>`: Replaced by the puzzle opened.
+/// - `Signal<NonogramPuzzle>`: Replaced by the puzzle opened.
+/// - `Signal<NonogramPalette>`: Replaced by the palette of the puzzle opened.
+/// - `Signal<NonogramData>`: Named after the puzzle opened, which is blind like the imported clues.
+#[component]
+fn LibrarySearchPanel() -> Element {
+    /// The number of puzzles listed.
+    const MAX_RESULTS: usize = 5;
+    let use_library = use_context::<Signal<SolutionLibrary>>();
+    let mut use_file = use_context::<Signal<NonogramFile>>();
+    let mut use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let mut use_palette = use_context::<Signal<NonogramPalette>>();
+    let mut use_data = use_context::<Signal<NonogramData>>();
+    let dispatcher = use_dispatcher();
+    let mut use_sketch = use_signal(|| NonogramSolution {
+        solution_grid: vec![vec![BACKGROUND; SILHOUETTE_SIZE]; SILHOUETTE_SIZE],
+    });
+    let mut use_stroke = use_signal(|| None::<usize>);
+    let results = use_memo(move || {
+        let library = use_library.read();
+        library
+            .search_by_drawing(&use_sketch())
+            .into_iter()
+            .filter(|(_, _, similarity)| *similarity > 0.0)
+            .take(MAX_RESULTS)
+            .map(|(hash, solution, similarity)| (hash.to_string(), solution.clone(), similarity))
+            .collect::<Vec<_>>()
+    });
+    let mut paint = move |row: usize, col: usize, color: usize| {
+        use_sketch.write().solution_grid[row][col] = color;
+    };
+    let mut open = move |hash: String, solution: NonogramSolution| {
+        let puzzle = NonogramPuzzle::from_solution(&solution);
+        let palette = clue_palette(&puzzle);
+        *use_file.write() = NonogramFile {
+            solution,
+            palette: palette.clone(),
+            signature: None,
+            notes: String::new(),
+        };
+        use_data.write().filename = format!("{hash}.ngram");
+        use_data.write().completed = false;
+        use_data.write().blind = true;
+        *use_palette.write() = palette;
+        dispatcher.dispatch(GridCommand::Replace {
+            tool: Tool::Load,
+            solution: NonogramSolution {
+                solution_grid: vec![vec![BACKGROUND; puzzle.cols]; puzzle.rows],
+            },
+        });
+        *use_puzzle.write() = puzzle;
+        info!("Opened the puzzle {hash} from the library");
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_library_search")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-start gap-6 py-2",
+                div { class: "flex flex-col items-center gap-2",
+                    div {
+                        class: "grid gap-px bg-gray-600 border border-gray-500 touch-none select-none",
+                        style: "grid-template-columns: repeat({SILHOUETTE_SIZE}, 1.5rem);",
+                        onpointerup: move |_| use_stroke.set(None),
+                        onpointerleave: move |_| use_stroke.set(None),
+                        for row in 0..SILHOUETTE_SIZE {
+                            for col in 0..SILHOUETTE_SIZE {
+                                div {
+                                    key: "sketch-{row}-{col}",
+                                    class: "w-6 h-6 cursor-pointer",
+                                    background_color: if use_sketch().solution_grid[row][col] != BACKGROUND { "#e5e7eb" } else { "#1f2937" },
+                                    onpointerdown: move |_| {
+                                        let color = usize::from(use_sketch.peek().solution_grid[row][col] == BACKGROUND);
+                                        paint(row, col, color);
+                                        use_stroke.set(Some(color));
+                                    },
+                                    onpointerenter: move |_| {
+                                        if let Some(color) = use_stroke() {
+                                            paint(row, col, color);
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                    }
+                    button {
+                        class: "px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800",
+                        onclick: move |_| use_sketch.write().clear(),
+                        {t!("button_clear_sketch")}
+                    }
+                }
+                div { class: "flex flex-col items-center gap-3",
+                    if results().is_empty() {
+                        span { class: "text-gray-400", {t!("library_search_empty")} }
+                    }
+                    for (hash , solution , similarity) in results() {
+                        div {
+                            key: "{hash}",
+                            class: "flex flex-row items-center gap-3 text-gray-200",
+                            div {
+                                class: "grid",
+                                style: "grid-template-columns: repeat({solution.cols()}, 4px);",
+                                for (i , row) in solution.solution_grid.iter().enumerate() {
+                                    for (j , &color) in row.iter().enumerate() {
+                                        div {
+                                            key: "{i}-{j}",
+                                            class: "w-1 h-1",
+                                            background_color: if color != BACKGROUND { "#e5e7eb" } else { "#1f2937" },
+                                        }
+                                    }
+                                }
+                            }
+                            span {
+                                {t!("library_search_similarity", percent : (similarity * 100.0).round() as i64)}
+                            }
+                            button {
+                                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                                onclick: move |_| open(hash.clone(), solution.clone()),
+                                {t!("button_open_puzzle")}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A panel writing the notes of the author of the drawing, such as the authoring intent or a
+/// hint for solving it, saved in the file and shown to the solvers.
+///
+/// # Context:
+/// - `Signal<AuthorNotes>`: The notes of the author.
+#[component]
+fn AuthorNotesPanel() -> Element {
+    let mut use_notes = use_context::<Signal<AuthorNotes>>();
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("label_author_notes")}
+            }
+            div { class: "flex flex-col items-center gap-3 py-2",
+                textarea {
+                    class: "w-full max-w-xl h-32 px-4 py-2 rounded border border-gray-500 bg-gray-800 text-white focus:ring focus:ring-blue-500 focus:outline-none",
+                    placeholder: t!("placeholder_notes"),
+                    value: "{use_notes().text}",
+                    oninput: move |event| use_notes.write().text = event.value(),
+                }
+                NotesView { text: use_notes().text }
+            }
+        }
+    }
+}
+
+/// Renders notes written in the markdown-lite syntax (see `notes::parse_notes`): headings, list
+/// items and paragraphs, with bold, italic and code text.
+///
+/// # Arguments:
+/// - `text`: The notes to render.
+#[component]
+fn NotesView(text: String) -> Element {
+    let blocks = parse_notes(&text);
+    if blocks.is_empty() {
+        return rsx! {};
+    }
+    rsx! {
+        div { class: "w-full max-w-xl flex flex-col gap-2 px-4 py-2 rounded bg-gray-800 text-gray-200 text-left",
+            for (index , block) in blocks.into_iter().enumerate() {
+                match block {
+                    NoteBlock::Heading(spans) => rsx! {
+                        h4 { key: "{index}", class: "font-bold text-white",
+                            NoteSpans { spans }
+                        }
+                    },
+                    NoteBlock::Item(spans) => rsx! {
+                        p { key: "{index}", class: "pl-4",
+                            "• "
+                            NoteSpans { spans }
+                        }
+                    },
+                    NoteBlock::Paragraph(spans) => rsx! {
+                        p { key: "{index}", NoteSpans { spans } }
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Renders the styled runs of text of a notes block.
+///
+/// # Arguments:
+/// - `spans`: The runs of text, with their inline style.
+#[component]
+fn NoteSpans(spans: Vec<NoteSpan>) -> Element {
+    rsx! {
+        for span in spans {
+            match span {
+                NoteSpan::Text(text) => rsx! {
+                    span { "{text}" }
+                },
+                NoteSpan::Bold(text) => rsx! {
+                    strong { "{text}" }
+                },
+                NoteSpan::Italic(text) => rsx! {
+                    em { "{text}" }
+                },
+                NoteSpan::Code(text) => rsx! {
+                    code { class: "px-1 rounded bg-gray-700 font-mono", "{text}" }
+                },
+            }
+        }
+    }
+}
+
+/// A panel with the weekly challenge: a puzzle generated from a published seed, the same for
+/// every player, whose solves produce a verification token to share and compare.
+///
+/// The token hashes the seed of the week with the active time, the actions and the loosest assist
+/// level of the solve, all computed locally.
+///
+/// # Context:
+/// - `Signal<f64>`: The current timestamp, which decides the week.
+/// - `Signal<Option<SolveTimer>>`: The active time of the solve.
+/// - `Signal<ActionLog>`: The actions performed since the puzzle was loaded.
+/// - `Signal<NonogramFile>`: Replaced by the puzzle of the week.
+/// - `Signal<NonogramPuzzle>`: Replaced by the puzzle of the week.
+/// - `Signal<NonogramPalette>`: Replaced by the palette of the week's puzzle.
+/// - `Signal<NonogramData>`: Named after the week, and whether it's completed.
+/// - `Signal<SessionHistory>`: The loosest assist level the puzzle was completed with (optional).
+/// - `I18n`: The language formatting the weeks and the statistics of the solves.
+#[component]
+fn WeeklyChallengePanel() -> Element {
+    let locale = use_locale_format();
+    let use_now = use_context::<Signal<f64>>();
+    let use_timer = use_context::<Signal<Option<SolveTimer>>>();
+    let use_log = use_context::<Signal<ActionLog>>();
+    let mut use_file = use_context::<Signal<NonogramFile>>();
+    let mut use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let mut use_palette = use_context::<Signal<NonogramPalette>>();
+    let mut use_data = use_context::<Signal<NonogramData>>();
+    let use_session = try_use_context::<Signal<SessionHistory>>();
+    let dispatcher = use_dispatcher();
+    let mut use_token = use_signal(String::new);
+    let challenge = use_memo(move || {
+        (use_now() > 0.0).then(|| WeeklyChallenge::at((use_now() / 1000.0) as i64))
+    });
+    let play_onclick = move |_| {
+        let Some(challenge) = challenge() else {
+            return;
+        };
+        let file = challenge.file();
+        let puzzle = NonogramPuzzle::from_solution(&file.solution);
+        let solution = NonogramSolution {
+            solution_grid: vec![vec![BACKGROUND; puzzle.cols]; puzzle.rows],
+        };
+        *use_puzzle.write() = puzzle;
+        *use_palette.write() = file.palette.clone();
+        *use_file.write() = file;
+        use_data.write().filename = challenge.filename();
+        use_data.write().completed = false;
+        use_data.write().blind = false;
+        dispatcher.dispatch(GridCommand::Replace {
+            tool: Tool::Load,
+            solution,
+        });
+        info!("Weekly challenge of {} loaded", challenge.start_date());
+    };
+    let Some(challenge) = challenge() else {
+        return rsx! {};
+    };
+    let verification = move || {
+        let data = use_data();
+        if !data.completed || data.filename != challenge.filename() {
+            return None;
+        }
+        let seconds = use_timer()?.elapsed(*use_now.peek()) / 1000.0;
+        let assist_level = use_session
+            .and_then(|use_session| use_session().completion(&data.filename))
+            .unwrap_or_default();
+        Some(challenge.verify(SolveStats {
+            seconds: seconds as u64,
+            actions: Timeline::count(&use_log.read()),
+            assist_level,
+        }))
+    };
+    let compared = move || {
+        let token = use_token();
+        if token.trim().is_empty() {
+            return None;
+        }
+        Some(match token.parse::<Verification>() {
+            Ok(other) if other.is_valid() && other.week != challenge.week => {
+                t!("challenge_token_other_week", date : locale.date(other.challenge().starts_at()))
+            }
+            Ok(other) if other.is_valid() => t!(
+                "challenge_token_valid",
+                time : locale.duration(other.stats.seconds as f64 * 1000.0),
+                actions : locale.count(other.stats.actions),
+                level : assist_level_name(other.stats.assist_level)
+            ),
+            _ => t!("challenge_token_invalid"),
+        })
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_weekly_challenge")}
+            }
+            div { class: "flex flex-col justify-items-center justify-center items-center gap-3 py-2 text-gray-200",
+                span { {t!("challenge_week", date : locale.date(challenge.starts_at()))} }
+                span { class: "font-mono text-gray-400",
+                    {t!("challenge_seed", seed : format!("{:016x}", challenge.seed()))}
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: play_onclick,
+                    {t!("button_play_challenge")}
+                }
+                if let Some(verification) = verification() {
+                    label { class: "py-2 text-gray-200 font-semibold select-none", {t!("label_verification")} }
+                    input {
+                        class: "w-80 px-4 py-1 font-mono rounded border border-gray-500 bg-gray-800 text-white text-center",
+                        readonly: true,
+                        value: "{verification}",
+                        onclick: move |_| {
+                            document::eval("document.activeElement?.select();");
+                        },
+                    }
+                }
+                input {
+                    class: "w-80 px-4 py-1 font-mono rounded border border-gray-500 bg-gray-800 text-white focus:ring focus:ring-blue-500 focus:outline-none",
+                    placeholder: t!("placeholder_verification"),
+                    value: "{use_token}",
+                    oninput: move |event| use_token.set(event.value()),
+                }
+                if let Some(message) = compared() {
+                    span { "{message}" }
+                }
+            }
+        }
+    }
+}
+
+/// A panel toggling the visibility of each color layer in the editor.
+///
+/// Each palette color (except the background) can be hidden or solo'd, helping to inspect
+/// complex multicolor artwork. Hidden colors are rendered as the background, but they're
+/// preserved in the solution grid.
+///
+/// # Context:
+/// - `Signal<NonogramPalette>`: Defines the color palette used.
+/// - `Signal<Layers>`: The visibility of each color layer.
+#[component]
+fn LayersPanel() -> Element {
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let mut use_layers = use_context::<Signal<Layers>>();
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_layers")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3 py-2",
+                for (color , value) in use_palette().color_palette.into_iter().enumerate().skip(BACKGROUND + 1) {
+                    div {
+                        key: "layer-{color}",
+                        class: "flex flex-row items-center gap-2 px-3 py-1 rounded border border-gray-500 bg-gray-800 text-white",
+                        opacity: if !use_layers().is_visible(color) { "0.5" },
+                        span {
+                            class: "w-6 h-6 rounded-full border border-gray-400",
+                            style: "background-color: {value}",
+                        }
+                        button {
+                            class: "px-2 font-semibold rounded hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                            onclick: move |_| use_layers.write().toggle(color),
+                            if use_layers().hidden.contains(&color) {
+                                {t!("button_show_layer")}
+                            } else {
+                                {t!("button_hide_layer")}
+                            }
+                        }
+                        button {
+                            class: "px-2 font-semibold rounded hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                            background_color: if use_layers().solo == Some(color) { "#1e40af" },
+                            onclick: move |_| use_layers.write().toggle_solo(color),
+                            {t!("button_solo_layer")}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A panel for loading a reference image beneath the editor grid and adjusting it.
+///
+/// The image is stretched over the solution grid, and it can be made more or less visible
+/// through the grid cells and moved with an offset, so users can trace artwork manually.
+///
+/// # Context:
+/// - `Signal<ReferenceImage>`: The reference image, its opacity and offset.
+#[component]
+fn ReferenceImagePanel() -> Element {
+    let mut use_reference = use_context::<Signal<ReferenceImage>>();
+    let load_image_onchange = move |event: FormEvent| async move {
+        use base64::prelude::*;
+
+        let Some(file_engine) = event.files() else {
+            error!("Event hadn't a file engine attached: {event:?}");
+            return;
+        };
+        let Some(file) = file_engine.files().into_iter().next() else {
+            error!("File engine had no attached files");
+            return;
+        };
+        match file_engine.read_file(&file).await {
+            Some(bytes) => {
+                use_reference.write().source = Some(format!(
+                    "data:{};base64,{}",
+                    image_mime(&file),
+                    BASE64_STANDARD.encode(bytes)
+                ));
+                info!("Loaded reference image '{file}'");
+            }
+            None => error!("Couldn't read file: '{file}'"),
+        }
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_reference_image")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                input {
+                    class: "appearance-none rounded border px-4 py-1 border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform cursor-pointer",
+                    r#type: "file",
+                    accept: "image/*",
+                    multiple: false,
+                    onchange: load_image_onchange,
+                }
+                div { class: "flex flex-row items-center gap-3",
+                    label {
+                        r#for: "reference-opacity-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_opacity")}
+                        ":"
+                    }
+                    input {
+                        id: "reference-opacity-input",
+                        class: "cursor-pointer",
+                        r#type: "range",
+                        min: "0",
+                        max: "100",
+                        oninput: move |event| {
+                            if let Ok(opacity) = event.value().parse::<f32>() {
+                                use_reference.write().opacity = opacity / 100.0;
+                            }
+                        },
+                        value: (use_reference().opacity * 100.0).round(),
+                    }
+                }
+                for (axis , label) in [(0, "X"), (1, "Y")] {
+                    div { key: "reference-offset-{label}", class: "flex flex-row items-center gap-3",
+                        label {
+                            r#for: "reference-offset-{label}-input",
+                            class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                            {t!("label_offset")}
+                            " {label}:"
+                        }
+                        input {
+                            id: "reference-offset-{label}-input",
+                            class: "appearance-none px-4 py-1 w-24 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform",
+                            r#type: "number",
+                            oninput: move |event| {
+                                if let Ok(offset) = event.value().parse::<i32>() {
+                                    let mut reference = use_reference.write();
+                                    if axis == 0 {
+                                        reference.offset.0 = offset;
+                                    } else {
+                                        reference.offset.1 = offset;
+                                    }
+                                }
+                            },
+                            value: if axis == 0 { use_reference().offset.0 } else { use_reference().offset.1 },
+                        }
+                    }
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-red-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: move |_| {
+                        *use_reference.write() = ReferenceImage::default();
+                        info!("Removed reference image");
+                    },
+                    {t!("button_remove_reference")}
+                }
+            }
+        }
+    }
+}
+
+/// Guesses the MIME type of an image from the extension of its file name.
+///
+/// # Arguments:
+/// - `file`: The name of the image file.
+///
+/// # Returns:
+/// The MIME type, `image/png` if the extension is unknown.
+fn image_mime(file: &str) -> &'static str {
+    match file
+        .rsplit('.')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase()
+        .as_str()
+    {
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "bmp" => "image/bmp",
+        _ => "image/png",
+    }
+}
+
+/// Decodes a photo in the background and sends it back one strip per grid row, each strip drawn
+/// into a small canvas with `IMPORT_SAMPLES` samples along each side of a cell. After each strip,
+/// it waits for the application to ask for the next one, or to cancel the import.
+const IMPORT_SCRIPT: &str = r#"
+    const [source, rows, cols, samples] = await dioxus.recv();
+    try {
+        const blob = await (await fetch(source)).blob();
+        const bitmap = await createImageBitmap(blob);
+        const canvas = document.createElement("canvas");
+        canvas.width = cols * samples;
+        canvas.height = samples;
+        const context = canvas.getContext("2d", { willReadFrequently: true });
+        const height = bitmap.height / rows;
+        for (let row = 0; row < rows; row++) {
+            context.clearRect(0, 0, canvas.width, canvas.height);
+            context.drawImage(bitmap, 0, row * height, bitmap.width, height, 0, 0, canvas.width, canvas.height);
+            const strip = context.getImageData(0, 0, canvas.width, canvas.height).data;
+            dioxus.send([row, Array.from(strip)]);
+            if (!(await dioxus.recv())) {
+                break;
+            }
+        }
+        bitmap.close();
+    } catch (error) {
+        dioxus.send(String(error));
+    }
+"#;
+
+/// A panel importing a photo into the solution grid, quantized to the palette.
+///
+/// The photo is stretched over the grid and decoded in the background, strip by strip (see
+/// `ImageImport`), so large photos neither freeze the interface nor exhaust the memory of mobile
+/// devices. A progress bar reports the rows imported, and the import can be cancelled, leaving the
+/// grid untouched. The finished import replaces the grid as a single undoable command.
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: The solution grid replaced, whose size is kept.
+/// - `Signal<NonogramPalette>`: The palette the colors of the photo are quantized to.
+/// - `Signal<ActionLog>`: Records the import.
+#[component]
+fn ImageImportPanel() -> Element {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let dispatcher = use_dispatcher();
+    let mut use_progress = use_signal(|| None::<f64>);
+    let mut use_cancel = use_signal(|| false);
+    let import_onchange = move |event: FormEvent| async move {
+        use base64::prelude::*;
+
+        let Some(file_engine) = event.files() else {
+            error!("Event hadn't a file engine attached: {event:?}");
+            return;
+        };
+        let Some(file) = file_engine.files().into_iter().next() else {
+            error!("File engine had no attached files");
+            return;
+        };
+        let Some(bytes) = file_engine.read_file(&file).await else {
+            error!("Couldn't read file: '{file}'");
+            return;
+        };
+        let source = format!(
+            "data:{};base64,{}",
+            image_mime(&file),
+            BASE64_STANDARD.encode(bytes)
+        );
+        let (rows, cols) = (use_solution.peek().rows(), use_solution.peek().cols());
+        let mut import = ImageImport::new(rows, cols, &use_palette.peek());
+        use_cancel.set(false);
+        use_progress.set(Some(0.0));
+        let mut strips = document::eval(IMPORT_SCRIPT);
+        let _ = strips.send((source, rows, cols, IMPORT_SAMPLES));
+        while !import.is_complete() {
+            let Ok((row, rgba)) = strips.recv::<(usize, Vec<u8>)>().await else {
+                error!("Couldn't decode the image '{file}'");
+                break;
+            };
+            if let Err(err) = import.add_strip(row, &rgba) {
+                error!("Couldn't import the image '{file}': {err}");
+                break;
+            }
+            use_progress.set(Some(import.progress()));
+            let cancelled = *use_cancel.peek();
+            let _ = strips.send(!cancelled);
+            if cancelled {
+                info!("Cancelled the import of the image '{file}'");
+                break;
+            }
+        }
+        if import.is_complete() {
+            info!("Imported the image '{file}'");
+            dispatcher.dispatch(GridCommand::Replace {
+                tool: Tool::Import,
+                solution: import.finish(),
+            });
+        }
+        use_progress.set(None);
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_import_image")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                input {
+                    class: "appearance-none rounded border px-4 py-1 border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform cursor-pointer",
+                    r#type: "file",
+                    accept: "image/*",
+                    multiple: false,
+                    disabled: use_progress().is_some(),
+                    onchange: import_onchange,
+                }
+                if let Some(progress) = use_progress() {
+                    span { class: "text-gray-200 font-semibold select-none",
+                        {t!("progress_import", percent : (progress * 100.0).floor())}
+                    }
+                    div {
+                        class: "w-64 h-3 rounded-full bg-gray-700 overflow-hidden",
+                        role: "progressbar",
+                        aria_valuenow: "{(progress * 100.0).floor()}",
+                        aria_valuemin: 0,
+                        aria_valuemax: 100,
+                        div {
+                            class: "h-full bg-blue-600",
+                            width: "{(progress * 100.0).floor()}%",
+                        }
+                    }
+                    button {
+                        class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-red-800 hover:scale-110 active:scale-125 transition-transform transform",
+                        disabled: use_cancel(),
+                        onclick: move |_| use_cancel.set(true),
+                        {t!("button_cancel")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A panel showing how many cells each palette color occupies, with a dialog to replace colors.
+///
+/// The histogram is drawn with a bar per palette color, and the replacement substitutes every
+/// cell of a color with another color in the whole solution grid. The panel can also reduce the
+/// used colors to a maximum, merging the perceptually closest ones (e.g. after importing an image).
+///
+/// # Context:
+/// - `Signal<NonogramPalette>`: Defines the color palette used.
+/// - `Signal<NonogramSolution>`: The solution grid whose colors are counted and replaced.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+#[component]
+fn ColorUsagePanel() -> Element {
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let dispatcher = use_dispatcher();
+    let use_from = use_signal(|| 0usize);
+    let use_to = use_signal(|| 0usize);
+    let mut use_max_colors = use_signal(|| 2usize);
+    let counts = use_memo(move || use_solution().color_counts(use_palette().len()));
+    let most_used = use_memo(move || counts().into_iter().max().unwrap_or(1).max(1));
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_color_usage")}
+            }
+            div { class: "flex flex-col gap-1 py-2",
+                for (color , count) in counts().into_iter().enumerate().take(use_palette().len()) {
+                    div {
+                        key: "usage-{color}",
+                        class: "flex flex-row items-center gap-3",
+                        span {
+                            class: "w-6 h-6 rounded-full border border-gray-400",
+                            style: "background-color: {use_palette().get(color)}",
+                        }
+                        span { class: "w-10 text-right text-gray-200 font-mono", "{count}" }
+                        div {
+                            class: "h-4 rounded",
+                            style: "background-color: {use_palette().get(color)}; width: {count * 300 / most_used()}px;",
+                        }
+                    }
+                }
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3",
+                label { class: "py-2 text-gray-200 font-semibold select-none",
+                    {t!("label_replace_color")}
+                }
+                for (id , mut use_color) in [("replace-from-select", use_from), ("replace-to-select", use_to)] {
+                    select {
+                        key: "{id}",
+                        id,
+                        class: "appearance-none px-4 py-1 rounded border border-gray-500 text-white hover:scale-110 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform cursor-pointer",
+                        style: "background-color: {use_palette().get(use_color().min(use_palette().len() - 1))}",
+                        onchange: move |event: FormEvent| {
+                            if let Ok(color) = event.value().parse::<usize>() {
+                                *use_color.write() = color;
+                            }
+                        },
+                        for (color , value) in use_palette().color_palette.into_iter().enumerate() {
+                            option {
+                                key: "{id}-{color}",
+                                value: "{color}",
+                                selected: use_color() == color,
+                                style: "background-color: {value}",
+                                "{color}"
+                            }
+                        }
+                    }
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: move |_| {
+                        info!("Replacing color {} with color {}", use_from(), use_to());
+                        dispatcher.dispatch(GridCommand::replace_color(use_from(), use_to(), None));
+                    },
+                    {t!("button_replace_color")}
+                }
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3",
+                label {
+                    r#for: "max-colors-input",
+                    class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                    {t!("label_max_colors")}
+                    ":"
+                }
+                input {
+                    id: "max-colors-input",
+                    class: "appearance-none px-4 py-1 w-20 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform",
+                    r#type: "number",
+                    min: "1",
+                    max: "{use_palette().len()}",
+                    onchange: move |event| {
+                        if let Ok(max_colors) = event.value().parse::<usize>() {
+                            *use_max_colors.write() = max_colors.max(1);
+                        }
+                    },
+                    value: use_max_colors(),
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: move |_| {
+                        info!("Reducing the nonogram solution grid to {} colors", use_max_colors());
+                        let mapping = use_palette().reduction_mapping(&counts(), use_max_colors());
+                        dispatcher.dispatch(GridCommand::Remap { mapping, area: None });
+                    },
+                    {t!("button_reduce_colors")}
+                }
+            }
+        }
+    }
+}
+
+/// A panel for cleaning the Nonogram solution grid with filters.
+///
+/// The selected filter is previewed next to the panel, with the number of cells it would change,
+/// before applying it to the solution grid.
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: The solution grid to clean.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+#[component]
+fn FilterPanel() -> Element {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let dispatcher = use_dispatcher();
+    let mut use_filter = use_signal(|| Filter::RemoveIsolated);
+    let preview = use_memo(move || use_filter().apply(&use_solution()));
+    let changes = use_memo(move || {
+        let original = use_solution();
+        original
+            .solution_grid
+            .iter()
+            .flatten()
+            .zip(preview().solution_grid.iter().flatten())
+            .filter(|(before, after)| before != after)
+            .count()
+    });
+    let filter_name = |filter: Filter| match filter {
+        Filter::RemoveIsolated => t!("filter_remove_isolated"),
+        Filter::FillHoles => t!("filter_fill_holes"),
+        Filter::Smooth => t!("filter_smooth"),
+    };
+    rsx! {
+        div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6",
+            div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                label {
+                    r#for: "filter-select",
+                    class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                    {t!("label_filter")}
+                    ":"
+                }
+                select {
+                    id: "filter-select",
+                    class: "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform cursor-pointer",
+                    onchange: move |event: FormEvent| {
+                        if let Some(filter) = event
+                            .value()
+                            .parse::<usize>()
+                            .ok()
+                            .and_then(|index| Filter::ALL.get(index))
+                        {
+                            *use_filter.write() = *filter;
+                        }
+                    },
+                    for (index , filter) in Filter::ALL.into_iter().enumerate() {
+                        option {
+                            key: "filter-{index}",
+                            value: "{index}",
+                            selected: use_filter() == filter,
+                            {filter_name(filter)}
+                        }
+                    }
+                }
+            }
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                onclick: move |_| {
+                    info!("Applying filter {:?} to the nonogram solution grid", use_filter());
+                    dispatcher.dispatch(GridCommand::Filter { filter: use_filter() });
+                },
+                {t!("button_apply_filter")}
+            }
+            div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                GridPreview { solution: preview() }
+                span { class: "text-gray-200", {t!("changed_cells", count : changes())} }
+            }
+        }
+    }
+}
+
+/// Displays the comparison of two solution grids as an overlay, where matching cells are faded
+/// and missing, extra and mismatched cells are highlighted.
+///
+/// # Parameters:
+/// - `diff`: The comparison of the grids.
+///
+/// # Context:
+/// - `Signal<NonogramPalette>`: Defines the color palette used.
+#[component]
+fn DiffGrid(diff: SolutionDiff) -> Element {
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let cell_style = move |cell: CellDiff| {
+        let palette = use_palette();
+        let color = |index: usize| {
+            palette
+                .color_palette
+                .get(index)
+                .cloned()
+                .unwrap_or_default()
+        };
+        match cell {
+            CellDiff::Match(index) => format!("background-color: {}55;", color(index)),
+            CellDiff::Missing(index) => {
+                format!(
+                    "background-color: {}; outline: 2px dashed #ef4444; outline-offset: -2px;",
+                    color(index)
+                )
+            }
+            CellDiff::Extra(index) => {
+                format!(
+                    "background-color: {}; outline: 2px solid #f59e0b; outline-offset: -2px;",
+                    color(index)
+                )
+            }
+            CellDiff::Mismatch { found, .. } => {
+                format!(
+                    "background-color: {}; outline: 2px solid #d946ef; outline-offset: -2px;",
+                    color(found)
+                )
+            }
+        }
+    };
+    rsx! {
+        table { class: "pointer-events-none border border-gray-400", draggable: false,
+            tbody {
+                for (i , row_data) in diff.cells.iter().enumerate() {
+                    tr { key: "diff-row-{i}",
+                        for (j , cell) in row_data.iter().enumerate() {
+                            td {
+                                key: "diff-cell-{i}-{j}",
+                                class: "select-none",
+                                style: "{cell_style(*cell)} width: 14px; height: 14px;",
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The longest side, in pixels, of the grid previews.
+const GRID_PREVIEW_SIDE: usize = 64;
+
+/// Displays a small, non-interactive preview of a solution grid.
+///
+/// Grids longer than `GRID_PREVIEW_SIDE` cells are downscaled (see `Thumbnail`).
+///
+/// # Parameters:
+/// - `solution`: The solution grid to preview.
+///
+/// # Context:
+/// - `Signal<NonogramPalette>`: Defines the color palette used.
+#[component]
+fn GridPreview(solution: NonogramSolution) -> Element {
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let thumbnail = Thumbnail::new(&solution, GRID_PREVIEW_SIDE);
+    rsx! {
+        table { class: "pointer-events-none", draggable: false,
+            tbody {
+                for (i , row_data) in thumbnail.pixels.iter().enumerate() {
+                    tr { key: "preview-row-{i}",
+                        for (j , cell) in row_data.iter().enumerate() {
+                            td {
+                                key: "preview-cell-{i}-{j}",
+                                class: "select-none",
+                                style: "background-color: {use_palette().color_palette[*cell]}; width: 6px; height: 6px;",
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A panel checking if the Nonogram can be solved by logic, without guessing.
+///
+/// The puzzle of the current solution grid is analyzed with the logic solver, reporting how many
+/// cells are deduced by line logic, how many need a single guess, and how many need deeper
+/// guessing. The cells needing guesses are highlighted with a heat overlay over the grid, which is
+/// cleared when the grid changes.
+///
+/// In check mode, the clue headers are colored by how constrained their lines are, and the
+/// number of tight, overlapping and slack lines is reported, summarizing the puzzle's difficulty.
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: The solution grid to analyze.
+/// - `Signal<Option<Heatmap>>`: The heat overlay highlighting the cells needing guesses.
+/// - `Signal<CheckMode>`: Whether the clue headers are colored by slack.
+#[component]
+fn GuessingLintPanel() -> Element {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let mut use_heatmap = use_context::<Signal<Option<Heatmap>>>();
+    let mut use_check_mode = use_context::<Signal<CheckMode>>();
+    let tightness_counts = use_memo(move || {
+        let (rows, cols) = puzzle_slack(&NonogramPuzzle::from_solution(&use_solution()));
+        let mut counts = [0; 3];
+        for slack in rows.into_iter().chain(cols).flatten() {
+            counts[slack.tightness as usize] += 1;
+        }
+        counts
+    });
+    let mut use_depths = use_signal(|| None::<[usize; 3]>);
+    use_effect(move || {
+        use_solution();
+        *use_heatmap.write() = None;
+        *use_depths.write() = None;
+    });
+    let check_onclick = move |_| {
+        let puzzle = NonogramPuzzle::from_solution(&use_solution());
+        let Some(depths) = search_depths(&puzzle) else {
+            error!("The puzzle has no solution");
+            return;
+        };
+        let mut counts = [0; 3];
+        for &depth in depths.iter().flatten() {
+            counts[depth as usize] += 1;
+        }
+        info!(
+            "Logic check: {} deduced, {} with one guess, {} with deeper guessing",
+            counts[0], counts[1], counts[2]
+        );
+        *use_depths.write() = Some(counts);
+        *use_heatmap.write() = Some(Heatmap {
+            cells: depths
+                .iter()
+                .map(|row| row.iter().map(|&depth| depth as f32 / 2.0).collect())
+                .collect(),
+        });
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_guessing_lint")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                button {
+                    class: "px-4 py-2 bg-blue-500 text-white font-bold rounded hover:bg-blue-600 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform",
+                    onclick: check_onclick,
+                    {t!("button_check_logic")}
+                }
+                if use_heatmap().is_some() {
+                    button {
+                        class: "px-4 py-2 bg-gray-700 text-white font-bold rounded hover:bg-gray-600 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform",
+                        onclick: move |_| *use_heatmap.write() = None,
+                        {t!("button_hide_overlay")}
+                    }
+                }
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    input {
+                        id: "check-mode-input",
+                        r#type: "checkbox",
+                        class: "w-5 h-5 cursor-pointer",
+                        checked: use_check_mode().enabled,
+                        onchange: move |event: FormEvent| {
+                            use_check_mode.write().enabled = event.checked();
+                        },
+                    }
+                    label {
+                        r#for: "check-mode-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_check_mode")}
+                    }
+                }
+            }
+            if use_check_mode().enabled {
+                div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6",
+                    span { class: "text-green-500",
+                        {t!("lines_tight", count : tightness_counts()[Tightness::Tight as usize])}
+                    }
+                    span { class: "text-amber-500",
+                        {t!("lines_overlapping", count : tightness_counts()[Tightness::Overlapping as usize])}
+                    }
+                    span { class: "text-red-500",
+                        {t!("lines_slack", count : tightness_counts()[Tightness::Slack as usize])}
+                    }
+                }
+            }
+            if let Some([deduced, single, deep]) = use_depths() {
+                div { class: "flex flex-col justify-items-center justify-center items-center text-gray-200",
+                    if single + deep == 0 {
+                        span { class: "text-green-500 font-semibold", {t!("logic_solvable")} }
+                    }
+                    span { {t!("cells_deduced", count : deduced)} }
+                    span { class: "text-red-300", {t!("cells_single_guess", count : single)} }
+                    span { class: "text-red-500", {t!("cells_deep_guess", count : deep)} }
+                }
+            }
+        }
+    }
+}
+
+/// A panel importing a puzzle from the plain-text clue notation, pasted into a textarea, or from
+/// a page of nonograms.org saved as HTML, which keeps the colors of the clues.
+///
+/// The imported puzzle has no known solution, so it's solved blind: the preview is hidden,
+/// mistakes aren't reported and the puzzle isn't kept in the session history.
+///
+/// # Context:
+/// - `Signal<NonogramPuzzle>`: Replaced by the puzzle described by the clues.
+/// - `Signal<NonogramFile>`: Replaced by an empty solution with the palette of the clues.
+/// - `Signal<NonogramPalette>`: Replaced by the palette of the clues.
+/// - `Signal<NonogramData>`: Marks the puzzle as blind, named after the imported page.
+/// - `Signal<ActionLog>`: Records the loading of the puzzle.
+/// - `Signal<Option<ClueSelection>>`: Cleared, since the clues of the imported puzzle are new (optional).
+#[component]
+fn ClueTextPanel() -> Element {
+    let mut use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let mut use_file = use_context::<Signal<NonogramFile>>();
+    let mut use_palette = use_context::<Signal<NonogramPalette>>();
+    let mut use_data = use_context::<Signal<NonogramData>>();
+    let dispatcher = use_dispatcher();
+    let use_selection = try_use_context::<Signal<Option<ClueSelection>>>();
+    let mut use_text = use_signal(String::new);
+    let mut use_error = use_signal(|| None::<ClueError>);
+    let mut import_puzzle =
+        move |puzzle: NonogramPuzzle, palette: NonogramPalette, name: String| {
+            let solution = NonogramSolution {
+                solution_grid: vec![vec![BACKGROUND; puzzle.cols]; puzzle.rows],
+            };
+            *use_file.write() = NonogramFile {
+                solution: solution.clone(),
+                palette: palette.clone(),
+                signature: None,
+                notes: String::new(),
+            };
+            *use_puzzle.write() = puzzle;
+            *use_palette.write() = palette;
+            use_data.write().filename = name;
+            use_data.write().completed = false;
+            use_data.write().blind = true;
+            if let Some(mut use_selection) = use_selection {
+                use_selection.set(None);
+            }
+            dispatcher.dispatch(GridCommand::Replace {
+                tool: Tool::Load,
+                solution,
+            });
+            *use_error.write() = None;
+        };
+    let import_onclick = move |_| match parse_clues(&use_text()) {
+        Ok(puzzle) => {
+            let palette = clue_palette(&puzzle);
+            import_puzzle(puzzle, palette, t!("untitled"));
+            info!("Nonogram imported from clues");
+        }
+        Err(err) => {
+            error!("Couldn't parse the clues: {err}");
+            *use_error.write() = Some(err);
+        }
+    };
+    let import_page_onchange = move |event: FormEvent| async move {
+        let Some(file_engine) = event.files() else {
+            error!("Event hadn't a file engine attached: {event:?}");
+            return;
+        };
+        let Some(file) = file_engine.files().into_iter().next() else {
+            error!("File engine had no attached files");
+            return;
+        };
+        let Some(html) = file_engine.read_file_to_string(&file).await else {
+            error!("Couldn't read file: '{file}'");
+            return;
+        };
+        match parse_nonograms_org(&html) {
+            Ok((puzzle, palette)) => {
+                import_puzzle(puzzle, palette, page_filename(&file));
+                info!("Nonogram imported from the nonograms.org page '{file}'");
+            }
+            Err(err) => {
+                error!("Couldn't parse the nonograms.org page '{file}': {err}");
+                *use_error.write() = Some(err);
+            }
+        }
+    };
+    let error_message = move || {
+        use_error().map(|err| match err {
+            ClueError::MissingLines => t!("clues_missing_lines"),
+            ClueError::InvalidClue { line, clue } => {
+                t!("clues_invalid_clue", line : line, clue : clue)
+            }
+            ClueError::LineTooLong { line } => t!("clues_line_too_long", line : line),
+            ClueError::ColorMismatch { color } => t!("clues_color_mismatch", color : color),
+        })
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_clue_text")}
+            }
+            div { class: "flex flex-col items-center gap-3 py-2",
+                textarea {
+                    class: "w-full max-w-xl h-48 px-4 py-2 font-mono rounded border border-gray-500 bg-gray-800 text-white focus:ring focus:ring-blue-500 focus:outline-none",
+                    placeholder: t!("placeholder_clue_text"),
+                    value: "{use_text}",
+                    oninput: move |event| *use_text.write() = event.value(),
+                }
+                if let Some(message) = error_message() {
+                    p { class: "text-red-400", "{message}" }
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: import_onclick,
+                    {t!("button_import_clues")}
+                }
+                div { class: "flex flex-row flex-wrap justify-center items-center gap-3",
+                    label { class: "py-2 text-gray-200 font-semibold select-none",
+                        {t!("label_import_nonograms_org")}
+                        ":"
+                    }
+                    input {
+                        class: "appearance-none rounded border px-4 py-1 border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform cursor-pointer",
+                        r#type: "file",
+                        accept: ".html,.htm",
+                        multiple: false,
+                        onchange: import_page_onchange,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A panel comparing the current solution grid against a reference solution.
+///
+/// The reference is the solution of the loaded puzzle (in the solver) or the solution of
+/// another `.ngram` file. The comparison is rendered as an overlay where matching cells are
+/// faded, and missing, extra and mismatched cells are highlighted, with the counts per color.
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: The solution grid to compare.
+/// - `Signal<NonogramPalette>`: Defines the color palette used.
+/// - `Signal<NonogramFile>`: The loaded puzzle, used as the default reference (optional).
+/// - `Signal<NonogramData>`: Whether the loaded puzzle is blind, without a reference unless its
+///   solution is cached.
+#[component]
+fn DiffPanel() -> Element {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let use_file = try_use_context::<Signal<NonogramFile>>();
+    let mut use_reference = use_signal(|| None::<NonogramSolution>);
+    let diff = use_memo(move || {
+        use_reference()
+            .or_else(|| {
+                use_file
+                    .filter(|_| !use_data().blind || use_data().solution_cached)
+                    .map(|use_file| use_file().solution)
+            })
+            .map(|reference| use_solution().diff(&reference))
+    });
+    let load_reference_onchange = move |event: FormEvent| async move {
+        let Some(file_engine) = event.files() else {
+            error!("Event hadn't a file engine attached: {event:?}");
+            return;
+        };
+        let Some(file) = file_engine.files().into_iter().next() else {
+            error!("File engine had no attached files");
+            return;
+        };
+        match read_nonogram_json(&file_engine, &file).await {
+            Some(json) => match serde_json::from_str::<NonogramFile>(&json) {
+                Ok(nonogram_file) => {
+                    *use_reference.write() = Some(nonogram_file.solution);
+                    info!("Comparing against '{file}'");
+                }
+                Err(err) => error!("Couldn't deserialize file '{file}': {err}"),
+            },
+            None => error!("Couldn't read file: '{file}'"),
+        }
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_diff")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                label { class: "py-2 text-gray-200 font-semibold select-none",
+                    {t!("label_compare_with")}
+                    ":"
+                }
+                input {
+                    class: "appearance-none rounded border px-4 py-1 border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform cursor-pointer",
+                    r#type: "file",
+                    accept: ".ngram,.png",
+                    multiple: false,
+                    onchange: load_reference_onchange,
+                }
+            }
+            if let Some(diff) = diff() {
+                div { class: "flex flex-row flex-wrap justify-items-center justify-center items-start gap-6",
+                    DiffGrid { diff: diff.clone() }
+                    table { class: "text-gray-200 text-right",
+                        thead {
+                            tr {
+                                th {}
+                                th { class: "px-2", {t!("diff_matching")} }
+                                th { class: "px-2 text-red-500", {t!("diff_missing")} }
+                                th { class: "px-2 text-amber-500", {t!("diff_extra")} }
+                            }
+                        }
+                        tbody {
+                            for (color , counts) in diff.counts.iter().enumerate() {
+                                tr { key: "diff-counts-{color}",
+                                    td {
+                                        span {
+                                            class: "inline-block w-5 h-5 rounded-full border border-gray-400",
+                                            style: "background-color: {use_palette().color_palette.get(color).cloned().unwrap_or_default()}",
+                                        }
+                                    }
+                                    td { class: "px-2 font-mono", "{counts.matching}" }
+                                    td { class: "px-2 font-mono", "{counts.missing}" }
+                                    td { class: "px-2 font-mono", "{counts.extra}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A panel for recording and replaying macros of editor commands.
+///
+/// While recording, every command dispatched in the editor is added to the macro. When the
+/// recording stops, the macro is named and stored in the settings, so it can be replayed on
+/// other grids (and in later sessions).
+///
+/// # Context:
+/// - `Signal<MacroRecorder>`: Records the dispatched commands.
+/// - `Signal<Settings>`: Stores the recorded macros.
+/// - `Signal<NonogramSolution>`: The solution grid where the macros are replayed.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+#[component]
+fn MacroPanel() -> Element {
+    let mut use_recorder = use_context::<Signal<MacroRecorder>>();
+    let mut use_settings = use_context::<Signal<Settings>>();
+    let dispatcher = use_dispatcher();
+    let mut use_name = use_signal(String::new);
+    let save_settings = move || {
+        if !use_settings().save() {
+            error!("Couldn't store the settings");
+        }
+    };
+    let toggle_recording = move |_| {
+        if use_recorder().is_recording() {
+            let mut name = use_name().trim().to_string();
+            if name.is_empty() {
+                name = format!("{} {}", t!("label_macro"), use_settings().macros.len() + 1);
+            }
+            match use_recorder.write().stop(name) {
+                Some(recorded) => {
+                    info!(
+                        "Recorded macro '{}' with {} commands",
+                        recorded.name,
+                        recorded.commands.len()
+                    );
+                    use_settings.write().macros.push(recorded);
+                    use_name.write().clear();
+                    save_settings();
+                }
+                None => info!("Discarded empty macro"),
+            }
+        } else {
+            info!("Recording macro...");
+            use_recorder.write().start();
+        }
+    };
+    rsx! {
+        div { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3",
+                label {
+                    r#for: "macro-name-input",
+                    class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                    {t!("label_macro")}
+                    ":"
+                }
+                input {
+                    id: "macro-name-input",
+                    class: "appearance-none px-4 py-1 w-min rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform",
+                    r#type: "text",
+                    placeholder: t!("label_macro"),
+                    oninput: move |event| *use_name.write() = event.value(),
+                    value: "{use_name}",
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    background_color: if use_recorder().is_recording() { "#991b1b" },
+                    onclick: toggle_recording,
+                    if use_recorder().is_recording() {
+                        {t!("button_stop_macro")}
+                    } else {
+                        {t!("button_record_macro")}
+                    }
+                }
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3",
+                for (index , recorded) in use_settings().macros.into_iter().enumerate() {
+                    div {
+                        key: "macro-{index}",
+                        class: "flex flex-row items-center gap-2 px-3 py-1 rounded border border-gray-500 bg-gray-800 text-white",
+                        button {
+                            class: "font-semibold hover:scale-110 active:scale-125 transition-transform transform",
+                            title: t!("button_play_macro"),
+                            onclick: move |_| {
+                                info!("Replaying macro '{}'", recorded.name);
+                                for command in recorded.commands.iter().cloned() {
+                                    dispatcher.dispatch(command);
+                                }
+                            },
+                            "▶ {recorded.name}"
+                        }
+                        button {
+                            class: "text-gray-400 hover:text-red-500 hover:scale-125 active:scale-150 transition-transform transform",
+                            title: t!("button_delete_macro"),
+                            onclick: move |_| {
+                                use_settings.write().macros.remove(index);
+                                save_settings();
+                            },
+                            "✕"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The feedback of a `NumberInput` about its last input.
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum NumberFeedback {
+    /// The input isn't a number, it's ignored.
+    Invalid,
+    /// The input is out of range, it's clamped to the given value.
+    Clamped(usize),
+}
+
+/// A labeled numeric input restricted to a range, shared by the toolbars.
+///
+/// Instead of silently ignoring invalid input, non-numeric input is rejected and out-of-range
+/// values are clamped to the range, with an inline message and a colored border explaining it.
+///
+/// # Arguments:
+/// - `id`: The identifier of the input, referenced by its label.
+/// - `label`: The text of the label.
+/// - `value`: The current value.
+/// - `min`: The minimum allowed value.
+/// - `max`: The maximum allowed value.
+/// - `step`: The step of the spin buttons (`1` by default).
+/// - `readonly`: Whether the input is read-only.
+/// - `onchange`: Called with the new (clamped) value.
+#[component]
+fn NumberInput(
+    id: String,
+    label: String,
+    value: usize,
+    min: usize,
+    max: usize,
+    #[props(default = 1)] step: usize,
+    #[props(default)] readonly: bool,
+    onchange: EventHandler<usize>,
+) -> Element {
+    let mut use_feedback = use_signal(|| None::<NumberFeedback>);
+    let check = move |input: &str| match input.trim().parse::<usize>() {
+        Ok(number) if (min..=max).contains(&number) => Ok(number),
+        Ok(number) => Err(NumberFeedback::Clamped(number.clamp(min, max))),
+        Err(_) => Err(NumberFeedback::Invalid),
+    };
+    let border_color = match use_feedback() {
+        Some(NumberFeedback::Invalid) => "#ef4444",
+        Some(NumberFeedback::Clamped(_)) => "#f59e0b",
+        None => "#6b7280",
+    };
+    rsx! {
+        div { class: "flex flex-col justify-items-center justify-center items-center",
+            div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                label {
+                    r#for: "{id}",
+                    class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                    pointer_events: if readonly { "none" },
+                    color: if readonly { "darkgray" },
+                    "{label}:"
+                }
+                input {
+                    id: "{id}",
+                    class: "appearance-none px-4 py-1 w-20 rounded border bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform",
+                    border_color,
+                    pointer_events: if readonly { "none" },
+                    color: if readonly { "darkgray" },
+                    readonly,
+                    r#type: "number",
+                    min: "{min}",
+                    max: "{max}",
+                    step: "{step}",
+                    oninput: move |event: FormEvent| {
+                        *use_feedback.write() = check(&event.value()).err();
+                    },
+                    onchange: move |event: FormEvent| {
+                        match check(&event.value()) {
+                            Ok(number) => {
+                                *use_feedback.write() = None;
+                                onchange.call(number);
+                            }
+                            Err(NumberFeedback::Clamped(number)) => {
+                                *use_feedback.write() = Some(NumberFeedback::Clamped(number));
+                                onchange.call(number);
+                            }
+                            Err(NumberFeedback::Invalid) => {
+                                *use_feedback.write() = Some(NumberFeedback::Invalid);
+                            }
+                        }
+                    },
+                    onblur: move |_| {
+                        if use_feedback() == Some(NumberFeedback::Invalid) {
+                            *use_feedback.write() = None;
+                        }
+                    },
+                    value: "{value}",
+                }
+            }
+            match use_feedback() {
+                Some(NumberFeedback::Invalid) => rsx! {
+                    span { class: "text-xs text-red-400 select-none", {t!("invalid_number", min : min, max : max)} }
+                },
+                Some(NumberFeedback::Clamped(number)) => rsx! {
+                    span { class: "text-xs text-amber-400 select-none",
+                        {t!("clamped_number", value : number, min : min, max : max)}
+                    }
+                },
+                None => rsx! {},
+            }
+        }
+    }
+}
+
+/// Builds the handler resizing the solution grid to some rows and columns.
+///
+/// Crops deleting colored cells aren't applied when there's a `PendingResize` context (in the
+/// editor): they're held there, so `ResizeWarning` asks what to do with the drawing.
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: The grid resized.
+/// - `Signal<ResizeMode>`: How the drawing is adapted to the new size (optional, crops by default).
+/// - `Signal<Option<PendingResize>>`: Holds the crops deleting colored cells (optional).
+fn use_resize() -> impl Fn(usize, usize) + Copy {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_resize_mode = try_use_context::<Signal<ResizeMode>>();
+    let use_pending = try_use_context::<Signal<Option<PendingResize>>>();
+    let dispatcher = use_dispatcher();
+    move |rows, cols| {
+        let mode = use_resize_mode.map(|mode| mode()).unwrap_or_default();
+        let lost = match mode {
+            ResizeMode::Crop => use_solution.peek().cells_outside(rows, cols),
+            _ => 0,
+        };
+        if let Some(mut use_pending) = use_pending.filter(|_| lost > 0) {
+            info!("Resize to {rows}x{cols} held, it would delete {lost} cells");
+            use_pending.set(Some(PendingResize { rows, cols, lost }));
+            return;
+        }
+        dispatcher.dispatch(GridCommand::Resize { rows, cols, mode });
+    }
+}
+
+/// A warning shown when a crop of the grid would delete colored cells, offering to slide the
+/// drawing inside the new size, to crop the grid around the drawing instead, or to resize anyway.
+///
+/// # Context:
+/// - `Signal<Option<PendingResize>>`: The resize held for confirmation.
+/// - `Signal<NonogramSolution>`: The grid resized.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+#[component]
+fn ResizeWarning() -> Element {
+    let mut use_pending = use_context::<Signal<Option<PendingResize>>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let dispatcher = use_dispatcher();
+    let Some(PendingResize { rows, cols, lost }) = use_pending() else {
+        return rsx! {};
+    };
+    let slide = use_solution().slide_to_fit(rows, cols);
+    let crop = move |dispatcher: Dispatcher| {
+        dispatcher.dispatch(GridCommand::Resize {
+            rows,
+            cols,
+            mode: ResizeMode::Crop,
+        });
+    };
+    rsx! {
+        div { class: "flex flex-col items-center gap-3 p-4 rounded border border-amber-500 bg-gray-800 text-white",
+            p { class: "font-semibold text-amber-400 text-center",
+                {t!("resize_warning", lost : lost, rows : rows, cols : cols)}
+            }
+            div { class: "flex flex-row flex-wrap justify-center items-center gap-3",
+                if let Some((dx, dy)) = slide {
+                    button {
+                        class: "px-4 py-1 font-bold rounded border border-gray-500 bg-blue-800 text-white hover:bg-blue-700 hover:scale-110 active:scale-125 transition-transform transform",
+                        onclick: move |_| {
+                            dispatcher.dispatch(GridCommand::Slide { dx, dy });
+                            crop(dispatcher);
+                            use_pending.set(None);
+                        },
+                        {t!("button_slide_and_resize")}
+                    }
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: move |_| {
+                        if let Some(area) = use_solution().bounding_box() {
+                            dispatcher.dispatch(GridCommand::Crop { area });
+                        }
+                        use_pending.set(None);
+                    },
+                    {t!("button_crop_to_drawing")}
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: move |_| {
+                        crop(dispatcher);
+                        use_pending.set(None);
+                    },
+                    {t!("button_resize_anyway")}
+                }
+                button {
+                    class: "px-4 py-1 rounded border border-gray-500 bg-gray-700 text-white hover:bg-gray-600",
+                    onclick: move |_| use_pending.set(None),
+                    {t!("button_cancel")}
+                }
+            }
+        }
+    }
+}
+
+/// A component for inputting the number of rows in the Nonogram solution.
+///
+/// This component allows the user to set the number of rows for the Nonogram puzzle.
+/// The input is validated to be within a reasonable range (2 to 40), clamping out-of-range values
+/// (see `NumberInput`), and updates the Nonogram solution.
+///
+/// # Parameters:
+/// - `readonly`: A boolean flag to indicate whether the input field should be read-only.
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: Provides access to and updates for the current Nonogram solution.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+/// - `Signal<ResizeMode>`: How the drawing is adapted to the new size (optional, crops by default).
+/// - `Signal<Option<PendingResize>>`: Holds the crops deleting colored cells (optional).
+#[component]
+fn RowsInput(readonly: bool) -> Element {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let resize = use_resize();
+    rsx! {
+        NumberInput {
+            id: "rows-input",
+            label: t!("label_rows"),
+            value: use_solution().rows(),
+            min: 2,
+            max: 40,
+            readonly,
+            onchange: move |rows| resize(rows, use_solution().cols()),
+        }
+    }
+}
+
+/// A component for inputting the number of columns in the Nonogram solution.
+///
+/// This component allows the user to set the number of columns for the Nonogram puzzle.
+/// The input is validated to be within a reasonable range (2 to 40), clamping out-of-range values
+/// (see `NumberInput`), and updates the Nonogram solution.
+///
+/// # Parameters:
+/// - `readonly`: A boolean flag to indicate whether the input field should be read-only.
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: Provides access to and updates for the current Nonogram solution.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+/// - `Signal<ResizeMode>`: How the drawing is adapted to the new size (optional, crops by default).
+/// - `Signal<Option<PendingResize>>`: Holds the crops deleting colored cells (optional).
+#[component]
+fn ColumnsInput(readonly: bool) -> Element {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let resize = use_resize();
+    rsx! {
+        NumberInput {
+            id: "cols-input",
+            label: t!("label_columns"),
+            value: use_solution().cols(),
+            min: 2,
+            max: 40,
+            readonly,
+            onchange: move |cols| resize(use_solution().rows(), cols),
+        }
+    }
+}
+
+/// A component for selecting how the drawing is adapted when the grid is resized.
+///
+/// The drawing can be cropped (or padded with the background color), or rescaled to the new
+/// dimensions using the nearest cell or the most common color of the covered cells.
+///
+/// # Context:
+/// - `Signal<ResizeMode>`: The selected resize mode.
+#[component]
+fn ResizeModeSelect() -> Element {
+    let mut use_resize_mode = use_context::<Signal<ResizeMode>>();
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            label {
+                r#for: "resize-mode-select",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_resize_mode")}
+                ":"
+            }
+            select {
+                id: "resize-mode-select",
+                class: "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform cursor-pointer",
+                onchange: move |event: FormEvent| {
+                    *use_resize_mode.write() = match event.value().as_str() {
+                        "nearest" => ResizeMode::Nearest,
+                        "majority" => ResizeMode::Majority,
+                        _ => ResizeMode::Crop,
+                    };
+                    info!("Changed resize mode to {:?}", use_resize_mode());
+                },
+                option {
+                    value: "crop",
+                    selected: use_resize_mode() == ResizeMode::Crop,
+                    {t!("resize_mode_crop")}
+                }
+                option {
+                    value: "nearest",
+                    selected: use_resize_mode() == ResizeMode::Nearest,
+                    {t!("resize_mode_nearest")}
+                }
+                option {
+                    value: "majority",
+                    selected: use_resize_mode() == ResizeMode::Majority,
+                    {t!("resize_mode_majority")}
+                }
+            }
+        }
+    }
+}
+
+/// A component for inputting the block size of the Nonogram grid.
+///
+/// This component allows the user to set the block size used in the Nonogram puzzle grid.
+/// The value is validated within a reasonable range (10 to 100), clamping out-of-range values
+/// (see `NumberInput`), and updates the Nonogram data accordingly.
+///
+/// Setting the size manually disables the automatic size (see `use_auto_block_size`), which can
+/// be enabled again with the checkbox next to the input.
+///
+/// # Context:
+/// - `Signal<NonogramData>`: Provides access to and updates for the Nonogram editor state, including block size.
+#[component]
+fn BlockSizeInput() -> Element {
+    let mut use_data = use_context::<Signal<NonogramData>>();
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            NumberInput {
+                id: "size-input",
+                label: t!("label_size"),
+                value: use_data().block_size,
+                min: 10,
+                max: 100,
+                step: 5,
+                onchange: move |size| {
+                    let mut data = use_data.write();
+                    data.block_size = size;
+                    data.auto_block_size = false;
+                },
+            }
+            input {
+                id: "auto-size-input",
+                r#type: "checkbox",
+                class: "w-5 h-5 cursor-pointer",
+                checked: use_data().auto_block_size,
+                onchange: move |event: FormEvent| {
+                    use_data.write().auto_block_size = event.checked();
+                },
+            }
+            label {
+                r#for: "auto-size-input",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_auto_size")}
+            }
+        }
+    }
+}
+
+/// A checkbox toggling the checkerboard pattern of the background cells.
+///
+/// Like image editors showing transparency, the pattern distinguishes the background cells from
+/// cells painted with a similar color. The choice is stored in the settings.
+///
+/// # Context:
+/// - `Signal<Settings>`: Stores whether the checkerboard is rendered.
+#[component]
+fn CheckerboardToggle() -> Element {
+    let mut use_settings = use_context::<Signal<Settings>>();
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            input {
+                id: "checkerboard-input",
+                r#type: "checkbox",
+                class: "w-5 h-5 cursor-pointer",
+                checked: use_settings().checkerboard,
+                onchange: move |event: FormEvent| {
+                    use_settings.write().checkerboard = event.checked();
+                    if !use_settings().save() {
+                        error!("Couldn't store the settings");
+                    }
+                },
+            }
+            label {
+                r#for: "checkerboard-input",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_checkerboard")}
+            }
+        }
+    }
+}
+
+/// Where a run of the solver starts its search from.
+#[derive(Clone, Copy, PartialEq)]
+enum SolveStart {
+    /// A new population, keeping the colors of the pinned cells.
+    Fresh,
+    /// A population around the painted grid.
+    Grid,
+    /// The final population of the last search.
+    Population,
+}
+
+/// A button component for solving the Nonogram puzzle.
+///
+/// This component initiates the process of solving the Nonogram puzzle by running a solution algorithm.
+/// It updates the Nonogram solution based on the result and handles a loading state during the process.
+/// The search runs in the background (see `run_solver`), so the interface stays responsive, and
+/// a stop button aborts it while it runs, keeping its best approach so far. The scores of each
+/// generation are streamed into the history as they're evolved, so the convergence graph is
+/// drawn live (in batches, in the low-power mode).
+///
+/// Once a search finishes, an "Evolve more" button continues it from its final population for
+/// another run of generations, instead of a new random population, extending its history.
+///
+/// The solve button always starts from a new population, keeping only the pinned cells, so its
+/// runs can be reproduced with a fixed seed. While the grid is painted, a "Continue from grid"
+/// button warm-starts the search around it instead (see `WarmStart`).
+///
+/// # Context:
+/// - `Signal<NonogramPuzzle>`: Provides access to the current Nonogram puzzle.
+/// - `Signal<History>`: Updates the history of Nonogram solving attempts, and keeps the last
+///   population to evolve it further.
+/// - `Signal<Option<Progress>>`: The progress of the running search.
+/// - `Signal<SolverConfig>`: The parameters of the genetic algorithm.
+/// - `Signal<NonogramSolution>`: Updates the Nonogram solution based on the solving result, and
+///   warm-starts the search when continued from it.
+/// - `Signal<PinnedCells>`: The cells whose colors the search never changes.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+/// - `Signal<Settings>`: Where the solutions found are saved automatically, and how often the
+///   progress is streamed.
+/// - `Signal<NonogramPalette>`: The palette saved with the solutions.
+/// - `Signal<NonogramData>`: The filename of the puzzle, next to which the solutions are saved.
+/// - `Signal<PopulationOverlay>`: Whether the population is displayed while solving.
+/// - `Signal<Option<Heatmap>>`: The heat overlay showing the cells the population disagrees on.
+/// - `Signal<SolverSeed>`: The seed of every run, if it's fixed.
+/// - `Signal<SessionRng>`: Draws the seed of each run, unless it's fixed.
+#[component]
+fn SolveButton() -> Element {
+    let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_pinned = use_context::<Signal<PinnedCells>>();
+    let use_settings = try_use_context::<Signal<Settings>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let mut use_history = use_context::<Signal<History>>();
+    let mut use_log = use_context::<Signal<ActionLog>>();
+    let dispatcher = use_dispatcher();
+    let mut use_running = use_signal(|| false);
+    let mut use_cancel = use_signal(CancelToken::default);
+    let use_overlay = use_context::<Signal<PopulationOverlay>>();
+    let mut use_heatmap = use_context::<Signal<Option<Heatmap>>>();
+    let mut use_rng = use_context::<Signal<SessionRng>>();
+    let mut use_progress = use_context::<Signal<Option<Progress>>>();
+    let use_config = use_context::<Signal<SolverConfig>>();
+    let use_seed = use_context::<Signal<SolverSeed>>();
+    #[cfg(feature = "telemetry")]
+    let use_active = try_use_context::<Signal<ActiveProfile>>();
+    let solve = move |from: SolveStart| async move {
+        if use_running() {
+            info!("Already solving nonogram!");
+        } else {
+            *use_running.write() = true;
+            let cancel = CancelToken::default();
+            use_cancel.set(cancel.clone());
+            let seed = match use_seed.peek().fixed {
+                Some(seed) => seed,
+                None => use_rng.write().next_seed(),
+            };
+            let config = use_config();
+            info!("Solving nonogram with seed {seed} and {config:?}...");
+            // Continues from the last population or from the painted grid, if asked to, always
+            // keeping the cells pinned by the user
+            let previous = (from == SolveStart::Population).then(|| use_history.peek().clone());
+            let pinned = use_pinned.peek().cells.clone();
+            let mut grid = use_solution.peek().clone();
+            if from == SolveStart::Fresh {
+                for (row, cells) in grid.solution_grid.iter_mut().enumerate() {
+                    for (col, color) in cells.iter_mut().enumerate() {
+                        if !pinned.contains(&(row, col)) {
+                            *color = BACKGROUND;
+                        }
+                    }
+                }
+            }
+            let start = Some(WarmStart {
+                grid,
+                pinned,
+                population: previous
+                    .as_ref()
+                    .map(|previous| previous.population.clone())
+                    .unwrap_or_default(),
+            })
+            .filter(|start| {
+                !start.population.is_empty()
+                    || !start.pinned.is_empty()
+                    || start
+                        .grid
+                        .solution_grid
+                        .iter()
+                        .flatten()
+                        .any(|&color| color != BACKGROUND)
+            });
+            if let Some(previous) = &previous {
+                info!(
+                    "Evolving the last population further, after {} generations",
+                    previous.iterations
+                );
+            } else if let Some(start) = start.as_ref().filter(|_| from == SolveStart::Grid) {
+                info!(
+                    "Warm-starting the solver from the painted grid, with {} pinned cells",
+                    start.pinned.len()
+                );
+            } else if let Some(start) = &start {
+                info!(
+                    "Starting the solver with {} pinned cells",
+                    start.pinned.len()
+                );
+            }
+            #[cfg(feature = "telemetry")]
+            let started = std::time::Instant::now();
+            if previous.is_none() {
+                let mut history = use_history.write();
+                history.iterations = 0;
+                history.best.clear();
+                history.median.clear();
+                history.worst.clear();
+                history.diversity.clear();
+                history.pareto_front.clear();
+            }
+            let power_mode = use_settings
+                .map(|use_settings| use_settings.peek().power_mode)
+                .unwrap_or_default();
+            // Progress not displayed yet, flushed every few generations, after the generations
+            // of the search continued
+            let mut pending = Vec::new();
+            let offset = previous.as_ref().map_or(0, |previous| previous.iterations);
+            let show_event = move |event: SearchEvent| match event {
+                SearchEvent::Progress(progress) => {
+                    pending.push(progress);
+                    if progress
+                        .iteration
+                        .is_multiple_of(power_mode.progress_interval())
+                    {
+                        let mut history = use_history.write();
+                        for progress in pending.drain(..) {
+                            history.record(&Progress {
+                                iteration: progress.iteration + offset,
+                                ..progress
+                            });
+                        }
+                        use_progress.set(Some(progress));
+                    }
+                }
+                SearchEvent::Sample(sample) => {
+                    if use_overlay.peek().enabled {
+                        *use_heatmap.write() = Some(Heatmap {
+                            cells: sample
+                                .agreement
+                                .iter()
+                                .map(|row| row.iter().map(|agreement| 1.0 - agreement).collect())
+                                .collect(),
+                        });
+                    }
+                }
+            };
+            let Some(mut history) = run_solver(
+                use_puzzle(),
+                config,
+                seed,
+                start,
+                power_mode.sample_interval(),
+                show_event,
+                cancel,
+            )
+            .await
+            else {
+                error!("The solver stopped unexpectedly");
+                use_progress.set(None);
+                *use_running.write() = false;
+                return;
+            };
+            use_progress.set(None);
+            *use_heatmap.write() = None;
+            let (Ok(result) | Err(result)) = &history.winner;
+            if history.winner.is_ok() {
+                info!("Nonogram solved!");
+            } else if history.cancelled {
+                info!("Nonogram solving stopped!");
+            } else if let Some(reason) = history.stop_reason {
+                info!("Nonogram not solved! Stopped by {reason}");
+            } else {
+                info!("Nonogram not solved!");
+            }
+            dispatcher.dispatch(GridCommand::Replace {
+                tool: Tool::Solver,
+                solution: result.clone(),
+            });
+            use_log.write().record(Action::SolverRun {
+                solved: history.winner.is_ok(),
+                score: history.winner_score(&use_puzzle(), config.fitness),
+                iterations: history.iterations,
+            });
+            let autosave = use_settings
+                .map(|use_settings| use_settings.peek().autosave)
+                .unwrap_or_default();
+            let saved = history.winner.as_ref().ok().and_then(|solution| {
+                autosave_solution(autosave, solution, &use_palette(), &use_data().filename)
+            });
+            #[cfg(feature = "telemetry")]
+            record_run(
+                &use_puzzle(),
+                &history,
+                &config,
+                seed,
+                started.elapsed(),
+                use_active
+                    .and_then(|use_active| use_active().profile)
+                    .as_ref(),
+                saved.filter(|_| autosave == Autosave::RunHistory),
+            );
+            #[cfg(not(feature = "telemetry"))]
+            let _ = saved;
+            if let Some(previous) = &previous {
+                history.prepend(previous);
+            }
+            *use_history.write() = history;
+            *use_running.write() = false;
+        }
+    };
+    // The last population can only be evolved further on a puzzle of its size
+    let resumable = use_history().population.first().is_some_and(|solution| {
+        let puzzle = use_puzzle();
+        solution.solution_grid.len() == puzzle.rows
+            && solution
+                .solution_grid
+                .iter()
+                .all(|row| row.len() == puzzle.cols)
+    });
+    let painted = use_solution()
+        .solution_grid
+        .iter()
+        .flatten()
+        .any(|&color| color != BACKGROUND);
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                onmousedown: move |_| {},
+                onclick: move |_| solve(SolveStart::Fresh),
+                {t!("button_solve_nonogram")}
+            }
+            if painted && !use_running() {
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    title: t!("hint_continue_from_grid"),
+                    onclick: move |_| solve(SolveStart::Grid),
+                    {t!("button_continue_from_grid")}
+                }
+            }
+            if resumable && !use_running() {
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    title: t!("hint_evolve_more"),
+                    onclick: move |_| solve(SolveStart::Population),
+                    {t!("button_evolve_more")}
+                }
+            }
+            if use_running() {
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-red-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: move |_| {
+                        info!("Stopping the solver...");
+                        use_cancel.peek().cancel();
+                    },
+                    {t!("button_stop_solver")}
+                }
+            }
+        }
+    }
+}
+
+/// The events of a solver run in the background.
+#[cfg(not(feature = "web"))]
+enum SolverEvent {
+    /// The progress or a sample of the population, reported while the search runs.
+    Search(SearchEvent),
+    /// The history of the finished search.
+    Finished(History),
+}
+
+#[cfg(not(feature = "web"))]
+/// Runs the solver in a background thread, streaming its progress and samples of its population
+/// through a channel.
+///
+/// # Arguments:
+/// - `puzzle`: The puzzle to solve.
+/// - `config`: The parameters of the genetic algorithm.
+/// - `seed`: The seed of the random number generator.
+/// - `start`: The grid painted by the user and its pinned cells, warm-starting the search, if any.
+/// - `sample_interval`: The generations between the samples of the population.
+/// - `onevent`: Called with the progress of each generation and each sample of the population, on
+///   the UI thread.
+/// - `cancel`: Stops the search before it finishes.
+///
+/// # Returns:
+/// The history of the search, or `None` if the thread stopped without finishing it.
+async fn run_solver(
+    puzzle: NonogramPuzzle,
+    config: SolverConfig,
+    seed: u64,
+    start: Option<WarmStart>,
+    sample_interval: usize,
+    mut onevent: impl FnMut(SearchEvent),
+    cancel: CancelToken,
+) -> Option<History> {
+    use futures::StreamExt;
+
+    let (sender, mut receiver) = futures::channel::mpsc::unbounded();
+    std::thread::spawn(move || {
+        let history = solve_nonogram_sampled(
+            puzzle,
+            &config,
+            seed,
+            start.as_ref(),
+            sample_interval,
+            &mut |event| {
+                let _ = sender.unbounded_send(SolverEvent::Search(event));
+            },
+            &cancel,
+        );
+        let _ = sender.unbounded_send(SolverEvent::Finished(history));
+    });
+    while let Some(event) = receiver.next().await {
+        match event {
+            SolverEvent::Search(event) => onevent(event),
+            SolverEvent::Finished(history) => return Some(history),
+        }
+    }
+    None
+}
+
+/// The generations evolved between the yields to the browser, while the solver runs on the web.
+#[cfg(feature = "web")]
+const GENERATIONS_PER_YIELD: usize = 2;
+
+#[cfg(feature = "web")]
+/// Runs the solver a few generations at a time, passing its progress and the samples of its
+/// population to a callback.
+///
+/// There are no threads on web platforms, so the search yields to the browser between chunks of
+/// generations, keeping the page responsive while it runs.
+///
+/// # Arguments:
+/// - `puzzle`: The puzzle to solve.
+/// - `config`: The parameters of the genetic algorithm.
+/// - `seed`: The seed of the random number generator.
+/// - `start`: The grid painted by the user and its pinned cells, warm-starting the search, if any.
+/// - `sample_interval`: The generations between the samples of the population.
+/// - `onevent`: Called with the progress of each generation and each sample of the population.
+/// - `cancel`: Stops the search before it finishes, checked between the chunks of generations.
+///
+/// # Returns:
+/// The history of the search, or `None` if the browser couldn't be yielded to.
+async fn run_solver(
+    puzzle: NonogramPuzzle,
+    config: SolverConfig,
+    seed: u64,
+    start: Option<WarmStart>,
+    sample_interval: usize,
+    mut onevent: impl FnMut(SearchEvent),
+    cancel: CancelToken,
+) -> Option<History> {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut search = EvolutiveSearch::new(puzzle, config, start.as_ref(), &mut rng);
+    loop {
+        if cancel.is_cancelled() {
+            search.stop();
+            return Some(search.finish());
+        }
+        for _ in 0..GENERATIONS_PER_YIELD {
+            if !search.step(&mut rng, sample_interval, &mut onevent) {
+                return Some(search.finish());
+            }
+        }
+        sleep(0).await?;
+    }
+}
+
+/// Waits for some milliseconds through a browser timer, without blocking the interface.
+///
+/// # Arguments:
+/// - `millis`: The milliseconds waited.
+///
+/// # Returns:
+/// `None` if the timer couldn't be set.
+async fn sleep(millis: u32) -> Option<()> {
+    document::eval(&format!(
+        "await new Promise((resolve) => setTimeout(resolve, {millis})); return true;"
+    ))
+    .join::<bool>()
+    .await
+    .ok()
+    .map(|_| ())
+}
+
+/// A panel tuning the parameters of the genetic algorithm used by the solve button, for the
+/// puzzle being solved. The probabilities are entered as percentages, and can adapt while the
+/// search runs (see `Adaptation`). The mix of mutation operators is entered as relative weights
+/// (see `MutationWeights`). A crowding factor keeps the population diverse (see `SolverConfig`),
+/// and the multi-objective mode minimizes the violations of the rows and of the columns apart
+/// (see `Replacement::Pareto`).
+///
+/// # Context:
+/// - `Signal<SolverConfig>`: The parameters of the genetic algorithm.
+#[component]
+fn SolverConfigPanel() -> Element {
+    let mut use_config = use_context::<Signal<SolverConfig>>();
+    let config = use_config();
+    let percent = |probability: f64| (probability * 100.0).round() as usize;
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_solver_parameters")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                NumberInput {
+                    id: "population-size-input",
+                    label: t!("label_population_size"),
+                    value: config.population_size,
+                    min: 2,
+                    max: 5000,
+                    step: 10,
+                    onchange: move |size: usize| use_config.write().population_size = size,
+                }
+                NumberInput {
+                    id: "cross-probability-input",
+                    label: t!("label_cross_probability"),
+                    value: percent(config.cross_probability),
+                    min: 0,
+                    max: 100,
+                    onchange: move |probability: usize| {
+                        use_config.write().cross_probability = probability as f64 / 100.0;
+                    },
+                }
+                NumberInput {
+                    id: "mutation-probability-input",
+                    label: t!("label_mutation_probability"),
+                    value: percent(config.mutation_probability),
+                    min: 0,
+                    max: 100,
+                    onchange: move |probability: usize| {
+                        use_config.write().mutation_probability = probability as f64 / 100.0;
+                    },
+                }
+                NumberInput {
+                    id: "tournament-size-input",
+                    label: t!("label_tournament_size"),
+                    value: config.tournament_size,
+                    min: 1,
+                    max: 50,
+                    onchange: move |size: usize| use_config.write().tournament_size = size,
+                }
+                NumberInput {
+                    id: "slide-tries-input",
+                    label: t!("label_slide_tries"),
+                    value: config.slide_tries,
+                    min: 0,
+                    max: 50,
+                    onchange: move |tries: usize| use_config.write().slide_tries = tries,
+                }
+                NumberInput {
+                    id: "slide-weight-input",
+                    label: t!("label_slide_weight"),
+                    value: config.mutation.slide.round() as usize,
+                    min: 0,
+                    max: 10,
+                    onchange: move |weight: usize| use_config.write().mutation.slide = weight as f64,
+                }
+                NumberInput {
+                    id: "reposition-weight-input",
+                    label: t!("label_reposition_weight"),
+                    value: config.mutation.reposition.round() as usize,
+                    min: 0,
+                    max: 10,
+                    onchange: move |weight: usize| use_config.write().mutation.reposition = weight as f64,
+                }
+                NumberInput {
+                    id: "reshuffle-weight-input",
+                    label: t!("label_reshuffle_weight"),
+                    value: config.mutation.reshuffle.round() as usize,
+                    min: 0,
+                    max: 10,
+                    onchange: move |weight: usize| use_config.write().mutation.reshuffle = weight as f64,
+                }
+                NumberInput {
+                    id: "max-iterations-input",
+                    label: t!("label_max_iterations"),
+                    value: config.stop.max_iterations,
+                    min: 1,
+                    max: 10000,
+                    step: 10,
+                    onchange: move |iterations: usize| use_config.write().stop.max_iterations = iterations,
+                }
+                NumberInput {
+                    id: "target-score-input",
+                    label: t!("label_target_score"),
+                    value: config.stop.target_score,
+                    min: 0,
+                    max: 10000,
+                    onchange: move |score: usize| use_config.write().stop.target_score = score,
+                }
+                NumberInput {
+                    id: "stagnation-window-input",
+                    label: t!("label_stagnation_window"),
+                    value: config.stop.stagnation.unwrap_or_default(),
+                    min: 0,
+                    max: 10000,
+                    step: 10,
+                    onchange: move |window: usize| {
+                        use_config.write().stop.stagnation = (window > 0).then_some(window);
+                    },
+                }
+                NumberInput {
+                    id: "time-limit-input",
+                    label: t!("label_time_limit"),
+                    value: config.stop.max_millis.map_or(0, |millis| (millis / 1000) as usize),
+                    min: 0,
+                    max: 3600,
+                    step: 5,
+                    onchange: move |seconds: usize| {
+                        use_config.write().stop.max_millis = (seconds > 0)
+                            .then_some(seconds as u64 * 1000);
+                    },
+                }
+                NumberInput {
+                    id: "crowding-factor-input",
+                    label: t!("label_crowding_factor"),
+                    value: config.crowding.unwrap_or_default(),
+                    min: 0,
+                    max: 20,
+                    onchange: move |factor: usize| {
+                        use_config.write().crowding = (factor > 0).then_some(factor);
+                    },
+                }
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    input {
+                        id: "adaptive-rates-input",
+                        r#type: "checkbox",
+                        class: "w-5 h-5 cursor-pointer",
+                        checked: config.adaptation.is_some(),
+                        onchange: move |event: FormEvent| {
+                            use_config.write().adaptation = event.checked().then(Adaptation::default);
+                        },
+                    }
+                    label {
+                        r#for: "adaptive-rates-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_adaptive_rates")}
+                    }
+                }
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    input {
+                        id: "pareto-selection-input",
+                        r#type: "checkbox",
+                        class: "w-5 h-5 cursor-pointer",
+                        checked: config.replacement == Replacement::Pareto,
+                        onchange: move |event: FormEvent| {
+                            use_config.write().replacement = if event.checked() {
+                                Replacement::Pareto
+                            } else {
+                                Replacement::default()
+                            };
+                        },
+                    }
+                    label {
+                        r#for: "pareto-selection-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_pareto_selection")}
+                    }
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: move |_| use_config.set(SolverConfig::default()),
+                    {t!("button_reset_parameters")}
+                }
+            }
+        }
+    }
+}
+
+/// An input fixing the seed of the solver, so its runs are reproducible, with a button drawing a
+/// random seed. While the input is empty, every run draws a new seed from the session.
+///
+/// # Context:
+/// - `Signal<SolverSeed>`: The seed of the solver.
+/// - `Signal<SessionRng>`: Draws the random seeds.
+#[component]
+fn SolverSeedInput() -> Element {
+    let mut use_seed = use_context::<Signal<SolverSeed>>();
+    let mut use_rng = use_context::<Signal<SessionRng>>();
+    let mut use_text = use_signal(|| {
+        use_seed
+            .peek()
+            .fixed
+            .map(|seed| seed.to_string())
+            .unwrap_or_default()
+    });
+    let mut use_invalid = use_signal(|| false);
+    let apply = move |_| {
+        let text = use_text();
+        let text = text.trim();
+        match text.parse::<u64>() {
+            _ if text.is_empty() => {
+                use_seed.write().fixed = None;
+                use_invalid.set(false);
+                info!("Solver seeded by the session");
+            }
+            Ok(seed) => {
+                use_seed.write().fixed = Some(seed);
+                use_invalid.set(false);
+                info!("Solver seeded with {seed}");
+            }
+            Err(err) => {
+                error!("Invalid solver seed: {err}");
+                use_invalid.set(true);
+            }
+        }
+    };
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            label {
+                r#for: "solver-seed-input",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_solver_seed")}
+                ":"
+            }
+            input {
+                id: "solver-seed-input",
+                r#type: "text",
+                inputmode: "numeric",
+                class: "w-48 px-2 py-1 font-mono rounded border bg-gray-800 text-white",
+                border_color: if use_invalid() { "#ef4444" } else { "#6b7280" },
+                placeholder: t!("placeholder_solver_seed"),
+                value: use_text(),
+                oninput: move |event: FormEvent| use_text.set(event.value()),
+                onchange: apply,
+            }
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                onclick: move |_| {
+                    let seed = use_rng.write().next_seed();
+                    use_seed.write().fixed = Some(seed);
+                    use_text.set(seed.to_string());
+                    use_invalid.set(false);
+                    info!("Solver seeded with {seed}");
+                },
+                {t!("button_random_seed")}
+            }
+        }
+    }
+}
+
+/// Displays a progress bar of the generations evolved by the running solver, with its best score.
+///
+/// # Context:
+/// - `Signal<Option<Progress>>`: The progress of the running search, `None` while it's idle.
+/// - `I18n`: The language grouping the thousands of the generations and the scores.
+#[component]
+fn SolverProgressBar() -> Element {
+    let locale = use_locale_format();
+    let use_progress = use_context::<Signal<Option<Progress>>>();
+    let Some(progress) = use_progress() else {
+        return rsx! {};
+    };
+    let percent = (progress.fraction() * 100.0).floor();
+    rsx! {
+        div { class: "flex flex-row flex-wrap justify-center items-center gap-3",
+            span { class: "text-gray-200 font-semibold select-none",
+                {t!("progress_solver", iteration : locale.count(progress.iteration), max : locale.count(progress.max_iterations))}
+            }
+            div {
+                class: "w-64 h-3 rounded-full bg-gray-700 overflow-hidden",
+                role: "progressbar",
+                aria_valuenow: "{percent}",
+                aria_valuemin: 0,
+                aria_valuemax: 100,
+                div { class: "h-full bg-blue-600", width: "{percent}%" }
+            }
+            span { class: "font-mono text-gray-200",
+                {t!("progress_solver_best", best : locale.count(progress.best))}
+            }
+        }
+    }
+}
+
+/// A checkbox toggling the low-power mode, which disables the hover effects and updates the
+/// interface less often while solving, to save battery on mobile devices.
+///
+/// # Context:
+/// - `Signal<Settings>`: Stores the power mode.
+#[component]
+fn LowPowerToggle() -> Element {
+    let mut use_settings = use_context::<Signal<Settings>>();
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            input {
+                id: "low-power-input",
+                r#type: "checkbox",
+                class: "w-5 h-5 cursor-pointer",
+                checked: use_settings().power_mode == PowerMode::LowPower,
+                onchange: move |event: FormEvent| {
+                    use_settings.write().power_mode = if event.checked() {
+                        PowerMode::LowPower
+                    } else {
+                        PowerMode::Normal
+                    };
+                    if !use_settings().save() {
+                        error!("Couldn't store the settings");
+                    }
+                },
+            }
+            label {
+                r#for: "low-power-input",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_low_power")}
+            }
+        }
+    }
+}
+
+/// A checkbox showing the cells the population of the genetic algorithm disagrees on, while the
+/// solver runs, as a heat overlay over the grid: the settled regions fade out as it converges.
+///
+/// # Context:
+/// - `Signal<PopulationOverlay>`: Whether the population overlay is enabled.
+#[component]
+fn PopulationOverlayToggle() -> Element {
+    let mut use_overlay = use_context::<Signal<PopulationOverlay>>();
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            input {
+                id: "population-overlay-input",
+                r#type: "checkbox",
+                class: "w-5 h-5 cursor-pointer",
+                checked: use_overlay().enabled,
+                onchange: move |event: FormEvent| {
+                    use_overlay.write().enabled = event.checked();
+                },
+            }
+            label {
+                r#for: "population-overlay-input",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_population_overlay")}
+            }
+        }
+    }
+}
+
+/// Returns the filename a solution of a puzzle is autosaved to: `<puzzle>.solved.ngram`.
+fn solved_filename(filename: &str) -> String {
+    match ngram_stem(filename) {
+        "" => String::from("nonogram.solved.ngram"),
+        name => format!("{name}.solved.ngram"),
+    }
+}
+
+/// Saves a solution found by the solver automatically, as chosen in the settings.
+///
+/// # Arguments:
+/// - `autosave`: Where the solution is saved.
+/// - `solution`: The solution found.
+/// - `palette`: The palette saved with the solution.
+/// - `filename`: The filename of the puzzle, next to which the solution is saved.
+///
+/// # Returns:
+/// The Nonogram file of the solution, unless autosaving is off, to be stored with the run.
+fn autosave_solution(
+    autosave: Autosave,
+    solution: &NonogramSolution,
+    palette: &NonogramPalette,
+    filename: &str,
+) -> Option<String> {
+    if autosave == Autosave::Off {
+        return None;
+    }
+    let file = NonogramFile {
+        solution: solution.clone(),
+        palette: palette.clone(),
+        signature: None,
+        notes: String::new(),
+    };
+    let json = serde_json::to_string(&file)
+        .inspect_err(|err| error!("Failed to serialize the solution: {err}"))
+        .ok()?;
+    if autosave == Autosave::SiblingFile {
+        save_nonogram(json.clone(), solved_filename(filename));
+        info!("Solution autosaved!");
+    }
+    Some(json)
+}
+
+/// A component for selecting where the solutions found by the solver are saved automatically.
+///
+/// Storing the solutions in the database of solver runs is only offered on desktop platforms.
+///
+/// # Context:
+/// - `Signal<Settings>`: Stores the chosen autosave.
+#[component]
+fn AutosaveSelect() -> Element {
+    let mut use_settings = use_context::<Signal<Settings>>();
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            label {
+                r#for: "autosave-select",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_autosave")}
+                ":"
+            }
+            select {
+                id: "autosave-select",
+                class: "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform cursor-pointer",
+                onchange: move |event: FormEvent| {
+                    use_settings.write().autosave = match event.value().as_str() {
+                        "sibling" => Autosave::SiblingFile,
+                        "history" => Autosave::RunHistory,
+                        _ => Autosave::Off,
+                    };
+                    info!("Changed autosave to {:?}", use_settings().autosave);
+                    if !use_settings().save() {
+                        error!("Couldn't store the settings");
+                    }
+                },
+                option {
+                    value: "off",
+                    selected: use_settings().autosave == Autosave::Off,
+                    {t!("autosave_off")}
+                }
+                option {
+                    value: "sibling",
+                    selected: use_settings().autosave == Autosave::SiblingFile,
+                    {t!("autosave_sibling_file")}
+                }
+                if cfg!(feature = "telemetry") {
+                    option {
+                        value: "history",
+                        selected: use_settings().autosave == Autosave::RunHistory,
+                        {t!("autosave_run_history")}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A select choosing what completing a puzzle means: satisfying the clues, which accepts
+/// alternate solutions, or matching the exact image stored in the puzzle file.
+///
+/// # Context:
+/// - `Signal<Settings>`: Where the completion criterion is stored.
+#[component]
+fn CompletionSelect() -> Element {
+    let mut use_settings = use_context::<Signal<Settings>>();
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            label {
+                r#for: "completion-select",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_completion")}
+                ":"
+            }
+            select {
+                id: "completion-select",
+                class: "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform cursor-pointer",
+                onchange: move |event: FormEvent| {
+                    use_settings.write().completion = match event.value().as_str() {
+                        "image" => Completion::ExactImage,
+                        _ => Completion::Constraints,
+                    };
+                    info!("Changed completion criterion to {:?}", use_settings().completion);
+                    if !use_settings().save() {
+                        error!("Couldn't store the settings");
+                    }
+                },
+                option {
+                    value: "constraints",
+                    selected: use_settings().completion == Completion::Constraints,
+                    {t!("completion_constraints")}
+                }
+                option {
+                    value: "image",
+                    selected: use_settings().completion == Completion::ExactImage,
+                    {t!("completion_exact_image")}
+                }
+            }
+        }
+    }
+}
+
+/// A select choosing what loading the same file as the puzzle being solved does: asking whether
+/// to keep the progress, keeping it, or clearing the grid as with any other file.
+///
+/// # Context:
+/// - `Signal<Settings>`: Where the behavior is stored.
+#[component]
+fn DuplicateLoadSelect() -> Element {
+    let mut use_settings = use_context::<Signal<Settings>>();
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            label {
+                r#for: "duplicate-load-select",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_duplicate_load")}
+                ":"
+            }
+            select {
+                id: "duplicate-load-select",
+                class: "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform cursor-pointer",
+                onchange: move |event: FormEvent| {
+                    use_settings.write().duplicate_load = match event.value().as_str() {
+                        "keep" => DuplicateLoad::KeepProgress,
+                        "clear" => DuplicateLoad::Clear,
+                        _ => DuplicateLoad::Ask,
+                    };
+                    info!("Changed duplicate load behavior to {:?}", use_settings().duplicate_load);
+                    if !use_settings().save() {
+                        error!("Couldn't store the settings");
+                    }
+                },
+                option {
+                    value: "ask",
+                    selected: use_settings().duplicate_load == DuplicateLoad::Ask,
+                    {t!("duplicate_load_ask")}
+                }
+                option {
+                    value: "keep",
+                    selected: use_settings().duplicate_load == DuplicateLoad::KeepProgress,
+                    {t!("duplicate_load_keep")}
+                }
+                option {
+                    value: "clear",
+                    selected: use_settings().duplicate_load == DuplicateLoad::Clear,
+                    {t!("duplicate_load_clear")}
+                }
+            }
+        }
+    }
+}
+
+/// A select choosing the assist level of the solver, bundling the assists: crossing completed
+/// lines and flashing mistakes (casual), and also hints and auto-filling trivial cells (relaxed).
+///
+/// # Context:
+/// - `Signal<Settings>`: Where the assist level is stored.
+#[component]
+fn AssistSelect() -> Element {
+    let mut use_settings = use_context::<Signal<Settings>>();
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            label {
+                r#for: "assist-select",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_assist_level")}
+                ":"
+            }
+            select {
+                id: "assist-select",
+                class: "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform cursor-pointer",
+                onchange: move |event: FormEvent| {
+                    use_settings.write().assist_level = match event.value().as_str() {
+                        "purist" => AssistLevel::Purist,
+                        "relaxed" => AssistLevel::Relaxed,
+                        _ => AssistLevel::Casual,
+                    };
+                    info!("Changed assist level to {:?}", use_settings().assist_level);
+                    if !use_settings().save() {
+                        error!("Couldn't store the settings");
+                    }
+                },
+                for (value , level) in [
+                    ("purist", AssistLevel::Purist),
+                    ("casual", AssistLevel::Casual),
+                    ("relaxed", AssistLevel::Relaxed),
+                ]
+                {
+                    option {
+                        key: "{value}",
+                        value,
+                        selected: use_settings().assist_level == level,
+                        {assist_level_name(level)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A checkbox toggling the strict mode, where the solve buttons and hints are locked so the
+/// completions count as unassisted. It can't be toggled while the solver runs, since its button
+/// would be removed.
+///
+/// # Context:
+/// - `Signal<Settings>`: Where the strict mode is stored.
+/// - `Signal<Option<Progress>>`: Whether the solver is running.
+#[component]
+fn StrictModeToggle() -> Element {
+    let mut use_settings = use_context::<Signal<Settings>>();
+    let use_progress = use_context::<Signal<Option<Progress>>>();
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            input {
+                id: "strict-mode-input",
+                r#type: "checkbox",
+                class: "w-5 h-5 cursor-pointer",
+                checked: use_settings().strict_mode,
+                disabled: use_progress().is_some(),
+                onchange: move |event: FormEvent| {
+                    use_settings.write().strict_mode = event.checked();
+                    info!("Changed strict mode to {}", event.checked());
+                    if !use_settings().save() {
+                        error!("Couldn't store the settings");
+                    }
+                },
+            }
+            label {
+                r#for: "strict-mode-input",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_strict_mode")}
+            }
+        }
+    }
+}
+
+/// The milliseconds the unlock button of the strict mode must be held down.
+const STRICT_UNLOCK_MILLIS: u32 = 800;
+
+/// A button unlocking the solve buttons and hints in the strict mode, for the puzzle being
+/// solved. It must be held down for `STRICT_UNLOCK_MILLIS` milliseconds, and then confirmed,
+/// since it marks the puzzle as assisted in the session history.
+///
+/// # Context:
+/// - `Signal<StrictUnlock>`: The unlock of the puzzle being solved.
+/// - `Signal<SessionHistory>`: Where the puzzle is marked as assisted (optional).
+/// - `Signal<NonogramData>`: The name of the puzzle.
+#[component]
+fn StrictUnlockButton() -> Element {
+    let mut use_unlock = use_context::<Signal<StrictUnlock>>();
+    let use_session = try_use_context::<Signal<SessionHistory>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let mut use_pressed = use_signal(|| false);
+    // Counts the presses, so a press released early doesn't unlock during a later one.
+    let mut use_presses = use_signal(|| 0usize);
+    let mut release = move || {
+        if *use_pressed.peek() {
+            use_pressed.set(false);
+        }
+    };
+    let confirm_onclick = move |_| {
+        use_unlock.set(StrictUnlock {
+            unlocked: true,
+            confirming: false,
+        });
+        let data = use_data.peek();
+        let name = if data.filename.is_empty() {
+            t!("untitled")
+        } else {
+            data.filename.clone()
+        };
+        info!("Unlocked the solver for {name}");
+        if let Some(mut use_session) = use_session {
+            use_session.write().mark_assisted(&name);
+        }
+    };
+    rsx! {
+        button {
+            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 transition-colors select-none",
+            style: "touch-action: none;",
+            background_color: if use_pressed() { "#1e40af" },
+            title: t!("title_hold_unlock"),
+            onpointerdown: move |_| async move {
+                use_pressed.set(true);
+                *use_presses.write() += 1;
+                let press = *use_presses.peek();
+                sleep(STRICT_UNLOCK_MILLIS).await;
+                if *use_pressed.peek() && *use_presses.peek() == press {
+                    use_pressed.set(false);
+                    use_unlock.write().confirming = true;
+                }
+            },
+            onpointerup: move |_| release(),
+            onpointerleave: move |_| release(),
+            onpointercancel: move |_| release(),
+            {t!("button_unlock_solver")}
+        }
+        if use_unlock().confirming {
+            div { class: "fixed inset-0 z-50 flex justify-center items-center bg-black bg-opacity-60",
+                div { class: "flex flex-col gap-6 p-6 max-w-lg rounded-lg border border-gray-600 bg-gray-900 text-white shadow-lg",
+                    h2 { class: "text-2xl font-bold text-center", {t!("title_unlock_solver")} }
+                    p { class: "text-center text-gray-300", {t!("unlock_solver_warning")} }
+                    div { class: "flex flex-row flex-wrap justify-center items-center gap-6",
+                        button {
+                            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                            onclick: confirm_onclick,
+                            {t!("button_confirm_unlock")}
+                        }
+                        button {
+                            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                            onclick: move |_| use_unlock.write().confirming = false,
+                            {t!("button_cancel")}
+                        }
                     }
                 }
             }
@@ -373,175 +6542,189 @@ fn EditorNonogram() -> Element {
     }
 }
 
-/// A component for inputting the number of rows in the Nonogram solution.
-///
-/// This component allows the user to set the number of rows for the Nonogram puzzle.
-/// It validates the input to ensure it is within a reasonable range (2 to 40) and updates the Nonogram solution.
-///
-/// # Parameters:
-/// - `readonly`: A boolean flag to indicate whether the input field should be read-only.
+/// Returns the translated name of an assist level.
+fn assist_level_name(level: AssistLevel) -> String {
+    match level {
+        AssistLevel::Purist => t!("assist_purist"),
+        AssistLevel::Casual => t!("assist_casual"),
+        AssistLevel::Relaxed => t!("assist_relaxed"),
+    }
+}
+
+/// A button painting a cell deduced by line logic that doesn't match the grid yet, shown when
+/// the assist level gives hints.
 ///
 /// # Context:
-/// - `Signal<NonogramSolution>`: Provides access to and updates for the current Nonogram solution.
+/// - `Signal<Settings>`: The assist level.
+/// - `Signal<NonogramPuzzle>`: The puzzle being solved.
+/// - `Signal<NonogramSolution>`: The grid painted with the hint.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
 #[component]
-fn RowsInput(readonly: bool) -> Element {
-    let mut use_solution = use_context::<Signal<NonogramSolution>>();
+fn HintButton() -> Element {
+    let use_settings = use_context::<Signal<Settings>>();
+    let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let dispatcher = use_dispatcher();
     rsx! {
-        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
-            label {
-                r#for: "rows-input",
-                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
-                pointer_events: if readonly { "none" },
-                color: if readonly { "darkgray" },
-                {t!("label_rows")}
-                ":"
-            }
-            input {
-                id: "rows-input",
-                class: "appearance-none px-4 py-1 w-20 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform",
-                pointer_events: if readonly { "none" },
-                color: if readonly { "darkgray" },
-                readonly,
-                r#type: "number",
-                min: "2",
-                max: "40",
-                onchange: move |event| {
-                    if let Ok(rows) = event.value().parse::<usize>() {
-                        if (2..=40).contains(&rows) {
-                            use_solution.write().set_rows(rows);
-                        }
-                    }
+        if use_settings().assist_level.assists().hints {
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                onclick: move |_| {
+                    let Some((row, col, color)) = hint(&use_puzzle(), &use_solution()) else {
+                        info!("No hint left for the nonogram");
+                        return;
+                    };
+                    info!("Hinted cell ({row}, {col}) with color {color}");
+                    dispatcher.dispatch(GridCommand::Paint { row, col, color });
                 },
-                value: use_solution().rows(),
+                {t!("button_hint")}
             }
         }
     }
 }
 
-/// A component for inputting the number of columns in the Nonogram solution.
+/// Records a run of the solver in the database of the profile.
 ///
-/// This component allows the user to set the number of columns for the Nonogram puzzle.
-/// It validates the input to ensure it is within a reasonable range (2 to 40) and updates the Nonogram solution.
-///
-/// # Parameters:
-/// - `readonly`: A boolean flag to indicate whether the input field should be read-only.
-///
-/// # Context:
-/// - `Signal<NonogramSolution>`: Provides access to and updates for the current Nonogram solution.
-#[component]
-fn ColumnsInput(readonly: bool) -> Element {
-    let mut use_solution = use_context::<Signal<NonogramSolution>>();
-    rsx! {
-        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
-            label {
-                r#for: "cols-input",
-                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
-                pointer_events: if readonly { "none" },
-                color: if readonly { "darkgray" },
-                {t!("label_columns")}
-                ":"
-            }
-            input {
-                id: "cols-input",
-                class: "appearance-none px-4 py-1 w-20 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform",
-                pointer_events: if readonly { "none" },
-                color: if readonly { "darkgray" },
-                readonly,
-                r#type: "number",
-                min: "2",
-                max: "40",
-                onchange: move |event: FormEvent| {
-                    if let Ok(cols) = event.value().parse::<usize>() {
-                        if (2..=40).contains(&cols) {
-                            use_solution.write().set_cols(cols);
-                        }
-                    }
-                },
-                value: use_solution().cols(),
-            }
-        }
+/// # Arguments:
+/// - `puzzle`: The solved puzzle.
+/// - `history`: The history of the run.
+/// - `config`: The parameters of the run.
+/// - `seed`: The seed of the run.
+/// - `elapsed`: The duration of the run.
+/// - `profile`: The profile in use, or `None` for the guest.
+/// - `solution`: The Nonogram file of the solution found, if it's autosaved with the run.
+#[cfg(feature = "telemetry")]
+fn record_run(
+    puzzle: &NonogramPuzzle,
+    history: &History,
+    config: &SolverConfig,
+    seed: u64,
+    elapsed: std::time::Duration,
+    profile: Option<&Profile>,
+    solution: Option<String>,
+) {
+    let mut run = SolverRun::new(
+        puzzle,
+        config,
+        seed,
+        (
+            history.winner.is_ok(),
+            history.winner_score(puzzle, config.fitness),
+            history.iterations,
+        ),
+        elapsed.as_millis() as u64,
+    );
+    run.solution = solution;
+    match RunStore::open_default(profile).map(|store| store.record(&run)) {
+        Some(Ok(id)) => info!("Recorded solver run {id}"),
+        Some(Err(err)) => error!("Failed to record solver run: {err}"),
+        None => error!("Failed to open the database of solver runs"),
     }
 }
 
-/// A component for inputting the block size of the Nonogram grid.
+/// A button solving the Nonogram puzzle deterministically by constraint propagation.
 ///
-/// This component allows the user to set the block size used in the Nonogram puzzle grid.
-/// The value is validated within a reasonable range (10 to 100) and updates the Nonogram data accordingly.
+/// Unlike the genetic solver, the result is instant and always the same. Puzzles that can't be
+/// fully deduced by line logic are partially solved, with the background in the undeduced cells.
 ///
 /// # Context:
-/// - `Signal<NonogramData>`: Provides access to and updates for the Nonogram editor state, including block size.
+/// - `Signal<NonogramPuzzle>`: The puzzle to solve.
+/// - `Signal<NonogramSolution>`: Updated with the deduced solution.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
 #[component]
-fn BlockSizeInput() -> Element {
-    let mut use_data = use_context::<Signal<NonogramData>>();
+fn LogicSolveButton() -> Element {
+    let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let dispatcher = use_dispatcher();
+    let mut use_unknown = use_signal(|| None::<usize>);
     rsx! {
         div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
-            label {
-                r#for: "size-input",
-                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
-                {t!("label_size")}
-                ":"
-            }
-            input {
-                id: "size-input",
-                class: "appearance-none px-4 py-1 w-20 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 focus:ring focus:ring-blue-500 focus:outline-none transition-transform transform",
-                r#type: "number",
-                min: "10",
-                max: "100",
-                step: "5",
-                value: use_data().block_size,
-                onchange: move |event| {
-                    if let Ok(size) = event.value().parse::<usize>() {
-                        if (10..=100).contains(&size) {
-                            use_data.write().block_size = size;
-                        }
-                    }
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                onclick: move |_| {
+                    let Some(solved) = solve_logic(&use_puzzle()) else {
+                        info!("The nonogram has no solution");
+                        use_unknown.set(None);
+                        return;
+                    };
+                    info!("Solved nonogram by logic, {} cells undeduced", solved.unknown);
+                    use_unknown.set((!solved.is_complete()).then_some(solved.unknown));
+                    dispatcher
+                        .dispatch(GridCommand::Replace {
+                            tool: Tool::Solver,
+                            solution: solved.solution,
+                        });
                 },
+                {t!("button_solve_logic")}
+            }
+            if let Some(unknown) = use_unknown() {
+                span { class: "text-amber-400 font-semibold select-none",
+                    {t!("logic_partial", cells : unknown)}
+                }
             }
         }
     }
 }
 
-/// A button component for solving the Nonogram puzzle.
+/// A button solving the Nonogram puzzle by exhaustive search (see `NonogramPuzzle::solve_exact`),
+/// a fallback for when the genetic solver stalls: a solution is always found if one exists.
 ///
-/// This component initiates the process of solving the Nonogram puzzle by running a solution algorithm.
-/// It updates the Nonogram solution based on the result and handles a loading state during the process.
+/// The solutions of the puzzles imported from their clues are cached in the library, so they can
+/// be previewed and compared thereafter.
 ///
 /// # Context:
-/// - `Signal<NonogramPuzzle>`: Provides access to the current Nonogram puzzle.
-/// - `Signal<History>`: Updates the history of Nonogram solving attempts.
-/// - `Signal<NonogramSolution>`: Updates the Nonogram solution based on the solving result.
+/// - `Signal<NonogramPuzzle>`: The puzzle to solve.
+/// - `Signal<NonogramSolution>`: Updated with the solution found.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+/// - `Signal<NonogramData>`: Whether the puzzle is blind, and whether its solution is cached.
+/// - `Signal<NonogramFile>`: Updated with the cached solution (optional).
+/// - `Signal<SolutionLibrary>`: Where the solutions of blind puzzles are cached (optional).
 #[component]
-fn SolveButton() -> Element {
+fn ExactSolveButton() -> Element {
     let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
-    let mut use_history = use_context::<Signal<History>>();
-    let mut use_solution = use_context::<Signal<NonogramSolution>>();
-    let mut use_running = use_signal(|| false);
+    let mut use_data = use_context::<Signal<NonogramData>>();
+    let use_file = try_use_context::<Signal<NonogramFile>>();
+    let use_library = try_use_context::<Signal<SolutionLibrary>>();
+    let dispatcher = use_dispatcher();
+    let mut cache_solution = move |solution: &NonogramSolution| {
+        let (Some(mut use_file), Some(mut use_library)) = (use_file, use_library) else {
+            return;
+        };
+        if !use_data.peek().blind || !use_library.write().insert(&use_puzzle(), solution.clone()) {
+            return;
+        }
+        if !use_library.peek().save() {
+            error!("Couldn't store the library of solutions");
+        }
+        use_file.write().solution = solution.clone();
+        use_data.write().solution_cached = true;
+        info!("Cached the solution of the imported clues in the library");
+    };
+    let mut use_unsolvable = use_signal(|| false);
     rsx! {
-        button {
-            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
-            onmousedown: move |_| {},
-            onclick: move |_| async move {
-                if use_running() {
-                    info!("Already solving nonogram!");
-                } else {
-                    *use_running.write() = true;
-                    info!("Solving nonogram...");
-                    let history = solve_nonogram(use_puzzle().clone());
-                    match &history.winner {
-                        Ok(winner) => {
-                            *use_solution.write() = winner.clone();
-                            info!("Nonogram solved!");
-                        }
-                        Err(loser) => {
-                            *use_solution.write() = loser.clone();
-                            info!("Nonogram not solved!");
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                onclick: move |_| {
+                    let solution = use_puzzle().solve_exact();
+                    use_unsolvable.set(solution.is_none());
+                    match solution {
+                        Some(solution) => {
+                            info!("Solved nonogram by exhaustive search");
+                            cache_solution(&solution);
+                            dispatcher
+                                .dispatch(GridCommand::Replace {
+                                    tool: Tool::Solver,
+                                    solution,
+                                });
                         }
+                        None => info!("The nonogram has no solution"),
                     }
-                    *use_history.write() = history;
-                    *use_running.write() = false;
-                }
-            },
-            {t!("button_solve_nonogram")}
+                },
+                {t!("button_solve_exact")}
+            }
+            if use_unsolvable() {
+                span { class: "text-red-400 font-semibold select-none", {t!("exact_unsolvable")} }
+            }
         }
     }
 }
@@ -584,19 +6767,20 @@ fn AnovaButton() -> Element {
 ///
 /// # Context:
 /// - `Signal<NonogramSolution>`: Provides access to and updates the Nonogram solution.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
 #[component]
 fn ClearSolutionButton() -> Element {
-    let mut use_solution = use_context::<Signal<NonogramSolution>>();
+    let dispatcher = use_dispatcher();
     rsx! {
         button {
             class: "flex justify-center items-center w-10 h-10 rounded-full border border-gray-400 bg-gray-700 hover:bg-red-800 hover:scale-125 active:scale-150 transition-transform transform",
             ondoubleclick: move |_| {
-                use_solution.write().clear();
+                dispatcher.dispatch(GridCommand::Clear);
                 info!("Cleared the nonogram solution grid");
             },
             onmousedown: move |event| {
                 if event.modifiers().ctrl() || event.modifiers().shift() {
-                    use_solution.write().clear();
+                    dispatcher.dispatch(GridCommand::Clear);
                     info!("Cleared the nonogram solution grid");
                 }
             },
@@ -609,6 +6793,78 @@ fn ClearSolutionButton() -> Element {
     }
 }
 
+/// A slider scrubbing through the history of the solution grid since the puzzle was loaded.
+///
+/// The grid is previewed live while the slider is dragged, without recording anything. Releasing
+/// it records the jump as a new action, so the states after it stay in the history and can be
+/// scrubbed back to.
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: Previewed and updated with the state of the history chosen.
+/// - `Signal<ActionLog>`: The history scrubbed, where the jumps are recorded.
+#[component]
+fn HistoryScrubber() -> Element {
+    let use_log = use_context::<Signal<ActionLog>>();
+    let mut use_solution = use_context::<Signal<NonogramSolution>>();
+    let dispatcher = use_dispatcher();
+    // The timeline being scrubbed and the grid before scrubbing, while the slider is dragged.
+    let mut use_scrub = use_signal(|| None::<(Timeline, NonogramSolution)>);
+    let mut use_position = use_signal(|| None::<usize>);
+    let steps = match use_scrub.read().as_ref() {
+        Some((timeline, _)) => timeline.len(),
+        None => Timeline::count(&use_log.read()),
+    };
+    let position = use_position().unwrap_or(steps);
+    rsx! {
+        div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+            label {
+                r#for: "history-input",
+                class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                {t!("label_history")}
+                ":"
+            }
+            input {
+                id: "history-input",
+                r#type: "range",
+                class: "cursor-pointer",
+                min: 0,
+                max: steps,
+                value: position,
+                disabled: steps == 0,
+                oninput: move |event: FormEvent| {
+                    let Ok(position) = event.value().parse::<usize>() else {
+                        return;
+                    };
+                    if use_scrub.peek().is_none() {
+                        let current = use_solution.peek().clone();
+                        let timeline = Timeline::new(&use_log.peek(), &current);
+                        use_scrub.set(Some((timeline, current)));
+                    }
+                    if let Some((timeline, _)) = use_scrub.peek().as_ref() {
+                        *use_solution.write() = timeline.state(position);
+                    }
+                    use_position.set(Some(position));
+                },
+                onchange: move |event: FormEvent| {
+                    let Some((timeline, current)) = use_scrub.take() else {
+                        return;
+                    };
+                    use_position.set(None);
+                    let position = event.value().parse::<usize>().unwrap_or(timeline.len());
+                    *use_solution.write() = current;
+                    info!("Scrubbed the history to step {position} of {}", timeline.len());
+                    dispatcher
+                        .dispatch(GridCommand::Replace {
+                            tool: Tool::Scrub,
+                            solution: timeline.state(position),
+                        });
+                },
+            }
+            span { class: "w-20 font-mono text-gray-200", "{position}/{steps}" }
+        }
+    }
+}
+
 /// A component with buttons to slide the Nonogram solution grid in four directions.
 ///
 /// This component provides buttons to slide the Nonogram solution grid left, right, up, or down.
@@ -616,10 +6872,11 @@ fn ClearSolutionButton() -> Element {
 ///
 /// # Context:
 /// - `Signal<NonogramSolution>`: Updates the Nonogram solution.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
 /// - `Signal<NonogramData>`: Uses the information on whether the puzzle is completed or not.
 #[component]
 fn SlideSolutionButtons() -> Element {
-    let mut use_solution = use_context::<Signal<NonogramSolution>>();
+    let dispatcher = use_dispatcher();
     let use_data = use_context::<Signal<NonogramData>>();
     rsx! {
         div {
@@ -628,7 +6885,7 @@ fn SlideSolutionButtons() -> Element {
             button {
                 class: "flex justify-center items-center w-10 h-10 rounded-full border border-gray-400 bg-gray-700 hover:bg-blue-800 hover:scale-125 active:scale-150 transition-transform transform",
                 onclick: move |_| {
-                    use_solution.write().slide(-1, 0);
+                    dispatcher.dispatch(GridCommand::Slide { dx: -1, dy: 0 });
                     info!("Sliding the nonogram solution grid left");
                 },
                 Icon {
@@ -640,7 +6897,7 @@ fn SlideSolutionButtons() -> Element {
             button {
                 class: "flex justify-center items-center w-10 h-10 rounded-full border border-gray-400 bg-gray-700 hover:bg-blue-800 hover:scale-125 active:scale-150 transition-transform transform",
                 onclick: move |_| {
-                    use_solution.write().slide(0, -1);
+                    dispatcher.dispatch(GridCommand::Slide { dx: 0, dy: -1 });
                     info!("Sliding the nonogram solution grid up");
                 },
                 Icon {
@@ -652,7 +6909,7 @@ fn SlideSolutionButtons() -> Element {
             button {
                 class: "flex justify-center items-center w-10 h-10 rounded-full border border-gray-400 bg-gray-700 hover:bg-blue-800 hover:scale-125 active:scale-150 transition-transform transform",
                 onclick: move |_| {
-                    use_solution.write().slide(0, 1);
+                    dispatcher.dispatch(GridCommand::Slide { dx: 0, dy: 1 });
                     info!("Sliding the nonogram solution grid down");
                 },
                 Icon {
@@ -664,7 +6921,7 @@ fn SlideSolutionButtons() -> Element {
             button {
                 class: "flex justify-center items-center w-10 h-10 rounded-full border border-gray-400 bg-gray-700 hover:bg-blue-800 hover:scale-125 active:scale-150 transition-transform transform",
                 onclick: move |_| {
-                    use_solution.write().slide(1, 0);
+                    dispatcher.dispatch(GridCommand::Slide { dx: 1, dy: 0 });
                     info!("Sliding the nonogram solution grid right");
                 },
                 Icon {
@@ -684,21 +6941,28 @@ fn SlideSolutionButtons() -> Element {
 ///
 /// # Context:
 /// - `Signal<NonogramPalette>`: Updates and manages the Nonogram palette.
+/// - `Signal<SessionRng>`: Draws the generator of the random colors.
 #[component]
 fn NewColorButton() -> Element {
     let mut use_palette = use_context::<Signal<NonogramPalette>>();
+    let mut use_rng = use_context::<Signal<SessionRng>>();
     rsx! {
         button {
             class: "flex justify-center items-center w-10 h-10 rounded-full border border-gray-400 bg-gray-700 hover:bg-blue-800 hover:scale-125 active:scale-150 transition-transform transform",
             onclick: move |_| {
                 let palette_len = use_palette().len();
+                // The solvers represent the colors of a cell in a 64-bit mask
+                if palette_len >= MAX_CLUE_COLORS {
+                    info!("The palette already has {} colors", MAX_CLUE_COLORS);
+                    return;
+                }
                 let getter = if palette_len < DEFAULT_PALETTE.len() {
                     use_palette
                         .write()
                         .add_color(String::from(DEFAULT_PALETTE.get(palette_len)));
                     "default"
                 } else {
-                    let mut rng = rand::thread_rng();
+                    let mut rng = use_rng.write().next_rng();
                     let random_color = format!(
                         "#{:02x}{:02x}{:02x}",
                         rng.gen_range(0..256),
@@ -720,43 +6984,577 @@ fn NewColorButton() -> Element {
     }
 }
 
-/// A component for displaying and managing the Nonogram color palette.
+/// A component for displaying and managing the Nonogram color palette.
+///
+/// This component allows users to select colors from the Nonogram palette. Colors can be removed
+/// if there is more than one color in the palette and if it is not used in the solution grid.
+///
+/// Unless the palette is readonly, several colors can be selected with shift-click or ctrl-click
+/// for bulk operations (see `PaletteSelectionPanel`).
+///
+/// # Context:
+/// - `Signal<NonogramPalette>`: Manages the Nonogram color palette.
+/// - `Signal<NonogramSolution>`: Manages the current Nonogram solution grid to check color usage.
+/// - `Signal<PaletteSelection>`: The selected colors (optional).
+#[component]
+fn ColorPalette(readonly: bool) -> Element {
+    let mut use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_selection = try_use_context::<Signal<PaletteSelection>>().filter(|_| !readonly);
+    let selected = use_selection
+        .map(|use_selection| use_selection().selected)
+        .unwrap_or_default();
+    rsx! {
+        for (i , color) in use_palette().color_palette.iter().enumerate() {
+            button {
+                key: "brush-{i}",
+                style: "background-color: {color}",
+                class: "w-10 h-10 rounded-full hover:bg-blue-800 hover:scale-125 active:scale-150 transition-transform transform",
+                outline: if selected.contains(&i) { "3px solid #3b82f6" },
+                outline_offset: if selected.contains(&i) { "2px" },
+                onclick: move |event| {
+                    if let Some(mut use_selection) = use_selection {
+                        if event.modifiers().shift() || event.modifiers().ctrl() {
+                            let mut selection = use_selection.write();
+                            if !selection.selected.remove(&i) {
+                                selection.selected.insert(i);
+                            }
+                            info!("Selected palette colors: {:?}", selection.selected);
+                            return;
+                        }
+                        use_selection.write().selected.clear();
+                    }
+                    use_palette.write().set_brush(i);
+                    info!("Changed brush color to: {}", use_palette().show_brush());
+                },
+                ondoubleclick: move |_| {
+                    if use_palette().len() > 1
+                        && use_solution()
+                            .solution_grid
+                            .iter()
+                            .map(|row| *row.iter().max().unwrap_or(&0))
+                            .max()
+                            .unwrap_or(0) < i
+                    {
+                        info!("Removing brush color: {} -> {}", i, use_palette().get(i));
+                        use_palette.write().remove_color(i);
+                    } else {
+                        info!("Cannot remove brush color: {}", use_palette().show_brush());
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// A panel with bulk operations on the selected palette colors.
+///
+/// The selected colors can be deleted (their cells become background), merged into the first
+/// selected color, lightened or darkened by a percentage, or exported as a standalone palette
+/// file (`.ngrampal`) together with the background.
+///
+/// # Context:
+/// - `Signal<PaletteSelection>`: The selected colors.
+/// - `Signal<NonogramPalette>`: Manages the Nonogram color palette.
+/// - `Signal<NonogramSolution>`: The solution grid remapped when colors are deleted or merged.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+/// - `Signal<NonogramData>`: The filename used for the exported palette.
+#[component]
+fn PaletteSelectionPanel() -> Element {
+    let mut use_selection = use_context::<Signal<PaletteSelection>>();
+    let mut use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let dispatcher = use_dispatcher();
+    let mut use_percent = use_signal(|| 10);
+    let selected = move || {
+        use_selection()
+            .selected
+            .into_iter()
+            .filter(|&index| index < use_palette().len())
+            .collect::<Vec<usize>>()
+    };
+    let mut remove_selected = move |replacement: usize| {
+        let removed = selected();
+        let mapping = use_palette.write().remove_colors(&removed, replacement);
+        info!("Removed palette colors {removed:?} into {replacement}");
+        dispatcher.dispatch(GridCommand::Remap {
+            mapping,
+            area: None,
+        });
+        use_selection.write().selected.clear();
+    };
+    let mut shade_selected = move |percent: i32| {
+        for index in selected() {
+            use_palette.write().shade(index, percent);
+        }
+        info!("Shaded palette colors {:?} by {percent}%", selected());
+    };
+    let export_onclick = move |_| {
+        let mut palette = use_palette();
+        let keep: Vec<usize> = (0..palette.len())
+            .filter(|index| *index != BACKGROUND && !selected().contains(index))
+            .collect();
+        palette.remove_colors(&keep, BACKGROUND);
+        palette.brush = BACKGROUND;
+        let filename = ngram_stem(&use_data().filename).to_string();
+        let filename = if filename.is_empty() {
+            String::from("palette")
+        } else {
+            filename
+        };
+        let palette_file = NonogramPaletteFile {
+            name: filename.clone(),
+            author: String::new(),
+            palette,
+        };
+        match serde_json::to_string(&palette_file) {
+            Ok(json) => {
+                save_nonogram(json, format!("{filename}.ngrampal"));
+                info!("Palette prepared for download!");
+            }
+            Err(err) => error!("Failed to serialize the palette: {err}"),
+        }
+    };
+    let button_class = "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform";
+    rsx! {
+        if !selected().is_empty() {
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3",
+                span { class: "py-2 text-gray-200 font-semibold select-none",
+                    {t!("selected_colors", count : selected().len())}
+                }
+                button {
+                    class: button_class,
+                    onclick: move |_| remove_selected(BACKGROUND),
+                    {t!("button_delete_colors")}
+                }
+                if selected().len() > 1 {
+                    button {
+                        class: button_class,
+                        onclick: move |_| remove_selected(selected()[0]),
+                        {t!("button_merge_colors")}
+                    }
+                }
+                input {
+                    class: "appearance-none w-20 px-2 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 focus:ring focus:ring-blue-500 focus:outline-none",
+                    r#type: "number",
+                    min: 1,
+                    max: 100,
+                    value: "{use_percent}",
+                    onchange: move |event: FormEvent| {
+                        if let Ok(percent) = event.value().parse::<i32>() {
+                            *use_percent.write() = percent.clamp(1, 100);
+                        }
+                    },
+                }
+                span { class: "text-gray-200 select-none", "%" }
+                button {
+                    class: button_class,
+                    onclick: move |_| shade_selected(use_percent()),
+                    {t!("button_lighten")}
+                }
+                button {
+                    class: button_class,
+                    onclick: move |_| shade_selected(-use_percent()),
+                    {t!("button_darken")}
+                }
+                button { class: button_class, onclick: export_onclick, {t!("button_export_palette")} }
+                button {
+                    class: button_class,
+                    onclick: move |_| use_selection.write().selected.clear(),
+                    {t!("button_clear_selection")}
+                }
+            }
+        }
+    }
+}
+
+/// A panel for exporting the palette as a standalone palette file (`.ngrampal`) and loading one,
+/// or copying it to the clipboard and pasting one.
+///
+/// A loaded or pasted palette either replaces the current one, remapping each color of the
+/// solution grid to the perceptually closest loaded color, or appends its missing colors to the
+/// current one.
+///
+/// # Context:
+/// - `Signal<NonogramPalette>`: Manages the Nonogram color palette.
+/// - `Signal<NonogramSolution>`: The solution grid remapped when a palette replaces the current one.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+#[component]
+fn PaletteFilePanel() -> Element {
+    let mut use_palette = use_context::<Signal<NonogramPalette>>();
+    let dispatcher = use_dispatcher();
+    let mut use_name = use_signal(String::new);
+    let mut use_author = use_signal(String::new);
+    let mut use_mode = use_signal(PaletteLoadMode::default);
+    let mut use_empty_clipboard = use_signal(|| false);
+    let palette_file = move || {
+        let mut palette = use_palette();
+        palette.brush = BACKGROUND;
+        NonogramPaletteFile {
+            name: use_name().trim().to_string(),
+            author: use_author().trim().to_string(),
+            palette,
+        }
+    };
+    // Applies a loaded or pasted palette with the chosen mode.
+    let mut apply_palette = move |palette_file: NonogramPaletteFile| {
+        match use_mode() {
+            PaletteLoadMode::Remap => {
+                let mapping = use_palette().closest_mapping(&palette_file.palette);
+                dispatcher.dispatch(GridCommand::Remap {
+                    mapping,
+                    area: None,
+                });
+                *use_palette.write() = palette_file.palette;
+            }
+            PaletteLoadMode::Append => {
+                let appended = use_palette.write().append_colors(&palette_file.palette);
+                info!("Appended {appended} colors");
+            }
+        }
+        *use_name.write() = palette_file.name;
+        *use_author.write() = palette_file.author;
+    };
+    let export_onclick = move |_| {
+        let palette_file = palette_file();
+        let filename = if palette_file.name.is_empty() {
+            String::from("palette")
+        } else {
+            palette_file.name.clone()
+        };
+        match serde_json::to_string(&palette_file) {
+            Ok(json) => {
+                save_nonogram(json, format!("{filename}.ngrampal"));
+                info!("Palette prepared for download!");
+            }
+            Err(err) => error!("Failed to serialize the palette: {err}"),
+        }
+    };
+    let load_palette_onchange = move |event: FormEvent| async move {
+        let Some(file_engine) = event.files() else {
+            error!("Event hadn't a file engine attached: {event:?}");
+            return;
+        };
+        let Some(file) = file_engine.files().into_iter().next() else {
+            error!("File engine had no attached files");
+            return;
+        };
+        let Some(json) = file_engine.read_file_to_string(&file).await else {
+            error!("Couldn't read file: '{file}'");
+            return;
+        };
+        let palette_file = match serde_json::from_str::<NonogramPaletteFile>(&json) {
+            Ok(palette_file) => palette_file,
+            Err(err) => {
+                error!("Couldn't deserialize file '{file}': {err}");
+                return;
+            }
+        };
+        if palette_file.palette.len() == 0 {
+            error!("The palette '{file}' has no colors");
+            return;
+        }
+        apply_palette(palette_file);
+        info!("Palette '{file}' loaded correctly!");
+    };
+    let copy_onclick = move |_| async move {
+        copy_to_clipboard(ClipboardContent::Palette(palette_file())).await;
+        info!("Palette copied to the clipboard");
+    };
+    let paste_onclick = move |_| async move {
+        let pasted = match paste_from_clipboard().await {
+            Some(ClipboardContent::Palette(palette_file)) if palette_file.palette.len() > 0 => {
+                Some(palette_file)
+            }
+            _ => None,
+        };
+        use_empty_clipboard.set(pasted.is_none());
+        match pasted {
+            Some(palette_file) => {
+                apply_palette(palette_file);
+                info!("Palette pasted from the clipboard");
+            }
+            None => info!("The clipboard holds no palette"),
+        }
+    };
+    let text_input_class = "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 focus:ring focus:ring-blue-500 focus:outline-none";
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_palette_file")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3 py-2",
+                input {
+                    class: text_input_class,
+                    r#type: "text",
+                    placeholder: t!("label_palette_name"),
+                    value: "{use_name}",
+                    oninput: move |event| *use_name.write() = event.value(),
+                }
+                input {
+                    class: text_input_class,
+                    r#type: "text",
+                    placeholder: t!("label_palette_author"),
+                    value: "{use_author}",
+                    oninput: move |event| *use_author.write() = event.value(),
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: export_onclick,
+                    {t!("button_export_palette")}
+                }
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3 py-2",
+                label { class: "py-2 text-gray-200 font-semibold select-none",
+                    {t!("label_load_palette")}
+                    ":"
+                }
+                select {
+                    class: "appearance-none px-4 py-1 rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 focus:ring focus:ring-blue-500 focus:outline-none cursor-pointer",
+                    onchange: move |event: FormEvent| {
+                        *use_mode.write() = if event.value() == "append" {
+                            PaletteLoadMode::Append
+                        } else {
+                            PaletteLoadMode::Remap
+                        };
+                    },
+                    option {
+                        value: "remap",
+                        selected: use_mode() == PaletteLoadMode::Remap,
+                        {t!("palette_load_remap")}
+                    }
+                    option {
+                        value: "append",
+                        selected: use_mode() == PaletteLoadMode::Append,
+                        {t!("palette_load_append")}
+                    }
+                }
+                input {
+                    class: "appearance-none rounded border px-4 py-1 border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform cursor-pointer",
+                    r#type: "file",
+                    accept: ".ngrampal",
+                    multiple: false,
+                    onchange: load_palette_onchange,
+                }
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3 py-2",
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: copy_onclick,
+                    {t!("button_copy_palette")}
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: paste_onclick,
+                    {t!("button_paste_palette")}
+                }
+                if use_empty_clipboard() {
+                    span { class: "text-sm text-amber-400", {t!("clipboard_no_palette")} }
+                }
+            }
+        }
+    }
+}
+
+/// Copies a content to the clipboard, natively with the `clipboard` feature or with the web
+/// Clipboard API otherwise. The content is also stored, so it can be pasted after a restart.
 ///
-/// This component allows users to select colors from the Nonogram palette. Colors can be removed
-/// if there is more than one color in the palette and if it is not used in the solution grid.
+/// # Arguments:
+/// - `content`: The content copied.
+async fn copy_to_clipboard(content: ClipboardContent) {
+    let Some(text) = content.to_text() else {
+        error!("Failed to serialize the content of the clipboard");
+        return;
+    };
+    if !content.remember() {
+        error!("Couldn't store the content of the clipboard");
+    }
+    #[cfg(feature = "clipboard")]
+    let copied = write_system(&text);
+    #[cfg(not(feature = "clipboard"))]
+    let copied = {
+        let writer = document::eval(
+            r#"
+            const text = await dioxus.recv();
+            try {
+                await navigator.clipboard.writeText(text);
+                return true;
+            } catch {
+                return false;
+            }
+            "#,
+        );
+        writer.send(text).is_ok() && writer.join::<bool>().await.unwrap_or(false)
+    };
+    if !copied {
+        error!("Couldn't write the system clipboard");
+    }
+}
+
+/// Pastes the content of the clipboard, natively with the `clipboard` feature or with the web
+/// Clipboard API otherwise.
+///
+/// # Returns
+///
+/// The content of the clipboard, or the last content copied if the clipboard holds no content of
+/// the application (for instance, after a restart on platforms that clear it).
+async fn paste_from_clipboard() -> Option<ClipboardContent> {
+    #[cfg(feature = "clipboard")]
+    let text = read_system();
+    #[cfg(not(feature = "clipboard"))]
+    let text = document::eval(
+        r#"
+        try {
+            return await navigator.clipboard.readText();
+        } catch {
+            return null;
+        }
+        "#,
+    )
+    .join::<Option<String>>()
+    .await
+    .ok()
+    .flatten();
+    text.and_then(|text| ClipboardContent::from_text(&text))
+        .or_else(ClipboardContent::remembered)
+}
+
+/// A panel copying a region of the solution grid to the clipboard and pasting a copied region,
+/// which survives restarts and can be pasted from other instances. The colors of a pasted region
+/// are mapped to the perceptually closest colors of the palette.
+///
+/// The rows and columns are entered starting at one, like in the logs.
 ///
 /// # Context:
-/// - `Signal<NonogramPalette>`: Manages the Nonogram color palette.
-/// - `Signal<NonogramSolution>`: Manages the current Nonogram solution grid to check color usage.
+/// - `Signal<NonogramSolution>`: The grid copied and pasted over.
+/// - `Signal<NonogramPalette>`: The palette of the grid.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
 #[component]
-fn ColorPalette(readonly: bool) -> Element {
-    let mut use_palette = use_context::<Signal<NonogramPalette>>();
+fn ClipboardPanel() -> Element {
     let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let dispatcher = use_dispatcher();
+    let mut use_start = use_signal(|| (1usize, 1usize));
+    let mut use_end = use_signal(|| (5usize, 5usize));
+    let mut use_at = use_signal(|| (1usize, 1usize));
+    let mut use_empty_clipboard = use_signal(|| false);
+    let (rows, cols) = {
+        let solution = use_solution();
+        (
+            solution.solution_grid.len().max(1),
+            solution
+                .solution_grid
+                .first()
+                .map_or(1, |row| row.len().max(1)),
+        )
+    };
+    let copy_onclick = move |_| async move {
+        let (start, end) = (use_start(), use_end());
+        let area = Area {
+            start: (start.0 - 1, start.1 - 1),
+            end: (end.0 - 1, end.1 - 1),
+        };
+        let Some(region) = Region::copy(&use_solution(), &use_palette(), area) else {
+            info!("The region is outside of the grid");
+            return;
+        };
+        info!(
+            "Copied a {}x{} region to the clipboard",
+            region.cells.len(),
+            region.cells[0].len()
+        );
+        copy_to_clipboard(ClipboardContent::Region(region)).await;
+    };
+    let paste_onclick = move |_| async move {
+        let Some(ClipboardContent::Region(region)) = paste_from_clipboard().await else {
+            info!("The clipboard holds no region");
+            use_empty_clipboard.set(true);
+            return;
+        };
+        use_empty_clipboard.set(false);
+        let at = use_at();
+        let mut solution = use_solution.peek().clone();
+        let changed = region.paste(&mut solution, &use_palette.peek(), (at.0 - 1, at.1 - 1));
+        info!(
+            "Pasted a region at ({}, {}), changing {changed} cells",
+            at.0, at.1
+        );
+        if changed > 0 {
+            dispatcher.dispatch(GridCommand::Replace {
+                tool: Tool::Paste,
+                solution,
+            });
+        }
+    };
     rsx! {
-        for (i , color) in use_palette().color_palette.iter().enumerate() {
-            button {
-                key: "brush-{i}",
-                style: "background-color: {color}",
-                class: "w-10 h-10 rounded-full hover:bg-blue-800 hover:scale-125 active:scale-150 transition-transform transform",
-                onclick: move |_| {
-                    use_palette.write().set_brush(i);
-                    info!("Changed brush color to: {}", use_palette().show_brush());
-                },
-                ondoubleclick: move |_| {
-                    if use_palette().len() > 1
-                        && use_solution()
-                            .solution_grid
-                            .iter()
-                            .map(|row| *row.iter().max().unwrap_or(&0))
-                            .max()
-                            .unwrap_or(0) < i
-                    {
-                        info!("Removing brush color: {} -> {}", i, use_palette().get(i));
-                        use_palette.write().remove_color(i);
-                    } else {
-                        info!("Cannot remove brush color: {}", use_palette().show_brush());
-                    }
-                },
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_clipboard")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3 py-2",
+                NumberInput {
+                    id: "copy-top-input",
+                    label: t!("label_copy_top"),
+                    value: use_start().0,
+                    min: 1,
+                    max: rows,
+                    onchange: move |row| use_start.write().0 = row,
+                }
+                NumberInput {
+                    id: "copy-left-input",
+                    label: t!("label_copy_left"),
+                    value: use_start().1,
+                    min: 1,
+                    max: cols,
+                    onchange: move |col| use_start.write().1 = col,
+                }
+                NumberInput {
+                    id: "copy-bottom-input",
+                    label: t!("label_copy_bottom"),
+                    value: use_end().0,
+                    min: 1,
+                    max: rows,
+                    onchange: move |row| use_end.write().0 = row,
+                }
+                NumberInput {
+                    id: "copy-right-input",
+                    label: t!("label_copy_right"),
+                    value: use_end().1,
+                    min: 1,
+                    max: cols,
+                    onchange: move |col| use_end.write().1 = col,
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: copy_onclick,
+                    {t!("button_copy_region")}
+                }
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3 py-2",
+                NumberInput {
+                    id: "paste-row-input",
+                    label: t!("label_paste_row"),
+                    value: use_at().0,
+                    min: 1,
+                    max: rows,
+                    onchange: move |row| use_at.write().0 = row,
+                }
+                NumberInput {
+                    id: "paste-column-input",
+                    label: t!("label_paste_column"),
+                    value: use_at().1,
+                    min: 1,
+                    max: cols,
+                    onchange: move |col| use_at.write().1 = col,
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: paste_onclick,
+                    {t!("button_paste_region")}
+                }
+                if use_empty_clipboard() {
+                    span { class: "text-sm text-amber-400", {t!("clipboard_no_region")} }
+                }
             }
         }
     }
@@ -799,7 +7597,7 @@ fn FileInput(readonly: bool) -> Element {
                     },
                     value: "{use_data().filename}",
                 }
-                if !use_data().filename.contains(".ngram") {
+                if !is_ngram(&use_data().filename) {
                     span {
                         class: "absolute inset-y-0 right-4 flex items-center pointer-events-none text-gray-400",
                         style: "font-family: monospace; color: darkgray;",
@@ -813,41 +7611,78 @@ fn FileInput(readonly: bool) -> Element {
 
 /// A component for loading a Nonogram solution from a file.
 ///
-/// This component provides an input field to load a Nonogram solution from a `.ngram` file.
+/// This component provides an input field to load a Nonogram solution from a `.ngram` file,
+/// or from a PNG picture embedding it (see `PictureSaveButton`).
 /// It handles file reading, deserialization, and updating the Nonogram state accordingly.
+/// Loading the same file as the puzzle being solved (by its content hash) keeps the progress,
+/// clears the grid, or asks which, as chosen in the settings (see `DuplicateLoadSelect`).
 ///
 /// # Context:
 /// - `Signal<NonogramFile>`: Manages the loaded Nonogram file.
 /// - `Signal<NonogramPuzzle>`: Updates the Nonogram puzzle based on the file data.
 /// - `Signal<NonogramSolution>`: Updates the Nonogram solution based on the loaded data.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
 /// - `Signal<NonogramPalette>`: Manages the Nonogram palette from the loaded file.
 /// - `Signal<NonogramData>`: Updates Nonogram data, including filename and completion status.
+/// - `Signal<Settings>`: What loading the same file again does.
 #[component]
 fn FileLoadInput() -> Element {
     let mut use_file = use_context::<Signal<NonogramFile>>();
     let mut use_puzzle = use_context::<Signal<NonogramPuzzle>>();
-    let mut use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let dispatcher = use_dispatcher();
     let mut use_palette = use_context::<Signal<NonogramPalette>>();
     let mut use_data = use_context::<Signal<NonogramData>>();
+    let use_settings = use_context::<Signal<Settings>>();
+    // The same file loaded again, with its filename, while asking whether to keep the progress.
+    let mut use_duplicate = use_signal(|| None::<(NonogramFile, String)>);
+
+    let mut load_file = move |nonogram_file: NonogramFile, filename: String| {
+        *use_file.write() = nonogram_file.clone();
+        *use_puzzle.write() = NonogramPuzzle::from_solution(&nonogram_file.solution);
+        *use_palette.write() = nonogram_file.palette;
+        use_data.write().filename = filename;
+        use_data.write().completed = false;
+        use_data.write().blind = false;
+        dispatcher.dispatch(GridCommand::Replace {
+            tool: Tool::Load,
+            solution: NonogramSolution {
+                solution_grid: vec![vec![0; use_puzzle().cols]; use_puzzle().rows],
+            },
+        });
+        info!("Nonogram loaded correctly!");
+    };
+    let mut keep_progress = move |filename: String| {
+        use_data.write().filename = filename;
+        info!("Same nonogram loaded again, the progress is kept");
+    };
+
     let load_nonogram_onchange = move |event: FormEvent| async move {
         info!("Loading nonogram...");
         match &event.files() {
             Some(file_engine) => {
                 let files = file_engine.files();
                 match files.get(0) {
-                    Some(file) => match file_engine.read_file_to_string(file).await {
+                    Some(file) => match read_nonogram_json(file_engine, file).await {
                         Some(json) => match serde_json::from_str::<NonogramFile>(&json) {
                             Ok(nonogram_file) => {
-                                *use_file.write() = nonogram_file.clone();
-                                use_solution.write().clear();
-                                *use_puzzle.write() =
-                                    NonogramPuzzle::from_solution(&nonogram_file.solution);
-                                *use_palette.write() = nonogram_file.palette;
-                                use_data.write().filename = file.clone();
-                                use_data.write().completed = false;
-                                use_solution.write().set_cols(use_puzzle().cols);
-                                use_solution.write().set_rows(use_puzzle().rows);
-                                info!("Nonogram loaded correctly!");
+                                let filename = ngram_filename(file);
+                                let duplicate = nonogram_file.content_hash()
+                                    == use_file.peek().content_hash()
+                                    && use_solution
+                                        .peek()
+                                        .solution_grid
+                                        .iter()
+                                        .flatten()
+                                        .any(|&cell| cell != BACKGROUND);
+                                match use_settings.peek().duplicate_load {
+                                    _ if !duplicate => load_file(nonogram_file, filename),
+                                    DuplicateLoad::Ask => {
+                                        use_duplicate.set(Some((nonogram_file, filename)))
+                                    }
+                                    DuplicateLoad::KeepProgress => keep_progress(filename),
+                                    DuplicateLoad::Clear => load_file(nonogram_file, filename),
+                                }
                             }
                             Err(err) => {
                                 error!("Couldn't deserialize file '{file}': {err}");
@@ -871,28 +7706,63 @@ fn FileLoadInput() -> Element {
         input {
             class: "appearance-none rounded border px-4 py-1 border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform cursor-pointer",
             r#type: "file",
-            accept: ".ngram",
+            accept: ".ngram,.png",
             multiple: false,
             onchange: load_nonogram_onchange,
             {t!("button_load_nonogram")}
         }
+        if let Some((nonogram_file, filename)) = use_duplicate() {
+            div { class: "fixed inset-0 z-50 flex justify-center items-center bg-black bg-opacity-60",
+                div { class: "flex flex-col gap-6 p-6 max-w-lg rounded-lg border border-gray-600 bg-gray-900 text-white shadow-lg",
+                    h2 { class: "text-2xl font-bold text-center", {t!("title_duplicate_load")} }
+                    p { class: "text-center text-gray-300", {t!("duplicate_load_warning")} }
+                    div { class: "flex flex-row flex-wrap justify-center items-center gap-6",
+                        button {
+                            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                            onclick: {
+                                let filename = filename.clone();
+                                move |_| {
+                                    keep_progress(filename.clone());
+                                    use_duplicate.set(None);
+                                }
+                            },
+                            {t!("button_keep_progress")}
+                        }
+                        button {
+                            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                            onclick: move |_| {
+                                load_file(nonogram_file.clone(), filename.clone());
+                                use_duplicate.set(None);
+                            },
+                            {t!("button_start_over")}
+                        }
+                    }
+                }
+            }
+        }
     }
 }
 
 /// A component for loading a Nonogram solution from a file.
 ///
-/// This component provides an input field to load a Nonogram solution from a `.ngram` file.
+/// This component provides an input field to load a Nonogram solution from a `.ngram` file,
+/// or from a PNG picture embedding it (see `PictureSaveButton`).
 /// It handles file reading, deserialization, and updating the Nonogram state accordingly.
 ///
 /// # Context:
 /// - `Signal<NonogramFile>`: Manages the loaded Nonogram file.
 /// - `Signal<NonogramPuzzle>`: Updates the Nonogram puzzle based on the file data.
 /// - `Signal<NonogramSolution>`: Updates the Nonogram solution based on the loaded data.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
 /// - `Signal<NonogramPalette>`: Manages the Nonogram palette from the loaded file.
 /// - `Signal<NonogramData>`: Updates Nonogram data, including filename and completion status.
+/// - `Signal<Option<Signature>>`: Replaced by the signature of the loaded file.
+/// - `Signal<AuthorNotes>`: Replaced by the notes of the author of the loaded file.
 #[component]
 fn FileLoadEditInput() -> Element {
-    let mut use_solution = use_context::<Signal<NonogramSolution>>();
+    let dispatcher = use_dispatcher();
+    let mut use_signature = use_context::<Signal<Option<Signature>>>();
+    let mut use_notes = use_context::<Signal<AuthorNotes>>();
     let mut use_palette = use_context::<Signal<NonogramPalette>>();
     let mut use_data = use_context::<Signal<NonogramData>>();
     let load_nonogram_onchange = move |event: FormEvent| async move {
@@ -901,14 +7771,17 @@ fn FileLoadEditInput() -> Element {
             Some(file_engine) => {
                 let files = file_engine.files();
                 match files.get(0) {
-                    Some(file) => match file_engine.read_file_to_string(file).await {
+                    Some(file) => match read_nonogram_json(file_engine, file).await {
                         Some(json) => match serde_json::from_str::<NonogramFile>(&json) {
                             Ok(nonogram_file) => {
-                                use_solution.write().set_cols(nonogram_file.solution.cols());
-                                use_solution.write().set_rows(nonogram_file.solution.rows());
-                                *use_solution.write() = nonogram_file.solution;
+                                dispatcher.dispatch(GridCommand::Replace {
+                                    tool: Tool::Load,
+                                    solution: nonogram_file.solution,
+                                });
                                 *use_palette.write() = nonogram_file.palette;
-                                use_data.write().filename = file.clone();
+                                *use_signature.write() = nonogram_file.signature;
+                                use_notes.write().text = nonogram_file.notes;
+                                use_data.write().filename = ngram_filename(file);
                                 use_data.write().completed = false;
                                 info!("Nonogram loaded correctly!");
                             }
@@ -929,69 +7802,490 @@ fn FileLoadEditInput() -> Element {
                 error!("Event hadn't a file engine attached: {event:?}");
             }
         }
-    };
-    rsx! {
-        input {
-            class: "appearance-none rounded border px-4 py-1 border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform cursor-pointer",
-            r#type: "file",
-            accept: ".ngram",
-            multiple: false,
-            onchange: load_nonogram_onchange,
-            {t!("button_load_nonogram")}
-        }
+    };
+    rsx! {
+        input {
+            class: "appearance-none rounded border px-4 py-1 border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform cursor-pointer",
+            r#type: "file",
+            accept: ".ngram,.png",
+            multiple: false,
+            onchange: load_nonogram_onchange,
+            {t!("button_load_nonogram")}
+        }
+    }
+}
+
+/// Reads the JSON of a Nonogram file, from a `.ngram` file or embedded in a PNG picture.
+///
+/// # Arguments:
+/// - `file_engine`: The file engine of the input event.
+/// - `file`: The name of the file, a `.png` extension marks a picture.
+///
+/// # Returns:
+/// The JSON of the Nonogram file, or `None` if it couldn't be read (the error is logged).
+async fn read_nonogram_json(file_engine: &Arc<dyn FileEngine>, file: &str) -> Option<String> {
+    if has_extension(file, ".png") {
+        let bytes = file_engine.read_file(file).await?;
+        read_metadata(&bytes)
+            .inspect_err(|err| error!("Couldn't read the picture '{file}': {err}"))
+            .ok()
+    } else {
+        file_engine.read_file_to_string(file).await
+    }
+}
+
+/// Returns the filename an imported web page is saved to, replacing its extension.
+fn page_filename(file: &str) -> String {
+    let stem = strip_extension(file, ".html")
+        .or_else(|| strip_extension(file, ".htm"))
+        .unwrap_or(file);
+    ngram_filename(stem)
+}
+
+#[cfg(not(feature = "web"))]
+/// A function to save a Nonogram solution to a file.
+///
+/// This function saves the provided Nonogram solution as a `.ngram` file.
+/// Depending on the platform, it behaves differently:
+/// - On non-web platforms, it writes the data directly to the file system.
+/// - On web platforms, it creates a downloadable data URI link for the Nonogram file.
+///   and clicks it programatically (there isn't a standard way to do it).
+///
+/// # Arguments:
+/// - `json`: The Nonogram solution in JSON format.
+/// - `filename`: The desired filename for the saved Nonogram file.
+fn save_nonogram(json: String, filename: String) {
+    use std::fs;
+    use std::io::Write;
+
+    let mut file = fs::File::create(&filename).expect("Failed to create ngram file");
+    file.write_all(json.as_bytes())
+        .expect("Failed to write data to ngram file");
+    println!("Nonogram saved to {}", filename);
+}
+
+#[cfg(feature = "web")]
+/// A function to save a Nonogram solution to a file.
+///
+/// This function saves the provided Nonogram solution as a `.ngram` file.
+/// Depending on the platform, it behaves differently:
+/// - On non-web platforms, it writes the data directly to the file system.
+/// - On web platforms, it creates a downloadable data URI link for the Nonogram file
+///   and clicks it programatically (there isn't a standard way to do it).
+///
+/// # Arguments:
+/// - `json`: The Nonogram solution in JSON format.
+/// - `filename`: The desired filename for the saved Nonogram file.
+fn save_nonogram(json: String, filename: String) {
+    let data_uri = format!(
+        "data:application/json;charset=utf-8,{}",
+        urlencoding::encode(&json)
+    );
+
+    let document = web_sys::window().unwrap().document().unwrap();
+    let a = document.create_element("a").unwrap();
+    a.set_attribute("href", &data_uri).unwrap();
+    a.set_attribute("download", &filename).unwrap();
+
+    let body = document.body().unwrap();
+    body.append_child(&a).unwrap();
+    let click_event = web_sys::MouseEvent::new("click").unwrap();
+    a.dispatch_event(&click_event).unwrap();
+    body.remove_child(&a).unwrap();
+}
+
+/// Saves a text file other than a Nonogram file (such as an exported table or picture).
+///
+/// # Arguments:
+/// - `text`: The content of the file.
+/// - `filename`: The desired filename.
+/// - `mime`: The media type of the content, only used on web platforms (see `save_binary`).
+fn save_file(text: String, filename: String, mime: &str) {
+    save_binary(text.into_bytes(), filename, mime);
+}
+
+#[cfg(not(feature = "web"))]
+/// Saves binary data (such as a picture) to a file.
+///
+/// # Arguments:
+/// - `bytes`: The data.
+/// - `filename`: The desired filename.
+/// - `_mime`: The media type of the data, only used on web platforms.
+fn save_binary(bytes: Vec<u8>, filename: String, _mime: &str) {
+    match std::fs::write(&filename, bytes) {
+        Ok(()) => info!("Saved {filename}"),
+        Err(err) => error!("Couldn't write '{filename}': {err}"),
+    }
+}
+
+#[cfg(feature = "web")]
+/// Saves binary data (such as a picture or an archive) to a file.
+///
+/// On web platforms the data is downloaded through an object URL of a `Blob`, linked and clicked
+/// programatically like in `save_nonogram`, so large archives don't hit the length limits of the
+/// data URIs.
+///
+/// # Arguments:
+/// - `bytes`: The data.
+/// - `filename`: The desired filename.
+/// - `mime`: The media type of the data.
+fn save_binary(bytes: Vec<u8>, filename: String, mime: &str) {
+    use web_sys::js_sys::{Array, Uint8Array};
+    use web_sys::{Blob, BlobPropertyBag, Url};
+
+    let parts = Array::of1(&Uint8Array::from(bytes.as_slice()));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime);
+    let Ok(url) = Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+        .and_then(|blob| Url::create_object_url_with_blob(&blob))
+    else {
+        error!("Couldn't create the download of '{filename}'");
+        return;
+    };
+
+    let document = web_sys::window().unwrap().document().unwrap();
+    let a = document.create_element("a").unwrap();
+    a.set_attribute("href", &url).unwrap();
+    a.set_attribute("download", &filename).unwrap();
+
+    let body = document.body().unwrap();
+    body.append_child(&a).unwrap();
+    let click_event = web_sys::MouseEvent::new("click").unwrap();
+    a.dispatch_event(&click_event).unwrap();
+    body.remove_child(&a).unwrap();
+    let _ = Url::revoke_object_url(&url);
+}
+
+/// Renders a button exporting the current solution as a PNG picture with the file embedded.
+///
+/// The `.ngram` file is stored in an `iTXt` chunk of the picture, so the picture itself can be
+/// loaded back in the editor or the solver without losing anything.
+///
+/// # Context:
+/// - `Signal<NonogramSolution>`: The solution drawn in the picture.
+/// - `Signal<NonogramPalette>`: The colors of the cells.
+/// - `Signal<NonogramData>`: The filename and the block size, used for the picture.
+/// - `Signal<Option<Signature>>`: The signature of the author, embedded in the file (optional).
+/// - `Signal<AuthorNotes>`: The notes of the author, embedded in the file (optional).
+#[component]
+fn PictureSaveButton() -> Element {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_signature = try_use_context::<Signal<Option<Signature>>>();
+    let use_notes = try_use_context::<Signal<AuthorNotes>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let save_picture_onclick = move |_| {
+        let file = NonogramFile {
+            solution: use_solution(),
+            palette: use_palette(),
+            signature: use_signature.and_then(|use_signature| use_signature()),
+            notes: use_notes
+                .map(|use_notes| use_notes().text)
+                .unwrap_or_default(),
+        };
+        let json = match serde_json::to_string(&file) {
+            Ok(json) => json,
+            Err(err) => {
+                error!("Failed to serialize the nonogram: {err}");
+                return;
+            }
+        };
+        let png = encode_png(&file.solution, &file.palette, use_data().block_size as u32);
+        match embed_metadata(&png, &json) {
+            Ok(picture) => {
+                let filename = use_data().filename;
+                let filename = match ngram_stem(&filename) {
+                    "" => "nonogram",
+                    name => name,
+                };
+                save_binary(picture, format!("{filename}.png"), "image/png");
+                info!("Picture prepared for download!");
+            }
+            Err(err) => error!("Couldn't embed the nonogram in the picture: {err}"),
+        }
+    };
+    rsx! {
+        button {
+            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+            onclick: save_picture_onclick,
+            {t!("button_save_picture")}
+        }
+    }
+}
+
+/// Renders buttons exporting the clues of the current solution as plain text or Markdown.
+///
+/// The clues of multicolor puzzles are marked with their color indexes, and a legend of the
+/// palette is included. The plain text can be imported back in the solver.
+///
+/// # Contexts:
+/// - `Signal<NonogramSolution>`: The solution whose clues are exported.
+/// - `Signal<NonogramPalette>`: The palette, for the legend of the colors.
+/// - `Signal<NonogramData>`: The filename, used for the exported file.
+#[component]
+fn ClueExportButtons() -> Element {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let export = move |format: ClueFormat| {
+        let puzzle = NonogramPuzzle::from_solution(&use_solution());
+        let text = format_clues(&puzzle, &use_palette(), format);
+        let filename = use_data().filename;
+        let filename = match ngram_stem(&filename) {
+            "" => "nonogram",
+            name => name,
+        };
+        save_file(
+            text,
+            format!("{filename}.{}", format.extension()),
+            "text/plain",
+        );
+        info!("Clues prepared for download!");
+    };
+    rsx! {
+        button {
+            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+            onclick: move |_| export(ClueFormat::PlainText),
+            {t!("button_export_clues_text")}
+        }
+        button {
+            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+            onclick: move |_| export(ClueFormat::Markdown),
+            {t!("button_export_clues_markdown")}
+        }
+    }
+}
+
+/// A panel printing the puzzle (its clues around an empty grid), or exporting it as an HTML page.
+///
+/// The ink-saver mode prints the clues in black with letter color codes over white backgrounds,
+/// and the grayscale mode prints the colors as grays of the same luminance, previewing the sheet
+/// below the panel to check how the puzzle looks when photocopied. In the solver the loaded puzzle
+/// is printed, in the editor the puzzle of the drawing.
+///
+/// # Context:
+/// - `Signal<NonogramFile>`: Only provided in the solver, where the loaded puzzle is printed.
+/// - `Signal<NonogramPuzzle>`: The puzzle of the solver.
+/// - `Signal<NonogramSolution>`: The drawing of the editor.
+/// - `Signal<NonogramPalette>`: The colors of the clues and cells.
+/// - `Signal<NonogramData>`: The filename, used for the title and the exported file.
+#[component]
+fn PrintPanel() -> Element {
+    let use_file = try_use_context::<Signal<NonogramFile>>();
+    let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let mut use_options = use_signal(PrintOptions::default);
+    let title = move || {
+        let filename = use_data().filename;
+        match ngram_stem(&filename) {
+            "" => String::from("nonogram"),
+            name => name.to_string(),
+        }
+    };
+    let sheet = move || {
+        let puzzle = match use_file {
+            Some(_) => use_puzzle(),
+            None => NonogramPuzzle::from_solution(&use_solution()),
+        };
+        print_sheet(&puzzle, &use_palette(), &title(), use_options())
+    };
+    let print_onclick = move |_| {
+        let Ok(html) = serde_json::to_string(&sheet()) else {
+            error!("Failed to serialize the printable sheet");
+            return;
+        };
+        document::eval(&format!(
+            r#"
+            const sheet = window.open("", "_blank");
+            if (sheet) {{
+                sheet.document.write({html});
+                sheet.document.close();
+                sheet.focus();
+                sheet.print();
+            }}
+            "#
+        ));
+        info!("Printing nonogram...");
+    };
+    let export_onclick = move |_| {
+        save_file(sheet(), format!("{}.html", title()), "text/html");
+        info!("Printable sheet prepared for download!");
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_print")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    input {
+                        id: "ink-saver-input",
+                        r#type: "checkbox",
+                        class: "w-5 h-5 cursor-pointer",
+                        checked: use_options().ink_saver,
+                        onchange: move |event: FormEvent| {
+                            use_options.write().ink_saver = event.checked();
+                        },
+                    }
+                    label {
+                        r#for: "ink-saver-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_ink_saver")}
+                    }
+                }
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    input {
+                        id: "grayscale-input",
+                        r#type: "checkbox",
+                        class: "w-5 h-5 cursor-pointer",
+                        checked: use_options().grayscale,
+                        onchange: move |event: FormEvent| {
+                            use_options.write().grayscale = event.checked();
+                        },
+                    }
+                    label {
+                        r#for: "grayscale-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_grayscale_preview")}
+                    }
+                }
+                NumberInput {
+                    id: "print-cell-input",
+                    label: t!("label_cell_size"),
+                    value: use_options().cell_size as usize,
+                    min: 8,
+                    max: 64,
+                    onchange: move |size: usize| use_options.write().cell_size = size as u32,
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: print_onclick,
+                    {t!("button_print")}
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: export_onclick,
+                    {t!("button_export_print")}
+                }
+            }
+            if use_options().grayscale {
+                iframe {
+                    class: "w-full h-96 rounded bg-white",
+                    title: t!("label_grayscale_preview"),
+                    srcdoc: sheet(),
+                }
+            }
+        }
     }
 }
 
-#[cfg(not(feature = "web"))]
-/// A function to save a Nonogram solution to a file.
-///
-/// This function saves the provided Nonogram solution as a `.ngram` file.
-/// Depending on the platform, it behaves differently:
-/// - On non-web platforms, it writes the data directly to the file system.
-/// - On web platforms, it creates a downloadable data URI link for the Nonogram file.
-///   and clicks it programatically (there isn't a standard way to do it).
-///
-/// # Arguments:
-/// - `json`: The Nonogram solution in JSON format.
-/// - `filename`: The desired filename for the saved Nonogram file.
-fn save_nonogram(json: String, filename: String) {
-    use std::fs;
-    use std::io::Write;
-
-    let mut file = fs::File::create(&filename).expect("Failed to create ngram file");
-    file.write_all(json.as_bytes())
-        .expect("Failed to write data to ngram file");
-    println!("Nonogram saved to {}", filename);
-}
-
-#[cfg(feature = "web")]
-/// A function to save a Nonogram solution to a file.
+/// A panel drawing arrows, circles and short labels over the grid, for teachers preparing
+/// explanations of the solving techniques.
 ///
-/// This function saves the provided Nonogram solution as a `.ngram` file.
-/// Depending on the platform, it behaves differently:
-/// - On non-web platforms, it writes the data directly to the file system.
-/// - On web platforms, it creates a downloadable data URI link for the Nonogram file
-///   and clicks it programatically (there isn't a standard way to do it).
+/// The marks are independent from the puzzle: they aren't saved in the Nonogram file, but can be
+/// hidden and exported with the grid as an SVG picture.
 ///
-/// # Arguments:
-/// - `json`: The Nonogram solution in JSON format.
-/// - `filename`: The desired filename for the saved Nonogram file.
-fn save_nonogram(json: String, filename: String) {
-    let data_uri = format!(
-        "data:application/json;charset=utf-8,{}",
-        urlencoding::encode(&json)
-    );
-
-    let document = web_sys::window().unwrap().document().unwrap();
-    let a = document.create_element("a").unwrap();
-    a.set_attribute("href", &data_uri).unwrap();
-    a.set_attribute("download", &filename).unwrap();
-
-    let body = document.body().unwrap();
-    body.append_child(&a).unwrap();
-    let click_event = web_sys::MouseEvent::new("click").unwrap();
-    a.dispatch_event(&click_event).unwrap();
-    body.remove_child(&a).unwrap();
+/// # Context:
+/// - `Signal<Annotations>`: The marks drawn, their visibility and the selected tool.
+/// - `Signal<NonogramSolution>`: The grid exported beneath the marks.
+/// - `Signal<NonogramPalette>`: The colors of the exported cells.
+/// - `Signal<NonogramData>`: The filename and block size of the exported picture.
+#[component]
+fn AnnotationPanel() -> Element {
+    let mut use_annotations = use_context::<Signal<Annotations>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let tools = [
+        (None, t!("button_annotation_pointer")),
+        (Some(AnnotationTool::Arrow), t!("button_annotation_arrow")),
+        (Some(AnnotationTool::Circle), t!("button_annotation_circle")),
+        (Some(AnnotationTool::Label), t!("button_annotation_label")),
+    ];
+    let export_onclick = move |_| {
+        let picture = annotated_svg(
+            &use_solution(),
+            &use_palette(),
+            &use_annotations().items,
+            use_data().block_size as u32,
+        );
+        let name = match ngram_stem(&use_data().filename) {
+            "" => String::from("nonogram"),
+            name => name.to_string(),
+        };
+        save_file(picture, format!("{name}-annotated.svg"), "image/svg+xml");
+        info!("Annotated picture prepared for download!");
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_annotations")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-3 py-2",
+                for (tool , label) in tools {
+                    button {
+                        class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                        background_color: if use_annotations().tool == tool { "#1e40af" },
+                        onclick: move |_| use_annotations.write().tool = tool,
+                        {label}
+                    }
+                }
+                input {
+                    r#type: "color",
+                    class: "w-10 h-10 cursor-pointer",
+                    value: use_annotations().color,
+                    oninput: move |event: FormEvent| use_annotations.write().color = event.value(),
+                }
+                input {
+                    r#type: "text",
+                    class: "px-2 py-1 rounded border border-gray-500 bg-gray-800 text-white",
+                    maxlength: 24,
+                    placeholder: t!("placeholder_annotation_label"),
+                    value: use_annotations().text,
+                    oninput: move |event: FormEvent| use_annotations.write().text = event.value(),
+                }
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                div { class: "flex flex-row justify-items-center justify-center items-center gap-3",
+                    input {
+                        id: "annotations-visible-input",
+                        r#type: "checkbox",
+                        class: "w-5 h-5 cursor-pointer",
+                        checked: use_annotations().visible,
+                        onchange: move |event: FormEvent| {
+                            use_annotations.write().visible = event.checked();
+                        },
+                    }
+                    label {
+                        r#for: "annotations-visible-input",
+                        class: "py-2 text-gray-200 font-semibold cursor-pointer select-none",
+                        {t!("label_show_annotations")}
+                    }
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    disabled: use_annotations().items.is_empty(),
+                    onclick: move |_| {
+                        use_annotations.write().undo();
+                    },
+                    {t!("button_annotation_undo")}
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    disabled: use_annotations().items.is_empty(),
+                    onclick: move |_| use_annotations.write().items.clear(),
+                    {t!("button_annotation_clear")}
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: export_onclick,
+                    {t!("button_export_annotated")}
+                }
+            }
+        }
+    }
 }
 
 /// Renders a button that allows saving a Nonogram solution.
@@ -1000,10 +8294,17 @@ fn save_nonogram(json: String, filename: String) {
 /// When clicked, it serializes the Nonogram solution to JSON and saves it either as a file
 /// on non-web platforms or as a downloadable data URI on web platforms.
 ///
+/// If the solution has empty rows or columns, the author is warned before saving (empty lines
+/// make the clues render oddly and reduce the difficulty), and can crop the grid around the
+/// drawing with a single click or save anyway.
+///
 /// # Contexts:
 /// - `Signal<NonogramSolution>`: Represents the current Nonogram solution.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
 /// - `Signal<NonogramPalette>`: Represents the color palette used in the Nonogram.
 /// - `Signal<NonogramData>`: Contains additional data like filename.
+/// - `Signal<Option<Signature>>`: The signature of the author, embedded in the file (optional).
+/// - `Signal<AuthorNotes>`: The notes of the author, embedded in the file (optional).
 ///
 /// # Events:
 /// - `onclick`: Initiates the save operation.
@@ -1013,27 +8314,34 @@ fn save_nonogram(json: String, filename: String) {
 #[component]
 fn FileSaveButton() -> Element {
     let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_signature = try_use_context::<Signal<Option<Signature>>>();
+    let use_notes = try_use_context::<Signal<AuthorNotes>>();
+    let dispatcher = use_dispatcher();
     let use_palette = use_context::<Signal<NonogramPalette>>();
     let use_data = use_context::<Signal<NonogramData>>();
+    let mut use_warning = use_signal(|| None::<(Vec<usize>, Vec<usize>)>);
 
-    let save_nonogram_onclick = move |_| {
+    let save = move || {
         info!("Saving nonogram...");
         let solution = use_solution().clone();
         let palette = use_palette().clone();
-        let file = NonogramFile { solution, palette };
+        let signature = use_signature.and_then(|use_signature| use_signature());
+        let notes = use_notes
+            .map(|use_notes| use_notes().text)
+            .unwrap_or_default();
+        let file = NonogramFile {
+            solution,
+            palette,
+            signature,
+            notes,
+        };
 
         match serde_json::to_string(&file) {
             Ok(json) => {
-                let mut filename = use_data().filename.to_string();
-                if filename.is_empty() {
-                    filename = "nonogram".to_string();
-                }
-                let extension = if filename.ends_with(".ngram") {
-                    ""
-                } else {
-                    ".ngram"
+                let filename = match use_data().filename.as_str() {
+                    "" => ngram_filename("nonogram"),
+                    filename => ngram_filename(filename),
                 };
-                let filename = format!("{}{}", filename, extension);
 
                 save_nonogram(json, filename);
 
@@ -1045,11 +8353,64 @@ fn FileSaveButton() -> Element {
         }
     };
 
+    let save_nonogram_onclick = move |_| {
+        let (rows, cols) = use_solution().empty_lines();
+        if rows.is_empty() && cols.is_empty() {
+            save();
+        } else {
+            info!("Nonogram has empty rows {rows:?} and columns {cols:?}");
+            *use_warning.write() = Some((rows, cols));
+        }
+    };
+
+    // Formats the empty lines as a list of 1-based indexes.
+    let show_lines = |lines: &[usize]| {
+        lines
+            .iter()
+            .map(|line| (line + 1).to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
     rsx! {
-        button {
-            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
-            onclick: save_nonogram_onclick,
-            {t!("button_save_nonogram")}
+        div { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                onclick: save_nonogram_onclick,
+                {t!("button_save_nonogram")}
+            }
+            if let Some((rows, cols)) = use_warning() {
+                div { class: "flex flex-col items-center gap-2 p-3 rounded border border-amber-500 bg-gray-800 text-amber-300",
+                    span { class: "font-semibold", {t!("warning_empty_lines")} }
+                    if !rows.is_empty() {
+                        span { {t!("label_rows")} ": {show_lines(&rows)}" }
+                    }
+                    if !cols.is_empty() {
+                        span { {t!("label_columns")} ": {show_lines(&cols)}" }
+                    }
+                    div { class: "flex flex-row gap-3",
+                        button {
+                            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-700 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                            onclick: move |_| {
+                                if let Some(area) = use_solution().bounding_box() {
+                                    info!("Cropping the nonogram solution grid to {area:?}");
+                                    dispatcher.dispatch(GridCommand::Crop { area });
+                                }
+                                *use_warning.write() = None;
+                            },
+                            {t!("button_crop")}
+                        }
+                        button {
+                            class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-700 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                            onclick: move |_| {
+                                *use_warning.write() = None;
+                                save();
+                            },
+                            {t!("button_save_anyway")}
+                        }
+                    }
+                }
+            }
         }
     }
 }
@@ -1062,10 +8423,12 @@ fn FileSaveButton() -> Element {
 /// # Contexts:
 /// - `Signal<NonogramFile>`: Provides the Nonogram solution and palette.
 /// - `Signal<usize>`: Displays the current score based on the solution.
+/// - `Signal<NonogramData>`: Whether the puzzle is blind, hiding the preview unless its solution is cached.
 #[component]
 fn SolutionPreview() -> Element {
     let use_file = use_context::<Signal<NonogramFile>>();
     let use_score = use_context::<Signal<usize>>();
+    let use_data = use_context::<Signal<NonogramData>>();
     let solution_grid = use_file().solution.solution_grid.clone();
     rsx! {
         div { class: "flex flex-row justify-center justify-items-center items-center",
@@ -1073,7 +8436,10 @@ fn SolutionPreview() -> Element {
                 {t!("score")}
                 ": {use_score()}"
             }
-            table { class: "pointer-events-none", draggable: false,
+            table {
+                class: "pointer-events-none",
+                hidden: use_data().blind && !use_data().solution_cached,
+                draggable: false,
                 tbody {
                     for (i , row_data) in solution_grid.iter().enumerate() {
                         tr {
@@ -1126,39 +8492,96 @@ fn ColorInput() -> Element {
 /// # Contexts:
 /// - `Signal<NonogramPalette>`: Provides colors for segments.
 /// - `Signal<NonogramData>`: Provides block sizes for styling.
+/// - `Signal<CheckMode>`: Colors the clues by the slack of their lines (optional).
+/// - `Signal<Settings>`: Aligns the clues to the grid or to the top edge (optional), and whether
+///   the clues of the columns that can no longer be completed are tinted red.
+/// - `Signal<NonogramSolution>`: The grid checked for contradictions, in the solver.
+/// - `Signal<Option<ClueSelection>>`: Selects the clicked clue for editing, in puzzles imported
+///   from their clues (optional).
 #[component]
 fn ColumnsConstraints(puzzle: NonogramPuzzle) -> Element {
     let use_data = use_context::<Signal<NonogramData>>();
     let use_palette = use_context::<Signal<NonogramPalette>>();
+    let check_mode = try_use_context::<Signal<CheckMode>>()
+        .map(|use_check_mode| use_check_mode().enabled)
+        .unwrap_or_default();
+    let alignment = try_use_context::<Signal<Settings>>()
+        .map(|use_settings| use_settings().clue_alignment)
+        .unwrap_or_default();
+    // The clues are only editable in puzzles imported from their clues.
+    let use_selection =
+        try_use_context::<Signal<Option<ClueSelection>>>().filter(|_| use_data().blind);
+    let selection = use_selection.and_then(|use_selection| use_selection());
+    let col_styles: Vec<&str> = if check_mode {
+        puzzle_slack(&puzzle)
+            .1
+            .into_iter()
+            .map(tightness_style)
+            .collect()
+    } else {
+        vec![""; puzzle.cols]
+    };
+    let contradictions = use_contradictions(&puzzle);
+    let col_contradictions: Vec<&str> = (0..puzzle.cols)
+        .map(|col| contradiction_style(contradictions.contains(&ClueLine::Column(col))))
+        .collect();
     let max_table_rows = puzzle
         .col_constraints
         .iter()
         .map(|segments| segments.len())
         .max()
         .unwrap_or(0);
+    let select = move |col: usize, clues: usize, slot: usize| {
+        if let Some(mut use_selection) = use_selection {
+            use_selection.set(Some(clue_selection(
+                ClueLine::Column(col),
+                alignment,
+                clues,
+                max_table_rows,
+                slot,
+            )));
+        }
+    };
+    let selected = move |col: usize, index: Option<usize>| {
+        selection.is_some_and(|selection| {
+            selection.line == ClueLine::Column(col)
+                && (selection.new || Some(selection.index) == index)
+        })
+    };
     rsx! {
         table {
             id: "col-constaints-table",
-            class: "min-w-full min-h-full pointer-events-none",
+            class: if use_selection.is_some() { "min-w-full min-h-full" } else { "min-w-full min-h-full pointer-events-none" },
             draggable: false,
             tbody {
                 for i in 0..max_table_rows {
                     tr {
                         for (j , segments) in puzzle.col_constraints.iter().enumerate() {
-                            if let Some(segment) = segments
-                                .get((segments.len() as isize - max_table_rows as isize + i as isize) as usize)
+                            if let Some(segment) = alignment
+                                .clue_index(segments.len(), max_table_rows, i)
+                                .map(|index| &segments[index])
                             {
                                 td {
                                     key: "col-{i}-{j}",
                                     class: "border select-none",
-                                    style: "background-color: {use_palette().color_palette[segment.color]}; min-width: {use_data().block_size}px; max-width: {use_data().block_size}px; height: {use_data().block_size}px; font-size: {use_data().block_size/2}px; color: {use_palette().text_color(segment.color)}",
-                                    border_color: use_palette().border_color(segment.color),
+                                    style: "background-color: {use_palette().color_palette[segment.color]}; min-width: {use_data().block_size}px; max-width: {use_data().block_size}px; height: {use_data().block_size}px; font-size: calc({use_data().block_size/2}px * var(--font-scale, 1)); color: {use_palette().text_color(segment.color)}; {col_styles[j]} {col_contradictions[j]}",
+                                    border_color: if selected(j, alignment.clue_index(segments.len(), max_table_rows, i)) { String::from("red") } else { use_palette().border_color(segment.color) },
+                                    cursor: if use_selection.is_some() { "pointer" },
+                                    onclick: {
+                                        let clues = segments.len();
+                                        move |_| select(j, clues, i)
+                                    },
                                     "{segment.length}"
                                 }
                             } else {
                                 td {
                                     key: "col-{i}-{j}",
                                     style: "min-width: {use_data().block_size}px; height: {use_data().block_size}px",
+                                    cursor: if use_selection.is_some() { "pointer" },
+                                    onclick: {
+                                        let clues = segments.len();
+                                        move |_| select(j, clues, i)
+                                    },
                                 }
                             }
                         }
@@ -1177,39 +8600,96 @@ fn ColumnsConstraints(puzzle: NonogramPuzzle) -> Element {
 /// # Contexts:
 /// - `Signal<NonogramPalette>`: Supplies color information for each segment.
 /// - `Signal<NonogramData>`: Provides block sizes and color styles.
+/// - `Signal<CheckMode>`: Colors the clues by the slack of their lines (optional).
+/// - `Signal<Settings>`: Aligns the clues to the grid or to the left edge (optional), and whether
+///   the clues of the rows that can no longer be completed are tinted red.
+/// - `Signal<NonogramSolution>`: The grid checked for contradictions, in the solver.
+/// - `Signal<Option<ClueSelection>>`: Selects the clicked clue for editing, in puzzles imported
+///   from their clues (optional).
 #[component]
 fn RowsConstraints(puzzle: NonogramPuzzle) -> Element {
     let use_palette = use_context::<Signal<NonogramPalette>>();
     let use_data = use_context::<Signal<NonogramData>>();
+    let check_mode = try_use_context::<Signal<CheckMode>>()
+        .map(|use_check_mode| use_check_mode().enabled)
+        .unwrap_or_default();
+    let alignment = try_use_context::<Signal<Settings>>()
+        .map(|use_settings| use_settings().clue_alignment)
+        .unwrap_or_default();
+    // The clues are only editable in puzzles imported from their clues.
+    let use_selection =
+        try_use_context::<Signal<Option<ClueSelection>>>().filter(|_| use_data().blind);
+    let selection = use_selection.and_then(|use_selection| use_selection());
+    let row_styles: Vec<&str> = if check_mode {
+        puzzle_slack(&puzzle)
+            .0
+            .into_iter()
+            .map(tightness_style)
+            .collect()
+    } else {
+        vec![""; puzzle.rows]
+    };
+    let contradictions = use_contradictions(&puzzle);
+    let row_contradictions: Vec<&str> = (0..puzzle.rows)
+        .map(|row| contradiction_style(contradictions.contains(&ClueLine::Row(row))))
+        .collect();
     let max_table_cols = puzzle
         .row_constraints
         .iter()
         .map(|segments| segments.len())
         .max()
         .unwrap_or(0);
+    let select = move |row: usize, clues: usize, slot: usize| {
+        if let Some(mut use_selection) = use_selection {
+            use_selection.set(Some(clue_selection(
+                ClueLine::Row(row),
+                alignment,
+                clues,
+                max_table_cols,
+                slot,
+            )));
+        }
+    };
+    let selected = move |row: usize, index: Option<usize>| {
+        selection.is_some_and(|selection| {
+            selection.line == ClueLine::Row(row)
+                && (selection.new || Some(selection.index) == index)
+        })
+    };
 
     rsx! {
         table {
-            class: "max-w-min min-h-full pointer-events-none",
+            class: if use_selection.is_some() { "max-w-min min-h-full" } else { "max-w-min min-h-full pointer-events-none" },
             draggable: false,
             tbody {
                 for (i , segments) in puzzle.row_constraints.iter().enumerate() {
                     tr {
                         for j in 0..max_table_cols {
-                            if let Some(segment) = segments
-                                .get((segments.len() as isize - max_table_cols as isize + j as isize) as usize)
+                            if let Some(segment) = alignment
+                                .clue_index(segments.len(), max_table_cols, j)
+                                .map(|index| &segments[index])
                             {
                                 td {
                                     key: "row-{i}-{j}",
                                     class: "border select-none",
-                                    style: "background-color: {use_palette().color_palette[segment.color]}; min-width: {use_data().block_size}px; max-width: {use_data().block_size}px; height: {use_data().block_size}px; font-size: {use_data().block_size/2}px; color: {use_palette().text_color(segment.color)}",
-                                    border_color: use_palette().border_color(segment.color),
+                                    style: "background-color: {use_palette().color_palette[segment.color]}; min-width: {use_data().block_size}px; max-width: {use_data().block_size}px; height: {use_data().block_size}px; font-size: calc({use_data().block_size/2}px * var(--font-scale, 1)); color: {use_palette().text_color(segment.color)}; {row_styles[i]} {row_contradictions[i]}",
+                                    border_color: if selected(i, alignment.clue_index(segments.len(), max_table_cols, j)) { String::from("red") } else { use_palette().border_color(segment.color) },
+                                    cursor: if use_selection.is_some() { "pointer" },
+                                    onclick: {
+                                        let clues = segments.len();
+                                        move |_| select(i, clues, j)
+                                    },
                                     "{segment.length}"
                                 }
                             } else {
                                 td {
                                     key: "row-{i}-{j}",
                                     style: "min-width: {use_data().block_size}px; height: {use_data().block_size}px",
+                                    cursor: if use_selection.is_some() { "pointer" },
+                                    onclick: {
+                                        let clues = segments.len();
+                                        move |_| select(i, clues, j)
+                                    },
                                 }
                             }
                         }
@@ -1220,29 +8700,249 @@ fn RowsConstraints(puzzle: NonogramPuzzle) -> Element {
     }
 }
 
+/// Selects the clue shown in a slot of a clue table, or a new clue for an empty slot, inserted
+/// next to the clues of the line.
+///
+/// # Arguments:
+/// - `line`: The line of the clue table.
+/// - `alignment`: How the clues are aligned in the table.
+/// - `clues`: The number of clues of the line.
+/// - `slots`: The number of slots of the table.
+/// - `slot`: The clicked slot, counted from the outer edge.
+fn clue_selection(
+    line: ClueLine,
+    alignment: ClueAlignment,
+    clues: usize,
+    slots: usize,
+    slot: usize,
+) -> ClueSelection {
+    match alignment.clue_index(clues, slots, slot) {
+        Some(index) => ClueSelection {
+            line,
+            index,
+            new: false,
+        },
+        None => ClueSelection {
+            line,
+            index: if alignment == ClueAlignment::Grid {
+                0
+            } else {
+                clues
+            },
+            new: true,
+        },
+    }
+}
+
+/// A form editing the clue selected in the clue tables, in puzzles imported from their clues,
+/// so a mistake can be fixed without retyping the clues of the whole line.
+///
+/// The length and the color of the clue are validated to fit in the line when applied. The
+/// crossing lines aren't checked, so a puzzle can be fixed one line at a time.
+///
+/// # Context:
+/// - `Signal<Option<ClueSelection>>`: The selected clue, cleared when the edit is applied.
+/// - `Signal<NonogramPuzzle>`: The puzzle whose clues are edited.
+/// - `Signal<NonogramPalette>`: The colors the clue can take.
+#[component]
+fn ClueEditForm() -> Element {
+    let mut use_selection = use_context::<Signal<Option<ClueSelection>>>();
+    let mut use_puzzle = use_context::<Signal<NonogramPuzzle>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let mut use_length = use_signal(|| 1);
+    let mut use_color = use_signal(|| 1);
+    let mut use_error = use_signal(|| false);
+    use_effect(move || {
+        let Some(selection) = use_selection() else {
+            return;
+        };
+        let segment = selection
+            .line
+            .clues(&use_puzzle.peek())
+            .and_then(|clues| clues.get(selection.index).cloned())
+            .filter(|_| !selection.new);
+        use_length.set(segment.as_ref().map_or(1, |segment| segment.length));
+        use_color.set(segment.map_or(use_palette.peek().brush, |segment| segment.color));
+        use_error.set(false);
+    });
+    let Some(selection) = use_selection() else {
+        return rsx! {};
+    };
+    let max_length = match selection.line {
+        ClueLine::Row(_) => use_puzzle().cols,
+        ClueLine::Column(_) => use_puzzle().rows,
+    };
+    // Replaces, inserts or removes (with `None`) the selected clue.
+    let mut edit = move |segment: Option<NonogramSegment>| {
+        let mut puzzle = use_puzzle();
+        let mut clues = selection.line.clues(&puzzle).cloned().unwrap_or_default();
+        let index = selection.index.min(clues.len());
+        match segment {
+            Some(segment) if selection.new => clues.insert(index, segment),
+            Some(segment) if index < clues.len() => clues[index] = segment,
+            None if !selection.new && index < clues.len() => {
+                clues.remove(index);
+            }
+            _ => {}
+        }
+        match set_line_clues(&mut puzzle, selection.line, clues) {
+            Ok(()) => {
+                info!("Edited the clues of {:?}", selection.line);
+                *use_puzzle.write() = puzzle;
+                use_selection.set(None);
+            }
+            Err(err) => {
+                error!("Couldn't edit the clue: {err}");
+                use_error.set(true);
+            }
+        }
+    };
+    rsx! {
+        div { class: "flex flex-row flex-wrap justify-center items-center gap-3 my-4",
+            span { class: "text-gray-200 font-semibold select-none",
+                match selection.line {
+                    ClueLine::Row(row) => t!("clue_edit_row", line : row + 1),
+                    ClueLine::Column(col) => t!("clue_edit_column", line : col + 1),
+                }
+            }
+            NumberInput {
+                id: "clue-length-input",
+                label: t!("label_clue_length"),
+                value: use_length(),
+                min: 1,
+                max: max_length.max(1),
+                onchange: move |length: usize| use_length.set(length),
+            }
+            div { class: "flex flex-row flex-wrap justify-center items-center gap-1",
+                for color in 1..use_palette().len() {
+                    button {
+                        key: "clue-color-{color}",
+                        class: "w-6 h-6 rounded border-2",
+                        style: "background-color: {use_palette().get(color)}",
+                        border_color: if use_color() == color { "red" } else { "#6b7280" },
+                        onclick: move |_| use_color.set(color),
+                    }
+                }
+            }
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                onclick: move |_| {
+                    edit(
+                        Some(NonogramSegment {
+                            color: use_color(),
+                            length: use_length(),
+                        }),
+                    )
+                },
+                if selection.new {
+                    {t!("button_add_clue")}
+                } else {
+                    {t!("button_apply_clue")}
+                }
+            }
+            if !selection.new {
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-red-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: move |_| edit(None),
+                    {t!("button_remove_clue")}
+                }
+            }
+            button {
+                class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                onclick: move |_| use_selection.set(None),
+                {t!("button_cancel")}
+            }
+            if use_error() {
+                p { class: "w-full text-center text-red-400", {t!("clue_edit_too_long")} }
+            }
+        }
+    }
+}
+
 /// Displays the interactive Nonogram solution grid with functionality for drawing and modifying cells.
 ///
 /// The `Solution` component provides a grid interface for solving the Nonogram puzzle.
-/// Users can click, drag, and modify cells using different brushes and color inputs.
-/// It supports shift and control modifications for more advanced interactions.
+/// Users can tap, drag, and modify cells with a mouse, a finger or a stylus, using different
+/// brushes and color inputs. It supports shift and control modifications for more advanced
+/// interactions, and the interaction settings (what a tap does, stylus-only drawing and pressure).
+/// The pointer events are handled once for the whole grid and captured while pressed: the cell
+/// is found from the pointer position, and the gestures are tracked by a `GridPointer`.
+///
+/// The grid itself is only rendered again when it's resized: each `SolutionCell` subscribes to
+/// its own slice of the solution, so a paint only renders the cells it changes.
+///
+/// # Arguments:
+/// - `auto_cross`: Whether the background cells of the lines satisfying their clues are crossed out.
 ///
 /// # Contexts:
 /// - `Signal<usize>`: The current score of the solution.
 /// - `Signal<NonogramPuzzle>`: Provides the puzzle structure.
 /// - `Signal<NonogramSolution>`: Contains the current solution state.
-/// - `Signal<NonogramPalette>`: Defines the color palette used.
-/// - `Signal<NonogramData>`: Contains additional data for block sizes and border colors.
+/// - `Signal<ActionLog>`: Records the modifications of the solution grid.
+/// - `Signal<NonogramPalette>`: The brush painting the cells.
+/// - `Signal<NonogramData>`: Whether the puzzle is completed, disabling the grid.
+/// - `Signal<Settings>`: Configures the pointer interaction (optional).
+/// - `Signal<PinnedCells>`: The cells pinned and unpinned with Alt+click (optional, Solver only).
 #[component]
-fn Solution() -> Element {
+fn Solution(#[props(default)] auto_cross: bool) -> Element {
     let mut use_score = use_context::<Signal<usize>>();
     let use_puzzle = use_context::<Signal<NonogramPuzzle>>();
-    let mut use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let dispatcher = use_dispatcher();
     let use_palette = use_context::<Signal<NonogramPalette>>();
     let use_data = use_context::<Signal<NonogramData>>();
-    let solution_grid = use_solution().solution_grid.clone();
-    let mut use_start = use_signal(|| None);
-    let mut use_end = use_signal(|| None);
-    let mut current_hover = use_signal(|| None);
+    let use_pinned = try_use_context::<Signal<PinnedCells>>();
+    let interaction = try_use_context::<Signal<Settings>>()
+        .map(|use_settings| use_settings().interaction)
+        .unwrap_or_default();
+    // The size of the grid, which is only rendered again when it's resized.
+    let size = use_memo(move || {
+        let solution = use_solution.read();
+        (
+            solution.solution_grid.len(),
+            solution.solution_grid.first().map_or(0, Vec::len),
+        )
+    });
+    let (rows, cols) = size();
+    let crossed = use_memo(use_reactive!(|auto_cross| {
+        if auto_cross {
+            crossed_cells(&use_puzzle(), &use_solution())
+        } else {
+            Vec::new()
+        }
+    }));
+    let mut use_mounted = use_signal(|| None::<Rc<MountedData>>);
+    let mut use_bounds = use_signal(|| None::<PixelsRect>);
+    let mut use_pointer = use_signal(GridPointer::default);
+    // Measures the grid again, since it moves when the page scrolls or is resized.
+    let measure = move || async move {
+        if let Some(mounted) = use_mounted() {
+            use_bounds.set(mounted.get_client_rect().await.ok());
+        }
+    };
+    // Converts the pointer position to the cell under it, using the last bounds measured.
+    let cell_at = move |event: &PointerEvent| {
+        let bounds = use_bounds()?;
+        let point = event.client_coordinates();
+        GridPointer::cell_at(
+            (point.x - bounds.origin.x, point.y - bounds.origin.y),
+            (bounds.size.width, bounds.size.height),
+            rows,
+            cols,
+        )
+    };
+    // Moves the pointer to a cell, writing the signal only when the pointer state changes.
+    let mut enter = move |cell: (usize, usize)| {
+        let mut pointer = *use_pointer.peek();
+        let command = pointer.enter(cell);
+        if pointer != *use_pointer.peek() {
+            use_pointer.set(pointer);
+        }
+        if let Some(command) = command {
+            info!("Changed cell ({}, {})", cell.0 + 1, cell.1 + 1);
+            dispatcher.dispatch(command);
+        }
+    };
     use_effect(move || {
         *use_score.write() = use_puzzle().score(&use_solution());
     });
@@ -1254,66 +8954,75 @@ fn Solution() -> Element {
             draggable: false,
             pointer_events: if use_data().completed { "none" },
             tbody {
-                for (i , row_data) in solution_grid.iter().enumerate() {
-                    tr {
-                        for (j , cell) in row_data.iter().enumerate() {
-                            // TODO!: FIX mouse over for mobile
-                            td {
+                id: "solution-grid",
+                // Touch gestures draw instead of scrolling, unless only a stylus draws.
+                style: if interaction.stylus_only { "touch-action: pan-x pan-y;" } else { "touch-action: none;" },
+                onmounted: move |event: MountedEvent| use_mounted.set(Some(event.data())),
+                onpointerenter: move |_| async move { measure().await },
+                onpointerdown: move |event: PointerEvent| async move {
+                    let pointer_type = event.pointer_type();
+                    if !interaction.accepts(&pointer_type)
+                        || event.trigger_button().is_some_and(|button| button != MouseButton::Primary)
+                    {
+                        return;
+                    }
+                    measure().await;
+                    let Some(cell) = cell_at(&event) else {
+                        return;
+                    };
+                    // Alt+click pins the cell as certain, or unpins it, instead of painting it.
+                    if let Some(mut use_pinned) = use_pinned.filter(|_| event.modifiers().alt()) {
+                        let mut pinned = use_pinned.write();
+                        if !pinned.cells.remove(&cell) {
+                            pinned.cells.insert(cell);
+                        }
+                        info!("Toggled the pin of ({}, {})", cell.0 + 1, cell.1 + 1);
+                        return;
+                    }
+                    // Keeps receiving the events of the pointer after it leaves the grid, so
+                    // touch and stylus strokes can cross every cell.
+                    document::eval(
+                        &format!(
+                            "try {{ document.getElementById('solution-grid').setPointerCapture({}); }} catch {{}}",
+                            event.pointer_id(),
+                        ),
+                    );
+                    let color = interaction.color(&use_palette(), &pointer_type, event.pressure());
+                    let freehand = event.modifiers().shift() || event.modifiers().ctrl();
+                    info!("Init press on ({}, {})", cell.0 + 1, cell.1 + 1);
+                    if let Some(command) = use_pointer.write().press(cell, color, freehand) {
+                        dispatcher.dispatch(command);
+                    }
+                },
+                onpointermove: move |event: PointerEvent| {
+                    if interaction.accepts(&event.pointer_type()) {
+                        if let Some(cell) = cell_at(&event) {
+                            enter(cell);
+                        }
+                    }
+                },
+                onpointerup: move |_| {
+                    let command = use_pointer.write().release(interaction.tap, &use_solution());
+                    if let Some(command) = command {
+                        info!("Exit press with {:?}", command);
+                        dispatcher.dispatch(command);
+                    }
+                },
+                onpointercancel: move |_| use_pointer.write().cancel(),
+                onpointerleave: move |_| {
+                    if use_pointer.peek().stroke.is_none() {
+                        use_pointer.write().cancel();
+                    }
+                },
+                for i in 0..rows {
+                    tr { key: "row-{i}",
+                        for j in 0..cols {
+                            SolutionCell {
                                 key: "cell-{i}-{j}",
-                                class: "border select-none cursor-pointer border-gray-400",
-                                style: "background-color: {use_palette().color_palette[*cell]}; min-width: {use_data().block_size}px; height: {use_data().block_size}px;",
-                                border_color: if use_solution().in_line(use_start(), use_end(), (i, j))
-    || current_hover() == Some((i, j)) { String::from("red") } else { use_palette().border_color(*cell) },
-                                border_width: if use_solution().in_line(use_start(), use_end(), (i, j))
-    || current_hover() == Some((i, j)) { "3px" } else { "1px" },
-                                onmousedown: move |event| {
-                                    if event.modifiers().shift() || event.modifiers().ctrl() {
-                                        let color = use_palette().brush;
-                                        info!(
-                                            "Changed cell ({}, {}) with color {}", i + 1, j + 1, use_palette()
-                                            .show_brush()
-                                        );
-                                        use_solution.write().solution_grid[i][j] = color;
-                                    } else {
-                                        info!("Init press on ({}, {})", i + 1, j + 1);
-                                        *use_start.write() = Some((i, j));
-                                        *use_end.write() = Some((i, j));
-                                    }
-                                },
-                                onmouseover: move |event| {
-                                    if event.held_buttons().contains(MouseButton::Primary) {
-                                        *current_hover.write() = None;
-                                        info!("Entered press on ({}, {})", i + 1, j + 1);
-                                        if event.modifiers().shift() || event.modifiers().ctrl() {
-                                            let color = use_palette().brush;
-                                            info!(
-                                                "Changed cell ({}, {}) with color {}", i + 1, j + 1, use_palette()
-                                                .show_brush()
-                                            );
-                                            use_solution.write().solution_grid[i][j] = color;
-                                        } else if use_start().is_some() {
-                                            *use_end.write() = Some((i, j));
-                                        }
-                                    } else {
-                                        *current_hover.write() = Some((i, j));
-                                        *use_start.write() = None;
-                                        *use_end.write() = None;
-                                    }
-                                },
-                                onmouseleave: move |_| {
-                                    *current_hover.write() = None;
-                                },
-                                onmouseup: move |_| {
-                                    if use_start().is_some() {
-                                        info!("Exit press on ({}, {})", i + 1, j + 1);
-                                        let color = use_palette().brush;
-                                        let start = use_start().unwrap();
-                                        use_solution.write().draw_line(start, (i, j), color);
-                                        *current_hover.write() = None;
-                                        *use_start.write() = None;
-                                        *use_end.write() = None;
-                                    }
-                                },
+                                row: i,
+                                col: j,
+                                crossed,
+                                pointer: use_pointer,
                             }
                         }
                     }
@@ -1323,6 +9032,172 @@ fn Solution() -> Element {
     }
 }
 
+/// A cell of the solution grid, subscribed to its own slice of the state.
+///
+/// The color, the cross, the highlight and the heat of the cell are memoized from the whole
+/// grid, so the cell is only rendered again when one of them changes, and not with every paint.
+///
+/// # Arguments:
+/// - `row`: The row of the cell.
+/// - `col`: The column of the cell.
+/// - `crossed`: The cells crossed out in the grid.
+/// - `pointer`: The pointer over the grid, highlighting the hovered cell and the line drawn.
+///
+/// # Contexts:
+/// - `Signal<NonogramSolution>`: The color of the cell.
+/// - `Signal<NonogramPalette>`: Defines the color palette used.
+/// - `Signal<NonogramData>`: Contains additional data for block sizes and border colors.
+/// - `Signal<Layers>`: The visibility of each color layer (optional, every layer is visible by default).
+/// - `Signal<ReferenceImage>`: Makes the cells translucent over the reference image (optional).
+/// - `Signal<Option<Heatmap>>`: Highlights some cells with a heat overlay (optional).
+/// - `Signal<Settings>`: Renders the background cells with a checkerboard (optional).
+/// - `Signal<PinnedCells>`: Marks the cell when it's pinned (optional).
+#[component]
+fn SolutionCell(
+    row: usize,
+    col: usize,
+    crossed: Memo<Vec<Vec<bool>>>,
+    pointer: Signal<GridPointer>,
+) -> Element {
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let use_heatmap = try_use_context::<Signal<Option<Heatmap>>>();
+    let cell = use_memo(move || {
+        use_solution
+            .read()
+            .solution_grid
+            .get(row)
+            .and_then(|cells| cells.get(col))
+            .copied()
+            .unwrap_or(BACKGROUND)
+    });
+    let is_crossed =
+        use_memo(move || crossed.read().get(row).and_then(|cells| cells.get(col)) == Some(&true));
+    let highlighted = use_memo(move || pointer.read().highlights(&use_solution.read(), (row, col)));
+    let use_pinned = try_use_context::<Signal<PinnedCells>>();
+    let pinned = use_memo(move || {
+        use_pinned.is_some_and(|use_pinned| use_pinned.read().cells.contains(&(row, col)))
+    });
+    let heat = use_memo(move || {
+        use_heatmap
+            .and_then(|use_heatmap| {
+                use_heatmap
+                    .read()
+                    .as_ref()
+                    .map(|heatmap| heatmap.cell_style(row, col))
+            })
+            .unwrap_or_default()
+    });
+    let layers = try_use_context::<Signal<Layers>>()
+        .map(|use_layers| use_layers())
+        .unwrap_or_default();
+    let cell_alpha = try_use_context::<Signal<ReferenceImage>>()
+        .and_then(|use_reference| use_reference().cell_alpha())
+        .unwrap_or_default();
+    let checkerboard = try_use_context::<Signal<Settings>>()
+        .is_some_and(|use_settings| use_settings().checkerboard);
+    let displayed = layers.displayed(cell());
+    // A 2x2 checkerboard inside each background cell, over its color.
+    let background_pattern = if checkerboard && displayed == BACKGROUND {
+        "background-image: conic-gradient(rgba(0, 0, 0, 0.12) 25%, transparent 0 50%, rgba(0, 0, 0, 0.12) 0 75%, transparent 0); background-size: 50% 50%;"
+    } else {
+        ""
+    };
+    let block_size = use_data().block_size;
+    rsx! {
+        td {
+            class: "border select-none cursor-pointer border-gray-400 text-center text-gray-500 leading-none",
+            style: "background-color: {use_palette().color_palette[displayed]}{cell_alpha}; min-width: {block_size}px; height: {block_size}px; {background_pattern} {heat}",
+            border_color: if highlighted() { String::from("red") } else { use_palette().border_color(displayed) },
+            border_width: if highlighted() { "3px" } else { "1px" },
+            if is_crossed() {
+                "×"
+            } else if pinned() {
+                span { title: t!("title_pinned_cell"), "•" }
+            }
+        }
+    }
+}
+
+#[cfg(any(target_os = "android", feature = "web"))]
+/// Displays nothing on web and mobile platforms, where the `image` crate isn't available.
+#[component]
+fn ReplayExportPanel() -> Element {
+    rsx! {}
+}
+
+#[cfg(not(any(target_os = "android", feature = "web")))]
+/// A panel exporting the recorded actions as an animated GIF replaying the solve.
+///
+/// Each modification of the solution grid becomes a frame, rendered with the chosen cell size
+/// and played at the chosen frame rate. The GIF is saved next to the Nonogram files.
+///
+/// # Context:
+/// - `Signal<ActionLog>`: The recorded modifications of the solution grid.
+/// - `Signal<NonogramSolution>`: The current solution grid, the last frame of the replay.
+/// - `Signal<NonogramPalette>`: The colors of the cells.
+/// - `Signal<NonogramData>`: The filename, used for the GIF.
+#[component]
+fn ReplayExportPanel() -> Element {
+    use crate::nonogram::replay::{encode_gif, ReplayOptions};
+
+    let use_log = use_context::<Signal<ActionLog>>();
+    let use_solution = use_context::<Signal<NonogramSolution>>();
+    let use_palette = use_context::<Signal<NonogramPalette>>();
+    let use_data = use_context::<Signal<NonogramData>>();
+    let mut use_options = use_signal(ReplayOptions::default);
+    let export_onclick = move |_| {
+        let frames = use_log.peek().frames(&use_solution());
+        info!("Encoding a replay of {} frames...", frames.len());
+        match encode_gif(&frames, &use_palette(), use_options()) {
+            Ok(bytes) => {
+                let filename = use_data().filename;
+                let filename = match ngram_stem(&filename) {
+                    "" => "nonogram",
+                    name => name,
+                };
+                let filename = format!("{filename}.gif");
+                match std::fs::write(&filename, bytes) {
+                    Ok(()) => info!("Replay saved to {filename}"),
+                    Err(err) => error!("Couldn't write the replay '{filename}': {err}"),
+                }
+            }
+            Err(err) => error!("Couldn't encode the replay: {err}"),
+        }
+    };
+    rsx! {
+        details { class: "flex flex-col justify-items-center justify-center items-center gap-3",
+            summary { class: "py-2 text-gray-200 font-semibold cursor-pointer select-none text-center",
+                {t!("title_replay_export")}
+            }
+            div { class: "flex flex-row flex-wrap justify-items-center justify-center items-center gap-6 py-2",
+                NumberInput {
+                    id: "replay-rate-input",
+                    label: t!("label_frame_rate"),
+                    value: use_options().frame_rate as usize,
+                    min: 1,
+                    max: 60,
+                    onchange: move |rate: usize| use_options.write().frame_rate = rate as u32,
+                }
+                NumberInput {
+                    id: "replay-cell-input",
+                    label: t!("label_cell_size"),
+                    value: use_options().cell_size as usize,
+                    min: 2,
+                    max: 50,
+                    onchange: move |size: usize| use_options.write().cell_size = size as u32,
+                }
+                button {
+                    class: "px-4 py-1 font-bold rounded border border-gray-500 bg-gray-800 text-white hover:bg-blue-800 hover:scale-110 active:scale-125 transition-transform transform",
+                    onclick: export_onclick,
+                    {t!("button_export_replay")}
+                }
+            }
+        }
+    }
+}
+
 #[cfg(any(target_os = "android", feature = "web"))]
 /// Displays nothing on web and mobile platforms due to plotters dependencies conflicts.
 #[component]
@@ -1334,6 +9209,7 @@ fn ConvergeGraphic() -> Element {
 /// Generates a convergence graph of Nonogram solving progress for non-web platforms.
 ///
 /// This version generates a PNG image of the convergence graph and provides it as a base64-encoded data URI for display.
+/// The labels of the axes are formatted in the language of the interface.
 #[component]
 fn ConvergeGraphic() -> Element {
     use base64::prelude::*;
@@ -1344,6 +9220,7 @@ fn ConvergeGraphic() -> Element {
     const GRAPH_WIDTH: u32 = 600;
     const GRAPH_HEIGHT: u32 = 400;
     let use_history = use_context::<Signal<History>>();
+    let locale = use_locale_format();
     let buf_size = (GRAPH_WIDTH * GRAPH_HEIGHT) as usize * 3;
     let mut buf = vec![0u8; buf_size];
     let root = BitMapBackend::with_buffer(buf.as_mut_slice(), (GRAPH_WIDTH, GRAPH_HEIGHT))
@@ -1371,6 +9248,8 @@ fn ConvergeGraphic() -> Element {
         .configure_mesh()
         .x_label_style(("sans-serif", 20).into_font())
         .y_label_style(("sans-serif", 20).into_font())
+        .x_label_formatter(&|iteration| locale.count(*iteration))
+        .y_label_formatter(&|score| locale.decimal(*score, 0))
         .x_desc(t!("iterations"))
         .y_desc(t!("score"))
         .draw()?;
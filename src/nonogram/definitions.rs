@@ -20,12 +20,17 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+/// The lines of a puzzle whose clues are edited.
+use super::clues::ClueLine;
 /// Macro for defining palettes used in Nonogram puzzles.
 use crate::define_palette;
 
 /// Serialization and deserialization support for Nonogram data structures.
 use serde::{Deserialize, Serialize};
 
+/// Sets of color indexes, used for the hidden layers and the selected palette colors.
+use std::collections::{BTreeSet, HashSet};
+
 /// Utilities for formatting and displaying Nonogram-related types.
 use std::fmt;
 
@@ -89,11 +94,35 @@ pub struct NonogramPuzzle {
     pub col_constraints: Vec<Vec<NonogramSegment>>,
 }
 
+/// Which lines of a candidate solution are compared against the clues when scoring it.
+///
+/// The chromosomes of the genetic algorithm satisfy the clues of their rows, so scoring only the
+/// columns is enough; scoring both dimensions also penalizes the candidates breaking their rows,
+/// such as the ones produced by operators not preserving the rows.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Fitness {
+    /// Only the columns are scored.
+    #[default]
+    Columns,
+    /// Both the rows and the columns are scored.
+    Symmetric,
+}
+
+impl fmt::Display for Fitness {
+    /// Formats the fitness for logs and comparisons, such as `symmetric`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fitness::Columns => write!(f, "columns"),
+            Fitness::Symmetric => write!(f, "symmetric"),
+        }
+    }
+}
+
 /// Represents the solution to a Nonogram puzzle.
 ///
 /// The solution is stored as a grid of color indices, where each index corresponds
 /// to an entry in the palette.
-#[derive(Deserialize, Serialize, Clone, Debug)]
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
 pub struct NonogramSolution {
     /// The solution grid, where each cell contains a color index.
     pub solution_grid: Vec<Vec<usize>>,
@@ -113,6 +142,178 @@ impl fmt::Display for NonogramSolution {
     }
 }
 
+/// A rectangular area of a solution grid, delimited by two opposite corners (inclusive).
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct Area {
+    /// The coordinate `(row, column)` of one corner of the area.
+    pub start: (usize, usize),
+    /// The coordinate `(row, column)` of the opposite corner of the area.
+    pub end: (usize, usize),
+}
+
+/// The comparison of a cell between two solution grids (see `NonogramSolution::diff`).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CellDiff {
+    /// Both grids have the same color.
+    Match(usize),
+    /// The reference grid has a color where the compared grid has the background.
+    Missing(usize),
+    /// The compared grid has a color where the reference grid has the background.
+    Extra(usize),
+    /// Both grids have different colors, none of them the background.
+    Mismatch {
+        /// The color of the reference grid.
+        expected: usize,
+        /// The color of the compared grid.
+        found: usize,
+    },
+}
+
+/// The number of matching, missing and extra cells of a color between two solution grids.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct DiffCounts {
+    /// The cells with the color in both grids.
+    pub matching: usize,
+    /// The cells with the color in the reference grid, but not in the compared grid.
+    pub missing: usize,
+    /// The cells with the color in the compared grid, but not in the reference grid.
+    pub extra: usize,
+}
+
+/// The differences between two solution grids, cell by cell and per color.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SolutionDiff {
+    /// The comparison of each cell, covering the dimensions of both grids.
+    pub cells: Vec<Vec<CellDiff>>,
+    /// The counts of each color index.
+    pub counts: Vec<DiffCounts>,
+}
+
+/// How the drawing of a solution grid is adapted when the grid is resized.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum ResizeMode {
+    /// Crops the drawing or pads it with the background color.
+    #[default]
+    Crop,
+    /// Rescales the drawing, taking the color of the nearest cell of the original grid.
+    Nearest,
+    /// Rescales the drawing, taking the most common color of the covered cells of the original grid.
+    Majority,
+}
+
+/// The visibility of the color layers of a solution grid in the editor.
+///
+/// Hidden colors are rendered as the background, but preserved in the solution grid.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Layers {
+    /// The color indexes of the hidden layers.
+    pub hidden: HashSet<usize>,
+    /// The color index of the only visible layer (besides the background), if any.
+    pub solo: Option<usize>,
+}
+
+/// A reference image displayed beneath the editor grid, so artwork can be traced manually.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ReferenceImage {
+    /// The data URI of the image, `None` if no image is loaded.
+    pub source: Option<String>,
+    /// How visible the image is through the grid cells, from `0.0` (hidden) to `1.0`.
+    pub opacity: f32,
+    /// The offset `(x, y)` of the image in pixels, relative to the grid.
+    pub offset: (i32, i32),
+}
+
+/// The fullscreen mode, where the header and toolbars are hidden to maximize the grid space.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct FullscreenMode {
+    /// Whether the fullscreen mode is enabled.
+    pub enabled: bool,
+}
+
+/// The Editor's dialog creating a new puzzle, opened when the Editor starts.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct NewPuzzleDialog {
+    /// Whether the dialog is open.
+    pub open: bool,
+}
+
+/// The Solver's unlock of the solve buttons and hints in the strict mode, reset when a puzzle is
+/// loaded.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct StrictUnlock {
+    /// Whether the solve buttons and hints are unlocked for the puzzle being solved.
+    pub unlocked: bool,
+    /// Whether the confirmation of the unlock is open.
+    pub confirming: bool,
+}
+
+/// The Solver's cells marked as certain by the user, which the solver never changes, reset when a
+/// puzzle is loaded.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct PinnedCells {
+    /// The pinned cells, as (row, column).
+    pub cells: HashSet<(usize, usize)>,
+}
+
+/// The Editor's notes of the author, saved in the file of the puzzle.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct AuthorNotes {
+    /// The text of the notes, in the markdown-lite syntax of `notes::parse_notes`.
+    pub text: String,
+}
+
+/// A resize of the editor grid held for confirmation, because it would delete colored cells.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PendingResize {
+    /// The requested number of rows.
+    pub rows: usize,
+    /// The requested number of columns.
+    pub cols: usize,
+    /// The colored cells the resize would delete.
+    pub lost: usize,
+}
+
+/// A clue selected for inline editing, in a puzzle imported from its clues.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ClueSelection {
+    /// The line of the clue.
+    pub line: ClueLine,
+    /// The index of the clue in its line, or where the new clue is inserted.
+    pub index: usize,
+    /// Whether a new clue is inserted, selected from an empty slot of the clue table.
+    pub new: bool,
+}
+
+/// The Editor's check mode, where the clue headers are colored by how constrained their lines are.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct CheckMode {
+    /// Whether the check mode is enabled.
+    pub enabled: bool,
+}
+
+/// The Solver's population overlay, where the heat overlay shows the cells the population of the
+/// genetic algorithm still disagrees on, while it runs.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct PopulationOverlay {
+    /// Whether the population overlay is enabled.
+    pub enabled: bool,
+}
+
+/// The seed of the Solver's genetic algorithm, chosen in the toolbar.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct SolverSeed {
+    /// The seed of every run, so they're reproducible, or `None` to draw a new seed from the
+    /// session for each run.
+    pub fixed: Option<u64>,
+}
+
+/// A heat overlay displayed over the grid cells, highlighting some of them.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Heatmap {
+    /// The heat of each cell, from `0.0` (not highlighted) to `1.0`.
+    pub cells: Vec<Vec<f32>>,
+}
+
 /// Represents the contents of a Nonogram puzzle file.
 ///
 /// This struct stores the solution grid and the associated color palette.
@@ -125,6 +326,59 @@ pub struct NonogramFile {
     pub solution: NonogramSolution,
     /// The color palette associated with the puzzle, defining the colors used in the solution.
     pub palette: NonogramPalette,
+    /// The signature of the author, embedded to credit them when the puzzle is shared.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Signature>,
+    /// The notes of the author, such as the authoring intent or a hint for solving the puzzle.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub notes: String,
+}
+
+/// The signature of the author of a puzzle, stored in its file.
+///
+/// The signature isn't part of the puzzle: it doesn't change the clues, and the visible signature
+/// row is drawn below the grid, outside of the cells being solved.
+#[derive(Deserialize, Serialize, Clone, PartialEq, Debug)]
+pub struct Signature {
+    /// The identifier of the profile of the author.
+    pub author_id: String,
+    /// The name of the author.
+    pub author: String,
+    /// When the puzzle was signed, in seconds since the Unix epoch.
+    pub signed_at: i64,
+    /// Whether the signature is also drawn as a row of cells below the grid.
+    #[serde(default)]
+    pub visible: bool,
+}
+
+/// Represents the contents of a standalone palette file (`.ngrampal`), reusable in other puzzles.
+#[derive(Deserialize, Serialize, Clone)]
+pub struct NonogramPaletteFile {
+    /// The name of the palette.
+    #[serde(default)]
+    pub name: String,
+    /// The author of the palette.
+    #[serde(default)]
+    pub author: String,
+    /// The colors of the palette, the first one being the background.
+    pub palette: NonogramPalette,
+}
+
+/// How the colors of a loaded palette file are applied to the current palette.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum PaletteLoadMode {
+    /// Replaces the palette, remapping each color of the grid to the closest loaded color.
+    #[default]
+    Remap,
+    /// Appends the loaded colors missing in the palette.
+    Append,
+}
+
+/// The palette colors selected in the editor for bulk operations.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct PaletteSelection {
+    /// The indexes of the selected colors.
+    pub selected: BTreeSet<usize>,
 }
 
 /// Metadata and state for a Nonogram puzzle.
@@ -136,6 +390,13 @@ pub struct NonogramData {
     pub filename: String,
     /// The size of each block in pixels, for display purposes.
     pub block_size: usize,
+    /// Whether the block size follows the viewport and puzzle dimensions, until the user sets it.
+    pub auto_block_size: bool,
     /// Whether the puzzle has been completed.
     pub completed: bool,
+    /// Whether the puzzle was imported from clues, so its solution is unknown.
+    pub blind: bool,
+    /// Whether the solution of a blind puzzle was found by the exact solver and cached in the
+    /// library, so it can be previewed and compared (completion is still checked by the clues).
+    pub solution_cached: bool,
 }
@@ -21,12 +21,41 @@
 // SOFTWARE.
 
 // Import necessary definitions
-use super::definitions::{NonogramPuzzle, NonogramSolution};
+use super::definitions::{Fitness, NonogramPuzzle, NonogramSolution};
+
+// Import the line solver, whose deductions refine the offspring, and the slack of the lines,
+// which picks the representation of the chromosomes
+use super::line::CellState;
+use super::logic::{initial_states, propagate, puzzle_slack, LineSlack};
 
 // Import logging and random number generation utilities
 use dioxus::logger::tracing::info;
 use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
 
+// Import formatting traits for displaying the replacement modes
+use std::fmt;
+
+// Import the set of cells pinned by the user
+use std::collections::HashSet;
+
+// Import the parallel iterators scoring and breeding the individuals on every core
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+// Import the clock measuring the running time of the searches, on platforms with a system clock
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Import the clock of the browser, measuring the running time of the searches in WebAssembly
+#[cfg(target_arch = "wasm32")]
+use web_sys::js_sys::Date;
+
+// Import the shared flag of the cancellation tokens, checked from the search thread
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
 /// Type alias for a new population, where each element is a `NonogramSolution`.
 type NewPopulation = Vec<NonogramSolution>;
 
@@ -36,25 +65,651 @@ type Population = Vec<(NonogramSolution, usize)>;
 
 // Constants for genetic algorithm
 /// Defines the population size for the genetic algorithm.
-const POPULATION_SIZE: usize = 500;
+pub const POPULATION_SIZE: usize = 500;
 
 /// Defines the probability of crossover between individuals.
-const CROSS_PROBABILITY: f64 = 0.6;
+pub const CROSS_PROBABILITY: f64 = 0.6;
 
 /// Defines the probability of mutation in the population.
-const MUTATION_PROBABILITY: f64 = 0.1;
+pub const MUTATION_PROBABILITY: f64 = 0.1;
 
 /// Defines the tournament size used for selection.
-const TOURNAMENT_SIZE: usize = 3;
+pub const TOURNAMENT_SIZE: usize = 3;
 
 /// Defines the maximum number of iterations for the genetic algorithm.
-const MAX_ITERATIONS: usize = 300;
+pub const MAX_ITERATIONS: usize = 300;
 
 /// Defines the number of tries for sliding window mutations.
-const SLIDE_TRIES: usize = 3;
+pub const SLIDE_TRIES: usize = 3;
 
 /// Defines the seed value for random number generation.
-const SEED: u64 = 23;
+pub const SEED: u64 = 23;
+
+/// Defines the fraction of the initial population seeded from the grid painted by the user, when
+/// the search is warm-started.
+pub const WARM_START_FRACTION: f64 = 0.25;
+
+/// A token cancelling a running search, shared between the search and whoever stops it.
+///
+/// The search checks the token before each generation, so it stops within one generation and
+/// returns its best approach so far.
+#[derive(Clone, Default, Debug)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    /// Requests the search to stop.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Checks if the search was requested to stop.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// How the next generation is chosen from the current population and its offspring.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Replacement {
+    /// (μ+λ) selection: parents and offspring compete together, and the best individuals survive.
+    #[default]
+    Plus,
+    /// Generational replacement: `population_size - elitism` offspring replace the population,
+    /// except for the `elitism` best parents, which survive unchanged.
+    Generational { elitism: usize },
+    /// Steady-state replacement: only `replaced` offspring are bred each generation, replacing
+    /// the worst parents.
+    SteadyState { replaced: usize },
+    /// NSGA-II selection: parents and offspring compete together on two objectives, the
+    /// violations of the rows and of the columns, and survive by their Pareto front, then by how
+    /// isolated they are within it (see `pareto_population`). The mutation also repaints single
+    /// cells, breaking the clues of their rows, so the rows can trade their violations for the
+    /// columns', and both dimensions are scored whatever the fitness.
+    Pareto,
+}
+
+impl Replacement {
+    /// Returns the number of offspring bred each generation.
+    ///
+    /// # Arguments
+    ///
+    /// * `population_size` - The size of the population.
+    pub fn offspring_size(&self, population_size: usize) -> usize {
+        match *self {
+            Replacement::Plus | Replacement::Pareto => population_size,
+            Replacement::Generational { elitism } => population_size.saturating_sub(elitism),
+            Replacement::SteadyState { replaced } => replaced.min(population_size),
+        }
+    }
+
+    /// Returns the fitness minimized with this replacement: the NSGA-II mode breaks the rows, so
+    /// it scores both dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `fitness` - The fitness configured for the search.
+    pub fn fitness(&self, fitness: Fitness) -> Fitness {
+        match self {
+            Replacement::Pareto => Fitness::Symmetric,
+            _ => fitness,
+        }
+    }
+}
+
+impl fmt::Display for Replacement {
+    /// Formats the replacement mode for logs and comparisons, such as `generational (elitism 5)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Replacement::Plus => write!(f, "plus"),
+            Replacement::Generational { elitism } => {
+                write!(f, "generational (elitism {elitism})")
+            }
+            Replacement::SteadyState { replaced } => {
+                write!(f, "steady-state ({replaced} replaced)")
+            }
+            Replacement::Pareto => write!(f, "nsga-ii"),
+        }
+    }
+}
+
+/// The dimension of the grid whose lines make up the chromosomes.
+///
+/// The lines of the chromosomes always satisfy their clues, so only the lines of the other
+/// dimension are scored.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Representation {
+    /// Picks the dimension whose lines have the fewest arrangements (see `Representation::resolve`).
+    #[default]
+    Auto,
+    /// The chromosomes are made of rows, and the columns are scored.
+    Rows,
+    /// The chromosomes are made of columns, and the rows are scored.
+    Columns,
+}
+
+impl Representation {
+    /// Resolves the representation used for a puzzle.
+    ///
+    /// The automatic representation picks the dimension with the smallest search space: the one
+    /// whose lines, being fewer or denser, have the fewest arrangements altogether.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle to be solved.
+    ///
+    /// # Returns
+    ///
+    /// `Representation::Rows` or `Representation::Columns`.
+    pub fn resolve(&self, puzzle: &NonogramPuzzle) -> Representation {
+        match self {
+            Representation::Auto => {
+                let (rows, cols) = puzzle_slack(puzzle);
+                if search_space(&cols) < search_space(&rows) {
+                    Representation::Columns
+                } else {
+                    Representation::Rows
+                }
+            }
+            representation => *representation,
+        }
+    }
+}
+
+impl fmt::Display for Representation {
+    /// Formats the representation for logs and comparisons, such as `columns`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Representation::Auto => write!(f, "auto"),
+            Representation::Rows => write!(f, "rows"),
+            Representation::Columns => write!(f, "columns"),
+        }
+    }
+}
+
+/// A crossover operator recombining two ancestors (see `CrossoverWeights`).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Crossover {
+    /// Takes each row from either ancestor (see `NonogramPuzzle::uniform_cross`).
+    Uniform,
+    /// Swaps a slab of rows (see `NonogramPuzzle::two_point_cross`).
+    TwoPoint,
+    /// Swaps a slab of columns (see `NonogramPuzzle::column_two_point_cross`).
+    Column,
+    /// Swaps a rectangular block of cells (see `NonogramPuzzle::block_cross`).
+    Block,
+    /// Mixes the gaps between the segments of each row (see `NonogramPuzzle::segment_cross`).
+    Segment,
+}
+
+/// The relative weights choosing the crossover operator applied to each pair of ancestors.
+///
+/// The weights don't need to add up to one; an operator with a zero weight is never chosen. Only
+/// the uniform and the row-wise two-point crossovers are used by default, evenly, so the other
+/// operators must be opted into.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CrossoverWeights {
+    /// The weight of the uniform crossover.
+    pub uniform: f64,
+    /// The weight of the row-wise two-point crossover.
+    pub two_point: f64,
+    /// The weight of the column-wise two-point crossover.
+    pub column: f64,
+    /// The weight of the block crossover.
+    pub block: f64,
+    /// The weight of the segment-level crossover.
+    pub segment: f64,
+}
+
+impl Default for CrossoverWeights {
+    fn default() -> Self {
+        Self {
+            uniform: 1.0,
+            two_point: 1.0,
+            column: 0.0,
+            block: 0.0,
+            segment: 0.0,
+        }
+    }
+}
+
+impl fmt::Display for CrossoverWeights {
+    /// Formats the weights for logs and comparisons, such as `uniform 1, two-point 1, column 0,
+    /// block 0, segment 0`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "uniform {}, two-point {}, column {}, block {}, segment {}",
+            self.uniform, self.two_point, self.column, self.block, self.segment
+        )
+    }
+}
+
+impl CrossoverWeights {
+    /// Chooses a crossover operator with a probability proportional to its weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - A mutable reference to a `StdRng` used to choose the operator.
+    ///
+    /// # Returns
+    ///
+    /// The chosen `Crossover`, or `Crossover::Uniform` when every weight is zero.
+    pub fn choose(&self, rng: &mut StdRng) -> Crossover {
+        let operators = [
+            (Crossover::Uniform, self.uniform),
+            (Crossover::TwoPoint, self.two_point),
+            (Crossover::Column, self.column),
+            (Crossover::Block, self.block),
+            (Crossover::Segment, self.segment),
+        ];
+        choose_weighted(&operators, rng).unwrap_or(Crossover::Uniform)
+    }
+}
+
+/// How the rows conflicting the most with the column constraints are repaired after crossover.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Repair {
+    /// The rows are regenerated at random (see `NonogramPuzzle::repair_chromosome`).
+    #[default]
+    Randomize,
+    /// The segments of the rows are greedily shifted while the columns improve (see
+    /// `NonogramPuzzle::column_repair_chromosome`), usually converging in fewer generations.
+    Shift,
+}
+
+impl fmt::Display for Repair {
+    /// Formats the repair for logs and comparisons, such as `shift`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Repair::Randomize => write!(f, "randomize"),
+            Repair::Shift => write!(f, "shift"),
+        }
+    }
+}
+
+/// A mutation operator altering an offspring (see `MutationWeights`).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Mutation {
+    /// Slides segments by swapping cells (see `NonogramPuzzle::chromosome_mutation`).
+    Slide,
+    /// Moves a whole segment within its row (see `NonogramPuzzle::segment_reposition_mutation`).
+    Reposition,
+    /// Regenerates whole rows (see `NonogramPuzzle::row_reshuffle_mutation`).
+    Reshuffle,
+}
+
+/// The relative weights choosing the mutation operator applied to each offspring.
+///
+/// The weights don't need to add up to one; an operator with a zero weight is never chosen. Only
+/// the slide mutation is used by default, as in the original solver: the segment reposition must
+/// be opted into, and the row reshuffle discards what the row had evolved.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct MutationWeights {
+    /// The weight of the slide mutation.
+    pub slide: f64,
+    /// The weight of the segment reposition.
+    pub reposition: f64,
+    /// The weight of the row reshuffle.
+    pub reshuffle: f64,
+}
+
+impl Default for MutationWeights {
+    fn default() -> Self {
+        Self {
+            slide: 1.0,
+            reposition: 0.0,
+            reshuffle: 0.0,
+        }
+    }
+}
+
+impl fmt::Display for MutationWeights {
+    /// Formats the weights for logs and comparisons, such as `slide 1, reposition 0, reshuffle 0`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "slide {}, reposition {}, reshuffle {}",
+            self.slide, self.reposition, self.reshuffle
+        )
+    }
+}
+
+impl MutationWeights {
+    /// Chooses a mutation operator with a probability proportional to its weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - A mutable reference to a `StdRng` used to choose the operator.
+    ///
+    /// # Returns
+    ///
+    /// The chosen `Mutation`, or `Mutation::Slide` when every weight is zero.
+    pub fn choose(&self, rng: &mut StdRng) -> Mutation {
+        let operators = [
+            (Mutation::Slide, self.slide),
+            (Mutation::Reposition, self.reposition),
+            (Mutation::Reshuffle, self.reshuffle),
+        ];
+        choose_weighted(&operators, rng).unwrap_or(Mutation::Slide)
+    }
+}
+
+/// Chooses an operator with a probability proportional to its weight, ignoring negative weights.
+///
+/// # Arguments
+///
+/// * `operators` - The operators and their weights.
+/// * `rng` - A mutable reference to a `StdRng` used to choose the operator.
+///
+/// # Returns
+///
+/// The chosen operator, or `None` when every weight is zero.
+fn choose_weighted<T: Copy>(operators: &[(T, f64)], rng: &mut StdRng) -> Option<T> {
+    let total: f64 = operators.iter().map(|&(_, weight)| weight.max(0.0)).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut point = rng.gen_range(0.0..total);
+    for &(operator, weight) in operators {
+        let weight = weight.max(0.0);
+        if point < weight {
+            return Some(operator);
+        }
+        point -= weight;
+    }
+    // Rounding can leave the point past the last weight
+    operators
+        .iter()
+        .rev()
+        .find(|&&(_, weight)| weight > 0.0)
+        .map(|&(operator, _)| operator)
+}
+
+/// Measures the search space of chromosomes made of some lines, as the bits needed to choose the
+/// arrangement of every line.
+fn search_space(lines: &[Option<LineSlack>]) -> f64 {
+    lines
+        .iter()
+        .flatten()
+        .map(|line| (line.arrangements as f64).log2())
+        .sum()
+}
+
+/// The criteria stopping a search before it finds a solution.
+///
+/// The search always stops once it finds a solution; these criteria stop long runs earlier, once
+/// they're good enough, too slow or stuck.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StopCriteria {
+    /// The maximum number of generations.
+    pub max_iterations: usize,
+    /// The best score good enough to stop the search, `0` to only stop on a solution.
+    pub target_score: usize,
+    /// The maximum running time of the search in milliseconds, or `None` for no limit.
+    pub max_millis: Option<u64>,
+    /// The generations without improving the best score before stopping, or `None` to never stop
+    /// on stagnation.
+    pub stagnation: Option<usize>,
+}
+
+impl Default for StopCriteria {
+    fn default() -> Self {
+        Self {
+            max_iterations: MAX_ITERATIONS,
+            target_score: 0,
+            max_millis: None,
+            stagnation: None,
+        }
+    }
+}
+
+impl StopCriteria {
+    /// Checks if a search must stop after a generation.
+    ///
+    /// # Arguments
+    ///
+    /// * `best` - The best score of the generation.
+    /// * `stagnation` - The generations since the best score last improved.
+    /// * `elapsed_millis` - The running time of the search, in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// The reason to stop the search, or `None` if it goes on.
+    pub fn reached(
+        &self,
+        best: usize,
+        stagnation: usize,
+        elapsed_millis: f64,
+    ) -> Option<StopReason> {
+        if best == 0 {
+            Some(StopReason::Solved)
+        } else if best <= self.target_score {
+            Some(StopReason::TargetScore)
+        } else if self
+            .stagnation
+            .is_some_and(|window| stagnation >= window.max(1))
+        {
+            Some(StopReason::Stagnation)
+        } else if self
+            .max_millis
+            .is_some_and(|max_millis| elapsed_millis >= max_millis as f64)
+        {
+            Some(StopReason::Timeout)
+        } else {
+            None
+        }
+    }
+}
+
+/// Why a search stopped by itself.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StopReason {
+    /// A solution was found.
+    Solved,
+    /// The best score reached the target score.
+    TargetScore,
+    /// The best score didn't improve for the stagnation window.
+    Stagnation,
+    /// The running time reached its limit.
+    Timeout,
+    /// The maximum number of generations was evolved.
+    MaxIterations,
+}
+
+impl fmt::Display for StopReason {
+    /// Formats the reason for logs and comparisons, such as `stagnation`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StopReason::Solved => write!(f, "solved"),
+            StopReason::TargetScore => write!(f, "target score"),
+            StopReason::Stagnation => write!(f, "stagnation"),
+            StopReason::Timeout => write!(f, "timeout"),
+            StopReason::MaxIterations => write!(f, "max iterations"),
+        }
+    }
+}
+
+/// Returns the current time in milliseconds, measuring the running time of the searches.
+fn now_millis() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    return Date::now();
+    #[cfg(not(target_arch = "wasm32"))]
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |elapsed| elapsed.as_secs_f64() * 1000.0)
+}
+
+/// The self-adaptive control of the crossover and mutation probabilities of the search.
+///
+/// When the best score stagnates for `patience` generations, both probabilities are raised by
+/// `boost` so the search explores more; every generation it improves, they decay back towards
+/// the configured probabilities.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Adaptation {
+    /// The generations without improving the best score before the probabilities are raised.
+    pub patience: usize,
+    /// The factor raising the probabilities while the best score stagnates.
+    pub boost: f64,
+    /// The fraction of the raise kept each generation the best score improves.
+    pub decay: f64,
+    /// The highest mutation probability reached.
+    pub max_mutation: f64,
+}
+
+impl Default for Adaptation {
+    fn default() -> Self {
+        Self {
+            patience: 10,
+            boost: 1.5,
+            decay: 0.8,
+            max_mutation: 0.5,
+        }
+    }
+}
+
+impl fmt::Display for Adaptation {
+    /// Formats the adaptation for logs and comparisons, such as `patience 10, boost 1.5, decay
+    /// 0.8, max 0.5`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "patience {}, boost {}, decay {}, max {}",
+            self.patience, self.boost, self.decay, self.max_mutation
+        )
+    }
+}
+
+impl Adaptation {
+    /// Returns the probabilities of crossover and mutation of the next generation.
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The configured probabilities of crossover and mutation.
+    /// * `current` - The probabilities of crossover and mutation of the last generation.
+    /// * `stagnation` - The generations since the best score last improved.
+    ///
+    /// # Returns
+    ///
+    /// The adapted probabilities of crossover and mutation.
+    pub fn adapt(&self, base: (f64, f64), current: (f64, f64), stagnation: usize) -> (f64, f64) {
+        let (cross, mutation) = current;
+        if stagnation == 0 {
+            let decay = |base: f64, rate: f64| base + (rate - base) * self.decay;
+            (decay(base.0, cross), decay(base.1, mutation))
+        } else if stagnation.is_multiple_of(self.patience.max(1)) {
+            (
+                (cross * self.boost).min(1.0),
+                (mutation * self.boost).min(self.max_mutation.max(base.1)),
+            )
+        } else {
+            current
+        }
+    }
+}
+
+/// The parameters of the evolutive search.
+///
+/// The defaults are the parameters used by the application.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SolverConfig {
+    /// The size of the population.
+    pub population_size: usize,
+    /// The probability of crossover between individuals.
+    pub cross_probability: f64,
+    /// The probability of mutation of each individual.
+    pub mutation_probability: f64,
+    /// The number of individuals competing in each tournament.
+    pub tournament_size: usize,
+    /// The number of tries for sliding window mutations.
+    pub slide_tries: usize,
+    /// The criteria stopping the search before it finds a solution.
+    pub stop: StopCriteria,
+    /// How the next generation is chosen from the population and its offspring.
+    pub replacement: Replacement,
+    /// The dimension of the grid whose lines make up the chromosomes.
+    pub representation: Representation,
+    /// The lines of the candidates compared against the clues when scoring them.
+    pub fitness: Fitness,
+    /// The relative weights of the crossover operators.
+    pub crossover: CrossoverWeights,
+    /// The relative weights of the mutation operators.
+    pub mutation: MutationWeights,
+    /// The maximum number of rows repaired in each child after crossover, `0` to disable the repair.
+    pub repair_rows: usize,
+    /// How the rows are repaired after crossover.
+    pub repair: Repair,
+    /// The maximum number of rows of each child re-sampled after mutation to agree with the cells
+    /// deduced by the line solver, `0` to disable this local search (making the search memetic).
+    pub local_search_rows: usize,
+    /// The self-adaptive control of the probabilities, or `None` to keep them fixed.
+    pub adaptation: Option<Adaptation>,
+    /// The crowding factor of the (μ+λ) selection, or `None` to disable the niching: each child
+    /// then replaces the most similar of this many individuals drawn at random, if it's not worse
+    /// (see `crowd_population`).
+    pub crowding: Option<usize>,
+}
+
+impl Default for SolverConfig {
+    fn default() -> Self {
+        Self {
+            population_size: POPULATION_SIZE,
+            cross_probability: CROSS_PROBABILITY,
+            mutation_probability: MUTATION_PROBABILITY,
+            tournament_size: TOURNAMENT_SIZE,
+            slide_tries: SLIDE_TRIES,
+            stop: StopCriteria::default(),
+            replacement: Replacement::default(),
+            representation: Representation::default(),
+            fitness: Fitness::default(),
+            crossover: CrossoverWeights::default(),
+            mutation: MutationWeights::default(),
+            repair_rows: 0,
+            repair: Repair::default(),
+            local_search_rows: 0,
+            adaptation: None,
+            crowding: None,
+        }
+    }
+}
+
+/// The grid painted by the user, or the population of a previous search, warm-starting the search.
+#[derive(Clone, PartialEq, Debug)]
+pub struct WarmStart {
+    /// The grid painted by the user.
+    pub grid: NonogramSolution,
+    /// The cells marked as certain by the user, as (row, column), whose colors in the grid are never
+    /// changed by the chromosome generation nor the genetic operators.
+    pub pinned: HashSet<(usize, usize)>,
+    /// The final population of a previous search, continued instead of generating a new one
+    /// around the grid (see `History::population`), or empty to start a new population.
+    pub population: Vec<NonogramSolution>,
+}
+
+impl WarmStart {
+    /// Computes the colors of the pinned cells, by row.
+    ///
+    /// # Returns
+    ///
+    /// The color of each pinned cell, `None` for the free cells, or no rows when no cell is
+    /// pinned.
+    pub fn pinned_colors(&self) -> Vec<Vec<Option<usize>>> {
+        if self.pinned.is_empty() {
+            return Vec::new();
+        }
+        self.grid
+            .solution_grid
+            .iter()
+            .enumerate()
+            .map(|(row, cells)| {
+                cells
+                    .iter()
+                    .enumerate()
+                    .map(|(col, &color)| self.pinned.contains(&(row, col)).then_some(color))
+                    .collect()
+            })
+            .collect()
+    }
+}
 
 /// Performs an Analysis of Variance (ANOVA) approach to optimize a Nonogram puzzle solution
 ///
@@ -107,16 +762,28 @@ pub fn anova(puzzle: NonogramPuzzle) {
                     );
 
                     // Perform evolutionary search with the given parameters
-                    let history = evolutive_search(
-                        ANOVA_POPULATION_SIZE,
-                        &puzzle,
+                    let config = SolverConfig {
+                        population_size: ANOVA_POPULATION_SIZE,
                         cross_probability,
                         mutation_probability,
-                        ANOVA_TOURNAMENT_SIZE,
+                        tournament_size: ANOVA_TOURNAMENT_SIZE,
                         slide_tries,
-                        ANOVA_MAX_ITERATIONS,
-                        &mut rng,
-                    );
+                        stop: StopCriteria {
+                            max_iterations: ANOVA_MAX_ITERATIONS,
+                            ..StopCriteria::default()
+                        },
+                        replacement: Replacement::default(),
+                        representation: Representation::default(),
+                        fitness: Fitness::default(),
+                        crossover: CrossoverWeights::default(),
+                        mutation: MutationWeights::default(),
+                        repair_rows: 0,
+                        repair: Repair::default(),
+                        local_search_rows: 0,
+                        adaptation: None,
+                        crowding: None,
+                    };
+                    let history = evolutive_search(&puzzle, &config, &mut rng);
 
                     info!("Obtained a score of: {}", history.best.last().unwrap());
 
@@ -157,19 +824,19 @@ pub fn anova(puzzle: NonogramPuzzle) {
 /// performs an evolutionary search to solve the provided `NonogramPuzzle`. The resulting
 /// solution or best score is logged, and the history of the solution process is returned.
 ///
+/// The search can continue from a grid painted by the user: part of the initial population is
+/// then seeded with variants of it, with its rows repaired to match their clues.
+///
 /// # Arguments
 ///
 /// * `puzzle` - A `NonogramPuzzle` instance that represents the puzzle to be solved.
+/// * `start` - The grid painted by the user, warm-starting the search and pinning some of its
+///   cells, or `None` to start from scratch.
 ///
 /// # Constants
 ///
 /// - `SEED`: The seed used to initialize the random number generator.
-/// - `POPULATION_SIZE`: The size of the population used in the genetic algorithm.
-/// - `CROSS_PROBABILITY`: The probability of crossover between individuals.
-/// - `MUTATION_PROBABILITY`: The probability of mutation applied to the population.
-/// - `TOURNAMENT_SIZE`: The size of the tournament used for selection in the genetic algorithm.
-/// - `SLIDE_TRIES`: Number of attempts for sliding window mutations.
-/// - `MAX_ITERATIONS`: The maximum number of iterations for the evolutionary search.
+/// - `SolverConfig::default()`: The default parameters of the genetic algorithm.
 ///
 /// # Returns
 ///
@@ -179,31 +846,168 @@ pub fn anova(puzzle: NonogramPuzzle) {
 ///
 /// ```rust
 /// let puzzle = NonogramPuzzle::new(...);
-/// let history = solve_nonogram(puzzle);
+/// let start = WarmStart { grid: painted, pinned, population: Vec::new() };
+/// let history = solve_nonogram(puzzle, Some(&start));
 /// ```
-pub fn solve_nonogram(puzzle: NonogramPuzzle) -> History {
-    let mut rng = StdRng::seed_from_u64(SEED);
-    let history = evolutive_search(
-        POPULATION_SIZE,
+pub fn solve_nonogram(puzzle: NonogramPuzzle, start: Option<&WarmStart>) -> History {
+    solve_nonogram_sampled(
+        puzzle,
+        &SolverConfig::default(),
+        SEED,
+        start,
+        0,
+        &mut |_| {},
+        &CancelToken::default(),
+    )
+}
+
+/// Solves a Nonogram puzzle like `solve_nonogram` with the given parameters and seed, sampling the
+/// population while it evolves.
+///
+/// # Arguments
+///
+/// * `puzzle` - A `NonogramPuzzle` instance that represents the puzzle to be solved.
+/// * `config` - The parameters of the genetic algorithm.
+/// * `seed` - The seed of the random number generator.
+/// * `start` - The grid painted by the user, warm-starting the search and pinning some of its
+///   cells, or `None` to start from scratch.
+/// * `sample_interval` - The generations between samples (`0` never samples).
+/// * `observer` - Receives the progress of each generation and the samples of the population,
+///   such as a channel to the UI.
+/// * `cancel` - Stops the search before it finishes.
+///
+/// # Returns
+///
+/// A `History` object containing the best solution or best scores from the evolutionary search.
+pub fn solve_nonogram_sampled(
+    puzzle: NonogramPuzzle,
+    config: &SolverConfig,
+    seed: u64,
+    start: Option<&WarmStart>,
+    sample_interval: usize,
+    observer: &mut dyn FnMut(SearchEvent),
+    cancel: &CancelToken,
+) -> History {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let history = evolutive_search_sampled(
         &puzzle,
-        CROSS_PROBABILITY,
-        MUTATION_PROBABILITY,
-        TOURNAMENT_SIZE,
-        SLIDE_TRIES,
-        MAX_ITERATIONS,
+        config,
+        start,
         &mut rng,
+        sample_interval,
+        observer,
+        cancel,
     );
     match &history.winner {
         Ok(winner) => info!("Nonogram Solution:\n{}", winner),
         Err(approach) => info!(
             "Best score: {}\nBest Solution:\n{}",
-            puzzle.score(approach),
+            puzzle.score_with(approach, config.fitness),
             approach
         ),
     }
     history
 }
 
+/// An event of a running search, streamed to an observer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SearchEvent {
+    /// The scores of a generation, reported after every generation.
+    Progress(Progress),
+    /// A sample of the population, taken every few generations.
+    Sample(PopulationSample),
+}
+
+/// The progress of a running search, reported after every generation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    /// The generation reported, starting at 1.
+    pub iteration: usize,
+    /// The maximum number of generations of the search.
+    pub max_iterations: usize,
+    /// The best score of the generation.
+    pub best: usize,
+    /// The median score of the generation.
+    pub median: f64,
+    /// The worst score of the generation.
+    pub worst: usize,
+    /// The mean Hamming distance between the individuals of the generation.
+    pub diversity: f64,
+}
+
+impl Progress {
+    /// Returns the fraction of the maximum number of generations evolved, from `0.0` to `1.0`.
+    pub fn fraction(&self) -> f64 {
+        (self.iteration as f64 / self.max_iterations.max(1) as f64).min(1.0)
+    }
+}
+
+/// A candidate of the Pareto front of a multi-objective search: no other individual has fewer
+/// violations in both its rows and its columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParetoCandidate {
+    /// The grid of the candidate.
+    pub solution: NonogramSolution,
+    /// The violations of the clues of the rows.
+    pub row_violations: usize,
+    /// The violations of the clues of the columns.
+    pub column_violations: usize,
+}
+
+/// A sample of the population taken while the search runs, streamed to an observer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PopulationSample {
+    /// The generation of the sample.
+    pub iteration: usize,
+    /// The best score of the population.
+    pub best: usize,
+    /// For each cell, the fraction of the population painting its most common color, from
+    /// `1 / population_size` (every individual disagrees) to `1.0` (the cell is settled).
+    pub agreement: Vec<Vec<f32>>,
+}
+
+impl PopulationSample {
+    /// Samples the agreement of a population on each cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `iteration` - The generation of the population.
+    /// * `population` - The population, sorted by score.
+    /// * `colors` - The number of colors of the puzzle, including the background.
+    fn new(iteration: usize, population: &Population, colors: usize) -> Self {
+        let (rows, cols) = population
+            .first()
+            .map_or((0, 0), |(solution, _)| (solution.rows(), solution.cols()));
+        let mut counts = vec![0usize; rows * cols * colors];
+        for (solution, _) in population {
+            for (i, row) in solution.solution_grid.iter().enumerate() {
+                for (j, &color) in row.iter().enumerate() {
+                    if color < colors {
+                        counts[(i * cols + j) * colors + color] += 1;
+                    }
+                }
+            }
+        }
+        let size = population.len().max(1) as f32;
+        let agreement = (0..rows)
+            .map(|i| {
+                (0..cols)
+                    .map(|j| {
+                        let cell = (i * cols + j) * colors;
+                        let mode = counts[cell..cell + colors].iter().max().copied();
+                        mode.unwrap_or(0) as f32 / size
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            iteration,
+            best: population.first().map_or(0, |(_, score)| *score),
+            agreement,
+        }
+    }
+}
+
 /// A struct representing the evolutionary search history.
 ///
 /// `History` tracks the progress of the genetic algorithm, including the number of iterations,
@@ -216,7 +1020,18 @@ pub fn solve_nonogram(puzzle: NonogramPuzzle) -> History {
 /// - `best`: A vector of best scores at each iteration.
 /// - `median`: A vector of median scores at each iteration.
 /// - `worst`: A vector of worst scores at each iteration.
+/// - `diversity`: A vector of the mean pairwise Hamming distances of the population at each iteration.
+/// - `pareto_front`: The trade-off candidates of the last generation, in the NSGA-II mode.
+/// - `population`: The final population, best first, to continue the search from it.
 /// - `winner`: A result containing either the best solution (`Ok`) or the worst approach (`Err`).
+/// - `replacement`: The replacement mode of the search, to compare runs.
+/// - `representation`: The dimension whose lines made up the chromosomes, once resolved.
+/// - `repaired`: The number of rows repaired after crossover at each bred generation.
+/// - `refined`: The number of rows refined by the local search at each bred generation.
+/// - `cross_rates`: The crossover probability used at each bred generation.
+/// - `mutation_rates`: The mutation probability used at each bred generation.
+/// - `cancelled`: Whether the search was stopped before finishing.
+/// - `stop_reason`: Why the search stopped by itself, or `None` while it runs or if it was cancelled.
 ///
 /// # Methods
 ///
@@ -224,17 +1039,33 @@ pub fn solve_nonogram(puzzle: NonogramPuzzle) -> History {
 /// - `push(&mut self, population: &Population)`: Adds a new population's scores to the history.
 /// - `get_median(population: &Population, population_size: usize) -> f64`: Calculates the median score
 ///   from the given population.
+/// - `get_diversity(population: &Population) -> f64`: Calculates the mean pairwise Hamming distance
+///   of the given population.
 /// - `winner(&mut self, population: &Population) -> bool`: Checks if the best score in the current
 ///   population is 0 and sets the best solution as the winner.
 /// - `loser(&mut self, population: &Population)`: Sets the worst approach as the winner if no optimal
 ///   solution was found.
+/// - `prepend(&mut self, previous: &History)`: Joins the history of the search continued from.
+/// - `winner_score(&self, puzzle: &NonogramPuzzle, fitness: Fitness) -> usize`: Scores the winner
+///   as the search did.
 #[derive(Debug, Clone)]
 pub struct History {
     pub iterations: usize,
     pub best: Vec<usize>,
     pub median: Vec<f64>,
     pub worst: Vec<usize>,
+    pub diversity: Vec<f64>,
+    pub pareto_front: Vec<ParetoCandidate>,
+    pub population: Vec<NonogramSolution>,
     pub winner: Result<NonogramSolution, NonogramSolution>,
+    pub replacement: Replacement,
+    pub representation: Representation,
+    pub repaired: Vec<usize>,
+    pub refined: Vec<usize>,
+    pub cross_rates: Vec<f64>,
+    pub mutation_rates: Vec<f64>,
+    pub cancelled: bool,
+    pub stop_reason: Option<StopReason>,
 }
 
 impl History {
@@ -255,7 +1086,18 @@ impl History {
             best: Vec::new(),
             median: Vec::new(),
             worst: Vec::new(),
+            diversity: Vec::new(),
+            pareto_front: Vec::new(),
+            population: Vec::new(),
             winner: Err(puzzle.new_chromosome_solution(rng)),
+            replacement: Replacement::default(),
+            representation: Representation::default(),
+            repaired: Vec::new(),
+            refined: Vec::new(),
+            cross_rates: Vec::new(),
+            mutation_rates: Vec::new(),
+            cancelled: false,
+            stop_reason: None,
         }
     }
 
@@ -271,6 +1113,20 @@ impl History {
         self.median
             .push(Self::get_median(population, population_size));
         self.worst.push(population[population_size - 1].1);
+        self.diversity.push(Self::get_diversity(population));
+    }
+
+    /// Updates the history with the scores of a generation reported by a running search.
+    ///
+    /// # Arguments
+    ///
+    /// * `progress` - The progress of the generation reported.
+    pub fn record(&mut self, progress: &Progress) {
+        self.iterations = progress.iteration;
+        self.best.push(progress.best);
+        self.median.push(progress.median);
+        self.worst.push(progress.worst);
+        self.diversity.push(progress.diversity);
     }
 
     /// Calculates the median score from the population.
@@ -294,6 +1150,46 @@ impl History {
         }
     }
 
+    /// Calculates the diversity of the population, as the mean Hamming distance between each pair
+    /// of individuals (the number of cells where they differ).
+    ///
+    /// Counting the colors of each cell across the population gives the same mean as comparing
+    /// every pair, without the quadratic cost.
+    ///
+    /// # Arguments
+    ///
+    /// * `population` - A reference to the current population of solutions with scores.
+    ///
+    /// # Returns
+    ///
+    /// Returns the mean distance, `0.0` for a population of identical individuals.
+    pub fn get_diversity(population: &Population) -> f64 {
+        let population_size = population.len();
+        let Some((first, _)) = population.first() else {
+            return 0.0;
+        };
+        if population_size < 2 {
+            return 0.0;
+        }
+        let mut differing_pairs = 0;
+        let mut counts = Vec::new();
+        for (row, line) in first.solution_grid.iter().enumerate() {
+            for col in 0..line.len() {
+                counts.clear();
+                for (solution, _) in population {
+                    let color = solution.solution_grid[row][col];
+                    if counts.len() <= color {
+                        counts.resize(color + 1, 0);
+                    }
+                    counts[color] += 1;
+                }
+                let same_pairs = counts.iter().map(|&count| count * count).sum::<usize>();
+                differing_pairs += (population_size * population_size - same_pairs) / 2;
+            }
+        }
+        differing_pairs as f64 / (population_size * (population_size - 1) / 2) as f64
+    }
+
     /// Checks if the best score in the current population is 0 and sets the solution as the winner.
     ///
     /// # Arguments
@@ -321,26 +1217,66 @@ impl History {
             self.winner = Err(population[0].0.clone());
         }
     }
-}
 
-/// Applies an evolutionary search (evolutive search) to minimize the score of the solution
-/// to a Nonogram puzzle using genetic algorithm techniques.
-///
-/// This function facilitates the optimization process by simulating the natural evolution of
-/// solutions over multiple generations. The goal is to iteratively improve the population
-/// of candidate solutions until an optimal solution is found or the maximum number of iterations
-/// is reached.
-///
-/// # Arguments
-///
-/// * `population_size` - The size of the initial population of solutions.
-/// * `puzzle` - A reference to the `NonogramPuzzle` instance that represents the puzzle to be solved.
-/// * `cross_probability` - The probability of performing crossover between pairs of solutions.
-/// * `mutation_probability` - The probability of applying mutation to solutions in the population.
-/// * `tournament_size` - The size of the tournament used for selection during reproduction.
-/// * `slide_tries` - The number of tries for applying sliding mutations.
-/// * `max_iterations` - The maximum number of generations (iterations) the evolutionary search will run.
-/// * `rng` - A mutable reference to the `StdRng` used for generating random values during mutation, crossover, and selection processes.
+    /// Joins the generations of a previous search before the generations of this one, which
+    /// continued from its population (see `WarmStart::population`), so the history covers both.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - The history of the search continued from.
+    pub fn prepend(&mut self, previous: &History) {
+        self.iterations += previous.iterations;
+        self.best.splice(0..0, previous.best.iter().copied());
+        self.median.splice(0..0, previous.median.iter().copied());
+        self.worst.splice(0..0, previous.worst.iter().copied());
+        self.diversity
+            .splice(0..0, previous.diversity.iter().copied());
+        self.repaired
+            .splice(0..0, previous.repaired.iter().copied());
+        self.refined.splice(0..0, previous.refined.iter().copied());
+        self.cross_rates
+            .splice(0..0, previous.cross_rates.iter().copied());
+        self.mutation_rates
+            .splice(0..0, previous.mutation_rates.iter().copied());
+    }
+
+    /// Scores the winner with the fitness the search minimized, against the lines its chromosomes
+    /// were made of.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle solved, in its original orientation.
+    /// * `fitness` - The fitness configured for the search.
+    ///
+    /// # Returns
+    ///
+    /// The score the search reached with the winner, 0 if it solved the puzzle.
+    pub fn winner_score(&self, puzzle: &NonogramPuzzle, fitness: Fitness) -> usize {
+        let (Ok(winner) | Err(winner)) = &self.winner;
+        let fitness = self.replacement.fitness(fitness);
+        match self.representation {
+            Representation::Columns => puzzle
+                .transposed()
+                .score_with(&winner.transposed(), fitness),
+            _ => puzzle.score_with(winner, fitness),
+        }
+    }
+}
+
+/// Applies an evolutionary search (evolutive search) to minimize the score of the solution
+/// to a Nonogram puzzle using genetic algorithm techniques.
+///
+/// This function facilitates the optimization process by simulating the natural evolution of
+/// solutions over multiple generations. The goal is to iteratively improve the population
+/// of candidate solutions until an optimal solution is found or the maximum number of iterations
+/// is reached.
+///
+/// # Arguments
+///
+/// * `puzzle` - A reference to the `NonogramPuzzle` instance that represents the puzzle to be solved.
+/// * `config` - The parameters of the search: population size, probabilities, tournament size,
+///   slide tries, maximum number of generations, replacement mode and repaired rows.
+/// * `rng` - A mutable reference to the `StdRng` used for generating random values during mutation, crossover, and selection processes.
 ///
 /// # Returns
 ///
@@ -359,42 +1295,377 @@ impl History {
 ///    - If no optimal solution is found, a loser (best non-optimal solution) is selected after reaching the
 ///      maximum number of iterations.
 ///
-/// 3. **Selection and Preservation**: At each step, the best solutions are preserved while weaker ones are discarded.
+/// 3. **Selection and Preservation**: At each step, the next generation is chosen from the population
+///    and its offspring according to the replacement mode (see `Replacement`).
 pub fn evolutive_search(
-    population_size: usize,
     puzzle: &NonogramPuzzle,
-    cross_probability: f64,
-    mutation_probability: f64,
-    tournament_size: usize,
-    slide_tries: usize,
-    max_iterations: usize,
+    config: &SolverConfig,
     rng: &mut StdRng,
 ) -> History {
-    let mut population = initial_population(puzzle, population_size, rng);
-    let mut history = History::new(puzzle, rng);
-    while history.iterations < max_iterations {
+    evolutive_search_sampled(
+        puzzle,
+        config,
+        None,
+        rng,
+        0,
+        &mut |_| {},
+        &CancelToken::default(),
+    )
+}
+
+/// Applies the evolutive search like `evolutive_search`, reporting the progress of every generation
+/// and sampling the population every `sample_interval` generations (and at the first one) for an
+/// observer, so the UI can display how the population converges while the search runs.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle to be solved.
+/// * `config` - The parameters of the search.
+/// * `start` - The grid painted by the user, warm-starting the search (see `solve_nonogram`).
+/// * `rng` - The random number generator of the search.
+/// * `sample_interval` - The generations between samples (`0` never samples).
+/// * `observer` - Receives the progress of each generation and the samples of the population.
+/// * `cancel` - Stops the search before it finishes, checked before each generation.
+///
+/// # Returns
+///
+/// The `History` of the search.
+pub fn evolutive_search_sampled(
+    puzzle: &NonogramPuzzle,
+    config: &SolverConfig,
+    start: Option<&WarmStart>,
+    rng: &mut StdRng,
+    sample_interval: usize,
+    observer: &mut dyn FnMut(SearchEvent),
+    cancel: &CancelToken,
+) -> History {
+    let mut search = EvolutiveSearch::new(puzzle.clone(), *config, start, rng);
+    while !cancel.is_cancelled() && search.step(rng, sample_interval, observer) {}
+    if cancel.is_cancelled() {
+        search.stop();
+    }
+    search.finish()
+}
+
+/// An evolutive search advanced one generation at a time, so it can be interleaved with other
+/// work, such as keeping a user interface responsive on platforms without threads.
+///
+/// Stepping a search until it finishes gives the same `History` as `evolutive_search_sampled`
+/// with the same random number generator.
+pub struct EvolutiveSearch {
+    /// The puzzle being solved.
+    puzzle: NonogramPuzzle,
+    /// The parameters of the search.
+    config: SolverConfig,
+    /// The number of colors of the puzzle, background included.
+    colors: usize,
+    /// The current population, sorted by score.
+    population: Population,
+    /// The number of children bred each generation.
+    offspring_size: usize,
+    /// The cells deduced by the line solver, for the local search.
+    deduced: Vec<Vec<CellState>>,
+    /// The colors of the cells pinned by the user, by row, with no rows when no cell is pinned.
+    pinned: Vec<Vec<Option<usize>>>,
+    /// The history of the search.
+    history: History,
+    /// The probabilities of crossover and mutation of the next generation.
+    rates: (f64, f64),
+    /// The generations since the best score last improved.
+    stagnation: usize,
+    /// When the search started, in milliseconds.
+    started_millis: f64,
+    /// Whether the search has finished.
+    finished: bool,
+}
+
+impl EvolutiveSearch {
+    /// Starts a search, generating its initial population.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle to be solved.
+    /// * `config` - The parameters of the search.
+    /// * `start` - The grid painted by the user, warm-starting the search (see `solve_nonogram`).
+    /// * `rng` - The random number generator of the search.
+    pub fn new(
+        puzzle: NonogramPuzzle,
+        config: SolverConfig,
+        start: Option<&WarmStart>,
+        rng: &mut StdRng,
+    ) -> Self {
+        let config = SolverConfig {
+            fitness: config.replacement.fitness(config.fitness),
+            ..config
+        };
+        let pinned = start.map(WarmStart::pinned_colors).unwrap_or_default();
+        let previous = start
+            .map(|start| start.population.clone())
+            .unwrap_or_default();
+        // Columns are evolved as the rows of the transposed puzzle
+        let representation = config.representation.resolve(&puzzle);
+        let (puzzle, start, pinned, previous) = match representation {
+            Representation::Columns => (
+                puzzle.transposed(),
+                start.map(|start| NonogramSolution {
+                    solution_grid: transpose(&start.grid.solution_grid),
+                }),
+                transpose(&pinned),
+                previous.iter().map(NonogramSolution::transposed).collect(),
+            ),
+            _ => (
+                puzzle,
+                start.map(|start| start.grid.clone()),
+                pinned,
+                previous,
+            ),
+        };
+        let colors = puzzle
+            .row_constraints
+            .iter()
+            .flatten()
+            .map(|segment| segment.color + 1)
+            .max()
+            .unwrap_or(1)
+            .max(2);
+        let mut population = if previous.is_empty() {
+            initial_population(
+                &puzzle,
+                config.population_size,
+                config.fitness,
+                start.as_ref(),
+                &pinned,
+                rng,
+            )
+        } else {
+            resumed_population(
+                &puzzle,
+                config.population_size,
+                config.fitness,
+                previous,
+                &pinned,
+                rng,
+            )
+        };
+        population.sort_by_key(|(_, score)| *score);
+        let mut history = History::new(&puzzle, rng);
+        history.replacement = config.replacement;
+        history.representation = representation;
+        let offspring_size = config.replacement.offspring_size(config.population_size);
+        let deduced = deduced_cells(&puzzle, config.local_search_rows);
+        Self {
+            puzzle,
+            config,
+            colors,
+            population,
+            offspring_size,
+            deduced,
+            pinned,
+            history,
+            rates: (config.cross_probability, config.mutation_probability),
+            stagnation: 0,
+            started_millis: now_millis(),
+            finished: false,
+        }
+    }
+
+    /// Returns the history of the generations evolved so far.
+    pub fn history(&self) -> &History {
+        &self.history
+    }
+
+    /// Evolves the next generation, reporting its progress and sampling the population every
+    /// `sample_interval` generations (and at the first one) for an observer.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator of the search.
+    /// * `sample_interval` - The generations between samples (`0` never samples).
+    /// * `observer` - Receives the progress of the generation and the samples of the population.
+    ///
+    /// # Returns
+    ///
+    /// `true` while the search goes on, `false` once a solution is found or a stop criterion is
+    /// reached (see `StopCriteria`).
+    pub fn step(
+        &mut self,
+        rng: &mut StdRng,
+        sample_interval: usize,
+        observer: &mut dyn FnMut(SearchEvent),
+    ) -> bool {
+        if self.finished {
+            return false;
+        }
+        if self.history.iterations >= self.config.stop.max_iterations {
+            self.finished = true;
+            self.history.stop_reason = Some(StopReason::MaxIterations);
+            return false;
+        }
+        let (puzzle, config, history) = (&self.puzzle, &self.config, &mut self.history);
         // Save results
-        history.push(&population);
+        history.push(&self.population);
+        observer(SearchEvent::Progress(Progress {
+            iteration: history.iterations,
+            max_iterations: config.stop.max_iterations,
+            best: history.best[history.iterations - 1],
+            median: history.median[history.iterations - 1],
+            worst: history.worst[history.iterations - 1],
+            diversity: history.diversity[history.iterations - 1],
+        }));
+        if sample_interval > 0 && (history.iterations - 1).is_multiple_of(sample_interval) {
+            let mut sample =
+                PopulationSample::new(history.iterations, &self.population, self.colors);
+            if history.representation == Representation::Columns {
+                sample.agreement = transpose(&sample.agreement);
+            }
+            observer(SearchEvent::Sample(sample));
+        }
+        let improved = history.iterations < 2
+            || history.best[history.iterations - 1] < history.best[history.iterations - 2];
+        self.stagnation = if improved { 0 } else { self.stagnation + 1 };
         // Stop criteria
-        if history.winner(&population) {
-            break;
+        history.winner(&self.population);
+        let elapsed_millis = match config.stop.max_millis {
+            Some(_) => now_millis() - self.started_millis,
+            None => 0.0,
+        };
+        let best = history.best[history.iterations - 1];
+        if let Some(reason) = config.stop.reached(best, self.stagnation, elapsed_millis) {
+            self.finished = true;
+            history.stop_reason = Some(reason);
+            return false;
+        }
+        // Adapt the probabilities
+        if let Some(adaptation) = config.adaptation {
+            self.rates = adaptation.adapt(
+                (config.cross_probability, config.mutation_probability),
+                self.rates,
+                self.stagnation,
+            );
         }
+        let (cross_probability, mutation_probability) = self.rates;
+        history.cross_rates.push(cross_probability);
+        history.mutation_rates.push(mutation_probability);
         // Recombinate
-        let mut offspring =
-            recombinate_population(puzzle, &population, cross_probability, tournament_size, rng);
+        let mut offspring = recombinate_population(
+            puzzle,
+            &self.population,
+            self.offspring_size,
+            cross_probability,
+            config.tournament_size,
+            &config.crossover,
+            rng,
+        );
+        // Repair
+        let repaired = repair_population(
+            puzzle,
+            &mut offspring,
+            config.repair_rows,
+            config.repair,
+            rng,
+        );
+        history.repaired.push(repaired);
+        pin_population(puzzle, &mut offspring, &self.pinned, rng);
         // Mutation
         mutate_population(
             puzzle,
             &mut offspring,
             mutation_probability,
-            slide_tries,
+            config.slide_tries,
+            &config.mutation,
+            &self.pinned,
+            rng,
+        );
+        if config.replacement == Replacement::Pareto {
+            repaint_population(
+                puzzle,
+                &mut offspring,
+                mutation_probability,
+                self.colors,
+                &self.pinned,
+                rng,
+            );
+        }
+        // Local search
+        let refined = refine_population(
+            puzzle,
+            &self.deduced,
+            &mut offspring,
+            config.local_search_rows,
             rng,
         );
-        // Select best
-        population = preserve_elite_population(puzzle, population, offspring);
+        history.refined.push(refined);
+        pin_population(puzzle, &mut offspring, &self.pinned, rng);
+        // Select the next generation
+        let population = std::mem::take(&mut self.population);
+        self.population = match config.replacement {
+            Replacement::Plus => preserve_elite_population(
+                puzzle,
+                population,
+                offspring,
+                config.fitness,
+                config.crowding,
+                rng,
+            ),
+            Replacement::Generational { .. } | Replacement::SteadyState { .. } => {
+                replace_worst_population(puzzle, population, offspring, config.fitness)
+            }
+            Replacement::Pareto => {
+                let (population, front) =
+                    pareto_population(puzzle, population, offspring, config.fitness);
+                history.pareto_front = front;
+                population
+            }
+        };
+        true
     }
-    history.loser(&population);
-    history
+
+    /// Stops the search before it finishes, marking its history as cancelled.
+    pub fn stop(&mut self) {
+        if !self.finished {
+            self.finished = true;
+            self.history.cancelled = true;
+        }
+    }
+
+    /// Finishes the search, choosing the best approach if no solution was found.
+    ///
+    /// # Returns
+    ///
+    /// The `History` of the search.
+    pub fn finish(mut self) -> History {
+        self.history.loser(&self.population);
+        self.history.population = self
+            .population
+            .iter()
+            .map(|(solution, _)| solution.clone())
+            .collect();
+        if self.history.representation == Representation::Columns {
+            for solution in &mut self.history.population {
+                *solution = solution.transposed();
+            }
+            self.history.winner = match &self.history.winner {
+                Ok(solution) => Ok(solution.transposed()),
+                Err(solution) => Err(solution.transposed()),
+            };
+            for candidate in &mut self.history.pareto_front {
+                *candidate = ParetoCandidate {
+                    solution: candidate.solution.transposed(),
+                    row_violations: candidate.column_violations,
+                    column_violations: candidate.row_violations,
+                };
+            }
+        }
+        self.history
+    }
+}
+
+/// Transposes a grid, so its rows become the columns and the columns become the rows.
+fn transpose<T: Copy>(grid: &[Vec<T>]) -> Vec<Vec<T>> {
+    let cols = grid.first().map_or(0, Vec::len);
+    (0..cols)
+        .map(|col| grid.iter().map(|row| row[col]).collect())
+        .collect()
 }
 
 /// Generates the initial population for solving a Nonogram puzzle using a genetic algorithm.
@@ -404,10 +1675,17 @@ pub fn evolutive_search(
 /// and calculates their scores using the provided Nonogram puzzle. The resulting population
 /// is returned as a collection of tuples, each containing a solution and its corresponding score.
 ///
+/// When the search is warm-started, a `WARM_START_FRACTION` of the population is generated
+/// around the grid painted by the user instead (see `NonogramPuzzle::warm_chromosome_solution`).
+/// Every solution keeps the colors of the cells pinned by the user.
+///
 /// # Arguments
 ///
 /// * `puzzle` - A reference to a `NonogramPuzzle` representing the puzzle to be solved.
 /// * `population_size` - The desired size of the initial population.
+/// * `fitness` - The lines of the solutions scored.
+/// * `start` - The grid painted by the user, or `None` to generate only random solutions.
+/// * `pinned` - The colors of the pinned cells, by row (see `WarmStart::pinned_colors`).
 /// * `rng` - A mutable reference to a `StdRng` for generating random solutions.
 ///
 /// # Returns
@@ -416,29 +1694,94 @@ pub fn evolutive_search(
 fn initial_population(
     puzzle: &NonogramPuzzle,
     population_size: usize,
+    fitness: Fitness,
+    start: Option<&NonogramSolution>,
+    pinned: &[Vec<Option<usize>>],
     rng: &mut StdRng,
 ) -> Population {
-    (0..population_size)
-        .map(|_| {
-            let solution = puzzle.new_chromosome_solution(rng); // Generate a new random solution
-            let score = puzzle.score(&solution); // Calculate the score of the solution
-            (solution, score) // Return solution and its score as a tuple
+    let warm_size = start.map_or(0, |_| {
+        (population_size as f64 * WARM_START_FRACTION).ceil() as usize
+    });
+    seeded_map(population_size, rng, |individual, rng| {
+        // Generate a new solution, around the painted grid for the first individuals
+        let solution = match start {
+            Some(start) if individual < warm_size => {
+                puzzle.warm_chromosome_solution(start, pinned, rng)
+            }
+            _ if !pinned.is_empty() => puzzle.pinned_chromosome_solution(pinned, rng),
+            _ => puzzle.new_chromosome_solution(rng),
+        };
+        let score = puzzle.score_with(&solution, fitness); // Calculate the score of the solution
+        (solution, score) // Return solution and its score as a tuple
+    })
+}
+
+/// Continues the population of a previous search, to evolve it further.
+///
+/// The solutions that don't fit the puzzle are dropped, the best ones are kept up to the desired
+/// size, and the population is completed with new random solutions if it's smaller. Every solution
+/// keeps the colors of the cells pinned by the user.
+///
+/// # Arguments
+///
+/// * `puzzle` - A reference to a `NonogramPuzzle` representing the puzzle to be solved.
+/// * `population_size` - The desired size of the population.
+/// * `fitness` - The lines of the solutions scored.
+/// * `previous` - The population of the previous search, best first.
+/// * `pinned` - The colors of the pinned cells, by row (see `WarmStart::pinned_colors`).
+/// * `rng` - A mutable reference to a `StdRng` for generating the missing solutions.
+///
+/// # Returns
+///
+/// A `Population`, which is a collection of tuples containing a solution and its score.
+fn resumed_population(
+    puzzle: &NonogramPuzzle,
+    population_size: usize,
+    fitness: Fitness,
+    previous: NewPopulation,
+    pinned: &[Vec<Option<usize>>],
+    rng: &mut StdRng,
+) -> Population {
+    let mut previous: NewPopulation = previous
+        .into_iter()
+        .filter(|solution| {
+            solution.solution_grid.len() == puzzle.rows
+                && solution
+                    .solution_grid
+                    .iter()
+                    .all(|row| row.len() == puzzle.cols)
         })
-        .collect()
+        .take(population_size)
+        .collect();
+    pin_population(puzzle, &mut previous, pinned, rng);
+    let missing = population_size - previous.len();
+    let mut population: Population = previous
+        .into_iter()
+        .map(|solution| {
+            let score = puzzle.score_with(&solution, fitness);
+            (solution, score)
+        })
+        .collect();
+    population.extend(initial_population(
+        puzzle, missing, fitness, None, pinned, rng,
+    ));
+    population
 }
 
 /// Generates a new population through recombination (crossover) of the given population.
 ///
 /// This function performs tournament selection to pick parent chromosomes from the current
-/// population, and then applies either uniform or two-point crossover (50 percent of the time for each)
-/// to create offspring. The resulting children are added to a new population until the desired size is reached.
+/// population, and then applies a crossover operator chosen by its weight (see
+/// `CrossoverWeights::choose`) to create offspring. The resulting children are added to a new population until the desired size is reached.
 ///
 /// # Arguments
 ///
 /// * `puzzle` - A reference to a `NonogramPuzzle` instance used for crossover operations.
 /// * `population` - A reference to the current population, a collection of solutions and scores.
+/// * `offspring_size` - The number of offspring to breed.
 /// * `cross_probability` - The probability that crossover will occur between selected parents.
 /// * `tournament_size` - The number of individuals participating in the tournament selection.
+/// * `weights` - The relative weights of the crossover operators.
 /// * `rng` - A mutable reference to a `StdRng` used for generating random decisions and solutions.
 ///
 /// # Returns
@@ -447,24 +1790,35 @@ fn initial_population(
 fn recombinate_population(
     puzzle: &NonogramPuzzle,
     population: &Population,
+    offspring_size: usize,
     cross_probability: f64,
     tournament_size: usize,
+    weights: &CrossoverWeights,
     rng: &mut StdRng,
 ) -> NewPopulation {
-    let mut new_population = Vec::with_capacity(population.len());
-    while new_population.len() < population.len() {
+    let mut new_population = Vec::with_capacity(offspring_size + 1);
+    while new_population.len() < offspring_size {
         let ancestor_1 = tournament_selection(population, tournament_size, rng); // Select first parent
         let ancestor_2 = tournament_selection(population, tournament_size, rng); // Select second parent
-        let (descendant_1, descendant_2) = if rng.gen_bool(0.5) {
-            puzzle.uniform_cross(ancestor_1, ancestor_2, cross_probability, rng)
-        // Apply uniform crossover
-        } else {
-            puzzle.two_point_cross(ancestor_1, ancestor_2, cross_probability, rng)
-            // Apply two-point crossover
+        let (descendant_1, descendant_2) = match weights.choose(rng) {
+            Crossover::Uniform => {
+                puzzle.uniform_cross(ancestor_1, ancestor_2, cross_probability, rng)
+            }
+            Crossover::TwoPoint => {
+                puzzle.two_point_cross(ancestor_1, ancestor_2, cross_probability, rng)
+            }
+            Crossover::Column => {
+                puzzle.column_two_point_cross(ancestor_1, ancestor_2, cross_probability, rng)
+            }
+            Crossover::Block => puzzle.block_cross(ancestor_1, ancestor_2, cross_probability, rng),
+            Crossover::Segment => {
+                puzzle.segment_cross(ancestor_1, ancestor_2, cross_probability, rng)
+            }
         };
         new_population.push(descendant_1); // Add first child to the new population
         new_population.push(descendant_2); // Add second child to the new population
     }
+    new_population.truncate(offspring_size); // Drop the extra child of the last pair
     new_population
 }
 
@@ -501,13 +1855,49 @@ fn tournament_selection<'population_scope>(
         .0 // Return the selected solution
 }
 
+/// Repairs the offspring right after crossover, re-randomizing the rows that conflict the most
+/// with the column constraints (see `NonogramPuzzle::repair_chromosome`), or shifting their
+/// segments towards the column constraints (see `NonogramPuzzle::column_repair_chromosome`).
+///
+/// Crossover can produce children whose rows lock the search, so replacing a bounded number of
+/// them with fresh random rows keeps the population moving, while the targeted shifts pull them
+/// closer to a solution.
+///
+/// # Arguments
+///
+/// * `puzzle` - A reference to a `NonogramPuzzle` instance used to find the conflicting rows.
+/// * `offspring` - A mutable reference to the offspring produced by the crossover.
+/// * `repair_rows` - The maximum number of rows repaired in each child, `0` to disable the repair.
+/// * `repair` - How the rows are repaired.
+/// * `rng` - A mutable reference to a `StdRng`, used for generating the new rows.
+///
+/// # Returns
+///
+/// The total number of rows repaired in the offspring.
+fn repair_population(
+    puzzle: &NonogramPuzzle,
+    offspring: &mut NewPopulation,
+    repair_rows: usize,
+    repair: Repair,
+    rng: &mut StdRng,
+) -> usize {
+    offspring
+        .iter_mut()
+        .map(|descendant| match repair {
+            Repair::Randomize => puzzle.repair_chromosome(descendant, repair_rows, rng),
+            Repair::Shift => puzzle.column_repair_chromosome(descendant, repair_rows),
+        })
+        .sum()
+}
+
 /// Applies mutations to the population by modifying chromosomes based on a given probability.
 ///
-/// This function iterates over each individual in the `offspring` population and applies mutations
-/// using the `chromosome_mutation` method from the `NonogramPuzzle` trait. The mutation alters
-/// the chromosomes by making small changes, such as flipping bits or sliding values, to potentially
-/// improve their scores. The mutation process is controlled by a specified probability and
-/// a fixed number of slide tries.
+/// This function iterates over each individual in the `offspring` population and applies a
+/// mutation operator chosen by its weight (see `MutationWeights::choose`): sliding segments with
+/// the `chromosome_mutation` method, repositioning whole segments, or regenerating whole rows. The
+/// mutation process is controlled by a specified probability and a fixed number of slide tries.
+/// The rows whose pinned cells are changed by a mutation are restored, so the pins are never
+/// changed.
 ///
 /// # Arguments
 ///
@@ -515,19 +1905,148 @@ fn tournament_selection<'population_scope>(
 /// * `offspring` - A mutable reference to a collection of mutated solution chromosomes.
 /// * `mutation_probability` - The probability of applying mutation to each individual in the population.
 /// * `slide_tries` - The number of attempts to apply sliding mutations.
+/// * `weights` - The relative weights of the mutation operators.
+/// * `pinned` - The colors of the pinned cells, by row (see `WarmStart::pinned_colors`).
 /// * `rng` - A mutable reference to a `StdRng`, used for generating random mutations.
 fn mutate_population(
     puzzle: &NonogramPuzzle,
     offspring: &mut NewPopulation,
     mutation_probability: f64,
     slide_tries: usize,
+    weights: &MutationWeights,
+    pinned: &[Vec<Option<usize>>],
     rng: &mut StdRng,
 ) {
-    offspring.iter_mut().for_each(|descendant| {
-        puzzle.chromosome_mutation(descendant, mutation_probability, slide_tries, rng)
+    seeded_for_each(offspring, rng, |descendant, rng| {
+        let original = (!pinned.is_empty()).then(|| descendant.clone());
+        match weights.choose(rng) {
+            Mutation::Slide => {
+                puzzle.chromosome_mutation(descendant, mutation_probability, slide_tries, rng)
+            }
+            Mutation::Reposition => {
+                puzzle.segment_reposition_mutation(descendant, mutation_probability, rng)
+            }
+            Mutation::Reshuffle => {
+                puzzle.row_reshuffle_mutation(descendant, mutation_probability, rng)
+            }
+        }
+        if let Some(original) = original {
+            puzzle.restore_pinned_rows(descendant, &original, pinned);
+        }
     });
 }
 
+/// Repaints single cells of the offspring with random colors (see
+/// `NonogramPuzzle::cell_mutation`), for the NSGA-II mode, where the violations of the rows are an
+/// objective of their own. The rows whose pinned cells are repainted are restored.
+///
+/// # Arguments
+///
+/// * `puzzle` - A reference to the `NonogramPuzzle` being solved.
+/// * `offspring` - A mutable reference to the offspring.
+/// * `mutation_probability` - The probability of repainting a cell of each row.
+/// * `colors` - The number of colors of the puzzle, including the background.
+/// * `pinned` - The colors of the pinned cells, by row (see `WarmStart::pinned_colors`).
+/// * `rng` - A mutable reference to a `StdRng`, used for choosing the cells and their colors.
+fn repaint_population(
+    puzzle: &NonogramPuzzle,
+    offspring: &mut NewPopulation,
+    mutation_probability: f64,
+    colors: usize,
+    pinned: &[Vec<Option<usize>>],
+    rng: &mut StdRng,
+) {
+    seeded_for_each(offspring, rng, |descendant, rng| {
+        let original = (!pinned.is_empty()).then(|| descendant.clone());
+        puzzle.cell_mutation(descendant, mutation_probability, colors, rng);
+        if let Some(original) = original {
+            puzzle.restore_pinned_rows(descendant, &original, pinned);
+        }
+    });
+}
+
+/// Samples again the rows of the offspring whose pinned cells were changed by the crossover, the
+/// repair or the local search (see `NonogramPuzzle::pin_chromosome`), which mix and regenerate
+/// whole rows.
+///
+/// # Arguments
+///
+/// * `puzzle` - A reference to the `NonogramPuzzle` being solved.
+/// * `offspring` - A mutable reference to the offspring.
+/// * `pinned` - The colors of the pinned cells, by row (see `WarmStart::pinned_colors`).
+/// * `rng` - A mutable reference to a `StdRng`, used for sampling the rows.
+fn pin_population(
+    puzzle: &NonogramPuzzle,
+    offspring: &mut NewPopulation,
+    pinned: &[Vec<Option<usize>>],
+    rng: &mut StdRng,
+) {
+    if pinned.is_empty() {
+        return;
+    }
+    offspring
+        .iter_mut()
+        .for_each(|descendant| puzzle.pin_chromosome(descendant, pinned, rng));
+}
+
+/// Deduces the cells of a puzzle with the line solver, once before the search, for the local
+/// search refining the offspring.
+///
+/// # Arguments
+///
+/// * `puzzle` - A reference to the `NonogramPuzzle` to be solved.
+/// * `local_search_rows` - The maximum number of rows refined in each child, `0` to skip the deduction.
+///
+/// # Returns
+///
+/// The deduced state of the cells, empty when the local search is disabled or the puzzle has no
+/// solution.
+fn deduced_cells(puzzle: &NonogramPuzzle, local_search_rows: usize) -> Vec<Vec<CellState>> {
+    if local_search_rows == 0 {
+        return Vec::new();
+    }
+    let mut deduced = initial_states(puzzle);
+    if propagate(puzzle, &mut deduced) {
+        deduced
+    } else {
+        Vec::new()
+    }
+}
+
+/// Refines the offspring right after mutation with a local search, re-sampling the rows that
+/// contradict the cells deduced by the line solver (see `NonogramPuzzle::refine_chromosome`).
+///
+/// The genetic operators ignore the deductions, so on large puzzles most of the offspring wastes
+/// rows on arrangements that can't be part of a solution. Combining them with this local search
+/// makes a memetic algorithm, which keeps converging where the pure genetic search plateaus.
+///
+/// # Arguments
+///
+/// * `puzzle` - A reference to a `NonogramPuzzle` instance used to sample the new rows.
+/// * `deduced` - The cells deduced by the line solver, empty to disable the local search.
+/// * `offspring` - A mutable reference to the mutated offspring.
+/// * `local_search_rows` - The maximum number of rows refined in each child.
+/// * `rng` - A mutable reference to a `StdRng`, used for sampling the new rows.
+///
+/// # Returns
+///
+/// The total number of rows refined in the offspring.
+fn refine_population(
+    puzzle: &NonogramPuzzle,
+    deduced: &[Vec<CellState>],
+    offspring: &mut NewPopulation,
+    local_search_rows: usize,
+    rng: &mut StdRng,
+) -> usize {
+    if deduced.is_empty() {
+        return 0;
+    }
+    offspring
+        .iter_mut()
+        .map(|descendant| puzzle.refine_chromosome(descendant, deduced, local_search_rows, rng))
+        .sum()
+}
+
 /// Combines the current population with offspring solutions and preserves only the top solutions.
 ///
 /// This function creates a combined population by merging the existing `population` with
@@ -541,6 +2060,7 @@ fn mutate_population(
 /// * `puzzle` - A reference to a `NonogramPuzzle` instance used to evaluate the fitness of solutions.
 /// * `population` - The current population of solutions represented as a vector of solution-score pairs.
 /// * `offspring` - The new population of solutions generated from recombination, which also includes their scores.
+/// * `fitness` - The lines of the offspring scored.
 ///
 /// # Returns
 ///
@@ -549,20 +2069,1066 @@ fn mutate_population(
 /// # Note
 ///
 /// The function truncates the combined population to ensure only the top `population_size` solutions are retained.
+/// With a crowding factor, the offspring instead compete with the individuals most similar to them
+/// (see `crowd_population`), so the best niche doesn't take over the whole population early.
 fn preserve_elite_population(
     puzzle: &NonogramPuzzle,
     population: Population,
     offspring: NewPopulation,
+    fitness: Fitness,
+    crowding: Option<usize>,
+    rng: &mut StdRng,
 ) -> Population {
     let population_size = population.len(); // Determine the size of the population
-    let mut combined_population: Vec<(NonogramSolution, usize)> = population
-        .into_iter()
-        .chain(offspring.into_iter().map(|solution| {
-            let score = puzzle.score(&solution); // Calculate the score for offspring solutions
-            (solution, score) // Pair solution with its score
-        }))
-        .collect();
+    let offspring = score_offspring(puzzle, offspring, fitness);
+    let mut combined_population = match crowding {
+        Some(factor) => crowd_population(population, offspring, factor, rng),
+        None => {
+            let mut combined_population = population;
+            combined_population.extend(offspring);
+            combined_population
+        }
+    };
     combined_population.sort_by_key(|(_, score)| *score); // Sort by scores in ascending order
     combined_population.truncate(population_size); // Retain only the top-performing solutions
     combined_population
 }
+
+/// Replaces the individuals of the population by crowding: each child is compared with `factor`
+/// individuals drawn at random, and replaces the most similar of them (by Hamming distance) if
+/// its score isn't worse.
+///
+/// The children only compete within their niche, so different approaches survive side by side
+/// instead of the best one taking over the population, which delays premature convergence. The
+/// best individual can only be replaced by a child at least as good, so the best score never
+/// worsens.
+///
+/// # Arguments
+///
+/// * `population` - The current population of solutions with their scores.
+/// * `offspring` - The scored offspring.
+/// * `factor` - The number of individuals each child is compared with.
+/// * `rng` - A mutable reference to a `StdRng`, used for drawing the individuals compared.
+///
+/// # Returns
+///
+/// The next `Population`, unsorted and preserving the original size.
+fn crowd_population(
+    mut population: Population,
+    offspring: Population,
+    factor: usize,
+    rng: &mut StdRng,
+) -> Population {
+    if population.is_empty() {
+        return population;
+    }
+    for (child, score) in offspring {
+        let closest = (0..factor.max(1))
+            .map(|_| rng.gen_range(0..population.len()))
+            .min_by_key(|&index| hamming_distance(&population[index].0, &child))
+            .unwrap_or_default();
+        if score <= population[closest].1 {
+            population[closest] = (child, score);
+        }
+    }
+    population
+}
+
+/// Counts the cells where two solutions differ.
+fn hamming_distance(a: &NonogramSolution, b: &NonogramSolution) -> usize {
+    a.solution_grid
+        .iter()
+        .zip(&b.solution_grid)
+        .map(|(a, b)| a.iter().zip(b).filter(|(a, b)| a != b).count())
+        .sum()
+}
+
+/// Replaces the worst individuals of the population with the offspring.
+///
+/// This implements both the generational replacement (where every individual except the elite is
+/// replaced) and the steady-state replacement (where only a few individuals are replaced each
+/// generation), as they only differ in the number of offspring bred.
+///
+/// # Arguments
+///
+/// * `puzzle` - A reference to a `NonogramPuzzle` instance used to evaluate the fitness of solutions.
+/// * `population` - The current population, sorted by score in ascending order.
+/// * `offspring` - The new solutions replacing the worst individuals, at most as many as the population.
+/// * `fitness` - The lines of the offspring scored.
+///
+/// # Returns
+///
+/// The next `Population`, sorted by score and preserving the original size.
+fn replace_worst_population(
+    puzzle: &NonogramPuzzle,
+    mut population: Population,
+    offspring: NewPopulation,
+    fitness: Fitness,
+) -> Population {
+    population.truncate(population.len().saturating_sub(offspring.len())); // Discard the worst individuals
+    population.extend(score_offspring(puzzle, offspring, fitness));
+    population.sort_by_key(|(_, score)| *score); // Sort by scores in ascending order
+    population
+}
+
+/// Chooses the next generation from the population and its offspring by NSGA-II selection, with
+/// the violations of the rows and of the columns as two objectives.
+///
+/// The individuals are sorted into Pareto fronts: the first front holds the individuals no other
+/// individual beats in both objectives, the second one those only beaten by the first front, and
+/// so on. Whole fronts survive while they fit in the population, and the front that doesn't fit
+/// is cut by keeping its most isolated individuals (by crowding distance), which spreads the
+/// survivors along the trade-off between the rows and the columns. The individual with the best
+/// score always survives, so the best score never worsens.
+///
+/// # Arguments
+///
+/// * `puzzle` - A reference to a `NonogramPuzzle` instance used to evaluate the solutions.
+/// * `population` - The current population of solutions with their scores.
+/// * `offspring` - The new solutions bred from the population.
+/// * `fitness` - The lines of the offspring scored.
+///
+/// # Returns
+///
+/// The next `Population`, sorted by score and preserving the original size, and the candidates
+/// of the first front with distinct objectives, by their row violations.
+fn pareto_population(
+    puzzle: &NonogramPuzzle,
+    population: Population,
+    offspring: NewPopulation,
+    fitness: Fitness,
+) -> (Population, Vec<ParetoCandidate>) {
+    let population_size = population.len();
+    let mut combined_population = population;
+    combined_population.extend(score_offspring(puzzle, offspring, fitness));
+    let objectives = combined_population
+        .iter()
+        .map(|(solution, _)| puzzle.objectives(solution))
+        .collect::<Vec<_>>();
+    let fronts = non_dominated_fronts(&objectives);
+    // The first front, keeping one candidate for each trade-off
+    let mut front = fronts.first().cloned().unwrap_or_default();
+    front.sort_by_key(|&index| objectives[index]);
+    front.dedup_by_key(|index| objectives[*index]);
+    let front = front
+        .into_iter()
+        .map(|index| ParetoCandidate {
+            solution: combined_population[index].0.clone(),
+            row_violations: objectives[index].0,
+            column_violations: objectives[index].1,
+        })
+        .collect();
+    // Whole fronts, then the most isolated individuals of the front that doesn't fit
+    let mut selected = Vec::with_capacity(population_size);
+    for front in &fronts {
+        let room = population_size - selected.len();
+        if front.len() <= room {
+            selected.extend(front);
+        } else {
+            let distances = crowding_distances(front, &objectives);
+            let mut ranked = front.iter().zip(distances).collect::<Vec<_>>();
+            ranked.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+            selected.extend(ranked.into_iter().take(room).map(|(&index, _)| index));
+        }
+        if selected.len() == population_size {
+            break;
+        }
+    }
+    let elite = (0..combined_population.len()).min_by_key(|&index| combined_population[index].1);
+    if let Some(elite) = elite.filter(|elite| !selected.contains(elite)) {
+        selected.pop();
+        selected.push(elite);
+    }
+    let mut individuals = combined_population
+        .into_iter()
+        .map(Some)
+        .collect::<Vec<_>>();
+    let mut population = selected
+        .into_iter()
+        .filter_map(|index| individuals[index].take())
+        .collect::<Population>();
+    population.sort_by_key(|(_, score)| *score);
+    (population, front)
+}
+
+/// Sorts the individuals into Pareto fronts by the fast non-dominated sorting of NSGA-II, where
+/// an individual dominates another one if it's no worse in both objectives and better in one.
+///
+/// # Arguments
+///
+/// * `objectives` - The objectives of each individual, minimized.
+///
+/// # Returns
+///
+/// The indexes of the individuals of each front, from the non-dominated one.
+fn non_dominated_fronts(objectives: &[(usize, usize)]) -> Vec<Vec<usize>> {
+    let dominates = |a: (usize, usize), b: (usize, usize)| a.0 <= b.0 && a.1 <= b.1 && a != b;
+    let mut dominated = vec![Vec::new(); objectives.len()];
+    let mut dominators = vec![0usize; objectives.len()];
+    for (i, &a) in objectives.iter().enumerate() {
+        for (j, &b) in objectives.iter().enumerate().skip(i + 1) {
+            if dominates(a, b) {
+                dominated[i].push(j);
+                dominators[j] += 1;
+            } else if dominates(b, a) {
+                dominated[j].push(i);
+                dominators[i] += 1;
+            }
+        }
+    }
+    let mut fronts = Vec::new();
+    let mut front = (0..objectives.len())
+        .filter(|&index| dominators[index] == 0)
+        .collect::<Vec<_>>();
+    while !front.is_empty() {
+        let mut next = Vec::new();
+        for &index in &front {
+            for &other in &dominated[index] {
+                dominators[other] -= 1;
+                if dominators[other] == 0 {
+                    next.push(other);
+                }
+            }
+        }
+        fronts.push(front);
+        front = next;
+    }
+    fronts
+}
+
+/// Measures how isolated the individuals of a front are: the sum, over both objectives, of the
+/// distance between their neighbors in the front, relative to the range of the objective. The
+/// individuals at the ends of the front are infinitely isolated, so they always survive.
+///
+/// # Arguments
+///
+/// * `front` - The indexes of the individuals of the front.
+/// * `objectives` - The objectives of every individual.
+///
+/// # Returns
+///
+/// The crowding distance of each individual of the front, in the same order.
+fn crowding_distances(front: &[usize], objectives: &[(usize, usize)]) -> Vec<f64> {
+    let mut distances = vec![0.0; front.len()];
+    let objective_fns: [fn((usize, usize)) -> usize; 2] = [|(rows, _)| rows, |(_, cols)| cols];
+    for objective in objective_fns {
+        let mut order = (0..front.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&position| objective(objectives[front[position]]));
+        let value = |position: usize| objective(objectives[front[order[position]]]) as f64;
+        let (Some(&first), Some(&last)) = (order.first(), order.last()) else {
+            continue;
+        };
+        distances[first] = f64::INFINITY;
+        distances[last] = f64::INFINITY;
+        let range = value(order.len() - 1) - value(0);
+        if range == 0.0 {
+            continue;
+        }
+        for position in 1..order.len().saturating_sub(1) {
+            distances[order[position]] += (value(position + 1) - value(position - 1)) / range;
+        }
+    }
+    distances
+}
+
+/// Scores the offspring, on every core with the `parallel` feature.
+///
+/// # Arguments
+///
+/// * `puzzle` - A reference to a `NonogramPuzzle` instance used to evaluate the fitness of solutions.
+/// * `offspring` - The new solutions scored.
+/// * `fitness` - The lines of the offspring scored.
+///
+/// # Returns
+///
+/// The offspring paired with their scores, in the same order.
+fn score_offspring(
+    puzzle: &NonogramPuzzle,
+    offspring: NewPopulation,
+    fitness: Fitness,
+) -> Population {
+    #[cfg(feature = "parallel")]
+    let offspring = offspring.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let offspring = offspring.into_iter();
+    offspring
+        .map(|solution| {
+            let score = puzzle.score_with(&solution, fitness); // Calculate the score for offspring solutions
+            (solution, score) // Pair solution with its score
+        })
+        .collect()
+}
+
+/// Builds some items with a random task, on every core with the `parallel` feature.
+///
+/// The parallel tasks can't share the random number generator of the search, so each one gets
+/// its own generator seeded from it, keeping the runs with the same seed reproducible. Without
+/// the feature, the tasks run in order with the same generators, so both builds make the same
+/// runs.
+///
+/// # Arguments
+///
+/// * `count` - The number of items built.
+/// * `rng` - The random number generator of the search.
+/// * `task` - Builds the item of an index with a random number generator.
+///
+/// # Returns
+///
+/// The items, in the order of their indexes.
+fn seeded_map<T: Send>(
+    count: usize,
+    rng: &mut StdRng,
+    task: impl Fn(usize, &mut StdRng) -> T + Sync,
+) -> Vec<T> {
+    let seeds = (0..count).map(|_| rng.gen::<u64>()).collect::<Vec<_>>();
+    #[cfg(feature = "parallel")]
+    let seeds = seeds.into_par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let seeds = seeds.into_iter();
+    seeds
+        .enumerate()
+        .map(|(index, seed)| task(index, &mut StdRng::seed_from_u64(seed)))
+        .collect()
+}
+
+/// Modifies some items with a random task, on every core with the `parallel` feature (see
+/// `seeded_map`).
+///
+/// # Arguments
+///
+/// * `items` - The items modified.
+/// * `rng` - The random number generator of the search.
+/// * `task` - Modifies an item with a random number generator.
+fn seeded_for_each<T: Send>(
+    items: &mut [T],
+    rng: &mut StdRng,
+    task: impl Fn(&mut T, &mut StdRng) + Sync,
+) {
+    let seeds = (0..items.len())
+        .map(|_| rng.gen::<u64>())
+        .collect::<Vec<_>>();
+    #[cfg(feature = "parallel")]
+    let items = items.par_iter_mut();
+    #[cfg(not(feature = "parallel"))]
+    let items = items.iter_mut();
+    items
+        .zip(seeds)
+        .for_each(|(item, seed)| task(item, &mut StdRng::seed_from_u64(seed)));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::definitions::BACKGROUND;
+    use crate::nonogram::generator::seeded_puzzle;
+    use crate::nonogram::puzzles::{
+        tree_empty_nonogram_solution, tree_nonogram_file, tree_nonogram_puzzle,
+    };
+
+    use super::*;
+
+    // Test that the repair is measured in the history only when enabled.
+    #[test]
+    fn repair_measured_in_history() {
+        let puzzle = tree_nonogram_puzzle();
+        for repair_rows in [0, 2] {
+            let config = SolverConfig {
+                population_size: 20,
+                stop: StopCriteria {
+                    max_iterations: 10,
+                    ..StopCriteria::default()
+                },
+                repair_rows,
+                ..SolverConfig::default()
+            };
+            let history = evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED));
+            let repaired = history.repaired.iter().sum::<usize>();
+            assert_eq!(repaired == 0, repair_rows == 0);
+            assert!(history
+                .repaired
+                .iter()
+                .all(|&rows| rows <= repair_rows * config.population_size));
+        }
+    }
+
+    // Test that the shift repair stays within its budget and still solves the puzzle.
+    #[test]
+    fn shift_repair_solves() {
+        let config = SolverConfig {
+            repair_rows: 2,
+            repair: Repair::Shift,
+            ..SolverConfig::default()
+        };
+        let history = evolutive_search(
+            &tree_nonogram_puzzle(),
+            &config,
+            &mut StdRng::seed_from_u64(SEED),
+        );
+        assert!(history.winner.is_ok());
+        assert!(history
+            .repaired
+            .iter()
+            .all(|&rows| rows <= config.repair_rows * config.population_size));
+    }
+
+    // Test that a search warm-started from a partially painted grid continues from it, in both
+    // representations.
+    #[test]
+    fn warm_start_continues_from_grid() {
+        let puzzle = tree_nonogram_puzzle();
+        let mut painted = tree_nonogram_file().solution;
+        painted.solution_grid[0] = vec![0; 5];
+        for representation in [Representation::Rows, Representation::Columns] {
+            let config = SolverConfig {
+                population_size: 20,
+                representation,
+                ..SolverConfig::default()
+            };
+            let history = evolutive_search_sampled(
+                &puzzle,
+                &config,
+                Some(&WarmStart {
+                    grid: painted.clone(),
+                    pinned: HashSet::new(),
+                    population: Vec::new(),
+                }),
+                &mut StdRng::seed_from_u64(SEED),
+                0,
+                &mut |_| {},
+                &CancelToken::default(),
+            );
+            assert!(history.winner.is_ok());
+            assert!(
+                history.best[0] < puzzle.score(&painted),
+                "{representation:?}"
+            );
+        }
+    }
+
+    // Test that the cells pinned by the user keep their colors in every representation, even when
+    // they contradict the solution.
+    #[test]
+    fn pinned_cells_never_change() {
+        let puzzle = tree_nonogram_puzzle();
+        let mut grid = tree_empty_nonogram_solution();
+        grid.solution_grid[0][0] = 1;
+        grid.solution_grid[3][2] = 2;
+        let start = WarmStart {
+            grid,
+            pinned: HashSet::from([(0, 0), (3, 2), (4, 4)]),
+            population: Vec::new(),
+        };
+        for representation in [Representation::Rows, Representation::Columns] {
+            let config = SolverConfig {
+                population_size: 20,
+                representation,
+                stop: StopCriteria {
+                    max_iterations: 20,
+                    ..StopCriteria::default()
+                },
+                ..SolverConfig::default()
+            };
+            let mut rng = StdRng::seed_from_u64(SEED);
+            let mut search = EvolutiveSearch::new(puzzle.clone(), config, Some(&start), &mut rng);
+            while search.step(&mut rng, 0, &mut |_| {}) {
+                for (candidate, _) in &search.population {
+                    assert!(candidate
+                        .solution_grid
+                        .iter()
+                        .zip(&search.pinned)
+                        .all(|(row, pins)| NonogramPuzzle::keeps_pins(row, pins)));
+                }
+            }
+            let history = search.finish();
+            let Err(approach) = history.winner else {
+                panic!("The pinned cells contradict the solution");
+            };
+            assert_eq!(approach.solution_grid[0][0], 1, "{representation:?}");
+            assert_eq!(approach.solution_grid[3][2], 2, "{representation:?}");
+            assert_eq!(
+                approach.solution_grid[4][4], BACKGROUND,
+                "{representation:?}"
+            );
+        }
+    }
+
+    // Test that the searches with the same seed give the same history, also when the individuals
+    // are bred on every core.
+    #[test]
+    fn seeded_searches_are_reproducible() {
+        let puzzle = NonogramPuzzle::from_solution(&seeded_puzzle(12, 12, 3, 7).solution);
+        let config = SolverConfig {
+            population_size: 40,
+            stop: StopCriteria {
+                max_iterations: 10,
+                ..StopCriteria::default()
+            },
+            ..SolverConfig::default()
+        };
+        let search = || evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED));
+        let (first, second) = (search(), search());
+        assert_eq!(first.best, second.best);
+        assert_eq!(first.median, second.median);
+        assert_eq!(first.winner, second.winner);
+    }
+
+    // Test that stepping a search gives the same history as running it at once.
+    #[test]
+    fn stepped_search_matches() {
+        let puzzle = tree_nonogram_puzzle();
+        let config = SolverConfig {
+            population_size: 20,
+            stop: StopCriteria {
+                max_iterations: 15,
+                ..StopCriteria::default()
+            },
+            ..SolverConfig::default()
+        };
+        let history = evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED));
+        let mut rng = StdRng::seed_from_u64(SEED);
+        let mut search = EvolutiveSearch::new(puzzle, config, None, &mut rng);
+        let mut steps = 0;
+        while search.step(&mut rng, 0, &mut |_| {}) {
+            steps += 1;
+            assert_eq!(search.history().iterations, steps);
+        }
+        assert!(!search.step(&mut rng, 0, &mut |_| {}));
+        let stepped = search.finish();
+        assert_eq!(stepped.iterations, history.iterations);
+        assert_eq!(stepped.best, history.best);
+        assert_eq!(stepped.winner, history.winner);
+    }
+
+    // Test that the local search converges on a puzzle deduced by logic, and is measured only
+    // when enabled.
+    #[test]
+    fn local_search_refines_offspring() {
+        let puzzle = tree_nonogram_puzzle();
+        for local_search_rows in [0, 5] {
+            let config = SolverConfig {
+                population_size: 20,
+                stop: StopCriteria {
+                    max_iterations: 10,
+                    ..StopCriteria::default()
+                },
+                mutation_probability: 0.0,
+                local_search_rows,
+                ..SolverConfig::default()
+            };
+            let history = evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED));
+            let refined = history.refined.iter().sum::<usize>();
+            assert_eq!(refined == 0, local_search_rows == 0);
+            if local_search_rows > 0 {
+                assert!(history.winner.is_ok());
+            }
+        }
+    }
+
+    // Test that sampling the population doesn't change the search.
+    #[test]
+    fn sampled_search_matches_search() {
+        let puzzle = tree_nonogram_puzzle();
+        let config = SolverConfig {
+            population_size: 20,
+            stop: StopCriteria {
+                max_iterations: 10,
+                ..StopCriteria::default()
+            },
+            ..SolverConfig::default()
+        };
+        let history = evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED));
+        let (mut samples, mut progress) = (Vec::new(), Vec::new());
+        let sampled = evolutive_search_sampled(
+            &puzzle,
+            &config,
+            None,
+            &mut StdRng::seed_from_u64(SEED),
+            3,
+            &mut |event| match event {
+                SearchEvent::Sample(sample) => samples.push(sample),
+                SearchEvent::Progress(generation) => progress.push(generation),
+            },
+            &CancelToken::default(),
+        );
+        assert_eq!(sampled.best, history.best);
+        assert!(progress
+            .iter()
+            .map(|generation| generation.best)
+            .eq(history.best.clone()));
+        assert!(progress
+            .iter()
+            .map(|generation| generation.worst)
+            .eq(history.worst.clone()));
+        assert_eq!(progress.len(), history.iterations);
+        assert_eq!(
+            progress.last().unwrap().fraction(),
+            history.iterations as f64 / 10.0
+        );
+        assert_eq!(sampled.winner, history.winner);
+        let iterations = samples.iter().map(|sample| sample.iteration);
+        assert!(iterations.eq((1..=history.iterations).step_by(3)));
+        for sample in &samples {
+            assert_eq!(sample.best, history.best[sample.iteration - 1]);
+            assert_eq!(sample.agreement.len(), puzzle.rows);
+            assert!(sample
+                .agreement
+                .iter()
+                .flatten()
+                .all(|&agreement| agreement > 0.0 && agreement <= 1.0));
+        }
+    }
+
+    // Test that a cancelled search stops at the next generation, keeping its best approach.
+    #[test]
+    fn cancelled_search_stops() {
+        // A puzzle too large to be solved before the search is cancelled
+        let puzzle = NonogramPuzzle::from_solution(&seeded_puzzle(12, 12, 3, 7).solution);
+        let config = SolverConfig {
+            population_size: 20,
+            stop: StopCriteria {
+                max_iterations: 50,
+                ..StopCriteria::default()
+            },
+            ..SolverConfig::default()
+        };
+        let cancel = CancelToken::default();
+        let token = cancel.clone();
+        let history = evolutive_search_sampled(
+            &puzzle,
+            &config,
+            None,
+            &mut StdRng::seed_from_u64(SEED),
+            1,
+            &mut |event| {
+                if let SearchEvent::Progress(Progress { iteration: 3, .. }) = event {
+                    token.cancel();
+                }
+            },
+            &cancel,
+        );
+        assert!(history.cancelled);
+        assert_eq!(history.iterations, 3);
+        assert_eq!(history.best.len(), 3);
+        let (Ok(approach) | Err(approach)) = &history.winner;
+        assert!(puzzle.score(approach) <= history.best[2]);
+        assert!(!evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED)).cancelled);
+    }
+
+    // Test that the probabilities rise while the search stagnates, and decay once it improves.
+    #[test]
+    fn adaptive_rates() {
+        let adaptation = Adaptation::default();
+        let base = (0.6, 0.1);
+        assert_eq!(adaptation.adapt(base, base, 3), base);
+        let raised = adaptation.adapt(base, base, adaptation.patience);
+        assert!(raised.0 > base.0 && raised.0 <= 1.0);
+        assert!(raised.1 > base.1 && raised.1 <= adaptation.max_mutation);
+        let decayed = adaptation.adapt(base, raised, 0);
+        assert!(decayed.1 < raised.1 && decayed.1 > base.1);
+
+        let puzzle = tree_nonogram_puzzle();
+        let config = SolverConfig {
+            population_size: 20,
+            stop: StopCriteria {
+                max_iterations: 40,
+                ..StopCriteria::default()
+            },
+            adaptation: Some(adaptation),
+            ..SolverConfig::default()
+        };
+        let history = evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED));
+        assert_eq!(history.mutation_rates.len(), history.repaired.len());
+        assert!(history
+            .mutation_rates
+            .iter()
+            .all(|&rate| (config.mutation_probability..=adaptation.max_mutation).contains(&rate)));
+
+        let fixed = evolutive_search(
+            &puzzle,
+            &SolverConfig {
+                adaptation: None,
+                ..config
+            },
+            &mut StdRng::seed_from_u64(SEED),
+        );
+        assert!(fixed
+            .cross_rates
+            .iter()
+            .all(|&rate| rate == config.cross_probability));
+    }
+
+    // Test that the chromosomes are made of the lines with the fewest arrangements, and the
+    // results of a search on columns are given in rows.
+    #[test]
+    fn column_representation() {
+        let puzzle = NonogramPuzzle::from_solution(&NonogramSolution {
+            solution_grid: vec![vec![1; 6], vec![1, 0, 0, 0, 0, 0]],
+        });
+        assert_eq!(Representation::Auto.resolve(&puzzle), Representation::Rows);
+        assert_eq!(
+            Representation::Auto.resolve(&puzzle.transposed()),
+            Representation::Columns
+        );
+        assert_eq!(
+            Representation::Rows.resolve(&puzzle.transposed()),
+            Representation::Rows
+        );
+
+        let file = seeded_puzzle(4, 7, 2, SEED);
+        let puzzle = NonogramPuzzle::from_solution(&file.solution);
+        let config = SolverConfig {
+            population_size: 20,
+            representation: Representation::Columns,
+            ..SolverConfig::default()
+        };
+        let mut samples = Vec::new();
+        let history = evolutive_search_sampled(
+            &puzzle,
+            &config,
+            None,
+            &mut StdRng::seed_from_u64(SEED),
+            1,
+            &mut |event| {
+                if let SearchEvent::Sample(sample) = event {
+                    samples.push(sample);
+                }
+            },
+            &CancelToken::default(),
+        );
+        assert_eq!(history.representation, Representation::Columns);
+        let (Ok(solution) | Err(solution)) = &history.winner;
+        assert_eq!(
+            (solution.rows(), solution.cols()),
+            (puzzle.rows, puzzle.cols)
+        );
+        assert!(samples
+            .iter()
+            .all(|sample| sample.agreement.len() == puzzle.rows
+                && sample.agreement[0].len() == puzzle.cols));
+        if history.winner.is_ok() {
+            assert_eq!(puzzle.score(solution), 0);
+        }
+        // The winner is scored against the columns it was made of
+        assert_eq!(
+            history.winner_score(&puzzle, config.fitness),
+            history.best[history.iterations - 1]
+        );
+    }
+
+    // Test that each task gets its own generator, seeded in order from the search, so the builds
+    // with and without the `parallel` feature make the same runs.
+    #[test]
+    fn seeded_tasks() {
+        let mut rng = StdRng::seed_from_u64(SEED);
+        let mut seeds = StdRng::seed_from_u64(SEED);
+        let expected = (0..8)
+            .map(|index| (index, StdRng::seed_from_u64(seeds.gen()).gen::<u64>()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            seeded_map(8, &mut rng, |index, rng| (index, rng.gen::<u64>())),
+            expected
+        );
+
+        let mut items = vec![0; 8];
+        seeded_for_each(&mut items, &mut rng, |item, rng| *item = rng.gen::<u64>());
+        let expected = (0..8)
+            .map(|_| StdRng::seed_from_u64(seeds.gen()).gen::<u64>())
+            .collect::<Vec<_>>();
+        assert_eq!(items, expected);
+    }
+
+    // Test that the crossover operators are chosen by their weights, and that a search with the new
+    // operators alone still finds the solution.
+    #[test]
+    fn crossover_weights() {
+        let mut rng = StdRng::seed_from_u64(SEED);
+        let only_block = CrossoverWeights {
+            uniform: 0.0,
+            two_point: 0.0,
+            column: 0.0,
+            block: 1.0,
+            segment: 0.0,
+        };
+        assert!((0..50).all(|_| only_block.choose(&mut rng) == Crossover::Block));
+        let none = CrossoverWeights {
+            block: 0.0,
+            ..only_block
+        };
+        assert_eq!(none.choose(&mut rng), Crossover::Uniform);
+
+        let config = SolverConfig {
+            crossover: CrossoverWeights {
+                segment: 1.0,
+                ..only_block
+            },
+            ..Default::default()
+        };
+        let history = evolutive_search(&tree_nonogram_puzzle(), &config, &mut rng);
+        assert!(history.winner.is_ok());
+    }
+
+    // Test that the mutation operators are chosen by their weights, and that a search with the new
+    // operators alone still finds the solution.
+    #[test]
+    fn mutation_weights() {
+        let mut rng = StdRng::seed_from_u64(SEED);
+        let only_reshuffle = MutationWeights {
+            slide: 0.0,
+            reposition: 0.0,
+            reshuffle: 1.0,
+        };
+        assert!((0..50).all(|_| only_reshuffle.choose(&mut rng) == Mutation::Reshuffle));
+        let none = MutationWeights {
+            reshuffle: 0.0,
+            ..only_reshuffle
+        };
+        assert_eq!(none.choose(&mut rng), Mutation::Slide);
+
+        let config = SolverConfig {
+            mutation: MutationWeights {
+                reposition: 1.0,
+                ..only_reshuffle
+            },
+            ..Default::default()
+        };
+        let history = evolutive_search(&tree_nonogram_puzzle(), &config, &mut rng);
+        assert!(history.winner.is_ok());
+    }
+
+    // Test that the search stops by the first criterion reached, and records why.
+    #[test]
+    fn stop_criteria() {
+        let stop = StopCriteria {
+            target_score: 2,
+            max_millis: Some(100),
+            stagnation: Some(5),
+            ..StopCriteria::default()
+        };
+        assert_eq!(stop.reached(0, 9, 500.0), Some(StopReason::Solved));
+        assert_eq!(stop.reached(2, 9, 500.0), Some(StopReason::TargetScore));
+        assert_eq!(stop.reached(3, 5, 500.0), Some(StopReason::Stagnation));
+        assert_eq!(stop.reached(3, 4, 100.0), Some(StopReason::Timeout));
+        assert_eq!(stop.reached(3, 4, 99.0), None);
+        assert_eq!(StopCriteria::default().reached(3, 1000, 1e9), None);
+
+        let puzzle = tree_nonogram_puzzle();
+        let config = SolverConfig {
+            population_size: 10,
+            stop: StopCriteria {
+                target_score: usize::MAX,
+                ..StopCriteria::default()
+            },
+            ..SolverConfig::default()
+        };
+        let history = evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED));
+        assert_eq!(history.iterations, 1);
+        assert!(matches!(
+            history.stop_reason,
+            Some(StopReason::TargetScore | StopReason::Solved)
+        ));
+
+        let config = SolverConfig {
+            population_size: 10,
+            stop: StopCriteria {
+                max_iterations: 200,
+                stagnation: Some(3),
+                ..StopCriteria::default()
+            },
+            ..SolverConfig::default()
+        };
+        let history = evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED));
+        if history.stop_reason == Some(StopReason::Stagnation) {
+            let best = &history.best[history.iterations - 4..];
+            assert!(best.windows(2).all(|scores| scores[0] == scores[1]));
+        } else {
+            assert_eq!(history.stop_reason, Some(StopReason::Solved));
+        }
+    }
+
+    // Test the number of offspring bred by each replacement mode.
+    #[test]
+    fn replacement_offspring_size() {
+        assert_eq!(Replacement::Plus.offspring_size(10), 10);
+        assert_eq!(
+            Replacement::Generational { elitism: 3 }.offspring_size(10),
+            7
+        );
+        assert_eq!(
+            Replacement::Generational { elitism: 30 }.offspring_size(10),
+            0
+        );
+        assert_eq!(
+            Replacement::SteadyState { replaced: 4 }.offspring_size(10),
+            4
+        );
+    }
+
+    // Test that the diversity is the mean Hamming distance between every pair of individuals, and
+    // is recorded for every generation.
+    #[test]
+    fn population_diversity() {
+        let empty = tree_empty_nonogram_solution();
+        let mut painted = empty.clone();
+        painted.solution_grid[0][0] = 1;
+        painted.solution_grid[1][1] = 1;
+        assert_eq!(History::get_diversity(&vec![(empty.clone(), 0); 4]), 0.0);
+        // The 2 distinct pairs of the 3 differ in 2 cells
+        let population = vec![(empty.clone(), 0), (empty, 0), (painted, 0)];
+        assert!((History::get_diversity(&population) - 4.0 / 3.0).abs() < 1e-9);
+
+        let config = SolverConfig {
+            population_size: 20,
+            stop: StopCriteria {
+                max_iterations: 10,
+                ..StopCriteria::default()
+            },
+            ..SolverConfig::default()
+        };
+        let history = evolutive_search(
+            &tree_nonogram_puzzle(),
+            &config,
+            &mut StdRng::seed_from_u64(SEED),
+        );
+        assert_eq!(history.diversity.len(), history.iterations);
+        assert!(history.diversity[0] > 0.0);
+    }
+
+    // Test that the crowding keeps the population size and never worsens the best score, keeping
+    // the population more diverse.
+    #[test]
+    fn crowding_keeps_diversity() {
+        let puzzle = NonogramPuzzle::from_solution(&seeded_puzzle(12, 12, 3, 7).solution);
+        let diversity = |crowding| {
+            let config = SolverConfig {
+                population_size: 40,
+                stop: StopCriteria {
+                    max_iterations: 30,
+                    ..StopCriteria::default()
+                },
+                crowding,
+                ..SolverConfig::default()
+            };
+            let history = evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED));
+            assert!(history.best.windows(2).all(|scores| scores[1] <= scores[0]));
+            history.diversity[history.iterations - 1]
+        };
+        assert!(diversity(Some(4)) > diversity(None));
+    }
+
+    // Test that the individuals are sorted into Pareto fronts, with the ends of each front kept by
+    // their crowding distance.
+    #[test]
+    fn non_dominated_sorting() {
+        let objectives = [(0, 4), (1, 1), (2, 2), (4, 0), (2, 2), (3, 3)];
+        assert_eq!(
+            non_dominated_fronts(&objectives),
+            vec![vec![0, 1, 3], vec![2, 4], vec![5]]
+        );
+        let distances = crowding_distances(&[0, 1, 3], &objectives);
+        assert!(distances[0].is_infinite() && distances[2].is_infinite());
+        assert!((distances[1] - 2.0).abs() < 1e-9);
+    }
+
+    // Test that the NSGA-II mode keeps the best score, and records a front of several trade-off
+    // candidates, none of them dominating another one, given in the rows of the puzzle.
+    #[test]
+    fn pareto_search() {
+        let puzzle = NonogramPuzzle::from_solution(&seeded_puzzle(12, 12, 3, 7).solution);
+        for representation in [Representation::Rows, Representation::Columns] {
+            let config = SolverConfig {
+                population_size: 30,
+                stop: StopCriteria {
+                    max_iterations: 20,
+                    ..StopCriteria::default()
+                },
+                replacement: Replacement::Pareto,
+                representation,
+                fitness: Fitness::Symmetric,
+                ..SolverConfig::default()
+            };
+            let history = evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED));
+            assert_eq!(history.replacement.to_string(), "nsga-ii");
+            assert!(history.best.windows(2).all(|scores| scores[1] <= scores[0]));
+            assert!(history.pareto_front.len() > 1);
+            for a in &history.pareto_front {
+                for b in &history.pareto_front {
+                    let dominates = a.row_violations <= b.row_violations
+                        && a.column_violations <= b.column_violations;
+                    assert!(!dominates || a == b);
+                }
+            }
+            for candidate in &history.pareto_front {
+                assert_eq!(
+                    puzzle.objectives(&candidate.solution),
+                    (candidate.row_violations, candidate.column_violations)
+                );
+            }
+        }
+    }
+
+    // Test that every replacement mode keeps the population size and records the mode in the history.
+    #[test]
+    fn replacement_modes_search() {
+        let puzzle = tree_nonogram_puzzle();
+        for replacement in [
+            Replacement::Plus,
+            Replacement::Generational { elitism: 2 },
+            Replacement::SteadyState { replaced: 5 },
+        ] {
+            let config = SolverConfig {
+                population_size: 20,
+                stop: StopCriteria {
+                    max_iterations: 15,
+                    ..StopCriteria::default()
+                },
+                replacement,
+                ..SolverConfig::default()
+            };
+            let history = evolutive_search(&puzzle, &config, &mut StdRng::seed_from_u64(SEED));
+            assert_eq!(history.replacement, replacement);
+            assert!(history.best.windows(2).all(|scores| scores[1] <= scores[0]));
+        }
+    }
+
+    // Test that a search continued from the final population of another one starts from its best
+    // score at least, in every representation, and that their histories are joined.
+    #[test]
+    fn resumed_search() {
+        let puzzle = NonogramPuzzle::from_solution(&seeded_puzzle(12, 12, 3, 7).solution);
+        for representation in [Representation::Rows, Representation::Columns] {
+            let config = SolverConfig {
+                population_size: 20,
+                representation,
+                stop: StopCriteria {
+                    max_iterations: 5,
+                    ..StopCriteria::default()
+                },
+                ..SolverConfig::default()
+            };
+            let mut rng = StdRng::seed_from_u64(SEED);
+            let previous = evolutive_search(&puzzle, &config, &mut rng);
+            assert_eq!(previous.population.len(), 20);
+            let (Ok(best) | Err(best)) = &previous.winner;
+            assert_eq!(&previous.population[0], best);
+            assert!(previous.best[4] > 0);
+            let start = WarmStart {
+                grid: tree_empty_nonogram_solution(),
+                pinned: HashSet::new(),
+                population: previous.population.clone(),
+            };
+            let mut history = evolutive_search_sampled(
+                &puzzle,
+                &config,
+                Some(&start),
+                &mut rng,
+                0,
+                &mut |_| {},
+                &CancelToken::default(),
+            );
+            assert!(history.best[0] <= previous.best[4], "{representation:?}");
+            history.prepend(&previous);
+            assert_eq!(history.iterations, 10);
+            assert_eq!(history.best.len(), 10);
+            assert_eq!(history.best[..5], previous.best[..]);
+        }
+    }
+}
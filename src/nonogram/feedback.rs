@@ -0,0 +1,136 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the journal actions that trigger the feedback.
+use super::journal::{Action, Tool};
+
+// Import the definitions of the solution grids checked for mistakes.
+use super::definitions::{NonogramSolution, BACKGROUND};
+
+/// A feedback event, played as a sound effect and a vibration.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Feedback {
+    /// Some cells were painted.
+    Paint,
+    /// A painted cell doesn't match the solution of the puzzle.
+    Mistake,
+    /// The puzzle was completed.
+    Completion,
+}
+
+impl Feedback {
+    /// Returns the tones of the sound effect, as `(frequency in Hz, duration in seconds)`.
+    pub fn tones(self) -> &'static [(f32, f32)] {
+        match self {
+            Feedback::Paint => &[(660.0, 0.04)],
+            Feedback::Mistake => &[(220.0, 0.12), (165.0, 0.2)],
+            Feedback::Completion => &[
+                (523.25, 0.12),
+                (659.25, 0.12),
+                (783.99, 0.12),
+                (1046.5, 0.4),
+            ],
+        }
+    }
+
+    /// Returns the vibration pattern, as alternating vibration and pause durations in milliseconds.
+    pub fn vibration(self) -> &'static [u32] {
+        match self {
+            Feedback::Paint => &[10],
+            Feedback::Mistake => &[60, 40, 60],
+            Feedback::Completion => &[100, 50, 100, 50, 200],
+        }
+    }
+
+    /// Classifies an action recorded in the journal.
+    ///
+    /// # Arguments
+    ///
+    /// * `action` - The recorded action.
+    /// * `reference` - The solution of the puzzle being solved, to detect mistakes (if any).
+    ///
+    /// # Returns
+    ///
+    /// `Mistake` if a cell was painted with a color different from the reference, `Paint` if cells
+    /// were painted with the brush or line tools, or `None` for other actions.
+    pub fn of_action(action: &Action, reference: Option<&NonogramSolution>) -> Option<Self> {
+        let Action::Cells {
+            tool: Tool::Brush | Tool::Line,
+            changes,
+        } = action
+        else {
+            return None;
+        };
+        let mistake = reference.is_some_and(|reference| {
+            changes.iter().any(|change| {
+                let expected = reference
+                    .solution_grid
+                    .get(change.row)
+                    .and_then(|row| row.get(change.col))
+                    .copied()
+                    .unwrap_or(BACKGROUND);
+                change.color != BACKGROUND && change.color != expected
+            })
+        });
+        Some(if mistake {
+            Feedback::Mistake
+        } else {
+            Feedback::Paint
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::puzzles::{tree_empty_nonogram_solution, tree_nonogram_file};
+
+    use super::*;
+
+    // Test that painting is classified as a mistake only when it contradicts the reference.
+    #[test]
+    fn classify_painting_actions() {
+        let reference = tree_nonogram_file().solution;
+        let empty = tree_empty_nonogram_solution();
+        let mut painted = empty.clone();
+        let (row, col) = (0..5)
+            .flat_map(|row| (0..5).map(move |col| (row, col)))
+            .find(|&(row, col)| reference.solution_grid[row][col] != BACKGROUND)
+            .unwrap();
+        painted.solution_grid[row][col] = reference.solution_grid[row][col];
+        let good = Action::edit(Tool::Brush, &empty, &painted).unwrap();
+        assert_eq!(
+            Feedback::of_action(&good, Some(&reference)),
+            Some(Feedback::Paint)
+        );
+
+        painted.solution_grid[row][col] = reference.solution_grid[row][col] % 2 + 1;
+        let bad = Action::edit(Tool::Line, &empty, &painted).unwrap();
+        assert_eq!(
+            Feedback::of_action(&bad, Some(&reference)),
+            Some(Feedback::Mistake)
+        );
+        assert_eq!(Feedback::of_action(&bad, None), Some(Feedback::Paint));
+
+        let cleared = Action::edit(Tool::Clear, &painted, &empty).unwrap();
+        assert_eq!(Feedback::of_action(&cleared, Some(&reference)), None);
+    }
+}
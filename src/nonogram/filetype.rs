@@ -0,0 +1,328 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the paths of the files opened from the command line, and of the files registering the
+// file type
+use std::path::Path;
+
+// Import the reading of the Nonogram files embedded in pictures
+use super::picture::read_metadata;
+
+/// The extension of the Nonogram files.
+pub const NGRAM_EXTENSION: &str = ".ngram";
+
+/// The MIME type of the Nonogram files, registered on desktop platforms.
+pub const NGRAM_MIME: &str = "application/x-ngram";
+
+/// Strips an extension from a filename, ignoring the case of the extension.
+///
+/// # Arguments
+///
+/// * `name` - The filename.
+/// * `extension` - The extension, with its leading dot, such as `.ngram`.
+///
+/// # Returns
+///
+/// The filename without the extension, or `None` if it doesn't end with it.
+pub fn strip_extension<'a>(name: &'a str, extension: &str) -> Option<&'a str> {
+    let stem = name.len().checked_sub(extension.len())?;
+    (name.is_char_boundary(stem) && name[stem..].eq_ignore_ascii_case(extension))
+        .then(|| &name[..stem])
+}
+
+/// Checks whether a filename ends with an extension, ignoring the case of the extension.
+///
+/// # Arguments
+///
+/// * `name` - The filename.
+/// * `extension` - The extension, with its leading dot, such as `.png`.
+pub fn has_extension(name: &str, extension: &str) -> bool {
+    strip_extension(name, extension).is_some()
+}
+
+/// Checks whether a filename is the one of a Nonogram file, such as `tree.ngram` or `TREE.NGRAM`
+/// (unlike `tree.ngram.png` or `tree.ngrampal`).
+pub fn is_ngram(name: &str) -> bool {
+    has_extension(name, NGRAM_EXTENSION)
+}
+
+/// Strips the `.ngram` extension from a filename once, ignoring its case.
+///
+/// # Returns
+///
+/// The filename without the extension, or the whole filename if it doesn't have it.
+pub fn ngram_stem(name: &str) -> &str {
+    strip_extension(name, NGRAM_EXTENSION).unwrap_or(name)
+}
+
+/// Returns the filename a file is saved to as a Nonogram file, normalizing the case of the
+/// `.ngram` extension and replacing the `.png` extension of the pictures embedding the file.
+///
+/// # Arguments
+///
+/// * `name` - The filename, such as `tree.NGRAM`, `tree.png` or `tree`.
+///
+/// # Returns
+///
+/// The filename with the `.ngram` extension, such as `tree.ngram`.
+pub fn ngram_filename(name: &str) -> String {
+    let stem = strip_extension(name, NGRAM_EXTENSION)
+        .or_else(|| strip_extension(name, ".png"))
+        .unwrap_or(name);
+    format!("{stem}{NGRAM_EXTENSION}")
+}
+
+/// The Nonogram file the application was launched with, such as a file opened from the file
+/// manager once the file type is registered (see `register_file_type`).
+#[derive(Clone, PartialEq, Debug)]
+pub struct LaunchFile {
+    /// The name of the file, without its directories.
+    pub filename: String,
+    /// The contents of the file, as JSON, or the reason it couldn't be read.
+    pub json: Result<String, String>,
+}
+
+impl LaunchFile {
+    /// Reads the Nonogram file passed on the command line, if any, from a `.ngram` file or
+    /// embedded in a PNG picture.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The command line arguments, starting with the executable.
+    ///
+    /// # Returns
+    ///
+    /// The first argument naming a Nonogram file or a picture, read, or `None` if there isn't
+    /// any. A file that couldn't be read is still returned, with the error, to report it.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Option<Self> {
+        let path = args
+            .into_iter()
+            .skip(1)
+            .find(|arg| is_ngram(arg) || has_extension(arg, ".png"))?;
+        let path = Path::new(&path);
+        let filename = path.file_name()?.to_string_lossy().to_string();
+        let json = if has_extension(&filename, ".png") {
+            std::fs::read(path)
+                .map_err(|err| err.to_string())
+                .and_then(|bytes| read_metadata(&bytes).map_err(|err| err.to_string()))
+        } else {
+            std::fs::read_to_string(path).map_err(|err| err.to_string())
+        };
+        Some(Self { filename, json })
+    }
+}
+
+/// Returns the shared MIME-info package declaring the Nonogram files, for the desktops of Linux.
+/// The globs of shared MIME-info are case-insensitive.
+pub fn mime_package() -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<mime-info xmlns="http://www.freedesktop.org/standards/shared-mime-info">
+  <mime-type type="{NGRAM_MIME}">
+    <comment>Nonogram puzzle</comment>
+    <glob pattern="*{NGRAM_EXTENSION}"/>
+  </mime-type>
+</mime-info>
+"#
+    )
+}
+
+/// Returns the desktop entry opening the Nonogram files with an executable, for the desktops of
+/// Linux.
+///
+/// # Arguments
+///
+/// * `executable` - The path of the executable of the application.
+pub fn desktop_entry(executable: &Path) -> String {
+    // Quoted as required by the desktop entry specification
+    let mut quoted = String::from("\"");
+    for char in executable.to_string_lossy().chars() {
+        match char {
+            '"' | '`' | '$' | '\\' => quoted.extend(['\\', char]),
+            '%' => quoted.push_str("%%"),
+            _ => quoted.push(char),
+        }
+    }
+    quoted.push('"');
+    format!(
+        "[Desktop Entry]\nType=Application\nName=ngram\nComment=Nonogram solver\nExec={quoted} %f\nMimeType={NGRAM_MIME};\nCategories=Game;LogicGame;\nTerminal=false\n"
+    )
+}
+
+/// Registers the Nonogram file type for the user, so opening a `.ngram` file from the file
+/// manager launches the application with it.
+///
+/// On Linux, a shared MIME-info package and a desktop entry are written in the data directory of
+/// the user, only if they changed, and the databases of the desktop are updated. On Windows, the
+/// extension is associated in the registry of the user. Nothing is registered on the other
+/// platforms.
+///
+/// # Arguments
+///
+/// * `executable` - The path of the executable of the application.
+///
+/// # Returns
+///
+/// `true` if the file type was registered again, `false` if it was already registered.
+#[cfg(not(any(target_arch = "wasm32", target_os = "android")))]
+pub fn register_file_type(executable: &Path) -> std::io::Result<bool> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::process::Command;
+
+        let data_dir = dirs::data_dir().ok_or(std::io::ErrorKind::NotFound)?;
+        let files = [
+            (data_dir.join("mime/packages/ngram.xml"), mime_package()),
+            (
+                data_dir.join("applications/ngram.desktop"),
+                desktop_entry(executable),
+            ),
+        ];
+        if files
+            .iter()
+            .all(|(path, contents)| std::fs::read_to_string(path).is_ok_and(|old| old == *contents))
+        {
+            return Ok(false);
+        }
+        for (path, contents) in &files {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, contents)?;
+        }
+        // The databases are only caches, the desktops without these tools read the files directly
+        let _ = Command::new("update-mime-database")
+            .arg(data_dir.join("mime"))
+            .status();
+        let _ = Command::new("update-desktop-database")
+            .arg(data_dir.join("applications"))
+            .status();
+        Ok(true)
+    }
+    #[cfg(target_os = "windows")]
+    {
+        use std::process::Command;
+
+        let command = format!("\"{}\" \"%1\"", executable.display());
+        let keys = [
+            (r"HKCU\Software\Classes\.ngram", None, "ngram.puzzle"),
+            (
+                r"HKCU\Software\Classes\.ngram",
+                Some("Content Type"),
+                NGRAM_MIME,
+            ),
+            (
+                r"HKCU\Software\Classes\ngram.puzzle",
+                None,
+                "Nonogram puzzle",
+            ),
+            (
+                r"HKCU\Software\Classes\ngram.puzzle\shell\open\command",
+                None,
+                command.as_str(),
+            ),
+        ];
+        for (key, value, data) in keys {
+            let mut reg = Command::new("reg");
+            reg.args(["add", key]);
+            match value {
+                Some(value) => reg.args(["/v", value]),
+                None => reg.arg("/ve"),
+            };
+            if !reg.args(["/d", data, "/f"]).status()?.success() {
+                return Err(std::io::Error::other(format!("Couldn't write {key}")));
+            }
+        }
+        Ok(true)
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "windows")))]
+    {
+        let _ = executable;
+        Ok(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nonogram::picture::PictureError;
+
+    // Test that the extensions are recognized and normalized ignoring their case, only at the end
+    // of the filenames.
+    #[test]
+    fn ngram_extensions() {
+        assert!(is_ngram("tree.ngram"));
+        assert!(is_ngram("TREE.NGRAM"));
+        assert!(!is_ngram("tree.ngram.png"));
+        assert!(!is_ngram("tree.ngrampal"));
+        assert!(!is_ngram("ngram"));
+        assert_eq!(ngram_stem("tree.Ngram"), "tree");
+        assert_eq!(ngram_stem("tree.ngram.ngram"), "tree.ngram");
+        assert_eq!(ngram_stem("my.ngram-puzzles/tree"), "my.ngram-puzzles/tree");
+        assert_eq!(ngram_filename("tree.NGRAM"), "tree.ngram");
+        assert_eq!(ngram_filename("tree.PNG"), "tree.ngram");
+        assert_eq!(ngram_filename("tree"), "tree.ngram");
+        assert_eq!(ngram_filename("árbol.ngram"), "árbol.ngram");
+        assert_eq!(strip_extension("índex.HTML", ".html"), Some("índex"));
+        assert_eq!(strip_extension("é", ".png"), None);
+    }
+
+    // Test that the Nonogram file passed on the command line is read, skipping the executable and
+    // the other arguments, and that the files that can't be read keep the error.
+    #[test]
+    fn launch_file_from_args() {
+        let dir = std::env::temp_dir().join(format!("ngram-launch-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("Tree.NGRAM");
+        std::fs::write(&path, "{}").unwrap();
+        let args = |file: &str| {
+            vec![
+                String::from("ngram.ngram"),
+                String::from("--verbose"),
+                file.to_string(),
+            ]
+        };
+
+        let launch = LaunchFile::from_args(args(&path.to_string_lossy())).unwrap();
+        assert_eq!(launch.filename, "Tree.NGRAM");
+        assert_eq!(launch.json, Ok(String::from("{}")));
+        let missing = LaunchFile::from_args(args(&dir.join("missing.ngram").to_string_lossy()));
+        let missing = missing.unwrap();
+        assert_eq!(missing.filename, "missing.ngram");
+        assert!(missing.json.is_err());
+        let picture = dir.join("tree.png");
+        std::fs::write(&picture, "{}").unwrap();
+        let picture = LaunchFile::from_args(args(&picture.to_string_lossy())).unwrap();
+        assert_eq!(picture.json, Err(PictureError::NotPng.to_string()));
+        assert_eq!(LaunchFile::from_args(args("tree.txt")), None);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    // Test that the desktop entry opens the files with the quoted executable.
+    #[test]
+    fn desktop_entry_quotes_executable() {
+        let entry = desktop_entry(Path::new("/opt/my $apps/100%/ngram"));
+        assert!(entry.contains("Exec=\"/opt/my \\$apps/100%%/ngram\" %f\n"));
+        assert!(entry.contains(&format!("MimeType={NGRAM_MIME};")));
+        assert!(mime_package().contains("<glob pattern=\"*.ngram\"/>"));
+    }
+}
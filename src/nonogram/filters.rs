@@ -0,0 +1,169 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the definitions of the solution grid transformed by the filters.
+use super::definitions::{NonogramSolution, BACKGROUND};
+
+// Import serialization support so filters can be stored in commands and macros.
+use serde::{Deserialize, Serialize};
+
+// Import a map to count the colors around a cell.
+use std::collections::HashMap;
+
+/// A cleanup filter removing noise from a solution grid, e.g. after importing an image.
+///
+/// Filters are pure transformations: they return a new grid, so the result can be previewed
+/// before applying it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum Filter {
+    /// Clears the colored cells without orthogonal neighbors of the same color.
+    RemoveIsolated,
+    /// Fills the background cells whose orthogonal neighbors all share the same color.
+    FillHoles,
+    /// Replaces each cell with the color occupying most of its 3x3 neighborhood.
+    Smooth,
+}
+
+impl Filter {
+    /// Every available filter, in the order they're shown to the user.
+    pub const ALL: [Filter; 3] = [Filter::RemoveIsolated, Filter::FillHoles, Filter::Smooth];
+
+    /// Applies the filter to a solution grid.
+    ///
+    /// Every cell is computed from the original grid, so the result doesn't depend on the
+    /// order in which the cells are visited.
+    ///
+    /// # Arguments
+    ///
+    /// * `solution` - The solution grid to filter.
+    ///
+    /// # Returns
+    ///
+    /// The filtered solution grid.
+    pub fn apply(&self, solution: &NonogramSolution) -> NonogramSolution {
+        let grid = &solution.solution_grid;
+        let solution_grid = (0..solution.rows())
+            .map(|row| {
+                (0..solution.cols())
+                    .map(|col| {
+                        match self {
+                            Filter::RemoveIsolated => remove_isolated(solution, row, col),
+                            Filter::FillHoles => fill_hole(solution, row, col),
+                            Filter::Smooth => smooth(solution, row, col),
+                        }
+                        .unwrap_or(grid[row][col])
+                    })
+                    .collect()
+            })
+            .collect();
+        NonogramSolution { solution_grid }
+    }
+}
+
+/// Returns the colors of the cells inside the grid around `(row, col)`.
+///
+/// # Arguments
+///
+/// * `solution` - The solution grid.
+/// * `row`, `col` - The coordinate of the central cell.
+/// * `diagonals` - Whether the diagonal neighbors (and the central cell) are included.
+fn neighbors(solution: &NonogramSolution, row: usize, col: usize, diagonals: bool) -> Vec<usize> {
+    let mut colors = Vec::new();
+    for dy in -1isize..=1 {
+        for dx in -1isize..=1 {
+            if !diagonals && dx.abs() + dy.abs() != 1 {
+                continue;
+            }
+            let (y, x) = (row as isize + dy, col as isize + dx);
+            if (0..solution.rows() as isize).contains(&y)
+                && (0..solution.cols() as isize).contains(&x)
+            {
+                colors.push(solution.solution_grid[y as usize][x as usize]);
+            }
+        }
+    }
+    colors
+}
+
+/// Clears the cell if it's a colored cell without orthogonal neighbors of its color.
+fn remove_isolated(solution: &NonogramSolution, row: usize, col: usize) -> Option<usize> {
+    let color = solution.solution_grid[row][col];
+    (color != BACKGROUND && !neighbors(solution, row, col, false).contains(&color))
+        .then_some(BACKGROUND)
+}
+
+/// Fills the cell if it's a background cell whose orthogonal neighbors share the same color.
+fn fill_hole(solution: &NonogramSolution, row: usize, col: usize) -> Option<usize> {
+    if solution.solution_grid[row][col] != BACKGROUND {
+        return None;
+    }
+    let colors = neighbors(solution, row, col, false);
+    let color = *colors.first()?;
+    (color != BACKGROUND && colors.iter().all(|&other| other == color)).then_some(color)
+}
+
+/// Returns the color occupying more than half of the 3x3 neighborhood of the cell, if any.
+fn smooth(solution: &NonogramSolution, row: usize, col: usize) -> Option<usize> {
+    let colors = neighbors(solution, row, col, true);
+    let mut counts = HashMap::new();
+    for &color in &colors {
+        *counts.entry(color).or_insert(0) += 1;
+    }
+    counts
+        .into_iter()
+        .find(|&(_, count)| 2 * count > colors.len())
+        .map(|(color, _)| color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Builds a solution grid from its rows.
+    fn grid(solution_grid: Vec<Vec<usize>>) -> NonogramSolution {
+        NonogramSolution { solution_grid }
+    }
+
+    // Test that isolated cells are removed while connected ones are preserved.
+    #[test]
+    fn remove_isolated_cells() {
+        let noisy = grid(vec![vec![1, 0, 0, 2], vec![0, 0, 0, 2], vec![0, 3, 0, 0]]);
+        let clean = grid(vec![vec![0, 0, 0, 2], vec![0, 0, 0, 2], vec![0, 0, 0, 0]]);
+        assert_eq!(Filter::RemoveIsolated.apply(&noisy), clean);
+    }
+
+    // Test that single-cell holes are filled, including those on the border of the grid.
+    #[test]
+    fn fill_single_cell_holes() {
+        let holed = grid(vec![vec![1, 1, 1, 0], vec![1, 0, 1, 2], vec![1, 1, 1, 0]]);
+        let filled = grid(vec![vec![1, 1, 1, 0], vec![1, 1, 1, 2], vec![1, 1, 1, 0]]);
+        assert_eq!(Filter::FillHoles.apply(&holed), filled);
+    }
+
+    // Test that smoothing replaces the cells disagreeing with most of their neighborhood.
+    #[test]
+    fn majority_smoothing() {
+        let noisy = grid(vec![vec![1, 1, 1], vec![1, 2, 1], vec![0, 1, 1]]);
+        let smooth = grid(vec![vec![1, 1, 1], vec![1, 1, 1], vec![0, 1, 1]]);
+        assert_eq!(Filter::Smooth.apply(&noisy), smooth);
+    }
+}
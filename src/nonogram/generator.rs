@@ -0,0 +1,110 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the Nonogram structures generated.
+use super::definitions::{NonogramFile, NonogramPuzzle, NonogramSolution, BACKGROUND};
+
+// Import the palette of new puzzles.
+use super::puzzles::{new_nonogram_file, GridTemplate};
+
+// Import random number generation, seeded to reproduce the puzzles.
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// The number of random grids tried before falling back to a filled grid.
+const MAX_ATTEMPTS: usize = 1000;
+
+/// The most guesses of the exact solver deciding whether a random grid has a single solution,
+/// so the grids too ambiguous to decide quickly are skipped.
+const MAX_GUESSES: usize = 100;
+
+/// Generates a deterministic puzzle of a size, for tests and benchmarks.
+///
+/// Random grids are drawn until the exact solver proves that the puzzle of one has a single
+/// solution, failing to find a second one (see `NonogramPuzzle::is_unique_within`). The same
+/// arguments always produce the same puzzle.
+///
+/// # Arguments
+///
+/// * `rows` - The number of rows of the grid.
+/// * `cols` - The number of columns of the grid.
+/// * `colors` - The number of colors besides the background.
+/// * `seed` - The seed of the random grids and colors.
+///
+/// # Returns
+///
+/// A `NonogramFile` with the solution and its palette; the puzzle is obtained with
+/// `NonogramPuzzle::from_solution`.
+pub fn seeded_puzzle(rows: usize, cols: usize, colors: usize, seed: u64) -> NonogramFile {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let colors = colors.max(1);
+    let mut file = new_nonogram_file(rows, cols, colors, GridTemplate::Empty, &mut rng);
+    for _ in 0..MAX_ATTEMPTS {
+        let density = rng.gen_range(0.5..0.8);
+        let solution = NonogramSolution {
+            solution_grid: (0..rows)
+                .map(|_| {
+                    (0..cols)
+                        .map(|_| {
+                            if rng.gen_bool(density) {
+                                rng.gen_range(1..=colors)
+                            } else {
+                                BACKGROUND
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+        };
+        if NonogramPuzzle::from_solution(&solution).is_unique_within(MAX_GUESSES) == Some(true) {
+            file.solution = solution;
+            return file;
+        }
+    }
+    // A grid filled with a single color has a single solution.
+    file.solution = NonogramSolution {
+        solution_grid: vec![vec![1; cols]; rows],
+    };
+    file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the generated puzzles are reproducible and have a single solution.
+    #[test]
+    fn seeded_puzzles() {
+        for seed in 0..10 {
+            let file = seeded_puzzle(10, 8, 1 + seed as usize % 3, seed);
+            let again = seeded_puzzle(10, 8, 1 + seed as usize % 3, seed);
+            assert_eq!(file.solution, again.solution);
+            assert_eq!(file.palette.color_palette, again.palette.color_palette);
+            assert_eq!(file.solution.solution_grid.len(), 10);
+            assert_eq!(file.solution.solution_grid[0].len(), 8);
+            assert!(NonogramPuzzle::from_solution(&file.solution).is_unique());
+        }
+        assert_ne!(
+            seeded_puzzle(10, 8, 2, 1).solution,
+            seeded_puzzle(10, 8, 2, 2).solution
+        );
+    }
+}
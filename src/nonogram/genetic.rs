@@ -22,7 +22,8 @@
 
 use crate::nsol;
 
-use super::definitions::{NonogramPuzzle, NonogramSegment, NonogramSolution, BACKGROUND};
+use super::definitions::{Fitness, NonogramPuzzle, NonogramSegment, NonogramSolution, BACKGROUND};
+use super::line::{sample_line, CellState};
 use rand::{rngs::StdRng, seq::SliceRandom, Rng};
 use std::mem;
 
@@ -31,52 +32,407 @@ impl NonogramPuzzle {
         let solution_grid = self
             .row_constraints
             .iter()
-            .map(|row_segments| {
-                let row_segments_length = row_segments
+            .map(|row_segments| self.new_row_chromosome(row_segments, rng))
+            .collect();
+        NonogramSolution { solution_grid }
+    }
+
+    // Builds a chromosome close to a grid painted by hand: the rows matching their clue are kept,
+    // and the rest are sampled around their painted cells, releasing painted cells at random
+    // until an arrangement of the clue fits them.
+    // The pinned cells, given by row (`None` for the free cells), are never released.
+    pub fn warm_chromosome_solution(
+        &self,
+        start: &NonogramSolution,
+        pinned: &[Vec<Option<usize>>],
+        rng: &mut StdRng,
+    ) -> NonogramSolution {
+        let colors = self.chromosome_colors();
+        let solution_grid = self
+            .row_constraints
+            .iter()
+            .enumerate()
+            .map(|(row, row_segments)| {
+                let pins = pinned.get(row).map_or(&[][..], Vec::as_slice);
+                let Some(painted) = start
+                    .solution_grid
+                    .get(row)
+                    .filter(|painted| painted.len() == self.cols)
+                else {
+                    return self.pinned_row_chromosome(row_segments, pins, rng);
+                };
+                if Self::row_segments(painted) == *row_segments && Self::keeps_pins(painted, pins) {
+                    return painted.clone();
+                }
+                let mut line = painted
                     .iter()
-                    .map(|segment| segment.length)
-                    .sum::<usize>();
-                let required_spaces = row_segments
-                    .windows(2)
-                    .filter(|segments| segments[0].color == segments[1].color)
-                    .count();
-                let chromosome_length = self.cols;
-                let mut remaining_spaces =
-                    chromosome_length - row_segments_length - required_spaces;
-                let mut row_chromosome = Vec::with_capacity(chromosome_length);
-                for (i, segment) in row_segments.iter().enumerate() {
-                    if rng.gen_bool(0.5) {
-                        let gap_size = rng.gen_range(0..=remaining_spaces);
-                        remaining_spaces -= gap_size;
-                        if gap_size != 0 {
-                            let mut gap_segment = vec![BACKGROUND; gap_size];
-                            row_chromosome.append(&mut gap_segment);
-                        }
-                    }
-                    let color = segment.color;
-                    let mut segment = vec![segment.color; segment.length];
-                    row_chromosome.append(&mut segment);
-                    if let Some(next_segment) = row_segments.get(i + 1) {
-                        if next_segment.color == color {
-                            row_chromosome.push(BACKGROUND);
-                        }
+                    .map(|&color| match color {
+                        BACKGROUND => CellState::any(colors),
+                        color if color < colors => CellState::known(color),
+                        _ => CellState::any(colors),
+                    })
+                    .collect::<Vec<_>>();
+                for (col, pin) in pins.iter().enumerate() {
+                    if let Some(color) = pin.filter(|&color| color < colors) {
+                        line[col] = CellState::known(color);
                     }
                 }
-                if remaining_spaces != 0 {
-                    let mut gap_segment = vec![BACKGROUND; remaining_spaces];
-                    row_chromosome.append(&mut gap_segment);
+                let mut fixed = (0..self.cols)
+                    .filter(|&col| line[col].color().is_some())
+                    .filter(|&col| pins.get(col).copied().flatten().is_none())
+                    .collect::<Vec<_>>();
+                fixed.shuffle(rng);
+                loop {
+                    if let Some(sampled) = sample_line(row_segments, &line, rng) {
+                        return sampled;
+                    }
+                    let Some(col) = fixed.pop() else {
+                        return self.pinned_row_chromosome(row_segments, pins, rng);
+                    };
+                    line[col] = CellState::any(colors);
                 }
-                row_chromosome
             })
             .collect();
         NonogramSolution { solution_grid }
     }
 
-    pub fn score(&self, candidate: &NonogramSolution) -> usize {
+    // Builds a chromosome keeping the colors of the pinned cells, given by row (`None` for the
+    // free cells).
+    pub fn pinned_chromosome_solution(
+        &self,
+        pinned: &[Vec<Option<usize>>],
+        rng: &mut StdRng,
+    ) -> NonogramSolution {
+        let solution_grid = self
+            .row_constraints
+            .iter()
+            .enumerate()
+            .map(|(row, row_segments)| {
+                let pins = pinned.get(row).map_or(&[][..], Vec::as_slice);
+                self.pinned_row_chromosome(row_segments, pins, rng)
+            })
+            .collect();
+        NonogramSolution { solution_grid }
+    }
+
+    // Samples a row around its pinned cells, or a random row when the pins don't fit its clue.
+    pub fn pinned_row_chromosome(
+        &self,
+        row_segments: &[NonogramSegment],
+        pins: &[Option<usize>],
+        rng: &mut StdRng,
+    ) -> Vec<usize> {
+        if pins.iter().all(Option::is_none) {
+            return self.new_row_chromosome(row_segments, rng);
+        }
+        let colors = self.chromosome_colors();
+        let line = (0..self.cols)
+            .map(|col| match pins.get(col).copied().flatten() {
+                Some(color) if color < colors => CellState::known(color),
+                _ => CellState::any(colors),
+            })
+            .collect::<Vec<_>>();
+        sample_line(row_segments, &line, rng)
+            .unwrap_or_else(|| self.new_row_chromosome(row_segments, rng))
+    }
+
+    // Checks whether a row keeps the colors of its pinned cells.
+    pub fn keeps_pins(row: &[usize], pins: &[Option<usize>]) -> bool {
+        row.iter()
+            .zip(pins)
+            .all(|(&color, pin)| pin.is_none_or(|pin| pin == color))
+    }
+
+    // Restores the rows of a mutated chromosome which changed a pinned cell, so the mutations
+    // never change them.
+    pub fn restore_pinned_rows(
+        &self,
+        candidate: &mut NonogramSolution,
+        original: &NonogramSolution,
+        pinned: &[Vec<Option<usize>>],
+    ) {
+        for ((row, original), pins) in candidate
+            .solution_grid
+            .iter_mut()
+            .zip(&original.solution_grid)
+            .zip(pinned)
+        {
+            if !Self::keeps_pins(row, pins) {
+                row.clone_from(original);
+            }
+        }
+    }
+
+    // Samples again the rows of a chromosome which changed a pinned cell, after the operators
+    // mixing or regenerating its rows.
+    pub fn pin_chromosome(
+        &self,
+        candidate: &mut NonogramSolution,
+        pinned: &[Vec<Option<usize>>],
+        rng: &mut StdRng,
+    ) {
+        for ((row, row_segments), pins) in candidate
+            .solution_grid
+            .iter_mut()
+            .zip(&self.row_constraints)
+            .zip(pinned)
+        {
+            if !Self::keeps_pins(row, pins) {
+                *row = self.pinned_row_chromosome(row_segments, pins, rng);
+            }
+        }
+    }
+
+    // Counts the colors of the clues, background included.
+    fn chromosome_colors(&self) -> usize {
+        self.row_constraints
+            .iter()
+            .flatten()
+            .map(|segment| segment.color + 1)
+            .max()
+            .unwrap_or(1)
+            .max(2)
+    }
+
+    pub fn new_row_chromosome(
+        &self,
+        row_segments: &[NonogramSegment],
+        rng: &mut StdRng,
+    ) -> Vec<usize> {
+        let row_segments_length = row_segments
+            .iter()
+            .map(|segment| segment.length)
+            .sum::<usize>();
+        let required_spaces = row_segments
+            .windows(2)
+            .filter(|segments| segments[0].color == segments[1].color)
+            .count();
+        let chromosome_length = self.cols;
+        let mut remaining_spaces = chromosome_length - row_segments_length - required_spaces;
+        let mut row_chromosome = Vec::with_capacity(chromosome_length);
+        for (i, segment) in row_segments.iter().enumerate() {
+            if rng.gen_bool(0.5) {
+                let gap_size = rng.gen_range(0..=remaining_spaces);
+                remaining_spaces -= gap_size;
+                if gap_size != 0 {
+                    let mut gap_segment = vec![BACKGROUND; gap_size];
+                    row_chromosome.append(&mut gap_segment);
+                }
+            }
+            let color = segment.color;
+            let mut segment = vec![segment.color; segment.length];
+            row_chromosome.append(&mut segment);
+            if let Some(next_segment) = row_segments.get(i + 1) {
+                if next_segment.color == color {
+                    row_chromosome.push(BACKGROUND);
+                }
+            }
+        }
+        if remaining_spaces != 0 {
+            let mut gap_segment = vec![BACKGROUND; remaining_spaces];
+            row_chromosome.append(&mut gap_segment);
+        }
+        row_chromosome
+    }
+
+    // Counts, for each row, the cells disagreeing with the color counts of their column: painted
+    // cells of a color the column has in excess, and background cells of a column lacking painted cells.
+    pub fn row_conflicts(&self, candidate: &NonogramSolution) -> Vec<usize> {
+        let colors = self
+            .col_constraints
+            .iter()
+            .flatten()
+            .map(|segment| segment.color)
+            .chain(candidate.solution_grid.iter().flatten().copied())
+            .max()
+            .unwrap_or(BACKGROUND)
+            + 1;
+        let mut surplus = vec![vec![0isize; colors]; self.cols];
+        for (col, segments) in self.col_constraints.iter().enumerate() {
+            for segment in segments {
+                surplus[col][segment.color] -= segment.length as isize;
+                surplus[col][BACKGROUND] -= segment.length as isize;
+            }
+        }
+        for row in &candidate.solution_grid {
+            for (col, &color) in row.iter().enumerate() {
+                if color != BACKGROUND {
+                    surplus[col][color] += 1;
+                    surplus[col][BACKGROUND] += 1;
+                }
+            }
+        }
         candidate
-            .col_constraints()
+            .solution_grid
             .iter()
-            .zip(self.col_constraints.iter())
+            .map(|row| {
+                row.iter()
+                    .enumerate()
+                    .filter(|&(col, &color)| {
+                        if color == BACKGROUND {
+                            surplus[col][BACKGROUND] < 0
+                        } else {
+                            surplus[col][color] > 0
+                        }
+                    })
+                    .count()
+            })
+            .collect()
+    }
+
+    // Re-randomizes up to `max_rows` rows with the most conflicts, returning how many were repaired.
+    pub fn repair_chromosome(
+        &self,
+        candidate: &mut NonogramSolution,
+        max_rows: usize,
+        rng: &mut StdRng,
+    ) -> usize {
+        if max_rows == 0 {
+            return 0;
+        }
+        let conflicts = self.row_conflicts(candidate);
+        let mut rows = (0..self.rows)
+            .filter(|&row| conflicts[row] > 0)
+            .collect::<Vec<_>>();
+        rows.sort_by_key(|&row| std::cmp::Reverse(conflicts[row]));
+        rows.truncate(max_rows);
+        for &row in &rows {
+            candidate.solution_grid[row] = self.new_row_chromosome(&self.row_constraints[row], rng);
+        }
+        rows.len()
+    }
+
+    // Greedily shifts the segments of up to `max_rows` rows with the most conflicts one cell at a
+    // time, while a shift lowers the score of the columns it touches, returning how many rows changed.
+    pub fn column_repair_chromosome(
+        &self,
+        candidate: &mut NonogramSolution,
+        max_rows: usize,
+    ) -> usize {
+        if max_rows == 0 {
+            return 0;
+        }
+        let conflicts = self.row_conflicts(candidate);
+        let mut rows = (0..self.rows)
+            .filter(|&row| conflicts[row] > 0)
+            .collect::<Vec<_>>();
+        rows.sort_by_key(|&row| std::cmp::Reverse(conflicts[row]));
+        rows.truncate(max_rows);
+        let mut repaired = 0;
+        for row in rows {
+            let row_segments = &self.row_constraints[row];
+            let mut starts = Self::segment_starts(&candidate.solution_grid[row]);
+            if starts.len() != row_segments.len() {
+                continue;
+            }
+            let mut changed = false;
+            // Every accepted shift lowers the score, so the loop ends
+            let mut improved = true;
+            while improved {
+                improved = false;
+                for k in 0..row_segments.len() {
+                    let (low, high) = self.segment_bounds(row_segments, &starts, k);
+                    let shifts = [starts[k].checked_sub(1), Some(starts[k] + 1)];
+                    for target in shifts.into_iter().flatten() {
+                        if target < low || target > high {
+                            continue;
+                        }
+                        let touched =
+                            starts[k].min(target)..starts[k].max(target) + row_segments[k].length;
+                        let before = self.columns_score(candidate, touched.clone());
+                        let mut shifted = starts.clone();
+                        shifted[k] = target;
+                        let previous = mem::replace(
+                            &mut candidate.solution_grid[row],
+                            self.place_segments(row_segments, &shifted),
+                        );
+                        if self.columns_score(candidate, touched) < before {
+                            starts = shifted;
+                            changed = true;
+                            improved = true;
+                            break;
+                        }
+                        candidate.solution_grid[row] = previous;
+                    }
+                }
+            }
+            repaired += usize::from(changed);
+        }
+        repaired
+    }
+
+    // Scores some columns of a candidate against their constraints.
+    fn columns_score(&self, candidate: &NonogramSolution, cols: std::ops::Range<usize>) -> usize {
+        cols.map(|col| {
+            let column: Vec<usize> = candidate.solution_grid.iter().map(|row| row[col]).collect();
+            Self::lines_score(
+                &[Self::row_segments(&column)],
+                std::slice::from_ref(&self.col_constraints[col]),
+            )
+        })
+        .sum()
+    }
+
+    // Re-samples up to `max_rows` rows contradicting the cells deduced by the line solver, the
+    // ones with the most contradicted cells first, returning how many were refined.
+    pub fn refine_chromosome(
+        &self,
+        candidate: &mut NonogramSolution,
+        deduced: &[Vec<CellState>],
+        max_rows: usize,
+        rng: &mut StdRng,
+    ) -> usize {
+        if max_rows == 0 {
+            return 0;
+        }
+        let contradicted = |row: usize| {
+            candidate.solution_grid[row]
+                .iter()
+                .zip(&deduced[row])
+                .filter(|(&color, state)| !state.allows(color))
+                .count()
+        };
+        let mut rows = (0..self.rows)
+            .map(|row| (row, contradicted(row)))
+            .filter(|&(_, cells)| cells > 0)
+            .collect::<Vec<_>>();
+        rows.sort_by_key(|&(_, cells)| std::cmp::Reverse(cells));
+        rows.truncate(max_rows);
+        for &(row, _) in &rows {
+            if let Some(line) = sample_line(&self.row_constraints[row], &deduced[row], rng) {
+                candidate.solution_grid[row] = line;
+            }
+        }
+        rows.len()
+    }
+
+    pub fn score(&self, candidate: &NonogramSolution) -> usize {
+        self.score_with(candidate, Fitness::Columns)
+    }
+
+    // The violations of the rows and of the columns, minimized apart by the multi-objective search
+    pub fn objectives(&self, candidate: &NonogramSolution) -> (usize, usize) {
+        (
+            Self::lines_score(&candidate.row_constraints(), &self.row_constraints),
+            Self::lines_score(&candidate.col_constraints(), &self.col_constraints),
+        )
+    }
+
+    pub fn score_with(&self, candidate: &NonogramSolution, fitness: Fitness) -> usize {
+        let columns = Self::lines_score(&candidate.col_constraints(), &self.col_constraints);
+        match fitness {
+            Fitness::Columns => columns,
+            // Also penalize the rows broken by the operators
+            Fitness::Symmetric => {
+                columns + Self::lines_score(&candidate.row_constraints(), &self.row_constraints)
+            }
+        }
+    }
+
+    fn lines_score(current: &[Vec<NonogramSegment>], expected: &[Vec<NonogramSegment>]) -> usize {
+        current
+            .iter()
+            .zip(expected.iter())
             .map(|(current_segments, expected_segments)| {
                 let max_len = current_segments.len().max(expected_segments.len());
                 let current = Self::normalize_vec(current_segments, max_len);
@@ -185,8 +541,9 @@ impl NonogramPuzzle {
         let mut descendant_1 = Vec::with_capacity(self.rows);
         let mut descendant_2 = Vec::with_capacity(self.rows);
 
-        let mut point_1 = rng.gen_range(1..(self.cols - 1));
-        let mut point_2 = rng.gen_range(1..(self.cols - 1));
+        // The cut points are row indexes, the rows between them (inclusive) are swapped
+        let mut point_1 = rng.gen_range(0..self.rows);
+        let mut point_2 = rng.gen_range(0..self.rows);
 
         if point_1 > point_2 {
             mem::swap(&mut point_1, &mut point_2);
@@ -229,6 +586,154 @@ impl NonogramPuzzle {
         (nsol!(descendant_1), nsol!(descendant_2))
     }
 
+    // Swaps the vertical slab between two column cut points, keeping the original row of a child
+    // wherever the swapped cells would break its row constraints.
+    pub fn column_two_point_cross(
+        &self,
+        ancestor_1: &NonogramSolution,
+        ancestor_2: &NonogramSolution,
+        cross_probability: f64,
+        rng: &mut StdRng,
+    ) -> (NonogramSolution, NonogramSolution) {
+        if !rng.gen_bool(cross_probability) {
+            return (ancestor_1.clone(), ancestor_2.clone());
+        }
+
+        let mut point_1 = rng.gen_range(0..self.cols);
+        let mut point_2 = rng.gen_range(0..self.cols);
+
+        if point_1 > point_2 {
+            mem::swap(&mut point_1, &mut point_2);
+        }
+
+        let mut descendant_1 = ancestor_1.clone();
+        let mut descendant_2 = ancestor_2.clone();
+
+        for (i, row_segments) in self.row_constraints.iter().enumerate() {
+            let mut row_1 = ancestor_1.solution_grid[i].clone();
+            let mut row_2 = ancestor_2.solution_grid[i].clone();
+            row_1[point_1..=point_2].swap_with_slice(&mut row_2[point_1..=point_2]);
+            if Self::row_segments(&row_1) == *row_segments {
+                descendant_1.solution_grid[i] = row_1;
+            }
+            if Self::row_segments(&row_2) == *row_segments {
+                descendant_2.solution_grid[i] = row_2;
+            }
+        }
+
+        (descendant_1, descendant_2)
+    }
+
+    // Swaps a rectangular block between two row cut points and two column cut points, keeping the
+    // original row of a child wherever the swapped cells would break its row constraints.
+    pub fn block_cross(
+        &self,
+        ancestor_1: &NonogramSolution,
+        ancestor_2: &NonogramSolution,
+        cross_probability: f64,
+        rng: &mut StdRng,
+    ) -> (NonogramSolution, NonogramSolution) {
+        if !rng.gen_bool(cross_probability) {
+            return (ancestor_1.clone(), ancestor_2.clone());
+        }
+
+        let mut row_1 = rng.gen_range(0..self.rows);
+        let mut row_2 = rng.gen_range(0..self.rows);
+        if row_1 > row_2 {
+            mem::swap(&mut row_1, &mut row_2);
+        }
+        let mut col_1 = rng.gen_range(0..self.cols);
+        let mut col_2 = rng.gen_range(0..self.cols);
+        if col_1 > col_2 {
+            mem::swap(&mut col_1, &mut col_2);
+        }
+
+        let mut descendant_1 = ancestor_1.clone();
+        let mut descendant_2 = ancestor_2.clone();
+
+        for i in row_1..=row_2 {
+            let mut line_1 = ancestor_1.solution_grid[i].clone();
+            let mut line_2 = ancestor_2.solution_grid[i].clone();
+            line_1[col_1..=col_2].swap_with_slice(&mut line_2[col_1..=col_2]);
+            if Self::row_segments(&line_1) == self.row_constraints[i] {
+                descendant_1.solution_grid[i] = line_1;
+            }
+            if Self::row_segments(&line_2) == self.row_constraints[i] {
+                descendant_2.solution_grid[i] = line_2;
+            }
+        }
+
+        (descendant_1, descendant_2)
+    }
+
+    // Recombines the gaps of each row: the segments before a cut keep the positions of one ancestor
+    // and the segments after it take the positions of the other, so the gap at the cut absorbs the
+    // difference. A child keeps its original row when both placements overlap.
+    pub fn segment_cross(
+        &self,
+        ancestor_1: &NonogramSolution,
+        ancestor_2: &NonogramSolution,
+        cross_probability: f64,
+        rng: &mut StdRng,
+    ) -> (NonogramSolution, NonogramSolution) {
+        let mut descendant_1 = ancestor_1.clone();
+        let mut descendant_2 = ancestor_2.clone();
+
+        for (i, row_segments) in self.row_constraints.iter().enumerate() {
+            if row_segments.len() < 2 || !rng.gen_bool(cross_probability) {
+                continue;
+            }
+            let starts_1 = Self::segment_starts(&ancestor_1.solution_grid[i]);
+            let starts_2 = Self::segment_starts(&ancestor_2.solution_grid[i]);
+            if starts_1.len() != row_segments.len() || starts_2.len() != row_segments.len() {
+                continue;
+            }
+            let cut = rng.gen_range(1..row_segments.len());
+            let mixed_1: Vec<usize> = starts_1[..cut]
+                .iter()
+                .chain(&starts_2[cut..])
+                .copied()
+                .collect();
+            let mixed_2: Vec<usize> = starts_2[..cut]
+                .iter()
+                .chain(&starts_1[cut..])
+                .copied()
+                .collect();
+            let row_1 = self.place_segments(row_segments, &mixed_1);
+            let row_2 = self.place_segments(row_segments, &mixed_2);
+            if Self::row_segments(&row_1) == *row_segments {
+                descendant_1.solution_grid[i] = row_1;
+            }
+            if Self::row_segments(&row_2) == *row_segments {
+                descendant_2.solution_grid[i] = row_2;
+            }
+        }
+
+        (descendant_1, descendant_2)
+    }
+
+    // Finds the first cell of each segment of a row chromosome.
+    fn segment_starts(row: &[usize]) -> Vec<usize> {
+        (0..row.len())
+            .filter(|&x| row[x] != BACKGROUND && (x == 0 || row[x - 1] != row[x]))
+            .collect()
+    }
+
+    // Paints the segments of a row starting at the given cells, later segments over earlier ones.
+    fn place_segments(&self, row_segments: &[NonogramSegment], starts: &[usize]) -> Vec<usize> {
+        let mut row = vec![BACKGROUND; self.cols];
+        for (segment, &start) in row_segments.iter().zip(starts) {
+            let end = (start + segment.length).min(self.cols);
+            row[start..end].fill(segment.color);
+        }
+        row
+    }
+
+    // Computes the segments of a single row chromosome.
+    fn row_segments(row: &[usize]) -> Vec<NonogramSegment> {
+        nsol!(vec![row.to_vec()]).row_constraints().remove(0)
+    }
+
     pub fn chromosome_mutation(
         &self,
         candidate: &mut NonogramSolution,
@@ -248,6 +753,92 @@ impl NonogramPuzzle {
         }
     }
 
+    // Moves a random segment of each mutated row to a random position between its neighbours,
+    // keeping the row constraints.
+    pub fn segment_reposition_mutation(
+        &self,
+        candidate: &mut NonogramSolution,
+        mutation_probability: f64,
+        rng: &mut StdRng,
+    ) {
+        for (row_segments, row) in self
+            .row_constraints
+            .iter()
+            .zip(candidate.solution_grid.iter_mut())
+        {
+            if row_segments.is_empty() || !rng.gen_bool(mutation_probability) {
+                continue;
+            }
+            let mut starts = Self::segment_starts(row);
+            if starts.len() != row_segments.len() {
+                continue;
+            }
+            let k = rng.gen_range(0..row_segments.len());
+            let (low, high) = self.segment_bounds(row_segments, &starts, k);
+            starts[k] = rng.gen_range(low..=high);
+            *row = self.place_segments(row_segments, &starts);
+        }
+    }
+
+    // Computes the first and last cells where a segment can start without touching its neighbours.
+    fn segment_bounds(
+        &self,
+        row_segments: &[NonogramSegment],
+        starts: &[usize],
+        k: usize,
+    ) -> (usize, usize) {
+        let segment = &row_segments[k];
+        // The same colored neighbours need a background cell between them
+        let low = match k.checked_sub(1) {
+            Some(prev) => {
+                starts[prev]
+                    + row_segments[prev].length
+                    + usize::from(row_segments[prev].color == segment.color)
+            }
+            None => 0,
+        };
+        let high = match row_segments.get(k + 1) {
+            Some(next) => starts[k + 1] - segment.length - usize::from(next.color == segment.color),
+            None => self.cols - segment.length,
+        };
+        (low, high)
+    }
+
+    // Regenerates each mutated row from scratch, like the rows of the initial population.
+    pub fn row_reshuffle_mutation(
+        &self,
+        candidate: &mut NonogramSolution,
+        mutation_probability: f64,
+        rng: &mut StdRng,
+    ) {
+        for (row_segments, row) in self
+            .row_constraints
+            .iter()
+            .zip(candidate.solution_grid.iter_mut())
+        {
+            if rng.gen_bool(mutation_probability) {
+                *row = self.new_row_chromosome(row_segments, rng);
+            }
+        }
+    }
+
+    // Repaints a random cell of each mutated row with a random color, unlike the other mutations
+    // breaking the clues of the row, for the NSGA-II mode to trade them for the columns'.
+    pub fn cell_mutation(
+        &self,
+        candidate: &mut NonogramSolution,
+        mutation_probability: f64,
+        colors: usize,
+        rng: &mut StdRng,
+    ) {
+        for row in candidate.solution_grid.iter_mut() {
+            if rng.gen_bool(mutation_probability) {
+                let col = rng.gen_range(0..row.len());
+                row[col] = rng.gen_range(0..colors);
+            }
+        }
+    }
+
     pub fn get_slidables(row_segment_colors: &Vec<usize>) -> Vec<(usize, usize)> {
         let mut slidable_segments = Vec::new();
 
@@ -326,7 +917,9 @@ impl NonogramPuzzle {
 mod tests {
     use rand::SeedableRng;
 
-    use crate::nonogram::puzzles::tree_nonogram_puzzle;
+    use crate::nonogram::logic::{initial_states, propagate};
+    use crate::nonogram::puzzles::{tree_nonogram_file, tree_nonogram_puzzle};
+    use std::collections::HashSet;
 
     use super::*;
 
@@ -471,6 +1064,278 @@ mod tests {
         assert_eq!(puzzle.row_constraints, mutated_2.row_constraints);
     }
 
+    // Test that the symmetric fitness also penalizes the rows, which the column fitness ignores.
+    #[test]
+    fn symmetric_fitness_scores_rows() {
+        let puzzle = NonogramPuzzle::from_solution(&NonogramSolution {
+            solution_grid: vec![vec![1, 1, 0], vec![0, 0, 0]],
+        });
+        let broken_rows = NonogramSolution {
+            solution_grid: vec![vec![1, 0, 0], vec![0, 1, 0]],
+        };
+        assert_eq!(puzzle.score_with(&broken_rows, Fitness::Columns), 0);
+        assert_eq!(puzzle.score_with(&broken_rows, Fitness::Symmetric), 2);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let puzzle = tree_nonogram_puzzle();
+        let candidate = puzzle.new_chromosome_solution(&mut rng);
+        assert_eq!(
+            puzzle.score_with(&candidate, Fitness::Symmetric),
+            puzzle.score(&candidate)
+        );
+        let solution = tree_nonogram_file().solution;
+        assert_eq!(puzzle.score_with(&solution, Fitness::Symmetric), 0);
+    }
+
+    // Test that the repair re-randomizes only the rows in conflict, keeping the row constraints.
+    #[test]
+    fn repair_rows_in_conflict() {
+        let puzzle = tree_nonogram_puzzle();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut solved = tree_nonogram_file().solution;
+        assert_eq!(puzzle.row_conflicts(&solved), vec![0; 5]);
+        assert_eq!(puzzle.repair_chromosome(&mut solved, 2, &mut rng), 0);
+
+        // Shift the top row to the left: the first column gets an extra leaf and the fourth
+        // column lacks one, so the top row has the most conflicts.
+        let mut candidate = solved.clone();
+        candidate.solution_grid[0] = vec![1, 1, 1, 0, 0];
+        assert_eq!(puzzle.row_conflicts(&candidate), vec![2, 1, 1, 1, 1]);
+        assert_eq!(puzzle.repair_chromosome(&mut candidate, 1, &mut rng), 1);
+        assert_eq!(candidate.row_constraints(), puzzle.row_constraints);
+        assert_eq!(candidate.solution_grid[1..], solved.solution_grid[1..]);
+    }
+
+    // Test that a warm-started chromosome keeps the rows painted by hand that match their clues,
+    // and fits the other rows to their clues, releasing the painted cells that contradict them.
+    #[test]
+    fn warm_chromosome_follows_painted_rows() {
+        let puzzle = tree_nonogram_puzzle();
+        let mut rng = StdRng::seed_from_u64(0);
+        let solved = tree_nonogram_file().solution;
+        let mut painted = solved.clone();
+        painted.solution_grid[0] = vec![BACKGROUND; 5];
+        painted.solution_grid[1] = vec![1, 0, 0, 0, 0];
+        painted.solution_grid[2] = vec![2, 0, 0, 0, 0];
+        for _ in 0..10 {
+            let candidate = puzzle.warm_chromosome_solution(&painted, &[], &mut rng);
+            assert_eq!(candidate.row_constraints(), puzzle.row_constraints);
+            assert_eq!(candidate.solution_grid[1..], solved.solution_grid[1..]);
+        }
+        let empty = nsol!(vec![vec![BACKGROUND; 5]; 2]);
+        let candidate = puzzle.warm_chromosome_solution(&empty, &[], &mut rng);
+        assert_eq!(candidate.row_constraints(), puzzle.row_constraints);
+    }
+
+    // Test that the refinement re-samples the rows contradicting the deductions of the line solver.
+    #[test]
+    fn refine_rows_against_deductions() {
+        let puzzle = tree_nonogram_puzzle();
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut deduced = initial_states(&puzzle);
+        assert!(propagate(&puzzle, &mut deduced));
+        let mut candidate = tree_nonogram_file().solution;
+        assert_eq!(
+            puzzle.refine_chromosome(&mut candidate, &deduced, 5, &mut rng),
+            0
+        );
+
+        // The tree is deduced by logic alone, so the shifted top row is re-sampled to the solution.
+        candidate.solution_grid[0] = vec![1, 1, 1, 0, 0];
+        assert_eq!(
+            puzzle.refine_chromosome(&mut candidate, &deduced, 5, &mut rng),
+            1
+        );
+        assert_eq!(
+            candidate.solution_grid,
+            tree_nonogram_file().solution.solution_grid
+        );
+    }
+
+    // Test that the two-point crossover swaps exactly one contiguous slab of rows, which can
+    // reach the first and the last rows, even on puzzles with two rows.
+    #[test]
+    fn two_point_cross_swaps_rows() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for rows in [2, 6] {
+            let ancestor_1 = nsol!(vec![vec![1, 0, 0]; rows]);
+            let ancestor_2 = nsol!(vec![vec![0, 0, 1]; rows]);
+            let puzzle = NonogramPuzzle::from_solution(&ancestor_1);
+            let (mut first, mut last) = (false, false);
+            for _ in 0..50 {
+                let (child_1, child_2) =
+                    puzzle.two_point_cross(&ancestor_1, &ancestor_2, 1.0, &mut rng);
+                let swapped: Vec<usize> = (0..rows)
+                    .filter(|&i| child_1.solution_grid[i] == ancestor_2.solution_grid[i])
+                    .collect();
+                for i in 0..rows {
+                    let (kept, given) = if swapped.contains(&i) {
+                        (&ancestor_2, &ancestor_1)
+                    } else {
+                        (&ancestor_1, &ancestor_2)
+                    };
+                    assert_eq!(child_1.solution_grid[i], kept.solution_grid[i]);
+                    assert_eq!(child_2.solution_grid[i], given.solution_grid[i]);
+                }
+                assert!(!swapped.is_empty());
+                assert!(swapped.windows(2).all(|pair| pair[1] == pair[0] + 1));
+                first |= swapped[0] == 0;
+                last |= swapped[swapped.len() - 1] == rows - 1;
+            }
+            assert!(first && last, "{rows} rows");
+        }
+    }
+
+    // Test that the column crossover never breaks the row constraints of the children.
+    #[test]
+    fn column_cross_keeps_rows() {
+        let puzzle = tree_nonogram_puzzle();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let ancestor_1 = puzzle.new_chromosome_solution(&mut rng);
+            let ancestor_2 = puzzle.new_chromosome_solution(&mut rng);
+            let (child_1, child_2) =
+                puzzle.column_two_point_cross(&ancestor_1, &ancestor_2, 1.0, &mut rng);
+            assert_eq!(child_1.row_constraints(), puzzle.row_constraints);
+            assert_eq!(child_2.row_constraints(), puzzle.row_constraints);
+        }
+    }
+
+    // Test that the block crossover never breaks the row constraints of the children.
+    #[test]
+    fn block_cross_keeps_rows() {
+        let puzzle = tree_nonogram_puzzle();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let ancestor_1 = puzzle.new_chromosome_solution(&mut rng);
+            let ancestor_2 = puzzle.new_chromosome_solution(&mut rng);
+            let (child_1, child_2) = puzzle.block_cross(&ancestor_1, &ancestor_2, 1.0, &mut rng);
+            assert_eq!(child_1.row_constraints(), puzzle.row_constraints);
+            assert_eq!(child_2.row_constraints(), puzzle.row_constraints);
+        }
+    }
+
+    // Test that the segment crossover mixes the gaps of both ancestors, keeping the row of the
+    // child whose segments would overlap.
+    #[test]
+    fn segment_cross_mixes_gaps() {
+        let puzzle = NonogramPuzzle::from_solution(&nsol!(vec![vec![1, 0, 1, 0, 0]]));
+        let ancestor_1 = nsol!(vec![vec![1, 0, 1, 0, 0]]);
+        let ancestor_2 = nsol!(vec![vec![0, 0, 1, 0, 1]]);
+        let mut rng = StdRng::seed_from_u64(0);
+        let (child_1, child_2) = puzzle.segment_cross(&ancestor_1, &ancestor_2, 1.0, &mut rng);
+        assert_eq!(child_1.solution_grid, vec![vec![1, 0, 0, 0, 1]]);
+        assert_eq!(child_2.solution_grid, ancestor_2.solution_grid);
+    }
+
+    // Test that the segment crossover never breaks the row constraints of the children.
+    #[test]
+    fn segment_cross_keeps_rows() {
+        let puzzle = tree_nonogram_puzzle();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let ancestor_1 = puzzle.new_chromosome_solution(&mut rng);
+            let ancestor_2 = puzzle.new_chromosome_solution(&mut rng);
+            let (child_1, child_2) = puzzle.segment_cross(&ancestor_1, &ancestor_2, 1.0, &mut rng);
+            assert_eq!(child_1.row_constraints(), puzzle.row_constraints);
+            assert_eq!(child_2.row_constraints(), puzzle.row_constraints);
+        }
+    }
+
+    // Test that the column repair lowers the score by shifting segments, keeping the rows.
+    #[test]
+    fn column_repair_shifts_segments() {
+        let puzzle = NonogramPuzzle::from_solution(&nsol!(vec![vec![0, 1, 0], vec![0, 0, 1]]));
+        let mut candidate = nsol!(vec![vec![1, 0, 0], vec![0, 0, 1]]);
+        assert_eq!(puzzle.column_repair_chromosome(&mut candidate, 1), 1);
+        assert_eq!(candidate.solution_grid, vec![vec![0, 1, 0], vec![0, 0, 1]]);
+
+        let puzzle = tree_nonogram_puzzle();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let mut candidate = puzzle.new_chromosome_solution(&mut rng);
+            let before = puzzle.score(&candidate);
+            puzzle.column_repair_chromosome(&mut candidate, 3);
+            assert!(puzzle.score(&candidate) <= before);
+            assert_eq!(candidate.row_constraints(), puzzle.row_constraints);
+        }
+    }
+
+    // Test that the segment reposition moves a segment within its neighbours, keeping the rows.
+    #[test]
+    fn segment_reposition_keeps_rows() {
+        let puzzle = NonogramPuzzle::from_solution(&nsol!(vec![vec![1, 0, 0, 0, 2, 0]]));
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut positions = HashSet::new();
+        for _ in 0..50 {
+            let mut candidate = nsol!(vec![vec![1, 0, 0, 0, 2, 0]]);
+            puzzle.segment_reposition_mutation(&mut candidate, 1.0, &mut rng);
+            assert_eq!(candidate.row_constraints(), puzzle.row_constraints);
+            positions.insert(candidate.solution_grid[0].clone());
+        }
+        assert!(positions.len() > 2);
+
+        let puzzle = tree_nonogram_puzzle();
+        for _ in 0..20 {
+            let mut candidate = puzzle.new_chromosome_solution(&mut rng);
+            puzzle.segment_reposition_mutation(&mut candidate, 1.0, &mut rng);
+            assert_eq!(candidate.row_constraints(), puzzle.row_constraints);
+        }
+    }
+
+    // Test that the row reshuffle regenerates rows that keep their constraints.
+    #[test]
+    fn row_reshuffle_keeps_rows() {
+        let puzzle = tree_nonogram_puzzle();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..20 {
+            let mut candidate = puzzle.new_chromosome_solution(&mut rng);
+            puzzle.row_reshuffle_mutation(&mut candidate, 1.0, &mut rng);
+            assert_eq!(candidate.row_constraints(), puzzle.row_constraints);
+        }
+    }
+
+    // Test that the chromosomes are generated around the pinned cells, and that the mutations and
+    // the crossovers never change them.
+    #[test]
+    fn pinned_cells_are_kept() {
+        let puzzle = NonogramPuzzle::from_solution(&nsol!(vec![
+            vec![1, 0, 0, 0, 0],
+            vec![0, 0, 2, 2, 0],
+            vec![0, 1, 0, 0, 0]
+        ]));
+        let mut rng = StdRng::seed_from_u64(0);
+        let pinned = vec![
+            vec![None, None, None, Some(1), None],
+            vec![Some(BACKGROUND), None, None, None, None],
+            vec![],
+        ];
+        let keeps_pins = |candidate: &NonogramSolution| {
+            candidate
+                .solution_grid
+                .iter()
+                .zip(&pinned)
+                .all(|(row, pins)| NonogramPuzzle::keeps_pins(row, pins))
+        };
+        for _ in 0..20 {
+            let ancestor_1 = puzzle.pinned_chromosome_solution(&pinned, &mut rng);
+            let ancestor_2 = puzzle.pinned_chromosome_solution(&pinned, &mut rng);
+            assert_eq!(ancestor_1.solution_grid[0], vec![0, 0, 0, 1, 0]);
+            assert!(keeps_pins(&ancestor_1));
+
+            let (mut descendant, _) = puzzle.segment_cross(&ancestor_1, &ancestor_2, 1.0, &mut rng);
+            puzzle.pin_chromosome(&mut descendant, &pinned, &mut rng);
+            assert!(keeps_pins(&descendant));
+
+            let original = descendant.clone();
+            puzzle.row_reshuffle_mutation(&mut descendant, 1.0, &mut rng);
+            puzzle.segment_reposition_mutation(&mut descendant, 1.0, &mut rng);
+            puzzle.restore_pinned_rows(&mut descendant, &original, &pinned);
+            assert!(keeps_pins(&descendant));
+            assert_eq!(descendant.row_constraints(), puzzle.row_constraints);
+        }
+    }
+
     // Test the combination of mutation and crossover in one operation, ensuring that the row_constraints are preserved.
     #[test]
     fn same_puzzle_after_mutation_and_cross() {
@@ -496,3 +1361,86 @@ mod tests {
         assert_eq!(puzzle.row_constraints, mutated_2.row_constraints);
     }
 }
+
+#[cfg(all(test, feature = "stress"))]
+mod stress_tests {
+    use rand::SeedableRng;
+
+    use super::*;
+
+    // Number of random puzzles generated by the stress test.
+    const PUZZLES: usize = 300;
+    // Number of mutate/cross cycles run on each puzzle.
+    const CYCLES: usize = 10;
+    // Maximum number of colors of the random puzzles, excluding the background.
+    const MAX_COLORS: usize = 8;
+
+    // Generates a puzzle from a random grid with 2..=40 rows and columns and up to 8 colors.
+    fn random_puzzle(rng: &mut StdRng) -> NonogramPuzzle {
+        let rows = rng.gen_range(2..=40);
+        let cols = rng.gen_range(2..=40);
+        let colors = rng.gen_range(1..=MAX_COLORS);
+        let density = rng.gen_range(0.0..=1.0);
+        let grid = (0..rows)
+            .map(|_| {
+                (0..cols)
+                    .map(|_| {
+                        if rng.gen_bool(density) {
+                            rng.gen_range(1..=colors)
+                        } else {
+                            BACKGROUND
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        NonogramPuzzle::from_solution(&nsol!(grid))
+    }
+
+    // Runs thousands of random mutate/cross/repair cycles on random puzzle shapes, asserting that
+    // every chromosome keeps the row constraints and the grid dimensions.
+    #[test]
+    fn random_cycles_keep_row_constraints() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..PUZZLES {
+            let puzzle = random_puzzle(&mut rng);
+            let assert_valid = |candidate: &NonogramSolution| {
+                assert_eq!(candidate.rows(), puzzle.rows);
+                assert_eq!(candidate.cols(), puzzle.cols);
+                assert_eq!(
+                    candidate.row_constraints(),
+                    puzzle.row_constraints,
+                    "Row constraints broken on a {}x{} puzzle",
+                    puzzle.rows,
+                    puzzle.cols
+                );
+            };
+            let mut ancestor_1 = puzzle.new_chromosome_solution(&mut rng);
+            let mut ancestor_2 = puzzle.new_chromosome_solution(&mut rng);
+            assert_valid(&ancestor_1);
+            assert_valid(&ancestor_2);
+            for _ in 0..CYCLES {
+                let probability = rng.gen_range(0.0..=1.0);
+                let (mut child_1, mut child_2) = match rng.gen_range(0..3) {
+                    0 => puzzle.uniform_cross(&ancestor_1, &ancestor_2, probability, &mut rng),
+                    1 => puzzle.two_point_cross(&ancestor_1, &ancestor_2, probability, &mut rng),
+                    _ => puzzle.column_two_point_cross(
+                        &ancestor_1,
+                        &ancestor_2,
+                        probability,
+                        &mut rng,
+                    ),
+                };
+                let repair_rows = rng.gen_range(0..=puzzle.rows);
+                puzzle.repair_chromosome(&mut child_1, repair_rows, &mut rng);
+                let slide_tries = rng.gen_range(1..=7);
+                puzzle.chromosome_mutation(&mut child_1, probability, slide_tries, &mut rng);
+                puzzle.chromosome_mutation(&mut child_2, probability, slide_tries, &mut rng);
+                assert_valid(&child_1);
+                assert_valid(&child_2);
+                puzzle.score(&child_1);
+                (ancestor_1, ancestor_2) = (child_1, child_2);
+            }
+        }
+    }
+}
@@ -22,7 +22,9 @@
 
 /// Imports definitions for Nonogram puzzle components and background.
 use super::definitions::{
-    NonogramPalette, NonogramPuzzle, NonogramSegment, NonogramSolution, BACKGROUND,
+    Area, CellDiff, DiffCounts, Heatmap, Layers, NonogramFile, NonogramPalette, NonogramPuzzle,
+    NonogramSegment, NonogramSolution, ReferenceImage, ResizeMode, Signature, SolutionDiff,
+    BACKGROUND,
 };
 /// Import macro to construct nonogram rules easily
 use crate::nrule;
@@ -50,6 +52,236 @@ impl NonogramPuzzle {
             col_constraints,
         }
     }
+
+    /// Identifies a puzzle by its constraints, with a 64-bit FNV-1a hash stable between versions.
+    ///
+    /// # Returns
+    ///
+    /// The hash as 16 hexadecimal digits.
+    pub fn constraint_hash(&self) -> String {
+        let mut hash: u64 = 0xcbf29ce484222325;
+        let mut write = |value: usize| {
+            for byte in (value as u64).to_le_bytes() {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(0x100000001b3);
+            }
+        };
+        write(self.rows);
+        write(self.cols);
+        for line in self.row_constraints.iter().chain(&self.col_constraints) {
+            write(line.len());
+            for segment in line {
+                write(segment.color);
+                write(segment.length);
+            }
+        }
+        format!("{hash:016x}")
+    }
+
+    /// Transposes the puzzle, so its rows become the columns and the columns become the rows.
+    ///
+    /// # Returns
+    ///
+    /// The transposed puzzle, solved by the transposed solutions of this puzzle.
+    pub fn transposed(&self) -> Self {
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            row_constraints: self.col_constraints.clone(),
+            col_constraints: self.row_constraints.clone(),
+        }
+    }
+
+    /// Computes a block size fitting the puzzle, with its clues, in a viewport.
+    ///
+    /// The grid takes most of the viewport width and height, and the size is rounded down to a
+    /// multiple of 5 pixels within the range accepted by the size input (10 to 100).
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the viewport in pixels.
+    /// * `height` - The height of the viewport in pixels.
+    ///
+    /// # Returns
+    ///
+    /// The size of each block in pixels.
+    pub fn auto_block_size(&self, width: f64, height: f64) -> usize {
+        let longest = |constraints: &[Vec<NonogramSegment>]| {
+            constraints
+                .iter()
+                .map(|segments| segments.len())
+                .max()
+                .unwrap_or(0)
+        };
+        let across = (self.cols + longest(&self.row_constraints)).max(1) as f64;
+        let down = (self.rows + longest(&self.col_constraints)).max(1) as f64;
+        let size = ((width * 0.9 - 64.0) / across).min((height * 0.8 - 64.0) / down);
+        let size = (size.max(0.0) as usize) / 5 * 5;
+        size.clamp(10, 100)
+    }
+}
+
+impl Area {
+    /// Checks if a cell is inside the area.
+    ///
+    /// # Arguments
+    ///
+    /// * `coord` - The coordinate `(row, column)` of the cell.
+    pub fn contains(&self, coord: (usize, usize)) -> bool {
+        let rows = self.start.0.min(self.end.0)..=self.start.0.max(self.end.0);
+        let cols = self.start.1.min(self.end.1)..=self.start.1.max(self.end.1);
+        rows.contains(&coord.0) && cols.contains(&coord.1)
+    }
+}
+
+impl Layers {
+    /// Checks if the layer of a color is visible.
+    ///
+    /// The background layer is always visible.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color index of the layer.
+    pub fn is_visible(&self, color: usize) -> bool {
+        color == BACKGROUND
+            || (!self.hidden.contains(&color) && self.solo.is_none_or(|solo| solo == color))
+    }
+
+    /// Returns the color displayed for a cell, the background if its layer is hidden.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color index of the cell.
+    pub fn displayed(&self, color: usize) -> usize {
+        if self.is_visible(color) {
+            color
+        } else {
+            BACKGROUND
+        }
+    }
+
+    /// Shows or hides the layer of a color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color index of the layer.
+    pub fn toggle(&mut self, color: usize) {
+        if !self.hidden.remove(&color) {
+            self.hidden.insert(color);
+        }
+    }
+
+    /// Solos the layer of a color, or stops soloing it if it was already solo'd.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color index of the layer.
+    pub fn toggle_solo(&mut self, color: usize) {
+        self.solo = (self.solo != Some(color)).then_some(color);
+    }
+}
+
+impl Default for ReferenceImage {
+    /// Creates an empty reference image, half visible once loaded.
+    fn default() -> Self {
+        Self {
+            source: None,
+            opacity: 0.5,
+            offset: (0, 0),
+        }
+    }
+}
+
+impl ReferenceImage {
+    /// Returns the hexadecimal alpha channel of the grid cells, so the image can be seen through them.
+    ///
+    /// # Returns
+    ///
+    /// The alpha channel to append to the `#RRGGBB` cell colors, or `None` if no image is loaded.
+    pub fn cell_alpha(&self) -> Option<String> {
+        self.source.as_ref().map(|_| {
+            let alpha = ((1.0 - self.opacity.clamp(0.0, 1.0)) * 255.0).round() as u8;
+            format!("{alpha:02x}")
+        })
+    }
+}
+
+impl Heatmap {
+    /// Returns the style of a cell highlighted by the heat overlay.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row of the cell.
+    /// * `col` - The column of the cell.
+    ///
+    /// # Returns
+    ///
+    /// A CSS declaration tinting the cell, empty if the cell isn't highlighted.
+    pub fn cell_style(&self, row: usize, col: usize) -> String {
+        match self.cells.get(row).and_then(|cells| cells.get(col)) {
+            Some(&heat) if heat > 0.0 => format!(
+                "box-shadow: inset 0 0 0 100px rgba(239, 68, 68, {:.2});",
+                heat.clamp(0.0, 1.0) * 0.75
+            ),
+            _ => String::new(),
+        }
+    }
+}
+
+impl Signature {
+    /// Creates the signature of an author, hidden by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `author_id` - The identifier of the profile of the author.
+    /// * `author` - The name of the author.
+    /// * `signed_at` - When the puzzle is signed, in seconds since the Unix epoch.
+    pub fn new(author_id: &str, author: &str, signed_at: i64) -> Self {
+        Self {
+            author_id: author_id.to_string(),
+            author: author.to_string(),
+            signed_at,
+            visible: false,
+        }
+    }
+
+    /// Derives the pattern of the visible signature row from the author and the date, so the row
+    /// can't be edited without changing the signature.
+    ///
+    /// # Arguments
+    ///
+    /// * `cols` - The number of columns of the grid.
+    ///
+    /// # Returns
+    ///
+    /// Whether each cell of the row is filled.
+    pub fn pattern(&self, cols: usize) -> Vec<bool> {
+        // FNV-1a hash of the author and the date, stable across platforms.
+        let hash = self
+            .author_id
+            .bytes()
+            .chain(self.signed_at.to_le_bytes())
+            .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            });
+        (0..cols).map(|col| hash >> (col % 64) & 1 == 1).collect()
+    }
+}
+
+impl NonogramFile {
+    /// Identifies a Nonogram file by its contents, with a 64-bit FNV-1a hash of its JSON, so the
+    /// same file loaded again is recognized.
+    ///
+    /// # Returns
+    ///
+    /// The hash as 16 hexadecimal digits.
+    pub fn content_hash(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap_or_default();
+        let hash = json.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        format!("{hash:016x}")
+    }
 }
 
 impl NonogramSolution {
@@ -242,6 +474,365 @@ impl NonogramSolution {
         }
     }
 
+    /// Resizes the nonogram solution grid, adapting the drawing according to the resize mode.
+    ///
+    /// With `ResizeMode::Crop` the grid is cropped or padded (see `set_rows` and `set_cols`),
+    /// while the other modes rescale the drawing to the new dimensions, e.g. to convert a
+    /// 10x10 sketch into a 25x25 puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The target number of rows.
+    /// * `cols` - The target number of columns.
+    /// * `mode` - How the drawing is adapted to the new dimensions.
+    pub fn resize(&mut self, rows: usize, cols: usize, mode: ResizeMode) {
+        let (rows, cols) = (rows.max(2), cols.max(2));
+        if mode == ResizeMode::Crop || (rows, cols) == (self.rows(), self.cols()) {
+            self.set_rows(rows);
+            self.set_cols(cols);
+            return;
+        }
+        let (source_rows, source_cols) = (self.rows(), self.cols());
+        // The range of source cells covered by the target cell `index` of `target` cells.
+        let span = |index: usize, target: usize, source: usize| {
+            let start = index * source / target;
+            let end = ((index + 1) * source).div_ceil(target).max(start + 1);
+            start..end
+        };
+        // The source cell nearest to the center of the target cell `index` of `target` cells.
+        let nearest =
+            |index: usize, target: usize, source: usize| (2 * index + 1) * source / (2 * target);
+        self.solution_grid = (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .map(|col| {
+                        let center = self.solution_grid[nearest(row, rows, source_rows)]
+                            [nearest(col, cols, source_cols)];
+                        if mode == ResizeMode::Nearest {
+                            return center;
+                        }
+                        let mut counts = std::collections::HashMap::new();
+                        for source_row in span(row, rows, source_rows) {
+                            for source_col in span(col, cols, source_cols) {
+                                *counts
+                                    .entry(self.solution_grid[source_row][source_col])
+                                    .or_insert(0) += 1;
+                            }
+                        }
+                        let majority = counts.values().copied().max().unwrap_or(0);
+                        // Ties are broken by the nearest cell, then by the lowest color index.
+                        if counts.get(&center) == Some(&majority) {
+                            center
+                        } else {
+                            counts
+                                .into_iter()
+                                .filter(|&(_, count)| count == majority)
+                                .map(|(color, _)| color)
+                                .min()
+                                .unwrap_or(BACKGROUND)
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+    }
+
+    /// Finds the empty rows and columns of the nonogram solution grid.
+    ///
+    /// Empty lines (whose clues would be empty) render oddly in black and white puzzles and
+    /// make them easier, so authors are warned about them.
+    ///
+    /// # Returns
+    ///
+    /// The indexes of the rows and the indexes of the columns with only background cells.
+    pub fn empty_lines(&self) -> (Vec<usize>, Vec<usize>) {
+        let rows = (0..self.rows())
+            .filter(|&row| {
+                self.solution_grid[row]
+                    .iter()
+                    .all(|&cell| cell == BACKGROUND)
+            })
+            .collect();
+        let cols = (0..self.cols())
+            .filter(|&col| self.solution_grid.iter().all(|row| row[col] == BACKGROUND))
+            .collect();
+        (rows, cols)
+    }
+
+    /// Computes the smallest area containing every colored cell of the nonogram solution grid.
+    ///
+    /// # Returns
+    ///
+    /// The bounding box of the drawing, or `None` if the grid only has background cells.
+    pub fn bounding_box(&self) -> Option<Area> {
+        let (empty_rows, empty_cols) = self.empty_lines();
+        let first = |empty: &[usize], len: usize| (0..len).find(|line| !empty.contains(line));
+        let last = |empty: &[usize], len: usize| (0..len).rev().find(|line| !empty.contains(line));
+        Some(Area {
+            start: (
+                first(&empty_rows, self.rows())?,
+                first(&empty_cols, self.cols())?,
+            ),
+            end: (
+                last(&empty_rows, self.rows())?,
+                last(&empty_cols, self.cols())?,
+            ),
+        })
+    }
+
+    /// Counts the colored cells that cropping the grid to some dimensions would delete.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows kept, from the top.
+    /// * `cols` - The number of columns kept, from the left.
+    pub fn cells_outside(&self, rows: usize, cols: usize) -> usize {
+        self.solution_grid
+            .iter()
+            .enumerate()
+            .flat_map(|(row, line)| {
+                line.iter()
+                    .enumerate()
+                    .filter(move |&(col, &cell)| cell != BACKGROUND && (row >= rows || col >= cols))
+            })
+            .count()
+    }
+
+    /// Computes the slide moving the drawing inside the top-left corner of some dimensions, so
+    /// the grid can be cropped to them without deleting colored cells.
+    ///
+    /// The drawing is moved as little as possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The number of rows kept, from the top.
+    /// * `cols` - The number of columns kept, from the left.
+    ///
+    /// # Returns
+    ///
+    /// The slide `(dx, dy)`, or `None` if the drawing doesn't fit in those dimensions.
+    pub fn slide_to_fit(&self, rows: usize, cols: usize) -> Option<(isize, isize)> {
+        let Some(Area { start, end }) = self.bounding_box() else {
+            return Some((0, 0));
+        };
+        if end.0 - start.0 >= rows || end.1 - start.1 >= cols {
+            return None;
+        }
+        let shift = |last: usize, len: usize| (len as isize - 1 - last as isize).min(0);
+        Some((shift(end.1, cols), shift(end.0, rows)))
+    }
+
+    /// Crops the nonogram solution grid to an area.
+    ///
+    /// The area is extended (inside the grid) to keep at least two rows and two columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `area` - The area of the grid to keep.
+    pub fn crop(&mut self, area: Area) {
+        let span = |start: usize, end: usize, len: usize| {
+            let (mut first, mut last) = (start.min(end), start.max(end).min(len - 1));
+            while last - first < 1 && last - first + 1 < len {
+                if last + 1 < len {
+                    last += 1;
+                } else {
+                    first -= 1;
+                }
+            }
+            first..=last
+        };
+        let rows = span(area.start.0, area.end.0, self.rows());
+        let cols = span(area.start.1, area.end.1, self.cols());
+        self.solution_grid = self.solution_grid[rows]
+            .iter()
+            .map(|row| row[cols.clone()].to_vec())
+            .collect();
+    }
+
+    /// Compares the nonogram solution grid against a reference grid.
+    ///
+    /// The cells outside of one of the grids (if their dimensions differ) are compared as background.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - The grid to compare against, e.g. the solution of the loaded file.
+    ///
+    /// # Returns
+    ///
+    /// The comparison of each cell, and the matching, missing and extra cells of each color.
+    pub fn diff(&self, reference: &NonogramSolution) -> SolutionDiff {
+        let color_at = |solution: &NonogramSolution, row: usize, col: usize| {
+            solution
+                .solution_grid
+                .get(row)
+                .and_then(|row_data| row_data.get(col))
+                .copied()
+                .unwrap_or(BACKGROUND)
+        };
+        let rows = self.rows().max(reference.rows());
+        let cols = self.cols().max(reference.cols());
+        let cells: Vec<Vec<CellDiff>> = (0..rows)
+            .map(|row| {
+                (0..cols)
+                    .map(|col| {
+                        let expected = color_at(reference, row, col);
+                        let found = color_at(self, row, col);
+                        if expected == found {
+                            CellDiff::Match(found)
+                        } else if found == BACKGROUND {
+                            CellDiff::Missing(expected)
+                        } else if expected == BACKGROUND {
+                            CellDiff::Extra(found)
+                        } else {
+                            CellDiff::Mismatch { expected, found }
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let colors = self
+            .color_counts(0)
+            .len()
+            .max(reference.color_counts(0).len());
+        let mut counts = vec![DiffCounts::default(); colors];
+        for cell in cells.iter().flatten() {
+            match *cell {
+                CellDiff::Match(color) => counts[color].matching += 1,
+                CellDiff::Missing(color) => counts[color].missing += 1,
+                CellDiff::Extra(color) => counts[color].extra += 1,
+                CellDiff::Mismatch { expected, found } => {
+                    counts[expected].missing += 1;
+                    counts[found].extra += 1;
+                }
+            }
+        }
+        SolutionDiff { cells, counts }
+    }
+
+    /// Finds the smallest area containing every cell that differs from a previous grid.
+    ///
+    /// If the dimensions of the grids differ, the whole grid is considered changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `previous` - The grid before it was edited.
+    ///
+    /// # Returns
+    ///
+    /// The area of the changed cells, or `None` if the grids are equal.
+    pub fn changed_area(&self, previous: &NonogramSolution) -> Option<Area> {
+        let (rows, cols) = (self.rows(), self.cols());
+        if (rows, cols) != (previous.rows(), previous.cols()) {
+            return (rows > 0 && cols > 0).then_some(Area {
+                start: (0, 0),
+                end: (rows - 1, cols - 1),
+            });
+        }
+        let mut area: Option<Area> = None;
+        for (row, (row_data, previous_row)) in self
+            .solution_grid
+            .iter()
+            .zip(&previous.solution_grid)
+            .enumerate()
+        {
+            for col in (0..cols).filter(|&col| row_data[col] != previous_row[col]) {
+                area = Some(match area {
+                    None => Area {
+                        start: (row, col),
+                        end: (row, col),
+                    },
+                    Some(Area { start, end }) => Area {
+                        start: (start.0.min(row), start.1.min(col)),
+                        end: (end.0.max(row), end.1.max(col)),
+                    },
+                });
+            }
+        }
+        area
+    }
+
+    /// Counts how many cells each color occupies in the nonogram solution grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `colors` - The number of colors in the palette.
+    ///
+    /// # Returns
+    ///
+    /// The number of cells of each color index, with at least `colors` entries.
+    pub fn color_counts(&self, colors: usize) -> Vec<usize> {
+        let mut counts = vec![0; colors];
+        for &color in self.solution_grid.iter().flatten() {
+            if color >= counts.len() {
+                counts.resize(color + 1, 0);
+            }
+            counts[color] += 1;
+        }
+        counts
+    }
+
+    /// Remaps the colors of the nonogram solution grid.
+    ///
+    /// Each cell with color `i` takes the color `mapping[i]`; colors outside of the mapping are kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `mapping` - The new color of each color index.
+    /// * `area` - The area of the grid to remap, or `None` to remap the whole grid.
+    pub fn remap(&mut self, mapping: &[usize], area: Option<Area>) {
+        for (row, row_data) in self.solution_grid.iter_mut().enumerate() {
+            for (col, cell) in row_data.iter_mut().enumerate() {
+                if area.is_none_or(|area| area.contains((row, col))) {
+                    *cell = mapping.get(*cell).copied().unwrap_or(*cell);
+                }
+            }
+        }
+    }
+
+    /// Rescales the shape of the drawing to a square grid, for comparing drawings of any size.
+    ///
+    /// Each block of the square covers the cells of the drawing under it, or the closest cell if
+    /// the drawing is smaller than the square.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The side of the square grid.
+    ///
+    /// # Returns
+    ///
+    /// The fraction of colored cells in each block of the square, row by row.
+    pub fn silhouette(&self, size: usize) -> Vec<f64> {
+        let (rows, cols) = (self.rows(), self.cols());
+        let span = |block: usize, len: usize| {
+            let start = block * len / size;
+            start..((block + 1) * len / size).max(start + 1).min(len)
+        };
+        (0..size * size)
+            .map(|block| {
+                let (rows, cols) = (span(block / size, rows), span(block % size, cols));
+                let cells = rows.len() * cols.len();
+                let filled = self.solution_grid[rows]
+                    .iter()
+                    .flat_map(|row| &row[cols.clone()])
+                    .filter(|&&color| color != BACKGROUND)
+                    .count();
+                filled as f64 / cells.max(1) as f64
+            })
+            .collect()
+    }
+
+    /// Transposes the solution grid, so its rows become the columns and the columns become the rows.
+    ///
+    /// # Returns
+    ///
+    /// The transposed grid.
+    pub fn transposed(&self) -> Self {
+        let solution_grid = (0..self.cols())
+            .map(|col| self.solution_grid.iter().map(|row| row[col]).collect())
+            .collect();
+        Self { solution_grid }
+    }
+
     /// Clears the entire nonogram solution grid, setting all cells to the default background color.
     pub fn clear(&mut self) {
         for row_data in self.solution_grid.iter_mut() {
@@ -375,6 +966,31 @@ impl NonogramPalette {
         }
     }
 
+    /// Chooses a color by the pressure of a stylus: the colors besides the background are ordered
+    /// from the lightest to the darkest, and a harder pressure picks a darker one.
+    ///
+    /// # Arguments
+    ///
+    /// * `pressure` - The pressure of the stylus, from `0.0` to `1.0`.
+    ///
+    /// # Returns
+    ///
+    /// The index of the chosen color, or the brush if there are no colors besides the background.
+    pub fn pressure_color(&self, pressure: f32) -> usize {
+        let luminance = |index: &usize| {
+            Self::parse_color(self.get(*index)).map_or(0.0, |(r, g, b)| {
+                0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32
+            })
+        };
+        let mut colors: Vec<usize> = (BACKGROUND + 1..self.len()).collect();
+        colors.sort_by(|first, second| luminance(second).total_cmp(&luminance(first)));
+        let step = (pressure.clamp(0.0, 1.0) * colors.len() as f32) as usize;
+        colors
+            .get(step.min(colors.len().saturating_sub(1)))
+            .copied()
+            .unwrap_or(self.brush)
+    }
+
     /// Determines the appropriate border color based on the background color's luminance.
     ///
     /// # Arguments
@@ -397,6 +1013,152 @@ impl NonogramPalette {
         }
     }
 
+    /// Computes the perceptually closest color of another palette for each color of the palette.
+    ///
+    /// The background is always mapped to the background of the other palette.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The palette replacing this one.
+    ///
+    /// # Returns
+    ///
+    /// The index in `other` of each color index, to be applied with `NonogramSolution::remap`.
+    pub fn closest_mapping(&self, other: &NonogramPalette) -> Vec<usize> {
+        (0..self.len())
+            .map(|index| {
+                if index == BACKGROUND || other.len() <= 1 {
+                    return BACKGROUND;
+                }
+                (1..other.len())
+                    .min_by(|&first, &second| {
+                        let distance = |candidate: usize| {
+                            Self::color_distance(self.get(index), other.get(candidate))
+                        };
+                        distance(first).total_cmp(&distance(second))
+                    })
+                    .unwrap_or(BACKGROUND)
+            })
+            .collect()
+    }
+
+    /// Appends the colors of another palette missing in this one (except its background).
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The palette whose colors are appended.
+    ///
+    /// # Returns
+    ///
+    /// The number of appended colors.
+    pub fn append_colors(&mut self, other: &NonogramPalette) -> usize {
+        let mut appended = 0;
+        for color in other.color_palette.iter().skip(BACKGROUND + 1) {
+            if !self
+                .color_palette
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(color))
+            {
+                self.add_color(color.clone());
+                appended += 1;
+            }
+        }
+        appended
+    }
+
+    /// Removes several colors from the palette, replacing them with another color.
+    ///
+    /// The background can't be removed, nor the replacement color. The remaining colors are
+    /// compacted, so the solution grid must be remapped with the returned mapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `removed` - The indexes of the colors to remove.
+    /// * `replacement` - The index of the color replacing the removed ones.
+    ///
+    /// # Returns
+    ///
+    /// The new index of each former color index, to be applied with `NonogramSolution::remap`.
+    pub fn remove_colors(&mut self, removed: &[usize], replacement: usize) -> Vec<usize> {
+        let is_removed =
+            |index: usize| index != BACKGROUND && index != replacement && removed.contains(&index);
+        let mut compacted = Vec::with_capacity(self.len());
+        let mut mapping: Vec<usize> = (0..self.len())
+            .map(|index| {
+                if is_removed(index) {
+                    0
+                } else {
+                    compacted.push(self.color_palette[index].clone());
+                    compacted.len() - 1
+                }
+            })
+            .collect();
+        let replacement = mapping.get(replacement).copied().unwrap_or(BACKGROUND);
+        for (index, new_index) in mapping.iter_mut().enumerate() {
+            if is_removed(index) {
+                *new_index = replacement;
+            }
+        }
+        self.color_palette = compacted;
+        self.brush = mapping.get(self.brush).copied().unwrap_or(BACKGROUND);
+        mapping
+    }
+
+    /// Lightens or darkens a color of the palette.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the color to shade.
+    /// * `percent` - How much the color is mixed with white (positive) or black (negative).
+    pub fn shade(&mut self, index: usize, percent: i32) {
+        let Some((r, g, b)) = self
+            .color_palette
+            .get(index)
+            .and_then(|color| Self::parse_color(color))
+        else {
+            return;
+        };
+        let amount = percent.clamp(-100, 100) as f32 / 100.0;
+        let mix = |c: u8| {
+            let c = c as f32;
+            let target = if amount >= 0.0 { 255.0 } else { 0.0 };
+            (c + (target - c) * amount.abs()).round() as u8
+        };
+        self.color_palette[index] = format!("#{:02x}{:02x}{:02x}", mix(r), mix(g), mix(b));
+    }
+
+    /// Maps a color to the gray of the same luminance, as it looks when photocopied.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - A string representing a hexadecimal color (e.g., `#RRGGBB`).
+    ///
+    /// # Returns
+    ///
+    /// The gray color, or the color unchanged if it can't be parsed.
+    pub fn grayscale_color(color: &str) -> String {
+        match Self::parse_color(color) {
+            Some((r, g, b)) => {
+                let level = (Self::luminance(r, g, b) * 255.0).round() as u8;
+                format!("#{level:02x}{level:02x}{level:02x}")
+            }
+            None => color.to_string(),
+        }
+    }
+
+    /// Returns the palette with every color mapped to the gray of the same luminance (see
+    /// `grayscale_color`), keeping the brush.
+    pub fn grayscale(&self) -> Self {
+        Self {
+            color_palette: self
+                .color_palette
+                .iter()
+                .map(|color| Self::grayscale_color(color))
+                .collect(),
+            brush: self.brush,
+        }
+    }
+
     /// Checks if a given color is darker based on its RGB values.
     ///
     /// # Arguments
@@ -409,12 +1171,26 @@ impl NonogramPalette {
     ///
     /// `true` if the color is darker, `false` otherwise.
     fn is_darker(r: u8, g: u8, b: u8) -> bool {
+        Self::luminance(r, g, b) <= 0.5
+    }
+
+    /// Computes the relative luminance of a color from its RGB values.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The red component of the color.
+    /// * `g` - The green component of the color.
+    /// * `b` - The blue component of the color.
+    ///
+    /// # Returns
+    ///
+    /// The luminance, from `0.0` (black) to `1.0` (white).
+    fn luminance(r: u8, g: u8, b: u8) -> f32 {
         let r = r as f32 / 255.0;
         let g = g as f32 / 255.0;
         let b = b as f32 / 255.0;
 
-        let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
-        luminance <= 0.5
+        0.2126 * r + 0.7152 * g + 0.0722 * b
     }
 
     /// Parses a hexadecimal color string into its RGB components.
@@ -426,7 +1202,7 @@ impl NonogramPalette {
     /// # Returns
     ///
     /// An `Option<(u8, u8, u8)>` containing the red, green, and blue components if the parsing is successful, otherwise `None`.
-    fn parse_color(color: &str) -> Option<(u8, u8, u8)> {
+    pub(crate) fn parse_color(color: &str) -> Option<(u8, u8, u8)> {
         if color.starts_with('#') && color.len() == 7 {
             let r = u8::from_str_radix(&color[1..3], 16).ok()?;
             let g = u8::from_str_radix(&color[3..5], 16).ok()?;
@@ -436,4 +1212,391 @@ impl NonogramPalette {
             None
         }
     }
+
+    /// Converts RGB components into the CIE L*a*b* color space (D65 white point).
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The red component of the color.
+    /// * `g` - The green component of the color.
+    /// * `b` - The blue component of the color.
+    ///
+    /// # Returns
+    ///
+    /// The `(L*, a*, b*)` components of the color.
+    pub(crate) fn to_lab(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+        let linear = |c: u8| {
+            let c = c as f32 / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let (r, g, b) = (linear(r), linear(g), linear(b));
+        let x = (0.4124 * r + 0.3576 * g + 0.1805 * b) / 0.95047;
+        let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let z = (0.0193 * r + 0.1192 * g + 0.9505 * b) / 1.08883;
+        let f = |t: f32| {
+            if t > 0.008856 {
+                t.cbrt()
+            } else {
+                7.787 * t + 16.0 / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(x), f(y), f(z));
+        (116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+    }
+
+    /// Computes the perceptual difference (CIE76 deltaE) between two colors of the palette.
+    ///
+    /// # Arguments
+    ///
+    /// * `first` - The index of the first color.
+    /// * `second` - The index of the second color.
+    ///
+    /// # Returns
+    ///
+    /// The deltaE between both colors, or `f32::INFINITY` if any of them can't be parsed.
+    pub fn delta_e(&self, first: usize, second: usize) -> f32 {
+        Self::color_distance(self.get(first), self.get(second))
+    }
+
+    /// Computes the perceptual difference (CIE76 deltaE) between two hexadecimal colors.
+    fn color_distance(first: &str, second: &str) -> f32 {
+        match (Self::parse_color(first), Self::parse_color(second)) {
+            (Some((r1, g1, b1)), Some((r2, g2, b2))) => {
+                let (l1, a1, b1) = Self::to_lab(r1, g1, b1);
+                let (l2, a2, b2) = Self::to_lab(r2, g2, b2);
+                ((l1 - l2).powi(2) + (a1 - a2).powi(2) + (b1 - b2).powi(2)).sqrt()
+            }
+            _ => f32::INFINITY,
+        }
+    }
+
+    /// Computes a color mapping reducing the used colors to at most `max_colors`.
+    ///
+    /// The perceptually closest pair of used colors is merged until the limit is reached: the
+    /// background absorbs any color merged with it, otherwise the most used color absorbs the
+    /// other one. Unused colors are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `counts` - The number of cells of each color (see `NonogramSolution::color_counts`).
+    /// * `max_colors` - The maximum number of used colors, including the background.
+    ///
+    /// # Returns
+    ///
+    /// The new color of each color index, to be applied with `NonogramSolution::remap`.
+    pub fn reduction_mapping(&self, counts: &[usize], max_colors: usize) -> Vec<usize> {
+        let mut mapping: Vec<usize> = (0..self.len()).collect();
+        let mut counts: Vec<usize> = (0..self.len())
+            .map(|color| counts.get(color).copied().unwrap_or(0))
+            .collect();
+        loop {
+            let used: Vec<usize> = (0..self.len()).filter(|&color| counts[color] > 0).collect();
+            if used.len() <= max_colors.max(1) {
+                return mapping;
+            }
+            let mut closest = (f32::INFINITY, used[0], used[1]);
+            for (i, &first) in used.iter().enumerate() {
+                for &second in &used[i + 1..] {
+                    let distance = self.delta_e(first, second);
+                    if distance < closest.0 {
+                        closest = (distance, first, second);
+                    }
+                }
+            }
+            let (_, first, second) = closest;
+            let (target, source) = if first == BACKGROUND || counts[first] >= counts[second] {
+                (first, second)
+            } else {
+                (second, first)
+            };
+            for color in mapping.iter_mut().filter(|color| **color == source) {
+                *color = target;
+            }
+            counts[target] += counts[source];
+            counts[source] = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::puzzles::{
+        tree_empty_nonogram_solution, tree_nonogram_file, LEAVES, WOOD,
+    };
+
+    use super::*;
+
+    // Test that the signature pattern depends on the author and the date, and survives a round trip.
+    #[test]
+    fn signature_pattern() {
+        let signature = Signature::new("ana", "Ana", 1_700_000_000);
+        let pattern = signature.pattern(70);
+        assert_eq!(pattern.len(), 70);
+        assert_eq!(pattern[..6], pattern[64..]);
+        assert_eq!(pattern, signature.pattern(70));
+        assert_ne!(
+            pattern,
+            Signature::new("ana", "Ana", 1_700_000_001).pattern(70)
+        );
+
+        let mut file = tree_nonogram_file();
+        let json = serde_json::to_string(&file).unwrap();
+        assert!(!json.contains("signature"));
+        file.signature = Some(signature.clone());
+        let json = serde_json::to_string(&file).unwrap();
+        let read: NonogramFile = serde_json::from_str(&json).unwrap();
+        assert_eq!(read.signature, Some(signature));
+    }
+
+    // Test that identical files have the same content hash, and any change gives another one.
+    #[test]
+    fn file_content_hash() {
+        let file = tree_nonogram_file();
+        let hash = file.content_hash();
+        assert_eq!(hash.len(), 16);
+        assert_eq!(hash, tree_nonogram_file().content_hash());
+
+        let mut noted = tree_nonogram_file();
+        noted.notes = String::from("Start from the trunk");
+        assert_ne!(hash, noted.content_hash());
+        let mut painted = tree_nonogram_file();
+        painted.solution.solution_grid[0][0] += 1;
+        assert_ne!(hash, painted.content_hash());
+    }
+
+    // Test that the diff classifies every cell and counts them per color.
+    #[test]
+    fn diff_against_reference() {
+        let reference = tree_nonogram_file().solution;
+        let mut attempt = reference.clone();
+        attempt.solution_grid[0][1] = BACKGROUND;
+        attempt.solution_grid[4][0] = LEAVES;
+        attempt.solution_grid[2][2] = LEAVES;
+
+        let diff = attempt.diff(&reference);
+        assert_eq!(diff.cells[0][1], CellDiff::Missing(LEAVES));
+        assert_eq!(diff.cells[4][0], CellDiff::Extra(LEAVES));
+        assert_eq!(
+            diff.cells[2][2],
+            CellDiff::Mismatch {
+                expected: WOOD,
+                found: LEAVES
+            }
+        );
+        assert_eq!(diff.cells[1][1], CellDiff::Match(LEAVES));
+        let leaves = reference.color_counts(3)[LEAVES];
+        assert_eq!(
+            diff.counts[LEAVES],
+            DiffCounts {
+                matching: leaves - 1,
+                missing: 1,
+                extra: 2,
+            }
+        );
+        assert_eq!(diff.counts[WOOD].missing, 1);
+    }
+
+    // Test that the empty lines are detected and cropped around the drawing.
+    #[test]
+    fn crop_empty_lines() {
+        let mut solution = tree_empty_nonogram_solution();
+        solution.set_rows(7);
+        solution.set_cols(6);
+        solution.solution_grid[1][2] = LEAVES;
+        solution.solution_grid[3][4] = WOOD;
+        assert_eq!(
+            solution.empty_lines(),
+            (vec![0, 2, 4, 5, 6], vec![0, 1, 3, 5])
+        );
+        let area = solution.bounding_box().unwrap();
+        solution.crop(area);
+        assert_eq!(
+            solution.solution_grid,
+            vec![vec![LEAVES, 0, 0], vec![0, 0, 0], vec![0, 0, WOOD]]
+        );
+
+        let mut line = tree_empty_nonogram_solution();
+        line.solution_grid[4][4] = LEAVES;
+        line.crop(line.bounding_box().unwrap());
+        assert_eq!(line.solution_grid, vec![vec![0, 0], vec![0, LEAVES]]);
+        assert_eq!(tree_empty_nonogram_solution().bounding_box(), None);
+    }
+
+    // Test that the drawing is slid to fit a smaller grid, instead of being cropped.
+    #[test]
+    fn slide_drawing_to_fit() {
+        let mut solution = tree_empty_nonogram_solution();
+        solution.solution_grid[3][3] = LEAVES;
+        solution.solution_grid[4][4] = WOOD;
+        assert_eq!(solution.cells_outside(4, 5), 1);
+        assert_eq!(solution.cells_outside(3, 3), 2);
+        assert_eq!(solution.slide_to_fit(3, 4), Some((-1, -2)));
+        assert_eq!(solution.slide_to_fit(1, 5), None);
+
+        let (dx, dy) = solution.slide_to_fit(2, 2).unwrap();
+        solution.slide(dx, dy);
+        assert_eq!(solution.cells_outside(2, 2), 0);
+        assert_eq!(solution.solution_grid[1][1], WOOD);
+    }
+
+    // Test that removing and merging palette colors compacts the palette and its indexes.
+    #[test]
+    fn remove_palette_colors() {
+        let mut palette = tree_nonogram_file().palette;
+        palette.add_color(String::from("#ff0000"));
+        palette.brush = 3;
+        let mapping = palette.remove_colors(&[BACKGROUND, LEAVES], BACKGROUND);
+        assert_eq!(mapping, vec![0, 0, 1, 2]);
+        assert_eq!(palette.len(), 3);
+        assert_eq!(palette.get(2), "#ff0000");
+        assert_eq!(palette.brush, 2);
+
+        let mut palette = tree_nonogram_file().palette;
+        let wood = palette.get(WOOD).to_string();
+        let mapping = palette.remove_colors(&[LEAVES, WOOD], WOOD);
+        assert_eq!(mapping, vec![0, 1, 1]);
+        assert_eq!(palette.color_palette[1], wood);
+    }
+
+    // Test that loaded palettes are remapped to the closest colors or appended.
+    #[test]
+    fn load_palette_colors() {
+        let mut palette = tree_nonogram_file().palette;
+        palette.color_palette = vec![
+            String::from("#ffffff"),
+            String::from("#ff0000"),
+            String::from("#0000ff"),
+        ];
+        let mut other = palette.clone();
+        other.color_palette = vec![
+            String::from("#000000"),
+            String::from("#0000aa"),
+            String::from("#aa0000"),
+            String::from("#00ff00"),
+        ];
+        assert_eq!(palette.closest_mapping(&other), vec![0, 2, 1]);
+        assert_eq!(palette.append_colors(&other), 3);
+        assert_eq!(palette.append_colors(&other), 0);
+        assert_eq!(palette.len(), 6);
+    }
+
+    // Test that shading mixes the colors with white or black.
+    #[test]
+    fn shade_palette_colors() {
+        let mut palette = tree_nonogram_file().palette;
+        palette.color_palette[1] = String::from("#804020");
+        palette.shade(1, 50);
+        assert_eq!(palette.get(1), "#c0a090");
+        palette.shade(1, -100);
+        assert_eq!(palette.get(1), "#000000");
+    }
+
+    // Test that the colors are mapped to the grays of the same luminance.
+    #[test]
+    fn grayscale_palette_colors() {
+        assert_eq!(NonogramPalette::grayscale_color("#ff0000"), "#363636");
+        assert_eq!(NonogramPalette::grayscale_color("#ffffff"), "#ffffff");
+        assert_eq!(NonogramPalette::grayscale_color("red"), "red");
+        let palette = tree_nonogram_file().palette;
+        let grayscale = palette.grayscale();
+        assert_eq!(grayscale.len(), palette.len());
+        assert_eq!(grayscale.brush, palette.brush);
+        assert!(grayscale
+            .color_palette
+            .iter()
+            .all(|color| color[1..3] == color[3..5] && color[3..5] == color[5..7]));
+    }
+
+    // Test that the block size fits the grid and its clues in the viewport, within the input range.
+    #[test]
+    fn auto_block_size_fits_viewport() {
+        let puzzle = NonogramPuzzle::from_solution(&tree_nonogram_file().solution);
+        let clues = puzzle.row_constraints.iter().map(Vec::len).max().unwrap();
+        let size = puzzle.auto_block_size(1280.0, 720.0);
+        assert_eq!(size % 5, 0);
+        assert!((size * (puzzle.cols + clues)) as f64 <= 1280.0 * 0.9);
+        assert_eq!(puzzle.auto_block_size(8000.0, 8000.0), 100);
+        assert_eq!(puzzle.auto_block_size(100.0, 100.0), 10);
+    }
+
+    // Test that the silhouette averages the colored cells of each block.
+    #[test]
+    fn downscaled_silhouette() {
+        let solution = NonogramSolution {
+            solution_grid: vec![vec![1, 1, 0, 0], vec![1, 0, 0, 0]],
+        };
+        assert_eq!(solution.silhouette(2), vec![1.0, 0.0, 0.5, 0.0]);
+        assert_eq!(solution.silhouette(1), vec![0.375]);
+        let corner = NonogramSolution {
+            solution_grid: vec![vec![1, 0], vec![0, 0]],
+        };
+        assert_eq!(
+            &corner.silhouette(4)[..8],
+            &[1.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0]
+        );
+        let silhouette = tree_nonogram_file().solution.silhouette(8);
+        assert_eq!(silhouette.len(), 64);
+        assert!(silhouette
+            .iter()
+            .all(|fraction| (0.0..=1.0).contains(fraction)));
+    }
+
+    // Test that the changed area bounds every edited cell, or the whole grid if it was resized.
+    #[test]
+    fn changed_area_bounds_edits() {
+        let original = tree_nonogram_file().solution;
+        assert_eq!(original.changed_area(&original), None);
+        let mut edited = original.clone();
+        edited.solution_grid[4][1] = 3;
+        edited.solution_grid[2][4] = 3;
+        assert_eq!(
+            edited.changed_area(&original),
+            Some(Area {
+                start: (2, 1),
+                end: (4, 4)
+            })
+        );
+        let resized = NonogramSolution {
+            solution_grid: vec![vec![0; 3]; 2],
+        };
+        assert_eq!(
+            resized.changed_area(&original),
+            Some(Area {
+                start: (0, 0),
+                end: (1, 2)
+            })
+        );
+    }
+
+    // Test that transposing swaps the rows and the columns of the puzzles and their solutions.
+    #[test]
+    fn transposed_puzzle_and_solution() {
+        let solution = tree_nonogram_file().solution;
+        let transposed = solution.transposed();
+        assert_eq!(transposed.rows(), solution.cols());
+        assert_eq!(transposed.solution_grid[2][4], solution.solution_grid[4][2]);
+        assert_eq!(transposed.transposed(), solution);
+        assert_eq!(
+            NonogramPuzzle::from_solution(&solution).transposed(),
+            NonogramPuzzle::from_solution(&transposed)
+        );
+    }
+
+    // Test that grids with different dimensions are compared as if padded with the background.
+    #[test]
+    fn diff_with_different_dimensions() {
+        let reference = tree_nonogram_file().solution;
+        let mut attempt = tree_empty_nonogram_solution();
+        attempt.set_rows(6);
+        let diff = attempt.diff(&reference);
+        assert_eq!(diff.cells.len(), 6);
+        assert_eq!(diff.cells[5], vec![CellDiff::Match(BACKGROUND); 5]);
+        let colored = reference.color_counts(3)[LEAVES..].iter().sum::<usize>();
+        let missing: usize = diff.counts.iter().map(|counts| counts.missing).sum();
+        assert_eq!(missing, colored);
+    }
 }
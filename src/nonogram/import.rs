@@ -0,0 +1,256 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Conversion of photos into solution grids, downscaled in strips.
+//!
+//! The photo is decoded by the platform and handed over one strip of pixels per grid row, each
+//! strip already reduced to a few samples per cell. Every strip is averaged and quantized to the
+//! palette as soon as it arrives, so only the grid being built is kept in memory, however large
+//! the photo is.
+
+// Import the grids built and the palettes their colors are quantized to
+use super::definitions::{NonogramPalette, NonogramSolution, BACKGROUND};
+
+// Import formatting traits for displaying the import errors.
+use std::fmt;
+
+/// The samples taken along each side of a cell, averaged into its color.
+pub const IMPORT_SAMPLES: usize = 4;
+
+/// The lowest alpha of a sample counted as opaque; cells with mostly transparent samples are
+/// left as background.
+const OPAQUE_ALPHA: u8 = 128;
+
+/// An error importing a strip of a photo.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ImportError {
+    /// The strip belongs to a row outside of the grid.
+    RowOutOfBounds { row: usize },
+    /// The strip doesn't hold the expected number of bytes.
+    StripSize { expected: usize, found: usize },
+}
+
+impl fmt::Display for ImportError {
+    /// Formats the error as a message for the logs.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::RowOutOfBounds { row } => {
+                write!(f, "the strip of row {} is outside of the grid", row + 1)
+            }
+            ImportError::StripSize { expected, found } => {
+                write!(f, "the strip has {found} bytes instead of {expected}")
+            }
+        }
+    }
+}
+
+/// A photo being converted into a solution grid, strip by strip.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ImageImport {
+    /// The grid built, with the rows imported so far.
+    grid: NonogramSolution,
+    /// The colors of the palette in the CIE L*a*b* space, `None` if they can't be parsed.
+    colors: Vec<Option<(f32, f32, f32)>>,
+    /// Whether each row was imported.
+    imported: Vec<bool>,
+}
+
+impl ImageImport {
+    /// Starts importing a photo stretched over a grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `rows` - The rows of the grid.
+    /// * `cols` - The columns of the grid.
+    /// * `palette` - The palette whose closest color is given to each cell.
+    pub fn new(rows: usize, cols: usize, palette: &NonogramPalette) -> Self {
+        let colors = palette
+            .color_palette
+            .iter()
+            .map(|color| {
+                NonogramPalette::parse_color(color)
+                    .map(|(r, g, b)| NonogramPalette::to_lab(r, g, b))
+            })
+            .collect();
+        Self {
+            grid: NonogramSolution {
+                solution_grid: vec![vec![BACKGROUND; cols]; rows],
+            },
+            colors,
+            imported: vec![false; rows],
+        }
+    }
+
+    /// Computes the number of bytes of each strip: `IMPORT_SAMPLES` lines of RGBA pixels, with
+    /// `IMPORT_SAMPLES` pixels for each column of the grid.
+    pub fn strip_len(&self) -> usize {
+        self.grid.cols() * IMPORT_SAMPLES * IMPORT_SAMPLES * 4
+    }
+
+    /// Imports the strip of pixels covering a row of the grid.
+    ///
+    /// Each cell takes the palette color closest to the average of its opaque samples.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - The row of the grid covered by the strip.
+    /// * `rgba` - The RGBA bytes of the strip, line by line (see `ImageImport::strip_len`).
+    ///
+    /// # Returns
+    ///
+    /// An `ImportError` if the row or the size of the strip are wrong.
+    pub fn add_strip(&mut self, row: usize, rgba: &[u8]) -> Result<(), ImportError> {
+        if row >= self.grid.rows() {
+            return Err(ImportError::RowOutOfBounds { row });
+        }
+        if rgba.len() != self.strip_len() {
+            return Err(ImportError::StripSize {
+                expected: self.strip_len(),
+                found: rgba.len(),
+            });
+        }
+        let width = self.grid.cols() * IMPORT_SAMPLES;
+        for col in 0..self.grid.cols() {
+            let mut sum = [0u32; 3];
+            let mut opaque = 0;
+            for line in 0..IMPORT_SAMPLES {
+                for sample in 0..IMPORT_SAMPLES {
+                    let pixel = (line * width + col * IMPORT_SAMPLES + sample) * 4;
+                    if rgba[pixel + 3] >= OPAQUE_ALPHA {
+                        for (channel, sum) in sum.iter_mut().enumerate() {
+                            *sum += rgba[pixel + channel] as u32;
+                        }
+                        opaque += 1;
+                    }
+                }
+            }
+            self.grid.solution_grid[row][col] = if opaque * 2 < IMPORT_SAMPLES * IMPORT_SAMPLES {
+                BACKGROUND
+            } else {
+                let [r, g, b] = sum.map(|sum| (sum / opaque as u32) as u8);
+                self.closest_color(r, g, b)
+            };
+        }
+        self.imported[row] = true;
+        Ok(())
+    }
+
+    /// Finds the palette color perceptually closest to a color.
+    fn closest_color(&self, r: u8, g: u8, b: u8) -> usize {
+        let (l, a, b) = NonogramPalette::to_lab(r, g, b);
+        self.colors
+            .iter()
+            .enumerate()
+            .filter_map(|(index, color)| {
+                color.map(|(l2, a2, b2)| {
+                    let distance = (l - l2).powi(2) + (a - a2).powi(2) + (b - b2).powi(2);
+                    (index, distance)
+                })
+            })
+            .min_by(|(_, first), (_, second)| first.total_cmp(second))
+            .map_or(BACKGROUND, |(index, _)| index)
+    }
+
+    /// Returns the fraction of the rows imported, from `0.0` to `1.0`.
+    pub fn progress(&self) -> f64 {
+        let imported = self.imported.iter().filter(|&&imported| imported).count();
+        imported as f64 / self.imported.len().max(1) as f64
+    }
+
+    /// Checks if every row of the grid was imported.
+    pub fn is_complete(&self) -> bool {
+        self.imported.iter().all(|&imported| imported)
+    }
+
+    /// Finishes the import, leaving the rows not imported as background.
+    ///
+    /// # Returns
+    ///
+    /// The solution grid of the photo.
+    pub fn finish(self) -> NonogramSolution {
+        self.grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nonogram::puzzles::tree_nonogram_palette;
+
+    // Builds a strip whose cells are painted with a single RGBA color each.
+    fn strip(cells: &[[u8; 4]]) -> Vec<u8> {
+        let mut rgba = Vec::new();
+        for _ in 0..IMPORT_SAMPLES {
+            for cell in cells {
+                for _ in 0..IMPORT_SAMPLES {
+                    rgba.extend(cell);
+                }
+            }
+        }
+        rgba
+    }
+
+    // Test that each cell takes the closest palette color, and transparent cells the background.
+    #[test]
+    fn strips_quantized_to_palette() {
+        let palette = NonogramPalette {
+            color_palette: vec!["#ffffff".into(), "#000000".into(), "#ff0000".into()],
+            brush: 0,
+        };
+        let mut import = ImageImport::new(2, 3, &palette);
+        import
+            .add_strip(
+                1,
+                &strip(&[[250, 240, 245, 255], [20, 10, 0, 255], [200, 30, 20, 255]]),
+            )
+            .unwrap();
+        assert_eq!(import.progress(), 0.5);
+        assert!(!import.is_complete());
+        import
+            .add_strip(0, &strip(&[[0, 0, 0, 0], [0, 0, 0, 255], [0, 0, 0, 10]]))
+            .unwrap();
+        assert!(import.is_complete());
+        assert_eq!(
+            import.finish().solution_grid,
+            vec![vec![0, 1, 0], vec![0, 1, 2]]
+        );
+    }
+
+    // Test that the strips outside of the grid or of the wrong size are rejected.
+    #[test]
+    fn wrong_strips_rejected() {
+        let mut import = ImageImport::new(2, 3, &tree_nonogram_palette());
+        let rgba = strip(&[[0, 0, 0, 255]; 3]);
+        assert_eq!(
+            import.add_strip(2, &rgba),
+            Err(ImportError::RowOutOfBounds { row: 2 })
+        );
+        assert_eq!(
+            import.add_strip(0, &rgba[4..]),
+            Err(ImportError::StripSize {
+                expected: rgba.len(),
+                found: rgba.len() - 4
+            })
+        );
+        assert_eq!(import.progress(), 0.0);
+    }
+}
@@ -0,0 +1,510 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the definitions of the solution grid being journaled.
+use super::definitions::NonogramSolution;
+
+// Import serialization support so the journal can be stored or sent to other instances.
+use serde::{Deserialize, Serialize};
+
+/// The tool (or process) responsible for an action in the journal.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum Tool {
+    /// Painting of single cells.
+    Brush,
+    /// Drawing of horizontal or vertical lines.
+    Line,
+    /// Clearing of the whole grid.
+    Clear,
+    /// Sliding of the whole grid.
+    Slide,
+    /// Resizing of the grid rows or columns.
+    Resize,
+    /// Loading of a Nonogram file.
+    Load,
+    /// Writing of a solver result.
+    Solver,
+    /// Cleanup of the grid with a filter.
+    Filter,
+    /// Remapping of the colors of the grid.
+    Remap,
+    /// Jumping to an earlier state of the grid with the history scrubber.
+    Scrub,
+    /// Importing of a photo into the grid.
+    Import,
+    /// Pasting of a region copied to the clipboard.
+    Paste,
+}
+
+/// A single cell modification, storing the previous color so it can be reverted.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub struct CellChange {
+    /// The row of the modified cell.
+    pub row: usize,
+    /// The column of the modified cell.
+    pub col: usize,
+    /// The color of the cell before the modification.
+    pub previous: usize,
+    /// The color of the cell after the modification.
+    pub color: usize,
+}
+
+/// An action recorded in the journal.
+///
+/// Every action stores enough information to be applied again (replay, sync) or reverted (undo).
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum Action {
+    /// Some cells changed their color, keeping the grid dimensions.
+    Cells {
+        /// The tool used to change the cells.
+        tool: Tool,
+        /// The modified cells.
+        changes: Vec<CellChange>,
+    },
+    /// The whole grid was replaced, usually because its dimensions changed.
+    Grid {
+        /// The tool used to replace the grid.
+        tool: Tool,
+        /// The grid before the action.
+        previous: NonogramSolution,
+        /// The grid after the action.
+        current: NonogramSolution,
+    },
+    /// A solver was run on the puzzle.
+    SolverRun {
+        /// Whether the solver found a solution satisfying every constraint.
+        solved: bool,
+        /// The score of the best candidate found, with the fitness the solver minimized.
+        score: usize,
+        /// The number of iterations the solver ran.
+        iterations: usize,
+    },
+}
+
+impl Action {
+    /// Builds the action that transforms the `previous` grid into the `current` one.
+    ///
+    /// # Returns
+    ///
+    /// `None` if both grids are equal, `Action::Cells` if they have the same dimensions,
+    /// or `Action::Grid` otherwise.
+    pub fn edit(
+        tool: Tool,
+        previous: &NonogramSolution,
+        current: &NonogramSolution,
+    ) -> Option<Self> {
+        if previous.solution_grid == current.solution_grid {
+            None
+        } else if previous.rows() == current.rows() && previous.cols() == current.cols() {
+            let changes = previous
+                .solution_grid
+                .iter()
+                .zip(current.solution_grid.iter())
+                .enumerate()
+                .flat_map(|(row, (previous_row, current_row))| {
+                    previous_row
+                        .iter()
+                        .zip(current_row.iter())
+                        .enumerate()
+                        .filter(|(_, (previous, color))| previous != color)
+                        .map(move |(col, (&previous, &color))| CellChange {
+                            row,
+                            col,
+                            previous,
+                            color,
+                        })
+                })
+                .collect();
+            Some(Action::Cells { tool, changes })
+        } else {
+            Some(Action::Grid {
+                tool,
+                previous: previous.clone(),
+                current: current.clone(),
+            })
+        }
+    }
+
+    /// Returns the tool that produced the action, if it modified the grid.
+    pub fn tool(&self) -> Option<Tool> {
+        match self {
+            Action::Cells { tool, .. } | Action::Grid { tool, .. } => Some(*tool),
+            Action::SolverRun { .. } => None,
+        }
+    }
+
+    /// Applies the action to a solution grid, as it happened originally.
+    pub fn apply(&self, solution: &mut NonogramSolution) {
+        match self {
+            Action::Cells { changes, .. } => {
+                for change in changes {
+                    solution.solution_grid[change.row][change.col] = change.color;
+                }
+            }
+            Action::Grid { current, .. } => *solution = current.clone(),
+            Action::SolverRun { .. } => {}
+        }
+    }
+
+    /// Reverts the action on a solution grid, restoring its state before the action.
+    pub fn revert(&self, solution: &mut NonogramSolution) {
+        match self {
+            Action::Cells { changes, .. } => {
+                for change in changes.iter().rev() {
+                    solution.solution_grid[change.row][change.col] = change.previous;
+                }
+            }
+            Action::Grid { previous, .. } => *solution = previous.clone(),
+            Action::SolverRun { .. } => {}
+        }
+    }
+}
+
+/// An action recorded in the journal with its position in the sequence.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Entry {
+    /// The position of the action in the journal, starting at zero.
+    pub index: usize,
+    /// The recorded action.
+    pub action: Action,
+}
+
+/// A hook called with every entry recorded in the journal.
+type Hook = Box<dyn FnMut(&Entry)>;
+
+/// Identifier of a subscription to the journal, used to unsubscribe.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Subscription(usize);
+
+/// An append-only journal of the actions performed on a Nonogram.
+///
+/// Components record every modification of the solution grid here, and features such as undo,
+/// replay, autosave or synchronization subscribe to be notified of each new entry.
+///
+/// Subscribers are called while the journal is borrowed, so they must not access the journal itself.
+#[derive(Default)]
+pub struct ActionLog {
+    /// The recorded entries, in the order they happened.
+    entries: Vec<Entry>,
+    /// The hooks called after recording each entry.
+    subscribers: Vec<(Subscription, Hook)>,
+    /// The identifier of the next subscription.
+    next_subscription: usize,
+}
+
+impl ActionLog {
+    /// Appends an action to the journal and notifies the subscribers.
+    ///
+    /// # Returns
+    ///
+    /// The index of the recorded entry.
+    pub fn record(&mut self, action: Action) -> usize {
+        let entry = Entry {
+            index: self.entries.len(),
+            action,
+        };
+        for (_, hook) in self.subscribers.iter_mut() {
+            hook(&entry);
+        }
+        self.entries.push(entry);
+        self.entries.len() - 1
+    }
+
+    /// Registers a hook called with every entry recorded from now on.
+    pub fn subscribe(&mut self, hook: impl FnMut(&Entry) + 'static) -> Subscription {
+        let subscription = Subscription(self.next_subscription);
+        self.next_subscription += 1;
+        self.subscribers.push((subscription, Box::new(hook)));
+        subscription
+    }
+
+    /// Removes a previously registered hook.
+    pub fn unsubscribe(&mut self, subscription: Subscription) {
+        self.subscribers.retain(|(id, _)| *id != subscription);
+    }
+
+    /// Returns every recorded entry, in the order they happened.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Returns the entries recorded from the given index onwards.
+    pub fn since(&self, index: usize) -> &[Entry] {
+        &self.entries[index.min(self.entries.len())..]
+    }
+
+    /// Returns the number of recorded entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Rebuilds the grid after each recorded modification, for replaying how it was drawn.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - The grid after the last recorded action, from which the initial grid is restored.
+    ///
+    /// # Returns
+    ///
+    /// The initial grid followed by the grid after each action modifying it.
+    pub fn frames(&self, current: &NonogramSolution) -> Vec<NonogramSolution> {
+        let mut solution = current.clone();
+        for entry in self.entries.iter().rev() {
+            entry.action.revert(&mut solution);
+        }
+        let mut frames = vec![solution.clone()];
+        for entry in self.entries.iter() {
+            if entry.action.tool().is_some() {
+                entry.action.apply(&mut solution);
+                frames.push(solution.clone());
+            }
+        }
+        frames
+    }
+
+    /// Applies every recorded action, in order, to a solution grid.
+    pub fn replay(&self, solution: &mut NonogramSolution) {
+        for entry in self.entries.iter() {
+            entry.action.apply(solution);
+        }
+    }
+}
+
+/// The number of steps between the full snapshots of a `Timeline`.
+pub const KEYFRAME_INTERVAL: usize = 32;
+
+/// The states of the grid since the last loaded puzzle, for scrubbing through the history.
+///
+/// Storing every state would take a grid per step in long sessions, so only a full snapshot
+/// (keyframe) every `KEYFRAME_INTERVAL` steps is stored, and the states in between are rebuilt
+/// by applying the recorded actions, which only store the modified cells.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Timeline {
+    /// The grid every `KEYFRAME_INTERVAL` steps, starting with the grid before the first step.
+    keyframes: Vec<NonogramSolution>,
+    /// The actions modifying the grid, in order.
+    steps: Vec<Action>,
+}
+
+impl Timeline {
+    /// Builds the timeline of the actions recorded since the last loaded puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `log` - The journal of the actions.
+    /// * `current` - The grid after the last recorded action.
+    pub fn new(log: &ActionLog, current: &NonogramSolution) -> Self {
+        let steps: Vec<Action> = Self::entries(log)
+            .iter()
+            .filter(|entry| entry.action.tool().is_some())
+            .map(|entry| entry.action.clone())
+            .collect();
+        let mut solution = current.clone();
+        for step in steps.iter().rev() {
+            step.revert(&mut solution);
+        }
+        let mut keyframes = vec![solution.clone()];
+        for (index, step) in steps.iter().enumerate() {
+            step.apply(&mut solution);
+            if (index + 1).is_multiple_of(KEYFRAME_INTERVAL) {
+                keyframes.push(solution.clone());
+            }
+        }
+        Self { keyframes, steps }
+    }
+
+    /// Counts the steps recorded since the last loaded puzzle, without rebuilding the states.
+    pub fn count(log: &ActionLog) -> usize {
+        Self::entries(log)
+            .iter()
+            .filter(|entry| entry.action.tool().is_some())
+            .count()
+    }
+
+    /// Returns the entries recorded after the last loaded puzzle.
+    fn entries(log: &ActionLog) -> &[Entry] {
+        let start = log
+            .entries()
+            .iter()
+            .rposition(|entry| entry.action.tool() == Some(Tool::Load))
+            .map_or(0, |index| index + 1);
+        log.since(start)
+    }
+
+    /// Returns the number of steps of the timeline, the last position.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Returns `true` if the grid wasn't modified since the last loaded puzzle.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Rebuilds the grid at a position of the timeline, from the nearest keyframe before it.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The number of steps applied, clamped to the length of the timeline.
+    pub fn state(&self, position: usize) -> NonogramSolution {
+        let position = position.min(self.steps.len());
+        let keyframe = position / KEYFRAME_INTERVAL;
+        let mut solution = self.keyframes[keyframe].clone();
+        for step in &self.steps[keyframe * KEYFRAME_INTERVAL..position] {
+            step.apply(&mut solution);
+        }
+        solution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::nonogram::puzzles::{tree_empty_nonogram_solution, tree_nonogram_file};
+
+    use super::*;
+
+    // Test that an edit between two grids of the same size records only the modified cells,
+    // and that reverting and applying it restores both grids.
+    #[test]
+    fn cells_action_reverts_and_applies() {
+        let previous = tree_empty_nonogram_solution();
+        let current = tree_nonogram_file().solution;
+        let action = Action::edit(Tool::Brush, &previous, &current).unwrap();
+        match &action {
+            Action::Cells { changes, .. } => assert_eq!(changes.len(), 15),
+            _ => panic!("Expected a cells action, got {:?}", action),
+        }
+
+        let mut solution = current.clone();
+        action.revert(&mut solution);
+        assert_eq!(solution.solution_grid, previous.solution_grid);
+        action.apply(&mut solution);
+        assert_eq!(solution.solution_grid, current.solution_grid);
+    }
+
+    // Test that the frames rebuild the grid after each modification, skipping solver runs.
+    #[test]
+    fn frames_rebuild_each_step() {
+        let empty = tree_empty_nonogram_solution();
+        let drawn = tree_nonogram_file().solution;
+        let mut half = empty.clone();
+        half.solution_grid[0] = drawn.solution_grid[0].clone();
+        let mut log = ActionLog::default();
+        log.record(Action::edit(Tool::Brush, &empty, &half).unwrap());
+        log.record(Action::SolverRun {
+            solved: true,
+            score: 0,
+            iterations: 1,
+        });
+        log.record(Action::edit(Tool::Line, &half, &drawn).unwrap());
+
+        let frames = log.frames(&drawn);
+        assert_eq!(frames, vec![empty, half, drawn]);
+    }
+
+    // Test that a resize is recorded as a whole grid replacement and that no action is
+    // recorded when the grid doesn't change.
+    #[test]
+    fn grid_action_on_resize() {
+        let previous = tree_nonogram_file().solution;
+        let mut current = previous.clone();
+        current.set_rows(7);
+        let action = Action::edit(Tool::Resize, &previous, &current).unwrap();
+        assert!(matches!(action, Action::Grid { .. }));
+
+        let mut solution = current.clone();
+        action.revert(&mut solution);
+        assert_eq!(solution.solution_grid, previous.solution_grid);
+        assert_eq!(Action::edit(Tool::Resize, &previous, &previous), None);
+    }
+
+    // Test that the timeline rebuilds every state since the last load from its keyframes.
+    #[test]
+    fn timeline_states_since_load() {
+        let empty = tree_empty_nonogram_solution();
+        let mut log = ActionLog::default();
+        let drawn = tree_nonogram_file().solution;
+        log.record(Action::edit(Tool::Load, &drawn, &empty).unwrap());
+        let mut states = vec![empty.clone()];
+        let mut solution = empty;
+        for step in 0..70 {
+            let mut next = solution.clone();
+            next.solution_grid[step % 5][step / 5 % 5] = step % 3;
+            if let Some(action) = Action::edit(Tool::Brush, &solution, &next) {
+                log.record(action);
+                states.push(next.clone());
+            }
+            solution = next;
+        }
+
+        let timeline = Timeline::new(&log, &solution);
+        assert_eq!(timeline.len(), states.len() - 1);
+        assert_eq!(Timeline::count(&log), timeline.len());
+        assert_eq!(
+            timeline.keyframes.len(),
+            timeline.len() / KEYFRAME_INTERVAL + 1
+        );
+        for (position, state) in states.iter().enumerate() {
+            assert_eq!(timeline.state(position), *state);
+        }
+        assert_eq!(timeline.state(usize::MAX), solution);
+    }
+
+    // Test that subscribers are notified of every entry until they unsubscribe, and that
+    // replaying the journal reproduces the final grid.
+    #[test]
+    fn subscribers_and_replay() {
+        let mut log = ActionLog::default();
+        let notified = Rc::new(RefCell::new(Vec::new()));
+        let hook_notified = notified.clone();
+        let subscription = log.subscribe(move |entry| hook_notified.borrow_mut().push(entry.index));
+
+        let empty = tree_empty_nonogram_solution();
+        let mut painted = empty.clone();
+        painted.draw_line((0, 0), (0, 4), 1);
+        log.record(Action::edit(Tool::Line, &empty, &painted).unwrap());
+        log.record(Action::SolverRun {
+            solved: false,
+            score: 3,
+            iterations: 10,
+        });
+        log.unsubscribe(subscription);
+        let mut cleared = painted.clone();
+        cleared.clear();
+        log.record(Action::edit(Tool::Clear, &painted, &cleared).unwrap());
+
+        assert_eq!(*notified.borrow(), vec![0, 1]);
+        assert_eq!(log.since(1).len(), 2);
+
+        let mut solution = empty.clone();
+        log.replay(&mut solution);
+        assert_eq!(solution.solution_grid, cleared.solution_grid);
+    }
+}
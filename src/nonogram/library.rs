@@ -0,0 +1,326 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the archives the library is exported to.
+use super::archive::Archive;
+
+// Import the palette given to the exported solutions, which carry no colors.
+use super::clues::clue_palette;
+
+// Import the puzzles and the solutions cached for them.
+use super::definitions::{NonogramFile, NonogramPuzzle, NonogramSolution};
+
+// Import the profiles, each with its own library.
+use super::profile::{namespaced, Profile};
+
+// Import the persistence layer, storing the library between sessions.
+use super::storage;
+
+// Import serialization of the stored library.
+use serde::{Deserialize, Serialize};
+
+// Import the ordered map of the solutions, by the hash of their puzzle.
+use std::collections::BTreeMap;
+
+/// The side of the grids the drawings are downscaled to, when searching the library by drawing.
+pub const SILHOUETTE_SIZE: usize = 8;
+
+/// The storage key of the library of solutions.
+pub const LIBRARY_KEY: &str = "ngram-library.json";
+
+/// The local library of the solutions found for the puzzles without a stored solution, such as the
+/// puzzles imported from their clues, and of the notes written by the user about each puzzle.
+///
+/// The solutions and the notes are identified by the constraints of their puzzle (see
+/// `NonogramPuzzle::constraint_hash`), so importing the same clues again finds them.
+#[derive(Clone, Default, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct SolutionLibrary {
+    /// The storage key of the library, namespaced in the profile it was loaded for.
+    #[serde(skip)]
+    key: String,
+    /// The cached solutions, by the hash of their puzzle.
+    solutions: BTreeMap<String, NonogramSolution>,
+    /// The notes of the user, such as solving strategies, by the hash of their puzzle.
+    notes: BTreeMap<String, String>,
+}
+
+impl SolutionLibrary {
+    /// Loads the library of a profile stored by a previous session.
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The profile whose library is loaded, or `None` for the guest library.
+    ///
+    /// # Returns
+    ///
+    /// The stored library, or an empty library if it's missing or unreadable.
+    pub fn load_profile(profile: Option<&Profile>) -> Self {
+        let key = namespaced(profile, LIBRARY_KEY);
+        let mut library: Self = storage::read(&key)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        library.key = key;
+        library
+    }
+
+    /// Stores the library for the next sessions.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the library could be stored.
+    pub fn save(&self) -> bool {
+        serde_json::to_string(self).is_ok_and(|json| storage::write(&self.key, &json))
+    }
+
+    /// Returns the solution cached for a puzzle, if any.
+    pub fn get(&self, puzzle: &NonogramPuzzle) -> Option<&NonogramSolution> {
+        self.solutions.get(&puzzle.constraint_hash())
+    }
+
+    /// Caches the solution of a puzzle, replacing the previous one.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the solution doesn't satisfy the puzzle, which isn't cached.
+    pub fn insert(&mut self, puzzle: &NonogramPuzzle, solution: NonogramSolution) -> bool {
+        if NonogramPuzzle::from_solution(&solution) != *puzzle {
+            return false;
+        }
+        self.solutions.insert(puzzle.constraint_hash(), solution);
+        true
+    }
+
+    /// Returns the notes written by the user about a puzzle, if any.
+    pub fn notes(&self, puzzle: &NonogramPuzzle) -> Option<&str> {
+        self.notes
+            .get(&puzzle.constraint_hash())
+            .map(String::as_str)
+    }
+
+    /// Replaces the notes written by the user about a puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle the notes are about.
+    /// * `notes` - The new notes, removed if they're blank.
+    pub fn set_notes(&mut self, puzzle: &NonogramPuzzle, notes: &str) {
+        let hash = puzzle.constraint_hash();
+        if notes.trim().is_empty() {
+            self.notes.remove(&hash);
+        } else {
+            self.notes.insert(hash, notes.to_string());
+        }
+    }
+
+    /// Adds every puzzle of the library to an archive, under `library/`: each cached solution as a
+    /// `.ngram` file, with the palette given to the puzzles imported from their clues, and the
+    /// notes of the user as `.md` files, all named after the hash of their puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `archive` - The archive the files are added to.
+    pub fn add_to_archive(&self, archive: &mut Archive) {
+        for (hash, solution) in &self.solutions {
+            let file = NonogramFile {
+                solution: solution.clone(),
+                palette: clue_palette(&NonogramPuzzle::from_solution(solution)),
+                signature: None,
+                notes: String::new(),
+            };
+            if let Ok(json) = serde_json::to_string(&file) {
+                archive.add(&format!("library/{hash}.ngram"), json.into_bytes());
+            }
+        }
+        for (hash, notes) in &self.notes {
+            archive.add(&format!("library/{hash}.md"), notes.clone().into_bytes());
+        }
+    }
+
+    /// Ranks the solutions of the library by their similarity with a rough sketch, so a
+    /// half-remembered puzzle can be found by drawing its shape.
+    ///
+    /// The sketch and the solutions are downscaled to `SILHOUETTE_SIZE` squares (see
+    /// `NonogramSolution::silhouette`) and compared by the correlation of their cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `sketch` - The rough shape drawn, in any size and colors.
+    ///
+    /// # Returns
+    ///
+    /// The hash of the puzzle of each solution, the solution, and its similarity from `-1.0` to
+    /// `1.0`, the most similar first.
+    pub fn search_by_drawing(
+        &self,
+        sketch: &NonogramSolution,
+    ) -> Vec<(&str, &NonogramSolution, f64)> {
+        let sketch = sketch.silhouette(SILHOUETTE_SIZE);
+        let mut ranked: Vec<(&str, &NonogramSolution, f64)> = self
+            .solutions
+            .iter()
+            .map(|(hash, solution)| {
+                let similarity = correlation(&sketch, &solution.silhouette(SILHOUETTE_SIZE));
+                (hash.as_str(), solution, similarity)
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.2.total_cmp(&a.2));
+        ranked
+    }
+
+    /// Returns the number of cached solutions.
+    pub fn len(&self) -> usize {
+        self.solutions.len()
+    }
+
+    /// Checks if no solution is cached.
+    pub fn is_empty(&self) -> bool {
+        self.solutions.is_empty()
+    }
+}
+
+/// Computes the Pearson correlation of two silhouettes of the same size.
+///
+/// # Returns
+///
+/// The correlation from `-1.0` to `1.0`, or `0.0` if a silhouette is uniform (such as an empty
+/// sketch), since its shape can't be compared.
+fn correlation(first: &[f64], second: &[f64]) -> f64 {
+    let len = first.len().min(second.len()).max(1) as f64;
+    let mean = |values: &[f64]| values.iter().sum::<f64>() / len;
+    let (first_mean, second_mean) = (mean(first), mean(second));
+    let (mut covariance, mut first_variance, mut second_variance) = (0.0, 0.0, 0.0);
+    for (a, b) in first.iter().zip(second) {
+        covariance += (a - first_mean) * (b - second_mean);
+        first_variance += (a - first_mean).powi(2);
+        second_variance += (b - second_mean).powi(2);
+    }
+    if first_variance == 0.0 || second_variance == 0.0 {
+        return 0.0;
+    }
+    covariance / (first_variance * second_variance).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::puzzles::{tree_nonogram_file, tree_nonogram_puzzle};
+
+    use super::*;
+
+    // Test that only the solutions satisfying their puzzle are cached, and found by their clues.
+    #[test]
+    fn cached_solutions() {
+        let mut library = SolutionLibrary::default();
+        let puzzle = tree_nonogram_puzzle();
+        let solution = tree_nonogram_file().solution;
+        assert!(library.get(&puzzle).is_none());
+        assert!(!library.insert(
+            &puzzle,
+            NonogramSolution {
+                solution_grid: vec![vec![0; puzzle.cols]; puzzle.rows],
+            },
+        ));
+        assert!(library.is_empty());
+        assert!(library.insert(&puzzle, solution.clone()));
+        assert_eq!(library.get(&puzzle), Some(&solution));
+
+        let json = serde_json::to_string(&library).unwrap();
+        let stored: SolutionLibrary = serde_json::from_str(&json).unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored.get(&puzzle), Some(&solution));
+    }
+
+    // Test that the notes are kept by puzzle, and removed when they're blank.
+    #[test]
+    fn puzzle_notes() {
+        let mut library = SolutionLibrary::default();
+        let puzzle = tree_nonogram_puzzle();
+        assert_eq!(library.notes(&puzzle), None);
+        library.set_notes(&puzzle, "Start with the **trunk**.");
+        let json = serde_json::to_string(&library).unwrap();
+        let stored: SolutionLibrary = serde_json::from_str(&json).unwrap();
+        assert_eq!(stored.notes(&puzzle), Some("Start with the **trunk**."));
+        assert!(stored.is_empty());
+        library.set_notes(&puzzle, "  \n");
+        assert_eq!(library.notes(&puzzle), None);
+    }
+
+    // Test that the solutions are ranked by the similarity of their shape with the sketch.
+    #[test]
+    fn search_by_drawing() {
+        let mut library = SolutionLibrary::default();
+        let tree = tree_nonogram_file().solution;
+        let bar = NonogramSolution {
+            solution_grid: vec![vec![0, 0, 0, 0, 0], vec![1; 5], vec![0, 0, 0, 0, 0]],
+        };
+        library.insert(&NonogramPuzzle::from_solution(&tree), tree.clone());
+        library.insert(&NonogramPuzzle::from_solution(&bar), bar.clone());
+
+        let sketch = NonogramSolution {
+            solution_grid: vec![vec![0, 0], vec![1, 1], vec![1, 1], vec![0, 0]],
+        };
+        let ranked = library.search_by_drawing(&sketch);
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].1, &bar);
+        assert!(ranked[0].2 > ranked[1].2);
+
+        let ranked = library.search_by_drawing(&tree);
+        assert_eq!(ranked[0].1, &tree);
+        assert!((ranked[0].2 - 1.0).abs() < 1e-9);
+
+        let empty = NonogramSolution {
+            solution_grid: vec![vec![0; 3]; 3],
+        };
+        assert!(library
+            .search_by_drawing(&empty)
+            .iter()
+            .all(|(_, _, similarity)| *similarity == 0.0));
+    }
+
+    // Test that the exported archive has a file for each solution and each note.
+    #[test]
+    fn library_archive() {
+        let mut library = SolutionLibrary::default();
+        let puzzle = tree_nonogram_puzzle();
+        let solution = tree_nonogram_file().solution;
+        library.insert(&puzzle, solution.clone());
+        library.set_notes(&puzzle, "Trunk first");
+        let mut archive = Archive::default();
+        library.add_to_archive(&mut archive);
+        let hash = puzzle.constraint_hash();
+        let names: Vec<&str> = archive
+            .entries()
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                format!("library/{hash}.ngram"),
+                format!("library/{hash}.md")
+            ]
+        );
+        let file: NonogramFile = serde_json::from_slice(&archive.entries()[0].data).unwrap();
+        assert_eq!(file.solution, solution);
+        assert_eq!(archive.entries()[1].data, b"Trunk first");
+    }
+}
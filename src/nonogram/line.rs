@@ -0,0 +1,332 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the segments of the lines solved.
+use super::definitions::{NonogramSegment, BACKGROUND};
+
+// Import the random number generation, for sampling the arrangements of a line.
+use rand::{rngs::StdRng, seq::SliceRandom};
+
+/// The knowledge about a cell while solving a puzzle by logic: the set of colors it can still take.
+///
+/// The set is stored as a bit mask, so puzzles can use up to 64 colors (including the background).
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct CellState(u64);
+
+impl CellState {
+    /// Creates the state of a cell that can take any of the first `colors` colors.
+    pub fn any(colors: usize) -> Self {
+        Self(if colors >= 64 {
+            u64::MAX
+        } else {
+            (1 << colors) - 1
+        })
+    }
+
+    /// Creates the state of a cell known to have a color.
+    pub fn known(color: usize) -> Self {
+        debug_assert!(color < 64, "color {color} doesn't fit in the cell state");
+        Self(1 << color)
+    }
+
+    /// Checks if the cell can still take a color.
+    pub fn allows(self, color: usize) -> bool {
+        color < 64 && self.0 & (1 << color) != 0
+    }
+
+    /// Returns the color of the cell if it's the only one it can take.
+    pub fn color(self) -> Option<usize> {
+        (self.0.count_ones() == 1).then(|| self.0.trailing_zeros() as usize)
+    }
+
+    /// Checks if the cell can't take any color, i.e. the puzzle has no solution from this state.
+    pub fn is_contradiction(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the colors the cell can still take.
+    pub fn colors(self) -> impl Iterator<Item = usize> {
+        (0..64).filter(move |&color| self.allows(color))
+    }
+
+    /// Returns the state without a color.
+    pub fn without(self, color: usize) -> Self {
+        debug_assert!(color < 64, "color {color} doesn't fit in the cell state");
+        Self(self.0 & !(1 << color))
+    }
+}
+
+/// What the line solver deduces about a line from the current state of its cells.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum LineDeduction {
+    /// No placement of the segments is consistent with the line, so it can't be completed.
+    Contradiction,
+    /// The colors the cells can still take.
+    Deduced {
+        /// The deduced state of each cell.
+        cells: Vec<CellState>,
+        /// The indexes of the cells narrowed from their current state, in order.
+        changed: Vec<usize>,
+    },
+}
+
+impl LineDeduction {
+    /// Returns the deduced state of each cell, or `None` if the line is contradicted.
+    pub fn cells(self) -> Option<Vec<CellState>> {
+        match self {
+            LineDeduction::Contradiction => None,
+            LineDeduction::Deduced { cells, .. } => Some(cells),
+        }
+    }
+
+    /// Checks if no placement of the segments is consistent with the line.
+    pub fn is_contradiction(&self) -> bool {
+        *self == LineDeduction::Contradiction
+    }
+}
+
+/// Deduces the colors the cells of a line can take, given its constraints.
+///
+/// Every placement of the segments consistent with the current state of the cells is considered:
+/// a cell keeps a color only if some placement gives it that color. Consecutive segments of the
+/// same color need at least one background cell between them, while segments of different colors
+/// can be adjacent.
+///
+/// The placements are explored with dynamic programming over the prefixes and suffixes of the line,
+/// so the cost is polynomial in the length of the line and the number of segments.
+///
+/// This is the single-line solver shared by the rest of the crate: the hints, the logic solver and
+/// the exact search deduce the grid with it (see `logic::propagate`), and the genetic algorithm samples
+/// and repairs its chromosomes with it (see `sample_line`).
+///
+/// # Arguments
+///
+/// * `constraints` - The segments of the line, in order.
+/// * `current` - The current state of the cells of the line.
+///
+/// # Returns
+///
+/// The deduced state of each cell and the cells narrowed, or a contradiction if no placement is
+/// consistent with the line.
+pub fn solve_line(constraints: &[NonogramSegment], current: &[CellState]) -> LineDeduction {
+    let n = current.len();
+    let k = constraints.len();
+    let background = |x: usize| current[x].allows(BACKGROUND);
+    // Whether the segment `j` can cover the cells `p..p + length`.
+    let fits = |j: usize, p: usize| {
+        let segment = &constraints[j];
+        p + segment.length <= n && (p..p + segment.length).all(|x| current[x].allows(segment.color))
+    };
+    // Whether the segment `j` must be separated from the segment `j - 1`.
+    let joined = |j: usize| j > 0 && j < k && constraints[j - 1].color == constraints[j].color;
+
+    // `prefix[i][j]`: the first `j` segments fit in the cells `0..i`.
+    // `prefix_gap[i][j]`: the same, with the cell `i - 1` as background (or `i == 0`).
+    let mut prefix = vec![vec![false; k + 1]; n + 1];
+    let mut prefix_gap = vec![vec![false; k + 1]; n + 1];
+    prefix[0][0] = true;
+    prefix_gap[0][0] = true;
+    for i in 1..=n {
+        for j in 0..=k {
+            prefix_gap[i][j] = background(i - 1) && prefix[i - 1][j];
+            prefix[i][j] = prefix_gap[i][j]
+                || (j > 0 && i >= constraints[j - 1].length && {
+                    let p = i - constraints[j - 1].length;
+                    fits(j - 1, p)
+                        && if joined(j - 1) {
+                            prefix_gap[p][j - 1]
+                        } else {
+                            prefix[p][j - 1]
+                        }
+                });
+        }
+    }
+    if !prefix[n][k] {
+        return LineDeduction::Contradiction;
+    }
+
+    // `suffix[i][j]`: the segments from `j` fit in the cells `i..n`.
+    // `suffix_gap[i][j]`: the same, with the cell `i` as background (or `i == n`).
+    let mut suffix = vec![vec![false; k + 1]; n + 1];
+    let mut suffix_gap = vec![vec![false; k + 1]; n + 1];
+    suffix[n][k] = true;
+    suffix_gap[n][k] = true;
+    for i in (0..n).rev() {
+        for j in (0..=k).rev() {
+            suffix_gap[i][j] = background(i) && suffix[i + 1][j];
+            suffix[i][j] = suffix_gap[i][j]
+                || (j < k && fits(j, i) && {
+                    let end = i + constraints[j].length;
+                    if joined(j + 1) {
+                        suffix_gap[end][j + 1]
+                    } else {
+                        suffix[end][j + 1]
+                    }
+                });
+        }
+    }
+
+    let mut possible = vec![0u64; n];
+    for (j, segment) in constraints.iter().enumerate() {
+        for p in 0..n {
+            if !fits(j, p) {
+                continue;
+            }
+            let end = p + segment.length;
+            let before = if joined(j) {
+                prefix_gap[p][j]
+            } else {
+                prefix[p][j]
+            };
+            let after = if joined(j + 1) {
+                suffix_gap[end][j + 1]
+            } else {
+                suffix[end][j + 1]
+            };
+            if before && after {
+                for cell in &mut possible[p..end] {
+                    *cell |= 1 << segment.color;
+                }
+            }
+        }
+    }
+    for (x, cell) in possible.iter_mut().enumerate() {
+        if background(x) && (0..=k).any(|j| prefix[x][j] && suffix[x + 1][j]) {
+            *cell |= 1 << BACKGROUND;
+        }
+    }
+    let cells: Vec<CellState> = current
+        .iter()
+        .zip(possible)
+        .map(|(state, possible)| CellState(state.0 & possible))
+        .collect();
+    if cells.iter().any(|state| state.is_contradiction()) {
+        return LineDeduction::Contradiction;
+    }
+    let changed = (0..n).filter(|&x| cells[x] != current[x]).collect();
+    LineDeduction::Deduced { cells, changed }
+}
+
+/// Picks a random arrangement of the segments of a line, consistent with the current state of
+/// its cells.
+///
+/// The cells are fixed one by one to a random color they can still take, deducing the rest of
+/// the line again after each one, so every choice leads to a valid arrangement.
+///
+/// # Arguments
+///
+/// * `constraints` - The segments of the line, in order.
+/// * `line` - The current state of the cells of the line.
+/// * `rng` - The random number generator choosing the colors.
+///
+/// # Returns
+///
+/// The colors of the cells, or `None` if no placement is consistent with the line.
+pub fn sample_line(
+    constraints: &[NonogramSegment],
+    line: &[CellState],
+    rng: &mut StdRng,
+) -> Option<Vec<usize>> {
+    let mut line = solve_line(constraints, line).cells()?;
+    for x in 0..line.len() {
+        if line[x].color().is_none() {
+            let colors: Vec<usize> = line[x].colors().collect();
+            line[x] = CellState::known(*colors.choose(rng)?);
+            line = solve_line(constraints, &line).cells()?;
+        }
+    }
+    line.into_iter().map(CellState::color).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::definitions::NonogramSolution;
+    use crate::nrule;
+    use rand::SeedableRng;
+
+    use super::*;
+
+    // Test the deductions of the line solver on lines with one and several colors.
+    #[test]
+    fn line_deductions() {
+        let unknown = vec![CellState::any(3); 5];
+        let overlap = solve_line(&[nrule!(1, 3)], &unknown).cells().unwrap();
+        assert_eq!(overlap[2], CellState::known(1));
+        assert_eq!(overlap[0], CellState::any(2));
+
+        let full = solve_line(&[nrule!(1, 2), nrule!(1, 2)], &unknown)
+            .cells()
+            .unwrap();
+        assert_eq!(
+            full.iter().map(|state| state.color()).collect::<Vec<_>>(),
+            vec![Some(1), Some(1), Some(0), Some(1), Some(1)]
+        );
+
+        let adjacent = solve_line(&[nrule!(1, 3), nrule!(2, 2)], &unknown)
+            .cells()
+            .unwrap();
+        assert!(adjacent.iter().all(|state| state.color().is_some()));
+        assert_eq!(adjacent[3], CellState::known(2));
+
+        assert!(solve_line(&[nrule!(1, 3), nrule!(1, 2)], &unknown).is_contradiction());
+        assert!(solve_line(&[], &[CellState::known(1)]).is_contradiction());
+
+        // Only the cells narrowed are reported
+        let mut partial = overlap;
+        partial[0] = CellState::known(1);
+        assert_eq!(
+            solve_line(&[nrule!(1, 3)], &partial),
+            LineDeduction::Deduced {
+                cells: vec![
+                    CellState::known(1),
+                    CellState::known(1),
+                    CellState::known(1),
+                    CellState::known(BACKGROUND),
+                    CellState::known(BACKGROUND),
+                ],
+                changed: vec![1, 3, 4],
+            }
+        );
+    }
+
+    // Test that the sampled arrangements respect the clues and the known cells.
+    #[test]
+    fn sampled_lines() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let constraints = [nrule!(1, 2), nrule!(2, 1)];
+        let mut line = vec![CellState::any(3); 6];
+        line[0] = CellState::known(BACKGROUND);
+        for _ in 0..20 {
+            let sampled = sample_line(&constraints, &line, &mut rng).unwrap();
+            let solution = NonogramSolution {
+                solution_grid: vec![sampled.clone()],
+            };
+            assert_eq!(solution.row_constraints()[0], constraints);
+            assert_eq!(sampled[0], BACKGROUND);
+        }
+        line[1] = CellState::known(BACKGROUND);
+        line[2] = CellState::known(BACKGROUND);
+        assert!(sample_line(&constraints, &line, &mut rng).is_some());
+        line[3] = CellState::known(BACKGROUND);
+        assert_eq!(sample_line(&constraints, &line, &mut rng), None);
+    }
+}
@@ -0,0 +1,253 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the identifiers of the languages of the interface
+use dioxus_i18n::unic_langid::LanguageIdentifier;
+
+// Import the conversion of timestamps into dates
+use super::timer::civil_date;
+
+/// The order of the day, the month and the year in the dates of a language.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DateOrder {
+    /// `MM/DD/YYYY`, as in the United States.
+    MonthDayYear,
+    /// `DD/MM/YYYY`, as in most of Latin America and Europe.
+    DayMonthYear,
+    /// `YYYY-MM-DD`, as in the ISO 8601 standard.
+    YearMonthDay,
+}
+
+/// The conventions of a language for displaying numbers, durations and dates, so the statistics,
+/// the charts and the notifications read naturally in the language of the interface.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LocaleFormat {
+    /// The separator between the groups of thousands.
+    pub thousands_separator: char,
+    /// The separator between the integer and the fractional digits.
+    pub decimal_separator: char,
+    /// The order of the day, the month and the year.
+    pub date_order: DateOrder,
+    /// Whether the times are written with a 12-hour clock (AM/PM).
+    pub twelve_hour: bool,
+}
+
+impl Default for LocaleFormat {
+    /// The conventions of the default language of the interface (`en-US`).
+    fn default() -> Self {
+        Self {
+            thousands_separator: ',',
+            decimal_separator: '.',
+            date_order: DateOrder::MonthDayYear,
+            twelve_hour: true,
+        }
+    }
+}
+
+impl LocaleFormat {
+    /// Chooses the conventions of a language, by its language and region.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The language of the interface, such as `es-MX`.
+    ///
+    /// # Returns
+    ///
+    /// The conventions of the language, or the ISO conventions for the unknown languages.
+    pub fn new(language: &LanguageIdentifier) -> Self {
+        let region = language.region.as_ref().map(|region| region.as_str());
+        let day_first = |thousands_separator, decimal_separator| Self {
+            thousands_separator,
+            decimal_separator,
+            date_order: DateOrder::DayMonthYear,
+            twelve_hour: false,
+        };
+        match (language.language.as_str(), region) {
+            ("en", None | Some("US")) => Self::default(),
+            ("en", _) => day_first(',', '.'),
+            // Mexico and the United States group the thousands like English
+            ("es", Some("MX" | "US")) => day_first(',', '.'),
+            ("es" | "pt" | "it" | "de" | "nl", _) => day_first('.', ','),
+            ("fr", _) => day_first('\u{202f}', ','),
+            _ => Self {
+                thousands_separator: ',',
+                decimal_separator: '.',
+                date_order: DateOrder::YearMonthDay,
+                twelve_hour: false,
+            },
+        }
+    }
+
+    /// Formats an integer, grouping its thousands, such as scores and population sizes.
+    pub fn integer(&self, value: impl Into<i64>) -> String {
+        let value: i64 = value.into();
+        let digits = value.unsigned_abs().to_string();
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+        if value < 0 {
+            grouped.push('-');
+        }
+        for (i, digit) in digits.chars().enumerate() {
+            if i > 0 && (digits.len() - i).is_multiple_of(3) {
+                grouped.push(self.thousands_separator);
+            }
+            grouped.push(digit);
+        }
+        grouped
+    }
+
+    /// Formats a count, grouping its thousands (see `integer`).
+    pub fn count(&self, value: usize) -> String {
+        self.integer(i64::try_from(value).unwrap_or(i64::MAX))
+    }
+
+    /// Formats a number with a fixed number of fractional digits, grouping its thousands.
+    pub fn decimal(&self, value: f64, digits: usize) -> String {
+        let fixed = format!("{:.*}", digits, value.abs());
+        let (integer, fraction) = fixed.split_once('.').unwrap_or((&fixed, ""));
+        let sign = if value < 0.0 && fixed.chars().any(|c| c.is_ascii_digit() && c != '0') {
+            "-"
+        } else {
+            ""
+        };
+        let integer = self.integer(integer.parse::<i64>().unwrap_or(i64::MAX));
+        if fraction.is_empty() {
+            format!("{sign}{integer}")
+        } else {
+            format!("{sign}{integer}{}{fraction}", self.decimal_separator)
+        }
+    }
+
+    /// Formats a duration in milliseconds as a clock, `mm:ss` (or `h:mm:ss` past one hour), for
+    /// the solve timer.
+    pub fn clock(&self, milliseconds: f64) -> String {
+        let seconds = (milliseconds.max(0.0) / 1000.0) as u64;
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        if hours > 0 {
+            format!("{}:{minutes:02}:{seconds:02}", self.count(hours as usize))
+        } else {
+            format!("{minutes:02}:{seconds:02}")
+        }
+    }
+
+    /// Formats a duration in milliseconds with its units, such as `12.5 s`, `3 min 05 s` or
+    /// `1 h 02 min`, for the statistics.
+    pub fn duration(&self, milliseconds: f64) -> String {
+        let milliseconds = milliseconds.max(0.0);
+        if milliseconds < 60_000.0 {
+            return format!("{} s", self.decimal(milliseconds / 1000.0, 1));
+        }
+        let minutes = (milliseconds / 60_000.0) as u64;
+        if minutes < 60 {
+            let seconds = (milliseconds / 1000.0) as u64 % 60;
+            format!("{minutes} min {seconds:02} s")
+        } else {
+            format!(
+                "{} h {:02} min",
+                self.count((minutes / 60) as usize),
+                minutes % 60
+            )
+        }
+    }
+
+    /// Formats a timestamp in seconds since the Unix epoch as a date and a time in UTC, such as
+    /// `02/29/2024 12:34 PM` in `en-US` or `29/02/2024 12:34` in `es-MX`.
+    pub fn date_time(&self, timestamp: i64) -> String {
+        let (_, _, _, hour, minute) = civil_date(timestamp);
+        let time = if self.twelve_hour {
+            let suffix = if hour < 12 { "AM" } else { "PM" };
+            format!("{}:{minute:02} {suffix}", (hour + 11) % 12 + 1)
+        } else {
+            format!("{hour:02}:{minute:02}")
+        };
+        format!("{} {time}", self.date(timestamp))
+    }
+
+    /// Formats the day of a timestamp in seconds since the Unix epoch, in UTC.
+    pub fn date(&self, timestamp: i64) -> String {
+        let (year, month, day, _, _) = civil_date(timestamp);
+        match self.date_order {
+            DateOrder::MonthDayYear => format!("{month:02}/{day:02}/{year:04}"),
+            DateOrder::DayMonthYear => format!("{day:02}/{month:02}/{year:04}"),
+            DateOrder::YearMonthDay => format!("{year:04}-{month:02}-{day:02}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus_i18n::unic_langid::langid;
+
+    use super::*;
+
+    // Test that the numbers group their thousands with the separators of each language.
+    #[test]
+    fn numbers_follow_the_language() {
+        let english = LocaleFormat::new(&langid!("en-US"));
+        assert_eq!(english.count(1_234_567), "1,234,567");
+        assert_eq!(english.integer(-1000), "-1,000");
+        assert_eq!(english.count(999), "999");
+        assert_eq!(english.decimal(12_345.678, 1), "12,345.7");
+        assert_eq!(english.decimal(-0.01, 1), "0.0");
+
+        let mexican = LocaleFormat::new(&langid!("es-MX"));
+        assert_eq!(mexican.count(500), "500");
+        assert_eq!(mexican.decimal(1234.5, 2), "1,234.50");
+
+        let spanish = LocaleFormat::new(&langid!("es-ES"));
+        assert_eq!(spanish.decimal(1234.5, 2), "1.234,50");
+    }
+
+    // Test that the durations are formatted as clocks and with their units.
+    #[test]
+    fn durations_follow_the_language() {
+        let english = LocaleFormat::default();
+        assert_eq!(english.clock(65_400.0), "01:05");
+        assert_eq!(english.clock(3_725_000.0), "1:02:05");
+        assert_eq!(english.duration(12_540.0), "12.5 s");
+        assert_eq!(english.duration(185_000.0), "3 min 05 s");
+        assert_eq!(english.duration(3_725_000.0), "1 h 02 min");
+        assert_eq!(
+            LocaleFormat::new(&langid!("de-DE")).duration(12_540.0),
+            "12,5 s"
+        );
+    }
+
+    // Test that the dates follow the order and the clock of each language.
+    #[test]
+    fn dates_follow_the_language() {
+        // 2024-02-29 12:34:56 UTC
+        let timestamp = 1_709_210_096;
+        assert_eq!(
+            LocaleFormat::new(&langid!("en-US")).date_time(timestamp),
+            "02/29/2024 12:34 PM"
+        );
+        assert_eq!(
+            LocaleFormat::new(&langid!("es-MX")).date_time(timestamp),
+            "29/02/2024 12:34"
+        );
+        assert_eq!(
+            LocaleFormat::new(&langid!("ja-JP")).date_time(timestamp),
+            "2024-02-29 12:34"
+        );
+        assert_eq!(LocaleFormat::default().date_time(0), "01/01/1970 12:00 AM");
+    }
+}
@@ -0,0 +1,782 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the definitions of the puzzles solved by logic and their segments.
+use super::definitions::{NonogramPuzzle, NonogramSegment, NonogramSolution, BACKGROUND};
+
+// Import the lines of the puzzles, flagged when they can no longer be completed.
+use super::clues::ClueLine;
+
+// Import the single-line solver deducing each line.
+use super::line::{solve_line, CellState, LineDeduction};
+
+// Import the SAT solver, taking over the exact searches that need many guesses.
+#[cfg(feature = "sat")]
+use super::sat::find_solutions_sat;
+
+/// The guesses of the backtracking search before an exact search is handed to the SAT solver.
+#[cfg(feature = "sat")]
+pub const SAT_GUESSES: usize = 1000;
+
+/// How constrained a line of a puzzle is by its clues.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Tightness {
+    /// The clues fill the line, there's a single arrangement.
+    Tight,
+    /// The slack is shorter than some segment, so the overlap of its placements is deduced at once.
+    Overlapping,
+    /// The slack is longer than every segment, nothing is deduced from the line alone.
+    Slack,
+}
+
+/// The freedom of the segments of a line to move.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LineSlack {
+    /// The number of free cells once the segments are packed to the start of the line.
+    pub slack: usize,
+    /// The number of arrangements of the segments in the line (saturated to `u128::MAX`).
+    pub arrangements: u128,
+    /// How constrained the line is.
+    pub tightness: Tightness,
+}
+
+/// Computes how freely the segments of a line can move.
+///
+/// The `slack` free cells can be distributed among the `k + 1` gaps around the `k` segments, so the
+/// line has `C(slack + k, k)` arrangements.
+///
+/// # Arguments
+///
+/// * `constraints` - The segments of the line, in order.
+/// * `length` - The length of the line.
+///
+/// # Returns
+///
+/// The slack of the line, or `None` if the segments don't fit in the line.
+pub fn line_slack(constraints: &[NonogramSegment], length: usize) -> Option<LineSlack> {
+    let gaps = constraints
+        .windows(2)
+        .filter(|pair| pair[0].color == pair[1].color)
+        .count();
+    let filled = constraints
+        .iter()
+        .map(|segment| segment.length)
+        .sum::<usize>()
+        + gaps;
+    let slack = length.checked_sub(filled)?;
+    let segments = constraints.len() as u128;
+    let arrangements = (1..=segments).fold(1u128, |arrangements, i| {
+        // The running product `C(slack + i, i)` is always divisible by `i`.
+        arrangements
+            .checked_mul(slack as u128 + i)
+            .map_or(u128::MAX, |product| product / i)
+    });
+    let longest = constraints
+        .iter()
+        .map(|segment| segment.length)
+        .max()
+        .unwrap_or(0);
+    let tightness = if slack == 0 || constraints.is_empty() {
+        Tightness::Tight
+    } else if slack < longest {
+        Tightness::Overlapping
+    } else {
+        Tightness::Slack
+    };
+    Some(LineSlack {
+        slack,
+        arrangements,
+        tightness,
+    })
+}
+
+/// Computes the slack of every line of a puzzle.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle to analyze.
+///
+/// # Returns
+///
+/// The slack of the rows and the columns, `None` for the lines whose segments don't fit.
+pub fn puzzle_slack(puzzle: &NonogramPuzzle) -> (Vec<Option<LineSlack>>, Vec<Option<LineSlack>>) {
+    (
+        puzzle
+            .row_constraints
+            .iter()
+            .map(|constraints| line_slack(constraints, puzzle.cols))
+            .collect(),
+        puzzle
+            .col_constraints
+            .iter()
+            .map(|constraints| line_slack(constraints, puzzle.rows))
+            .collect(),
+    )
+}
+
+/// Creates the initial state of the cells of a puzzle, before any deduction.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle to solve.
+///
+/// # Returns
+///
+/// A grid where each cell can take the background or any color of the constraints.
+pub fn initial_states(puzzle: &NonogramPuzzle) -> Vec<Vec<CellState>> {
+    let colors = puzzle
+        .row_constraints
+        .iter()
+        .chain(puzzle.col_constraints.iter())
+        .flatten()
+        .map(|segment| segment.color + 1)
+        .max()
+        .unwrap_or(1)
+        .max(BACKGROUND + 1);
+    vec![vec![CellState::any(colors); puzzle.cols]; puzzle.rows]
+}
+
+/// Applies the line solver to the rows and columns of a puzzle until no more cells can be deduced.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle being solved.
+/// * `grid` - The current state of the cells, updated with the deductions.
+///
+/// # Returns
+///
+/// `false` if a line has no consistent placement, i.e. the puzzle has no solution from this state.
+pub fn propagate(puzzle: &NonogramPuzzle, grid: &mut [Vec<CellState>]) -> bool {
+    propagate_lines(
+        puzzle,
+        grid,
+        vec![true; puzzle.rows],
+        vec![true; puzzle.cols],
+    )
+}
+
+/// Applies the line solver starting from some lines, following the lines whose cells change.
+fn propagate_lines(
+    puzzle: &NonogramPuzzle,
+    grid: &mut [Vec<CellState>],
+    mut dirty_rows: Vec<bool>,
+    mut dirty_cols: Vec<bool>,
+) -> bool {
+    while dirty_rows.contains(&true) || dirty_cols.contains(&true) {
+        for row in 0..puzzle.rows {
+            if !std::mem::take(&mut dirty_rows[row]) {
+                continue;
+            }
+            let LineDeduction::Deduced { cells, changed } =
+                solve_line(&puzzle.row_constraints[row], &grid[row])
+            else {
+                return false;
+            };
+            for col in changed {
+                grid[row][col] = cells[col];
+                dirty_cols[col] = true;
+            }
+        }
+        for col in 0..puzzle.cols {
+            if !std::mem::take(&mut dirty_cols[col]) {
+                continue;
+            }
+            let line: Vec<CellState> = grid.iter().map(|row| row[col]).collect();
+            let LineDeduction::Deduced { cells, changed } =
+                solve_line(&puzzle.col_constraints[col], &line)
+            else {
+                return false;
+            };
+            for row in changed {
+                grid[row][col] = cells[row];
+                dirty_rows[row] = true;
+            }
+        }
+    }
+    true
+}
+
+/// Computes the depth of search needed to deduce each cell of a puzzle.
+///
+/// - Depth `0`: the cell is deduced by line logic alone (see `propagate`).
+/// - Depth `1`: the cell is deduced by trying a color in a single cell and finding a contradiction.
+/// - Depth `2`: the cell can't be deduced without deeper guessing (or the puzzle isn't unique).
+///
+/// Authors can use it to tweak their artwork toward puzzles solvable by pure logic.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle to analyze.
+///
+/// # Returns
+///
+/// The depth of each cell, or `None` if the puzzle has no solution.
+pub fn search_depths(puzzle: &NonogramPuzzle) -> Option<Vec<Vec<u8>>> {
+    let mut grid = initial_states(puzzle);
+    if !propagate(puzzle, &mut grid) {
+        return None;
+    }
+    let known = |grid: &[Vec<CellState>], depth: u8| -> Vec<Vec<u8>> {
+        grid.iter()
+            .map(|row| {
+                row.iter()
+                    .map(|state| if state.color().is_some() { depth } else { 2 })
+                    .collect()
+            })
+            .collect()
+    };
+    let mut depths = known(&grid, 0);
+    loop {
+        let mut progress = false;
+        for row in 0..puzzle.rows {
+            for col in 0..puzzle.cols {
+                if grid[row][col].color().is_some() {
+                    continue;
+                }
+                for color in grid[row][col].colors() {
+                    let mut trial = grid.clone();
+                    trial[row][col] = CellState::known(color);
+                    let mut dirty_rows = vec![false; puzzle.rows];
+                    let mut dirty_cols = vec![false; puzzle.cols];
+                    dirty_rows[row] = true;
+                    dirty_cols[col] = true;
+                    if !propagate_lines(puzzle, &mut trial, dirty_rows, dirty_cols) {
+                        grid[row][col] = grid[row][col].without(color);
+                        progress = true;
+                    }
+                }
+            }
+        }
+        if !progress {
+            return Some(depths);
+        }
+        if !propagate(puzzle, &mut grid) {
+            return None;
+        }
+        for (depth_row, known_row) in depths.iter_mut().zip(known(&grid, 1)) {
+            for (depth, known) in depth_row.iter_mut().zip(known_row) {
+                *depth = (*depth).min(known);
+            }
+        }
+    }
+}
+
+/// Finds a hint for a grid being solved: a cell deduced by line logic from the clues whose
+/// color doesn't match the grid yet.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle being solved.
+/// * `solution` - The grid being solved.
+///
+/// # Returns
+///
+/// The cell `(row, column, color)` to paint, or `None` if every deduced cell already matches.
+pub fn hint(puzzle: &NonogramPuzzle, solution: &NonogramSolution) -> Option<(usize, usize, usize)> {
+    let mut grid = initial_states(puzzle);
+    if !propagate(puzzle, &mut grid) {
+        return None;
+    }
+    grid.iter().enumerate().find_map(|(row, states)| {
+        states.iter().enumerate().find_map(|(col, state)| {
+            let color = state.color()?;
+            let current = solution.solution_grid.get(row)?.get(col)?;
+            (*current != color).then_some((row, col, color))
+        })
+    })
+}
+
+/// Finds the trivial cells of a puzzle: the cells deduced from the clues of their row or their
+/// column alone, like the cells of full lines or the overlaps of long segments.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle to analyze.
+///
+/// # Returns
+///
+/// A grid with the colors of the trivial cells, and `None` in the rest.
+pub fn trivial_cells(puzzle: &NonogramPuzzle) -> Vec<Vec<Option<usize>>> {
+    let initial = initial_states(puzzle);
+    let mut cells = vec![vec![None; puzzle.cols]; puzzle.rows];
+    for (row, constraints) in puzzle.row_constraints.iter().enumerate() {
+        if let Some(deduced) = solve_line(constraints, &initial[row]).cells() {
+            for (col, state) in deduced.into_iter().enumerate() {
+                cells[row][col] = state.color();
+            }
+        }
+    }
+    for (col, constraints) in puzzle.col_constraints.iter().enumerate() {
+        let line: Vec<CellState> = initial.iter().map(|states| states[col]).collect();
+        if let Some(deduced) = solve_line(constraints, &line).cells() {
+            for (row, state) in deduced.into_iter().enumerate() {
+                cells[row][col] = cells[row][col].or(state.color());
+            }
+        }
+    }
+    cells
+}
+
+/// Validates every line of a grid being solved against the clues of the puzzle.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle being solved.
+/// * `solution` - The grid being solved.
+///
+/// # Returns
+///
+/// Whether each row and each column satisfies its clues exactly.
+pub fn satisfied_lines(
+    puzzle: &NonogramPuzzle,
+    solution: &NonogramSolution,
+) -> (Vec<bool>, Vec<bool>) {
+    let current = NonogramPuzzle::from_solution(solution);
+    let satisfied = |clues: &[Vec<NonogramSegment>], lines: &[Vec<NonogramSegment>]| {
+        (0..clues.len())
+            .map(|line| lines.get(line) == clues.get(line))
+            .collect()
+    };
+    (
+        satisfied(&puzzle.row_constraints, &current.row_constraints),
+        satisfied(&puzzle.col_constraints, &current.col_constraints),
+    )
+}
+
+/// Finds the cells crossed out automatically in a grid being solved: the background cells of
+/// the lines that satisfy their clues exactly, which can't hold any other color.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle being solved.
+/// * `solution` - The grid being solved.
+///
+/// # Returns
+///
+/// A grid marking the crossed cells.
+pub fn crossed_cells(puzzle: &NonogramPuzzle, solution: &NonogramSolution) -> Vec<Vec<bool>> {
+    let (rows, cols) = satisfied_lines(puzzle, solution);
+    solution
+        .solution_grid
+        .iter()
+        .enumerate()
+        .map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(j, &cell)| {
+                    cell == BACKGROUND
+                        && (rows.get(i).copied().unwrap_or_default()
+                            || cols.get(j).copied().unwrap_or_default())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// The result of solving a puzzle by line logic.
+#[derive(Clone, PartialEq, Debug)]
+pub struct LogicSolution {
+    /// The deduced grid, with the background in the undeduced cells.
+    pub solution: NonogramSolution,
+    /// The number of cells that couldn't be deduced.
+    pub unknown: usize,
+}
+
+impl LogicSolution {
+    /// Returns whether every cell was deduced, which proves the puzzle has a single solution.
+    pub fn is_complete(&self) -> bool {
+        self.unknown == 0
+    }
+}
+
+/// Solves a puzzle deterministically, applying the line solver to the rows and columns until
+/// no more cells can be deduced (see `propagate`).
+///
+/// Most puzzles made for humans are fully deduced instantly; the rest are partially deduced.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle to solve.
+///
+/// # Returns
+///
+/// The fully or partially deduced solution, or `None` if the puzzle has no solution.
+pub fn solve_logic(puzzle: &NonogramPuzzle) -> Option<LogicSolution> {
+    let mut grid = initial_states(puzzle);
+    if !propagate(puzzle, &mut grid) {
+        return None;
+    }
+    Some(LogicSolution {
+        unknown: grid
+            .iter()
+            .flatten()
+            .filter(|state| state.color().is_none())
+            .count(),
+        solution: to_solution(&grid),
+    })
+}
+
+/// Explores the grid by backtracking, guessing the colors of the undeduced cells and pruning the
+/// guesses with the line solver, until `limit` solutions are found or the `guesses` left run out.
+///
+/// Returns `false` if the guesses ran out before the search finished.
+fn backtrack(
+    puzzle: &NonogramPuzzle,
+    grid: Vec<Vec<CellState>>,
+    limit: usize,
+    guesses: &mut usize,
+    solutions: &mut Vec<NonogramSolution>,
+) -> bool {
+    // The undeduced cell with the fewest colors left, so the guesses branch the least.
+    let guess = grid
+        .iter()
+        .enumerate()
+        .flat_map(|(row, states)| {
+            states
+                .iter()
+                .enumerate()
+                .map(move |(col, state)| (row, col, *state))
+        })
+        .filter(|(_, _, state)| state.color().is_none())
+        .min_by_key(|(_, _, state)| state.colors().count());
+    let Some((row, col, state)) = guess else {
+        solutions.push(to_solution(&grid));
+        return true;
+    };
+    for color in state.colors() {
+        if solutions.len() >= limit {
+            return true;
+        }
+        if *guesses == 0 {
+            return false;
+        }
+        *guesses -= 1;
+        let mut trial = grid.clone();
+        trial[row][col] = CellState::known(color);
+        let mut dirty_rows = vec![false; puzzle.rows];
+        let mut dirty_cols = vec![false; puzzle.cols];
+        dirty_rows[row] = true;
+        dirty_cols[col] = true;
+        if propagate_lines(puzzle, &mut trial, dirty_rows, dirty_cols)
+            && !backtrack(puzzle, trial, limit, guesses, solutions)
+        {
+            return false;
+        }
+    }
+    true
+}
+
+/// Finds up to `limit` solutions of a puzzle by exhaustive search.
+///
+/// With the `sat` feature, the puzzles the backtracking search doesn't settle within
+/// `SAT_GUESSES` guesses are handed to the SAT solver, which learns from its conflicts (see
+/// `find_solutions_sat`).
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle to solve.
+/// * `limit` - The most solutions to find.
+///
+/// # Returns
+///
+/// The solutions found, in no particular order; empty if the puzzle has no solution.
+pub fn find_solutions(puzzle: &NonogramPuzzle, limit: usize) -> Vec<NonogramSolution> {
+    #[cfg(feature = "sat")]
+    return find_solutions_within(puzzle, limit, SAT_GUESSES)
+        .unwrap_or_else(|| find_solutions_sat(puzzle, limit));
+    #[cfg(not(feature = "sat"))]
+    find_solutions_within(puzzle, limit, usize::MAX).unwrap_or_default()
+}
+
+/// Finds up to `limit` solutions of a puzzle by exhaustive search, guessing at most `max_guesses`
+/// times, so huge ambiguous puzzles are given up on instead of searched for long.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle to solve.
+/// * `limit` - The most solutions to find.
+/// * `max_guesses` - The most guesses of the colors of the cells.
+///
+/// # Returns
+///
+/// The solutions found, in no particular order, or `None` if the guesses ran out before finding
+/// `limit` solutions or exhausting the search.
+pub fn find_solutions_within(
+    puzzle: &NonogramPuzzle,
+    limit: usize,
+    max_guesses: usize,
+) -> Option<Vec<NonogramSolution>> {
+    let mut grid = initial_states(puzzle);
+    let mut solutions = Vec::new();
+    let mut guesses = max_guesses;
+    if limit > 0
+        && propagate(puzzle, &mut grid)
+        && !backtrack(puzzle, grid, limit, &mut guesses, &mut solutions)
+    {
+        return None;
+    }
+    Some(solutions)
+}
+
+impl NonogramPuzzle {
+    /// Solves the puzzle exactly, by backtracking over the cells the line solver can't deduce.
+    ///
+    /// Each guess is pruned with the dynamic-programming line solver (see `solve_line`), so the
+    /// search is exhaustive: a solution is always found if one exists. Unlike `solve_nonogram`,
+    /// the result doesn't depend on chance, though the search can take long on huge ambiguous
+    /// puzzles. With the `sat` feature, the searches needing many guesses are finished by the SAT
+    /// solver (see `find_solutions`).
+    ///
+    /// # Returns
+    ///
+    /// A solution of the puzzle, or `None` if it has none.
+    pub fn solve_exact(&self) -> Option<NonogramSolution> {
+        find_solutions(self, 1).pop()
+    }
+
+    /// Returns whether the puzzle has exactly one solution.
+    pub fn is_unique(&self) -> bool {
+        find_solutions(self, 2).len() == 1
+    }
+
+    /// Decides whether the puzzle has exactly one solution, guessing at most `max_guesses` times
+    /// (see `find_solutions_within`).
+    ///
+    /// # Returns
+    ///
+    /// Whether the puzzle has exactly one solution, or `None` if the guesses ran out first.
+    pub fn is_unique_within(&self, max_guesses: usize) -> Option<bool> {
+        find_solutions_within(self, 2, max_guesses).map(|solutions| solutions.len() == 1)
+    }
+
+    /// Finds the lines of a grid being solved that can no longer be completed.
+    ///
+    /// The painted cells of each line keep their colors, while the background cells can still
+    /// take any color, so a line is contradicted when no placement of its segments fits its
+    /// painted cells (see `solve_line`). The lines are checked independently of each other.
+    ///
+    /// The cells painted with a color beyond the 64 a cell state can hold are in no clue, so
+    /// their lines are contradicted.
+    ///
+    /// # Arguments
+    ///
+    /// * `solution` - The grid being solved.
+    ///
+    /// # Returns
+    ///
+    /// The contradicted rows, then the contradicted columns, in order.
+    pub fn find_contradictions(&self, solution: &NonogramSolution) -> Vec<ClueLine> {
+        let mut grid = initial_states(self);
+        for (states, row) in grid.iter_mut().zip(&solution.solution_grid) {
+            for (state, &color) in states.iter_mut().zip(row) {
+                if color != BACKGROUND {
+                    *state = if color < 64 {
+                        CellState::known(color)
+                    } else {
+                        CellState::any(0)
+                    };
+                }
+            }
+        }
+        let rows = (0..self.rows)
+            .filter(|&row| solve_line(&self.row_constraints[row], &grid[row]).is_contradiction())
+            .map(ClueLine::Row);
+        let cols = (0..self.cols)
+            .filter(|&col| {
+                let line: Vec<CellState> = grid.iter().map(|states| states[col]).collect();
+                solve_line(&self.col_constraints[col], &line).is_contradiction()
+            })
+            .map(ClueLine::Column);
+        rows.chain(cols).collect()
+    }
+}
+
+/// Converts the state of the cells into a solution grid.
+///
+/// # Arguments
+///
+/// * `grid` - The state of the cells.
+///
+/// # Returns
+///
+/// A solution grid with the deduced colors, and the background in the undeduced cells.
+pub fn to_solution(grid: &[Vec<CellState>]) -> NonogramSolution {
+    NonogramSolution {
+        solution_grid: grid
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|state| state.color().unwrap_or(BACKGROUND))
+                    .collect()
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::puzzles::{tree_nonogram_file, tree_nonogram_puzzle};
+    use crate::nrule;
+
+    use super::*;
+
+    // Test the slack and the arrangements of lines with and without gaps between segments.
+    #[test]
+    fn line_slack_arrangements() {
+        let tight = line_slack(&[nrule!(1, 2), nrule!(1, 2)], 5).unwrap();
+        assert_eq!((tight.slack, tight.arrangements), (0, 1));
+        assert_eq!(tight.tightness, Tightness::Tight);
+
+        let adjacent = line_slack(&[nrule!(1, 2), nrule!(2, 2)], 5).unwrap();
+        assert_eq!((adjacent.slack, adjacent.arrangements), (1, 3));
+        assert_eq!(adjacent.tightness, Tightness::Overlapping);
+
+        let free = line_slack(&[nrule!(1, 1), nrule!(1, 1)], 10).unwrap();
+        assert_eq!((free.slack, free.arrangements), (7, 36));
+        assert_eq!(free.tightness, Tightness::Slack);
+
+        assert_eq!(line_slack(&[], 4).unwrap().tightness, Tightness::Tight);
+        assert_eq!(line_slack(&[nrule!(1, 5)], 4), None);
+    }
+
+    // Test that the tree puzzle is solved by propagation alone.
+    #[test]
+    fn tree_is_solved_by_logic() {
+        let puzzle = tree_nonogram_puzzle();
+        let mut grid = initial_states(&puzzle);
+        assert!(propagate(&puzzle, &mut grid));
+        assert_eq!(to_solution(&grid), tree_nonogram_file().solution);
+        let solved = solve_logic(&puzzle).unwrap();
+        assert!(solved.is_complete());
+        assert_eq!(solved.solution, tree_nonogram_file().solution);
+        assert!(search_depths(&puzzle)
+            .unwrap()
+            .iter()
+            .flatten()
+            .all(|&depth| depth == 0));
+    }
+
+    // Test that a puzzle with several solutions reports the ambiguous cells.
+    #[test]
+    fn ambiguous_cells_need_guessing() {
+        let puzzle = NonogramPuzzle::from_solution(&NonogramSolution {
+            solution_grid: vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 0]],
+        });
+        let depths = search_depths(&puzzle).unwrap();
+        assert_eq!(depths[2], vec![0, 0, 0]);
+        assert_eq!(depths[0][..2], [2, 2]);
+        assert_eq!(solve_logic(&puzzle).unwrap().unknown, 4);
+    }
+
+    // Test that hints reveal deduced cells not painted yet, and trivial cells come from full lines.
+    #[test]
+    fn hints_and_trivial_cells() {
+        let puzzle = tree_nonogram_puzzle();
+        let mut solution = tree_nonogram_file().solution;
+        assert_eq!(hint(&puzzle, &solution), None);
+        let color = solution.solution_grid[1][2];
+        solution.solution_grid[1][2] = BACKGROUND;
+        assert_eq!(hint(&puzzle, &solution), Some((1, 2, color)));
+
+        let trivial = trivial_cells(&puzzle);
+        assert_eq!(trivial[1], vec![Some(1); 5]);
+        assert_eq!(trivial[0][2], Some(1));
+        assert_eq!(trivial[0][0], None);
+    }
+
+    // Test that the background cells of the satisfied lines are crossed out.
+    #[test]
+    fn crossed_lines() {
+        let puzzle = NonogramPuzzle::from_solution(&NonogramSolution {
+            solution_grid: vec![vec![1, 0, 0], vec![0, 1, 1], vec![1, 0, 0]],
+        });
+        let solution = NonogramSolution {
+            solution_grid: vec![vec![1, 0, 0], vec![0, 0, 0], vec![0, 0, 0]],
+        };
+        let (rows, cols) = satisfied_lines(&puzzle, &solution);
+        assert_eq!(rows, vec![true, false, false]);
+        assert_eq!(cols, vec![false, false, false]);
+        let crossed = crossed_cells(&puzzle, &solution);
+        assert_eq!(crossed[0], vec![false, true, true]);
+        assert_eq!(crossed[1], vec![false; 3]);
+    }
+
+    // Test that only the lines whose painted cells can't be completed are contradicted, including
+    // the cells painted with a color the puzzle doesn't use.
+    #[test]
+    fn contradicted_lines() {
+        let solved = NonogramSolution {
+            solution_grid: vec![vec![1, 0, 0], vec![0, 1, 1], vec![1, 0, 0]],
+        };
+        let puzzle = NonogramPuzzle::from_solution(&solved);
+        assert_eq!(puzzle.find_contradictions(&solved), vec![]);
+        let empty = NonogramSolution {
+            solution_grid: vec![vec![0; 3]; 3],
+        };
+        assert_eq!(puzzle.find_contradictions(&empty), vec![]);
+        let painted = NonogramSolution {
+            solution_grid: vec![vec![1, 1, 0], vec![1, 0, 0], vec![0, 0, 1]],
+        };
+        assert_eq!(
+            puzzle.find_contradictions(&painted),
+            vec![ClueLine::Row(0), ClueLine::Column(0)]
+        );
+        let recolored = NonogramSolution {
+            solution_grid: vec![vec![0; 3], vec![0, 2, 0], vec![0; 3]],
+        };
+        assert_eq!(
+            puzzle.find_contradictions(&recolored),
+            vec![ClueLine::Row(1), ClueLine::Column(1)]
+        );
+        let beyond = NonogramSolution {
+            solution_grid: vec![vec![0; 3], vec![0; 3], vec![0, 0, 64]],
+        };
+        assert_eq!(
+            puzzle.find_contradictions(&beyond),
+            vec![ClueLine::Row(2), ClueLine::Column(2)]
+        );
+    }
+
+    // Test that the exact solver finds the solutions that need guessing, and detects uniqueness.
+    #[test]
+    fn exact_search() {
+        let ambiguous = NonogramPuzzle::from_solution(&NonogramSolution {
+            solution_grid: vec![vec![1, 0, 0], vec![0, 1, 0], vec![0, 0, 0]],
+        });
+        let solution = ambiguous.solve_exact().unwrap();
+        assert_eq!(NonogramPuzzle::from_solution(&solution), ambiguous);
+        assert_eq!(find_solutions(&ambiguous, 10).len(), 2);
+        assert!(!ambiguous.is_unique());
+
+        let tree = tree_nonogram_puzzle();
+        assert!(tree.is_unique());
+        assert_eq!(tree.solve_exact(), Some(tree_nonogram_file().solution));
+
+        assert_eq!(ambiguous.is_unique_within(0), None);
+        assert_eq!(ambiguous.is_unique_within(100), Some(false));
+        assert_eq!(tree.is_unique_within(0), Some(true));
+
+        let mut impossible = tree_nonogram_puzzle();
+        impossible.row_constraints[0].clear();
+        assert_eq!(impossible.solve_exact(), None);
+    }
+}
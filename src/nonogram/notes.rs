@@ -0,0 +1,191 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Markdown-lite notes about a puzzle, written by its author or by the user.
+//!
+//! The notes support a small subset of markdown, enough for writing down solving strategies:
+//! `# ` headings, `- ` (or `* `) list items, paragraphs of consecutive lines, and the inline
+//! `**bold**`, `*italic*` and `` `code` `` styles. Everything else is kept as plain text.
+
+/// A run of text of a notes block, with its inline style.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum NoteSpan {
+    /// Plain text.
+    Text(String),
+    /// Text between `**` markers.
+    Bold(String),
+    /// Text between `*` markers.
+    Italic(String),
+    /// Text between backticks, such as clues or coordinates.
+    Code(String),
+}
+
+/// A block of the notes, made of styled runs of text.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum NoteBlock {
+    /// A line starting with `# `.
+    Heading(Vec<NoteSpan>),
+    /// A line starting with `- ` or `* `.
+    Item(Vec<NoteSpan>),
+    /// Consecutive lines of text, joined with spaces.
+    Paragraph(Vec<NoteSpan>),
+}
+
+/// Parses the notes of a puzzle into blocks.
+///
+/// # Arguments
+///
+/// * `text` - The notes, in the markdown-lite syntax described in the module.
+///
+/// # Returns
+///
+/// The headings, list items and paragraphs of the notes, in order. Blank lines only separate
+/// paragraphs.
+pub fn parse_notes(text: &str) -> Vec<NoteBlock> {
+    let mut blocks = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    for line in text.lines().map(str::trim) {
+        let heading = line.strip_prefix("# ");
+        let item = line.strip_prefix("- ").or_else(|| line.strip_prefix("* "));
+        let block = match (heading, item) {
+            (Some(heading), _) => Some(NoteBlock::Heading(parse_spans(heading.trim()))),
+            (None, Some(item)) => Some(NoteBlock::Item(parse_spans(item.trim()))),
+            (None, None) => None,
+        };
+        if block.is_some() || line.is_empty() {
+            if !paragraph.is_empty() {
+                blocks.push(NoteBlock::Paragraph(parse_spans(&paragraph.join(" "))));
+                paragraph.clear();
+            }
+            blocks.extend(block);
+        } else {
+            paragraph.push(line);
+        }
+    }
+    if !paragraph.is_empty() {
+        blocks.push(NoteBlock::Paragraph(parse_spans(&paragraph.join(" "))));
+    }
+    blocks
+}
+
+/// Parses the inline styles of a line of the notes.
+///
+/// A marker without its closing marker, or enclosing no text or text with surrounding spaces (as
+/// in `2 * 3 * 4`), is kept as plain text.
+///
+/// # Arguments
+///
+/// * `text` - The line to parse.
+///
+/// # Returns
+///
+/// The styled runs of the line, in order.
+pub fn parse_spans(text: &str) -> Vec<NoteSpan> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let styled = match c {
+            '`' => enclosed(rest, "`").map(|(code, len)| (NoteSpan::Code(code), len)),
+            '*' => enclosed(rest, "**")
+                .map(|(bold, len)| (NoteSpan::Bold(bold), len))
+                .or_else(|| {
+                    enclosed(rest, "*").map(|(italic, len)| (NoteSpan::Italic(italic), len))
+                }),
+            _ => None,
+        };
+        match styled {
+            Some((span, len)) => {
+                if !plain.is_empty() {
+                    spans.push(NoteSpan::Text(std::mem::take(&mut plain)));
+                }
+                spans.push(span);
+                rest = &rest[len..];
+            }
+            None => {
+                plain.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    if !plain.is_empty() {
+        spans.push(NoteSpan::Text(plain));
+    }
+    spans
+}
+
+/// Finds the text enclosed by a marker at the start of a line.
+///
+/// # Returns
+///
+/// The enclosed text and the length consumed with both markers, or `None` if the marker isn't
+/// closed, or the enclosed text is empty or surrounded by spaces.
+fn enclosed(text: &str, marker: &str) -> Option<(String, usize)> {
+    let inner = text.strip_prefix(marker)?;
+    let end = inner.find(marker)?;
+    let enclosed = &inner[..end];
+    if enclosed.is_empty() || enclosed.trim() != enclosed {
+        return None;
+    }
+    Some((enclosed.to_string(), end + 2 * marker.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the notes are split into headings, list items and paragraphs.
+    #[test]
+    fn note_blocks() {
+        let notes = "# Strategy\nStart with the\nlong rows.\n\n- Fill the trunk\n* Then the leaves";
+        assert_eq!(
+            parse_notes(notes),
+            vec![
+                NoteBlock::Heading(vec![NoteSpan::Text("Strategy".into())]),
+                NoteBlock::Paragraph(vec![NoteSpan::Text("Start with the long rows.".into())]),
+                NoteBlock::Item(vec![NoteSpan::Text("Fill the trunk".into())]),
+                NoteBlock::Item(vec![NoteSpan::Text("Then the leaves".into())]),
+            ]
+        );
+        assert!(parse_notes("\n  \n").is_empty());
+    }
+
+    // Test that the inline styles are parsed, and unmatched markers are kept as text.
+    #[test]
+    fn note_spans() {
+        assert_eq!(
+            parse_spans("Row `3` is **full**, *mostly*"),
+            vec![
+                NoteSpan::Text("Row ".into()),
+                NoteSpan::Code("3".into()),
+                NoteSpan::Text(" is ".into()),
+                NoteSpan::Bold("full".into()),
+                NoteSpan::Text(", ".into()),
+                NoteSpan::Italic("mostly".into()),
+            ]
+        );
+        assert_eq!(
+            parse_spans("2 * 3 = 6 and ** or `"),
+            vec![NoteSpan::Text("2 * 3 = 6 and ** or `".into())]
+        );
+    }
+}
@@ -0,0 +1,324 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Nonogram structures for the palette and the rendered grid.
+use super::definitions::{NonogramPalette, NonogramSolution, BACKGROUND};
+
+// Import formatting traits for displaying the picture errors.
+use std::fmt;
+
+/// The signature every PNG file starts with.
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// The keyword of the text chunk embedding the Nonogram file.
+pub const METADATA_KEYWORD: &str = "ngram";
+
+/// The largest block of a stored (uncompressed) deflate stream.
+const MAX_STORED_BLOCK: usize = 0xFFFF;
+
+/// An error reading the Nonogram file embedded in a PNG picture.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PictureError {
+    /// The data doesn't start with the PNG signature.
+    NotPng,
+    /// A chunk is truncated or its checksum doesn't match.
+    Corrupted,
+    /// The picture has no Nonogram file embedded.
+    MissingMetadata,
+}
+
+impl fmt::Display for PictureError {
+    /// Formats the error as a message for the logs.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PictureError::NotPng => write!(f, "the file isn't a PNG picture"),
+            PictureError::Corrupted => write!(f, "the PNG picture is corrupted"),
+            PictureError::MissingMetadata => {
+                write!(f, "the PNG picture has no nonogram embedded")
+            }
+        }
+    }
+}
+
+/// A chunk of a PNG file.
+struct Chunk<'a> {
+    /// The type of the chunk, such as `IHDR` or `iTXt`.
+    kind: [u8; 4],
+    /// The data of the chunk.
+    data: &'a [u8],
+}
+
+/// Computes the CRC-32 checksum of PNG chunks (and the checksums of other formats, such as the
+/// zip archives).
+///
+/// # Arguments
+///
+/// * `parts` - The data, split in parts (the chunk type and its data).
+pub(crate) fn crc32(parts: &[&[u8]]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in parts.iter().flat_map(|part| part.iter()) {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                0xEDB8_8320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Computes the Adler-32 checksum of a zlib stream.
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65_521;
+        b = (b + a) % 65_521;
+    }
+    (b << 16) | a
+}
+
+/// Appends a chunk to a PNG file, with its length and checksum.
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    png.extend_from_slice(&crc32(&[kind, data]).to_be_bytes());
+}
+
+/// Splits a PNG file into its chunks, verifying their checksums.
+fn read_chunks(png: &[u8]) -> Result<Vec<Chunk<'_>>, PictureError> {
+    let mut rest = png
+        .strip_prefix(&PNG_SIGNATURE)
+        .ok_or(PictureError::NotPng)?;
+    let mut chunks = Vec::new();
+    while !rest.is_empty() {
+        if rest.len() < 12 {
+            return Err(PictureError::Corrupted);
+        }
+        let length = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+        if rest.len() < 12 + length {
+            return Err(PictureError::Corrupted);
+        }
+        let kind = [rest[4], rest[5], rest[6], rest[7]];
+        let data = &rest[8..8 + length];
+        let crc = &rest[8 + length..12 + length];
+        if crc32(&[&kind, data]).to_be_bytes() != crc {
+            return Err(PictureError::Corrupted);
+        }
+        chunks.push(Chunk { kind, data });
+        rest = &rest[12 + length..];
+    }
+    Ok(chunks)
+}
+
+/// Renders a solution grid as an indexed PNG picture, each cell a square of pixels.
+///
+/// The pixels are stored without compression, keeping the encoder small; the pictures of
+/// Nonograms are tiny anyway.
+///
+/// # Arguments
+///
+/// * `solution` - The grid to render.
+/// * `palette` - The colors of the cells (only the first 256 colors fit in a PNG palette).
+/// * `cell_size` - The size of each cell in pixels.
+///
+/// # Returns
+///
+/// The bytes of the PNG file.
+pub fn encode_png(
+    solution: &NonogramSolution,
+    palette: &NonogramPalette,
+    cell_size: u32,
+) -> Vec<u8> {
+    let cell_size = cell_size.max(1) as usize;
+    let (width, height) = (solution.cols() * cell_size, solution.rows() * cell_size);
+    let colors = palette.color_palette.len().clamp(1, 256);
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(height as u32).to_be_bytes());
+    // 8-bit indexed colors, default compression and filtering, no interlacing.
+    header.extend_from_slice(&[8, 3, 0, 0, 0]);
+
+    let mut plte = Vec::with_capacity(colors * 3);
+    for index in 0..colors {
+        let (r, g, b) = palette
+            .color_palette
+            .get(index)
+            .and_then(|color| NonogramPalette::parse_color(color))
+            .unwrap_or((0, 0, 0));
+        plte.extend_from_slice(&[r, g, b]);
+    }
+
+    // Each scanline starts with its filter type (none).
+    let mut pixels = Vec::with_capacity((width + 1) * height);
+    for cells in &solution.solution_grid {
+        let mut scanline = Vec::with_capacity(width + 1);
+        scanline.push(0);
+        for &color in cells {
+            let index = if color < colors { color } else { BACKGROUND };
+            scanline.extend(std::iter::repeat_n(index as u8, cell_size));
+        }
+        for _ in 0..cell_size {
+            pixels.extend_from_slice(&scanline);
+        }
+    }
+
+    // A zlib stream of stored deflate blocks.
+    let mut idat = vec![0x78, 0x01];
+    let blocks = pixels.chunks(MAX_STORED_BLOCK).collect::<Vec<_>>();
+    if blocks.is_empty() {
+        idat.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    for (index, block) in blocks.iter().enumerate() {
+        idat.push(u8::from(index + 1 == blocks.len()));
+        idat.extend_from_slice(&(block.len() as u16).to_le_bytes());
+        idat.extend_from_slice(&(!(block.len() as u16)).to_le_bytes());
+        idat.extend_from_slice(block);
+    }
+    idat.extend_from_slice(&adler32(&pixels).to_be_bytes());
+
+    let mut png = PNG_SIGNATURE.to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"PLTE", &plte);
+    write_chunk(&mut png, b"IDAT", &idat);
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// Embeds a Nonogram file in a PNG picture, as an uncompressed `iTXt` chunk.
+///
+/// The chunk is placed before the end of the picture, replacing any Nonogram file embedded
+/// before, so the picture can be imported back losslessly with `read_metadata`.
+///
+/// # Arguments
+///
+/// * `png` - The bytes of the PNG picture.
+/// * `json` - The Nonogram file, serialized as JSON.
+///
+/// # Returns
+///
+/// The bytes of the PNG picture with the file embedded, or the error reading the picture.
+pub fn embed_metadata(png: &[u8], json: &str) -> Result<Vec<u8>, PictureError> {
+    let chunks = read_chunks(png)?;
+    let mut itxt = Vec::with_capacity(METADATA_KEYWORD.len() + json.len() + 5);
+    // The keyword, uncompressed text, and empty language and translated keyword.
+    itxt.extend_from_slice(METADATA_KEYWORD.as_bytes());
+    itxt.extend_from_slice(&[0, 0, 0, 0, 0]);
+    itxt.extend_from_slice(json.as_bytes());
+
+    let mut embedded = PNG_SIGNATURE.to_vec();
+    for chunk in chunks {
+        if chunk_metadata(&chunk).is_some() {
+            continue;
+        }
+        if &chunk.kind == b"IEND" {
+            write_chunk(&mut embedded, b"iTXt", &itxt);
+        }
+        write_chunk(&mut embedded, &chunk.kind, chunk.data);
+    }
+    Ok(embedded)
+}
+
+/// Reads the Nonogram file embedded in a PNG picture by `embed_metadata`.
+///
+/// Uncompressed `iTXt` chunks and `tEXt` chunks with the `ngram` keyword are recognized.
+///
+/// # Returns
+///
+/// The Nonogram file, serialized as JSON, or the error reading the picture.
+pub fn read_metadata(png: &[u8]) -> Result<String, PictureError> {
+    read_chunks(png)?
+        .iter()
+        .find_map(chunk_metadata)
+        .ok_or(PictureError::MissingMetadata)
+}
+
+/// Returns the text of a chunk embedding a Nonogram file, if it's one.
+fn chunk_metadata(chunk: &Chunk) -> Option<String> {
+    let mut fields = chunk.data.splitn(2, |&byte| byte == 0);
+    if fields.next()? != METADATA_KEYWORD.as_bytes() {
+        return None;
+    }
+    let rest = fields.next()?;
+    match &chunk.kind {
+        b"tEXt" => Some(rest.iter().map(|&byte| byte as char).collect()),
+        b"iTXt" => {
+            // Compressed text isn't supported, it's never written by `embed_metadata`.
+            let (&[0, _], rest) = rest.split_at_checked(2)? else {
+                return None;
+            };
+            // Skips the language tag and the translated keyword.
+            let mut fields = rest.splitn(3, |&byte| byte == 0);
+            let text = fields.nth(2)?;
+            String::from_utf8(text.to_vec()).ok()
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::puzzles::tree_nonogram_file;
+
+    use super::*;
+
+    // Test that the embedded file is read back, replacing the file embedded before.
+    #[test]
+    fn embed_and_read_metadata() {
+        let file = tree_nonogram_file();
+        let png = encode_png(&file.solution, &file.palette, 4);
+        assert_eq!(read_metadata(&png), Err(PictureError::MissingMetadata));
+
+        let embedded = embed_metadata(&png, "{\"old\":1}").unwrap();
+        let embedded = embed_metadata(&embedded, "{\"ñ\":2}").unwrap();
+        assert_eq!(read_metadata(&embedded).unwrap(), "{\"ñ\":2}");
+        assert!(embedded.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82]));
+
+        assert_eq!(read_metadata(b"GIF89a"), Err(PictureError::NotPng));
+        let mut corrupted = embedded.clone();
+        corrupted[40] ^= 0xFF;
+        assert_eq!(read_metadata(&corrupted), Err(PictureError::Corrupted));
+    }
+
+    #[cfg(not(any(target_os = "android", feature = "web")))]
+    // Test that the rendered picture is a valid PNG with the colors of the cells.
+    #[test]
+    fn decode_rendered_picture() {
+        use crate::nonogram::definitions::NonogramFile;
+
+        let NonogramFile {
+            solution, palette, ..
+        } = tree_nonogram_file();
+        let png = encode_png(&solution, &palette, 3);
+        let image = image::load_from_memory(&png).unwrap().to_rgb8();
+        assert_eq!(
+            image.dimensions(),
+            (solution.cols() as u32 * 3, solution.rows() as u32 * 3)
+        );
+        let color = solution.solution_grid[4][2];
+        let (r, g, b) = NonogramPalette::parse_color(&palette.color_palette[color]).unwrap();
+        assert_eq!(image.get_pixel(2 * 3 + 1, 4 * 3 + 2).0, [r, g, b]);
+    }
+}
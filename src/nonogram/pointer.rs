@@ -0,0 +1,275 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the commands produced by the pointer gestures.
+use super::command::GridCommand;
+
+// Import the solution grid, to know the cells highlighted by a line.
+use super::definitions::NonogramSolution;
+
+// Import the action performed by a tap.
+use super::settings::TapAction;
+
+/// A stroke of a pressed pointer on the grid.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Stroke {
+    /// The cell `(row, column)` where the pointer was pressed.
+    pub start: (usize, usize),
+    /// The cell `(row, column)` where the pointer is now.
+    pub end: (usize, usize),
+    /// The color painted by the stroke, chosen when the pointer was pressed.
+    pub color: usize,
+    /// Whether every cell crossed is painted (with shift or control), instead of a line.
+    pub freehand: bool,
+}
+
+/// The state of a pointer (mouse, finger or stylus) on the solution grid.
+///
+/// The grid handles the pointer events in one place and forwards them here, already converted to
+/// cells, so the gestures behave the same with every kind of pointer: pressing and dragging draws
+/// a horizontal or vertical line when released, and with shift or control paints every cell
+/// crossed instead.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct GridPointer {
+    /// The stroke of the pressed pointer, if any.
+    pub stroke: Option<Stroke>,
+    /// The cell under the pointer while it isn't pressed.
+    pub hover: Option<(usize, usize)>,
+}
+
+impl GridPointer {
+    /// Converts a position on the grid to the cell under it.
+    ///
+    /// # Arguments
+    ///
+    /// * `position` - The position `(x, y)` relative to the top left corner of the grid.
+    /// * `size` - The size `(width, height)` of the grid.
+    /// * `rows` - The number of rows of the grid.
+    /// * `cols` - The number of columns of the grid.
+    ///
+    /// # Returns
+    ///
+    /// The cell `(row, column)`, or `None` if the position is outside the grid.
+    pub fn cell_at(
+        position: (f64, f64),
+        size: (f64, f64),
+        rows: usize,
+        cols: usize,
+    ) -> Option<(usize, usize)> {
+        let (x, y) = position;
+        let (width, height) = size;
+        if x < 0.0 || y < 0.0 || x >= width || y >= height {
+            return None;
+        }
+        let row = (y / height * rows as f64) as usize;
+        let col = (x / width * cols as f64) as usize;
+        (row < rows && col < cols).then_some((row, col))
+    }
+
+    /// Presses the pointer on a cell, starting a stroke.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The cell `(row, column)` pressed.
+    /// * `color` - The color painted by the stroke.
+    /// * `freehand` - Whether every cell crossed is painted (with shift or control).
+    ///
+    /// # Returns
+    ///
+    /// The command painting the cell, if the stroke is freehand.
+    pub fn press(
+        &mut self,
+        cell: (usize, usize),
+        color: usize,
+        freehand: bool,
+    ) -> Option<GridCommand> {
+        self.hover = None;
+        self.stroke = Some(Stroke {
+            start: cell,
+            end: cell,
+            color,
+            freehand,
+        });
+        freehand.then_some(GridCommand::Paint {
+            row: cell.0,
+            col: cell.1,
+            color,
+        })
+    }
+
+    /// Moves the pointer to a cell, extending the stroke if it's pressed.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The cell `(row, column)` under the pointer.
+    ///
+    /// # Returns
+    ///
+    /// The command painting the cell, if the stroke is freehand and entered a new cell.
+    pub fn enter(&mut self, cell: (usize, usize)) -> Option<GridCommand> {
+        let Some(stroke) = self.stroke.as_mut() else {
+            self.hover = Some(cell);
+            return None;
+        };
+        if stroke.end == cell {
+            return None;
+        }
+        stroke.end = cell;
+        stroke.freehand.then_some(GridCommand::Paint {
+            row: cell.0,
+            col: cell.1,
+            color: stroke.color,
+        })
+    }
+
+    /// Releases the pointer, finishing the stroke.
+    ///
+    /// # Arguments
+    ///
+    /// * `tap` - What a tap (a release on the cell where the stroke started) does.
+    /// * `solution` - The solution grid, with the current color of the tapped cell.
+    ///
+    /// # Returns
+    ///
+    /// The command drawing the line or the tap, if the stroke wasn't freehand.
+    pub fn release(&mut self, tap: TapAction, solution: &NonogramSolution) -> Option<GridCommand> {
+        let stroke = self.stroke.take()?;
+        if stroke.freehand {
+            None
+        } else if stroke.start == stroke.end {
+            let (row, col) = stroke.start;
+            let cell = solution.solution_grid[row][col];
+            Some(GridCommand::Paint {
+                row,
+                col,
+                color: tap.color(cell, stroke.color),
+            })
+        } else {
+            Some(GridCommand::DrawLine {
+                start: stroke.start,
+                end: stroke.end,
+                color: stroke.color,
+            })
+        }
+    }
+
+    /// Forgets the stroke and the hovered cell, when the pointer leaves or is cancelled.
+    pub fn cancel(&mut self) {
+        self.stroke = None;
+        self.hover = None;
+    }
+
+    /// Returns whether a cell is highlighted, by the line being drawn or the pointer hovering it.
+    ///
+    /// # Arguments
+    ///
+    /// * `solution` - The solution grid.
+    /// * `cell` - The cell `(row, column)`.
+    pub fn highlights(&self, solution: &NonogramSolution, cell: (usize, usize)) -> bool {
+        match self.stroke {
+            Some(stroke) if !stroke.freehand => {
+                solution.in_line(Some(stroke.start), Some(stroke.end), cell)
+            }
+            Some(_) => false,
+            None => self.hover == Some(cell),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that positions are converted to cells, and outside positions are ignored.
+    #[test]
+    fn cells_from_positions() {
+        assert_eq!(
+            GridPointer::cell_at((0.0, 0.0), (100.0, 50.0), 5, 10),
+            Some((0, 0))
+        );
+        assert_eq!(
+            GridPointer::cell_at((99.0, 49.0), (100.0, 50.0), 5, 10),
+            Some((4, 9))
+        );
+        assert_eq!(
+            GridPointer::cell_at((35.0, 25.0), (100.0, 50.0), 5, 10),
+            Some((2, 3))
+        );
+        assert_eq!(
+            GridPointer::cell_at((100.0, 10.0), (100.0, 50.0), 5, 10),
+            None
+        );
+        assert_eq!(
+            GridPointer::cell_at((-1.0, 10.0), (100.0, 50.0), 5, 10),
+            None
+        );
+    }
+
+    // Test that drags draw lines, freehand drags paint each cell, and taps toggle.
+    #[test]
+    fn gestures() {
+        let solution = NonogramSolution {
+            solution_grid: vec![vec![0, 2, 0], vec![0, 0, 0]],
+        };
+        let mut pointer = GridPointer::default();
+
+        assert_eq!(pointer.press((0, 0), 2, false), None);
+        assert_eq!(pointer.enter((0, 1)), None);
+        assert_eq!(pointer.enter((0, 2)), None);
+        assert!(pointer.highlights(&solution, (0, 1)));
+        assert_eq!(
+            pointer.release(TapAction::Toggle, &solution),
+            Some(GridCommand::DrawLine {
+                start: (0, 0),
+                end: (0, 2),
+                color: 2,
+            })
+        );
+
+        assert_eq!(pointer.press((0, 1), 2, false), None);
+        assert_eq!(
+            pointer.release(TapAction::Toggle, &solution),
+            Some(GridCommand::Paint {
+                row: 0,
+                col: 1,
+                color: 0,
+            })
+        );
+
+        assert!(pointer.press((1, 0), 1, true).is_some());
+        assert_eq!(pointer.enter((1, 0)), None);
+        assert_eq!(
+            pointer.enter((1, 1)),
+            Some(GridCommand::Paint {
+                row: 1,
+                col: 1,
+                color: 1,
+            })
+        );
+        assert_eq!(pointer.release(TapAction::Paint, &solution), None);
+
+        assert_eq!(pointer.enter((1, 2)), None);
+        assert!(pointer.highlights(&solution, (1, 2)));
+        pointer.cancel();
+        assert!(!pointer.highlights(&solution, (1, 2)));
+    }
+}
@@ -0,0 +1,228 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Nonogram structures for the printed puzzle and its palette.
+use super::definitions::{NonogramPalette, NonogramPuzzle, NonogramSegment, BACKGROUND};
+
+// Import the ordered set of the colors used by the clues, for the legend.
+use std::collections::BTreeSet;
+
+/// The options of a printed puzzle.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PrintOptions {
+    /// Prints the clues in black with letter color codes, over white backgrounds, saving ink.
+    pub ink_saver: bool,
+    /// The size of each cell in pixels.
+    pub cell_size: u32,
+    /// Prints the colors as the grays of the same luminance, previewing how the puzzle looks
+    /// when photocopied.
+    pub grayscale: bool,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        Self {
+            ink_saver: false,
+            cell_size: 24,
+            grayscale: false,
+        }
+    }
+}
+
+/// Returns the letter code of a color for ink-saver printing: `a` for the first color after the
+/// background, then `b`, ..., `z`, `aa`, `ab`, ...
+pub fn color_code(color: usize) -> String {
+    let mut code = Vec::new();
+    let mut rest = color;
+    while rest > 0 {
+        rest -= 1;
+        code.push(b'a' + (rest % 26) as u8);
+        rest /= 26;
+    }
+    code.iter().rev().map(|&letter| letter as char).collect()
+}
+
+/// Renders a puzzle as a standalone HTML page, ready to be printed: the clues around an empty grid.
+///
+/// In ink-saver mode every background is white and the clues are black; the colors of
+/// multicolor puzzles are written as letter codes next to the lengths (e.g. `3b`), explained
+/// by a legend.
+///
+/// # Arguments
+///
+/// * `puzzle` - The printed puzzle.
+/// * `palette` - The colors of the clues and cells.
+/// * `title` - The title of the page.
+/// * `options` - Whether to save ink or print in grayscale, and the size of the cells.
+///
+/// # Returns
+///
+/// The HTML page.
+pub fn print_sheet(
+    puzzle: &NonogramPuzzle,
+    palette: &NonogramPalette,
+    title: &str,
+    options: PrintOptions,
+) -> String {
+    let grayscale;
+    let palette = if options.grayscale {
+        grayscale = palette.grayscale();
+        &grayscale
+    } else {
+        palette
+    };
+    let colors = puzzle
+        .row_constraints
+        .iter()
+        .chain(&puzzle.col_constraints)
+        .flatten()
+        .map(|segment| segment.color)
+        .collect::<BTreeSet<_>>();
+    let multicolor = colors.len() > 1;
+    let clue = |segment: &NonogramSegment| {
+        if options.ink_saver {
+            let code = if multicolor {
+                color_code(segment.color)
+            } else {
+                String::new()
+            };
+            format!("<span>{}{code}</span>", segment.length)
+        } else {
+            format!(
+                "<span style=\"background:{};color:{}\">{}</span>",
+                palette.get(segment.color),
+                palette.text_color(segment.color),
+                segment.length
+            )
+        }
+    };
+    let cell_background = if options.ink_saver {
+        String::from("#ffffff")
+    } else {
+        palette.get(BACKGROUND).to_string()
+    };
+
+    let title = title
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n\
+        body {{ font-family: sans-serif; color: #000000; background: #ffffff; }}\n\
+        table {{ border-collapse: collapse; }}\n\
+        td {{ padding: 0; text-align: center; font-size: {font}px; }}\n\
+        td.cell {{ width: {size}px; height: {size}px; border: 1px solid #9ca3af; background: {cell_background}; }}\n\
+        td.cols {{ vertical-align: bottom; }}\n\
+        td.rows {{ text-align: right; white-space: nowrap; }}\n\
+        td.cols span {{ display: block; }}\n\
+        span {{ display: inline-block; min-width: {font}px; margin: 1px; padding: 0 2px; }}\n\
+        </style>\n</head>\n<body>\n<h1>{title}</h1>\n<p>{} × {}</p>\n<table>\n<tr><td></td>",
+        puzzle.cols,
+        puzzle.rows,
+        size = options.cell_size,
+        font = options.cell_size * 2 / 3,
+    );
+    for segments in &puzzle.col_constraints {
+        html += "<td class=\"cols\">";
+        html.extend(segments.iter().map(clue));
+        html += "</td>";
+    }
+    html += "</tr>\n";
+    for segments in &puzzle.row_constraints {
+        html += "<tr><td class=\"rows\">";
+        html.extend(segments.iter().map(clue));
+        html += "</td>";
+        html += &"<td class=\"cell\"></td>".repeat(puzzle.cols);
+        html += "</tr>\n";
+    }
+    html += "</table>\n";
+    if options.ink_saver && multicolor {
+        html += "<ul>\n";
+        for &color in &colors {
+            html += &format!(
+                "<li><b>{}</b>: <span style=\"border:1px solid #000000;background:{}\"></span> {}</li>\n",
+                color_code(color),
+                palette.get(color),
+                palette.get(color)
+            );
+        }
+        html += "</ul>\n";
+    }
+    html += "</body>\n</html>\n";
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::definitions::NonogramSolution;
+
+    use super::*;
+
+    // Test that the letter codes continue after `z`.
+    #[test]
+    fn color_codes() {
+        assert_eq!(color_code(1), "a");
+        assert_eq!(color_code(26), "z");
+        assert_eq!(color_code(27), "aa");
+        assert_eq!(color_code(28), "ab");
+    }
+
+    // Test that ink-saver sheets only color the legend, and code the colors of the clues.
+    #[test]
+    fn ink_saver_sheet() {
+        let solution = NonogramSolution {
+            solution_grid: vec![vec![1, 1, 2], vec![0, 2, 2]],
+        };
+        let puzzle = NonogramPuzzle::from_solution(&solution);
+        let palette = NonogramPalette {
+            color_palette: vec![
+                String::from("#ffeedd"),
+                String::from("#000000"),
+                String::from("#ff0000"),
+            ],
+            brush: 1,
+        };
+        let color = PrintOptions::default();
+        let sheet = print_sheet(&puzzle, &palette, "Flag", color);
+        assert!(sheet.contains("<span style=\"background:#000000;color:#ffffff\">2</span>"));
+        assert!(sheet.contains("background: #ffeedd"));
+
+        let ink_saver = PrintOptions {
+            ink_saver: true,
+            ..color
+        };
+        let sheet = print_sheet(&puzzle, &palette, "Flag", ink_saver);
+        assert!(sheet.contains("<span>2a</span><span>1b</span>"));
+        assert!(!sheet.contains("#ffeedd"));
+        assert_eq!(sheet.matches("background:#ff0000").count(), 1);
+        assert_eq!(sheet.matches("<td class=\"cell\"></td>").count(), 6);
+
+        let grayscale = PrintOptions {
+            grayscale: true,
+            ..color
+        };
+        let sheet = print_sheet(&puzzle, &palette, "Flag", grayscale);
+        assert!(sheet.contains("<span style=\"background:#363636;color:#ffffff\">"));
+        assert!(sheet.contains("background: #f0f0f0"));
+        assert!(!sheet.contains("#ff0000"));
+    }
+}
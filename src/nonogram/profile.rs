@@ -0,0 +1,172 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the persistence layer, namespaced per profile.
+use super::storage;
+
+// Import serialization support to persist the profiles as JSON.
+use serde::{Deserialize, Serialize};
+
+/// The key of the stored profiles.
+const PROFILES_KEY: &str = "ngram-profiles.json";
+
+/// A local user profile, with its own settings and data.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct Profile {
+    /// The unique identifier of the profile, used to namespace its data.
+    pub id: String,
+    /// The name of the user.
+    pub name: String,
+    /// The color of the user's avatar, as a hexadecimal string.
+    pub color: String,
+}
+
+impl Profile {
+    /// Namespaces a storage key in the profile, so each profile has its own data.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The storage key, such as `ngram-settings.json`.
+    ///
+    /// # Returns
+    ///
+    /// The storage key of the profile's data.
+    pub fn namespace(&self, key: &str) -> String {
+        format!("profiles/{}/{}", self.id, key)
+    }
+
+    /// Returns the initial of the user, displayed in the avatar.
+    pub fn initial(&self) -> String {
+        self.name
+            .chars()
+            .next()
+            .map(|initial| initial.to_uppercase().to_string())
+            .unwrap_or_default()
+    }
+}
+
+/// Namespaces a storage key in a profile, or leaves it global without a profile.
+pub fn namespaced(profile: Option<&Profile>, key: &str) -> String {
+    profile.map_or_else(|| key.to_string(), |profile| profile.namespace(key))
+}
+
+/// The local profiles of the device.
+#[derive(Clone, Default, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Profiles {
+    /// The profiles, in creation order.
+    pub profiles: Vec<Profile>,
+}
+
+impl Profiles {
+    /// Loads the profiles stored by a previous session.
+    pub fn load() -> Self {
+        storage::read(PROFILES_KEY)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    /// Stores the profiles for the next sessions.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the profiles could be stored.
+    pub fn save(&self) -> bool {
+        serde_json::to_string(self).is_ok_and(|json| storage::write(PROFILES_KEY, &json))
+    }
+
+    /// Adds a profile, with a unique identifier derived from its name.
+    ///
+    /// # Returns
+    ///
+    /// The added profile.
+    pub fn add(&mut self, name: &str, color: &str) -> Profile {
+        let slug: String = name
+            .trim()
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        let slug = if slug.trim_matches('-').is_empty() {
+            String::from("profile")
+        } else {
+            slug.trim_matches('-').to_string()
+        };
+        let mut id = slug.clone();
+        let mut suffix = 1;
+        while self.profiles.iter().any(|profile| profile.id == id) {
+            suffix += 1;
+            id = format!("{slug}-{suffix}");
+        }
+        let profile = Profile {
+            id,
+            name: name.trim().to_string(),
+            color: color.to_string(),
+        };
+        self.profiles.push(profile.clone());
+        profile
+    }
+
+    /// Removes a profile (its stored data is removed by the caller).
+    pub fn remove(&mut self, id: &str) {
+        self.profiles.retain(|profile| profile.id != id);
+    }
+}
+
+/// The profile in use, chosen at startup.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct ActiveProfile {
+    /// The profile in use, or `None` for the guest (global) data.
+    pub profile: Option<Profile>,
+    /// Whether the profile was chosen, otherwise the profile picker is displayed.
+    pub chosen: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that profiles get unique identifiers and namespace their storage keys.
+    #[test]
+    fn profiles_namespace_their_data() {
+        let mut profiles = Profiles::default();
+        let ana = profiles.add(" Ana María ", "#ff0000");
+        let other = profiles.add("Ana María", "#00ff00");
+        let symbols = profiles.add("¿?", "#0000ff");
+        assert_eq!(ana.id, "ana-mar-a");
+        assert_eq!(other.id, "ana-mar-a-2");
+        assert_eq!(symbols.id, "profile");
+        assert_eq!(ana.name, "Ana María");
+        assert_eq!(ana.initial(), "A");
+        assert_eq!(
+            namespaced(Some(&ana), "ngram-settings.json"),
+            "profiles/ana-mar-a/ngram-settings.json"
+        );
+        assert_eq!(
+            namespaced(None, "ngram-settings.json"),
+            "ngram-settings.json"
+        );
+
+        profiles.remove(&other.id);
+        assert_eq!(profiles.profiles, vec![ana, symbols]);
+    }
+}
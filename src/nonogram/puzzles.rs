@@ -29,7 +29,10 @@ use super::definitions::{
 };
 
 // Default palette index for the background color.
-use super::definitions::BACKGROUND;
+use super::definitions::{BACKGROUND, DEFAULT_PALETTE};
+
+// Random number generation, for the colors and drawings of new puzzles.
+use rand::Rng;
 
 /// A macro for defining Nonogram rules (constraints) concisely.
 use crate::nrule;
@@ -61,6 +64,8 @@ pub fn tree_nonogram_file() -> NonogramFile {
             ],
         },
         palette: tree_nonogram_palette(),
+        signature: None,
+        notes: String::new(),
     }
 }
 
@@ -124,3 +129,113 @@ pub fn tree_nonogram_palette() -> NonogramPalette {
         brush: 0, // Default brush color (background)
     }
 }
+
+/// The side lengths of the square grid presets offered for new puzzles.
+pub const SIZE_PRESETS: [usize; 4] = [5, 10, 15, 20];
+
+/// The drawing a new puzzle starts with.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum GridTemplate {
+    /// An empty grid.
+    #[default]
+    Empty,
+    /// A frame along the edges of the grid, with the first color.
+    Border,
+    /// A random pattern mirrored between the left and right halves of the grid.
+    SymmetricMask,
+}
+
+/// Creates a new puzzle file to draw in the editor.
+///
+/// The palette takes the colors of the default palette first, followed by random colors.
+///
+/// # Arguments
+/// - `rows`: The number of rows of the grid.
+/// - `cols`: The number of columns of the grid.
+/// - `colors`: The number of colors besides the background.
+/// - `template`: The drawing the grid starts with.
+/// - `rng`: The generator of the random colors and patterns.
+///
+/// # Returns
+/// A `NonogramFile` with the starting drawing and its palette, with the first color as the brush.
+pub fn new_nonogram_file(
+    rows: usize,
+    cols: usize,
+    colors: usize,
+    template: GridTemplate,
+    rng: &mut impl Rng,
+) -> NonogramFile {
+    let colors = colors.max(1);
+    let default_palette = DEFAULT_PALETTE;
+    let default = &default_palette.color_palette;
+    let color_palette = (0..=colors)
+        .map(|index| match default.get(index) {
+            Some(color) => color.clone(),
+            None => format!(
+                "#{:02x}{:02x}{:02x}",
+                rng.gen_range(0..256),
+                rng.gen_range(0..256),
+                rng.gen_range(0..256)
+            ),
+        })
+        .collect();
+    let mut solution_grid = vec![vec![BACKGROUND; cols]; rows];
+    match template {
+        GridTemplate::Empty => {}
+        GridTemplate::Border => {
+            for (row, line) in solution_grid.iter_mut().enumerate() {
+                for (col, cell) in line.iter_mut().enumerate() {
+                    if row == 0 || col == 0 || row + 1 == rows || col + 1 == cols {
+                        *cell = 1;
+                    }
+                }
+            }
+        }
+        GridTemplate::SymmetricMask => {
+            for line in solution_grid.iter_mut() {
+                for col in 0..cols.div_ceil(2) {
+                    if rng.gen_bool(0.5) {
+                        let color = rng.gen_range(1..=colors);
+                        line[col] = color;
+                        line[cols - 1 - col] = color;
+                    }
+                }
+            }
+        }
+    }
+    NonogramFile {
+        solution: NonogramSolution { solution_grid },
+        palette: NonogramPalette {
+            color_palette,
+            brush: 1,
+        },
+        signature: None,
+        notes: String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use super::*;
+
+    // Test the drawings and palettes of the templates of new puzzles.
+    #[test]
+    fn new_puzzle_templates() {
+        let mut rng = StdRng::seed_from_u64(23);
+        let border = new_nonogram_file(3, 4, 2, GridTemplate::Border, &mut rng);
+        assert_eq!(
+            border.solution.solution_grid,
+            vec![vec![1, 1, 1, 1], vec![1, 0, 0, 1], vec![1, 1, 1, 1]]
+        );
+        assert_eq!(border.palette.color_palette.len(), 3);
+
+        let mask = new_nonogram_file(10, 7, 12, GridTemplate::SymmetricMask, &mut rng);
+        assert_eq!(mask.palette.color_palette.len(), 13);
+        for line in &mask.solution.solution_grid {
+            assert!(line.iter().eq(line.iter().rev()));
+            assert!(line.iter().all(|&cell| cell <= 12));
+        }
+    }
+}
@@ -0,0 +1,159 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Nonogram structures for the palette and the replayed grids.
+use super::definitions::{NonogramPalette, NonogramSolution, BACKGROUND};
+
+// Import the GIF encoder and the frame types of the `image` crate.
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame, ImageError, Rgba, RgbaImage};
+
+/// How long the last frame is held before the animation loops, in milliseconds.
+const FINAL_FRAME_MS: u32 = 2000;
+
+/// The options of an animated replay.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ReplayOptions {
+    /// The number of frames displayed per second.
+    pub frame_rate: u32,
+    /// The size of each cell in pixels.
+    pub cell_size: u32,
+}
+
+impl Default for ReplayOptions {
+    fn default() -> Self {
+        Self {
+            frame_rate: 10,
+            cell_size: 12,
+        }
+    }
+}
+
+/// Renders a grid into an image, filling the area outside the grid with the background.
+///
+/// # Arguments
+///
+/// * `solution` - The grid to render.
+/// * `palette` - The colors of the cells.
+/// * `width` - The width of the image in cells.
+/// * `height` - The height of the image in cells.
+/// * `cell_size` - The size of each cell in pixels.
+fn render_frame(
+    solution: &NonogramSolution,
+    palette: &NonogramPalette,
+    (width, height): (usize, usize),
+    cell_size: u32,
+) -> RgbaImage {
+    let colors = palette
+        .color_palette
+        .iter()
+        .map(|color| {
+            let (r, g, b) = NonogramPalette::parse_color(color).unwrap_or((0, 0, 0));
+            Rgba([r, g, b, 255])
+        })
+        .collect::<Vec<_>>();
+    let background = colors
+        .get(BACKGROUND)
+        .copied()
+        .unwrap_or(Rgba([0, 0, 0, 0]));
+    RgbaImage::from_fn(
+        width as u32 * cell_size,
+        height as u32 * cell_size,
+        |x, y| {
+            let (row, col) = ((y / cell_size) as usize, (x / cell_size) as usize);
+            solution
+                .solution_grid
+                .get(row)
+                .and_then(|cells| cells.get(col))
+                .and_then(|&color| colors.get(color).copied())
+                .unwrap_or(background)
+        },
+    )
+}
+
+/// Encodes the replay of a solve as an animated GIF, looping forever.
+///
+/// Grids of different dimensions (after a resize) are drawn over the largest canvas, and the
+/// last frame is held for a while before the animation loops.
+///
+/// # Arguments
+///
+/// * `frames` - The grids of the replay, in order (see `ActionLog::frames`).
+/// * `palette` - The colors of the cells.
+/// * `options` - The frame rate and the cell size.
+///
+/// # Returns
+///
+/// The bytes of the GIF file, or the error of the encoder.
+pub fn encode_gif(
+    frames: &[NonogramSolution],
+    palette: &NonogramPalette,
+    options: ReplayOptions,
+) -> Result<Vec<u8>, ImageError> {
+    let size = (
+        frames.iter().map(|frame| frame.cols()).max().unwrap_or(0),
+        frames.iter().map(|frame| frame.rows()).max().unwrap_or(0),
+    );
+    let cell_size = options.cell_size.max(1);
+    let frame_ms = 1000 / options.frame_rate.max(1);
+    let mut bytes = Vec::new();
+    {
+        let mut encoder = GifEncoder::new_with_speed(&mut bytes, 10);
+        encoder.set_repeat(Repeat::Infinite)?;
+        for (index, solution) in frames.iter().enumerate() {
+            let delay = if index + 1 == frames.len() {
+                frame_ms.max(FINAL_FRAME_MS)
+            } else {
+                frame_ms
+            };
+            encoder.encode_frame(Frame::from_parts(
+                render_frame(solution, palette, size, cell_size),
+                0,
+                0,
+                Delay::from_numer_denom_ms(delay, 1),
+            ))?;
+        }
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::puzzles::{tree_empty_nonogram_solution, tree_nonogram_file};
+
+    use super::*;
+
+    // Test that the replay is encoded as a GIF with the size of the largest grid.
+    #[test]
+    fn encode_replay_gif() {
+        let file = tree_nonogram_file();
+        let frames = vec![tree_empty_nonogram_solution(), file.solution];
+        let options = ReplayOptions {
+            frame_rate: 5,
+            cell_size: 4,
+        };
+        let bytes = encode_gif(&frames, &file.palette, options).unwrap();
+        assert!(bytes.starts_with(b"GIF89a"));
+        assert_eq!(u16::from_le_bytes([bytes[6], bytes[7]]), 20);
+        assert_eq!(u16::from_le_bytes([bytes[8], bytes[9]]), 20);
+    }
+}
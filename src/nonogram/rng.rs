@@ -0,0 +1,106 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the seedable random number generator handed out to the randomized actions.
+use rand::{rngs::StdRng, SeedableRng};
+
+/// The random number generator service of the session.
+///
+/// Every randomized action (new random colors, the genetic algorithm, ...) draws its own
+/// generator from this service instead of creating one from entropy, so the whole session can be
+/// reproduced from the displayed seed: the same seed hands out the same generators, in order.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SessionRng {
+    /// The seed of the session.
+    seed: u64,
+    /// The number of generators drawn since the session was seeded.
+    draws: u64,
+}
+
+impl SessionRng {
+    /// Creates the service from a seed.
+    pub fn new(seed: u64) -> Self {
+        Self { seed, draws: 0 }
+    }
+
+    /// Creates the service from a random seed.
+    pub fn from_entropy() -> Self {
+        Self::new(rand::random())
+    }
+
+    /// Returns the seed of the session.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the number of generators drawn since the session was seeded.
+    pub fn draws(&self) -> u64 {
+        self.draws
+    }
+
+    /// Seeds the session again, so the next generators repeat the ones of that seed.
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::new(seed);
+    }
+
+    /// Draws the seed of the next randomized action.
+    ///
+    /// The seeds are derived from the session seed and the number of draws with SplitMix64, so
+    /// consecutive actions get unrelated streams.
+    pub fn next_seed(&mut self) -> u64 {
+        let mut z = self
+            .seed
+            .wrapping_add(self.draws.wrapping_add(1).wrapping_mul(0x9e3779b97f4a7c15));
+        self.draws += 1;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Draws the generator of the next randomized action.
+    pub fn next_rng(&mut self) -> StdRng {
+        StdRng::seed_from_u64(self.next_seed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use super::*;
+
+    // Test that reseeding repeats the generators handed out, and draws differ between them.
+    #[test]
+    fn reseed_repeats_draws() {
+        let mut session = SessionRng::new(23);
+        let first: Vec<u32> = (0..3).map(|_| session.next_rng().gen()).collect();
+        assert_eq!(session.draws(), 3);
+        session.reseed(23);
+        let again: Vec<u32> = (0..3).map(|_| session.next_rng().gen()).collect();
+        assert_eq!(first, again);
+        assert_ne!(first[0], first[1]);
+        assert_ne!(
+            SessionRng::new(24).next_seed(),
+            SessionRng::new(23).next_seed()
+        );
+    }
+}
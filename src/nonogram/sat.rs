@@ -0,0 +1,413 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the puzzles encoded and the solutions decoded from the models.
+use super::definitions::{NonogramPuzzle, NonogramSegment, NonogramSolution, BACKGROUND};
+
+// Import the number of colors of a puzzle, shared with the line solver.
+use super::logic::{initial_states, propagate, to_solution};
+
+// Import the CDCL SAT solver searching the models of the formulas.
+use varisat::{ExtendFormula, Lit, Solver};
+
+/// A literal of a CNF formula in the DIMACS convention: the variable `v` (starting at 1) is the
+/// literal `v`, and its negation is `-v`.
+pub type Literal = i32;
+
+/// A formula in conjunctive normal form: every clause (a disjunction of literals) must hold.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Cnf {
+    /// The number of variables, numbered from 1.
+    pub variables: usize,
+    /// The clauses of the formula.
+    pub clauses: Vec<Vec<Literal>>,
+}
+
+impl Cnf {
+    /// Creates a new variable.
+    pub fn variable(&mut self) -> Literal {
+        self.variables += 1;
+        self.variables as Literal
+    }
+
+    /// Adds a clause to the formula.
+    pub fn clause(&mut self, clause: Vec<Literal>) {
+        self.clauses.push(clause);
+    }
+
+    /// Adds the clauses forcing exactly one of the literals to hold.
+    pub fn exactly_one(&mut self, literals: &[Literal]) {
+        self.clause(literals.to_vec());
+        for (i, &a) in literals.iter().enumerate() {
+            for &b in &literals[i + 1..] {
+                self.clause(vec![-a, -b]);
+            }
+        }
+    }
+
+    /// Writes the formula in the DIMACS format, to solve it with an external SAT solver.
+    pub fn to_dimacs(&self) -> String {
+        let mut dimacs = format!("p cnf {} {}\n", self.variables, self.clauses.len());
+        for clause in &self.clauses {
+            for literal in clause {
+                dimacs.push_str(&format!("{literal} "));
+            }
+            dimacs.push_str("0\n");
+        }
+        dimacs
+    }
+}
+
+/// A puzzle encoded as a CNF formula.
+///
+/// Each cell has a variable per color, exactly one of them true. Each segment of each line has a
+/// variable per possible start, exactly one of them true: the starts follow the order of the
+/// segments (with a gap between segments of the same color), a segment colors the cells it
+/// covers, and a colored cell must be covered by a segment of its color in its row and column.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Encoding {
+    /// The formula.
+    pub cnf: Cnf,
+    /// The variable of each color of each cell, by row and column.
+    cells: Vec<Vec<Vec<Literal>>>,
+}
+
+impl Encoding {
+    /// Encodes the constraints of a puzzle.
+    pub fn new(puzzle: &NonogramPuzzle) -> Self {
+        let colors = initial_states(puzzle)
+            .first()
+            .and_then(|row| row.first())
+            .map_or(BACKGROUND + 1, |state| state.colors().count());
+        let mut cnf = Cnf::default();
+        let cells: Vec<Vec<Vec<Literal>>> = (0..puzzle.rows)
+            .map(|_| {
+                (0..puzzle.cols)
+                    .map(|_| {
+                        let variables: Vec<Literal> = (0..colors).map(|_| cnf.variable()).collect();
+                        cnf.exactly_one(&variables);
+                        variables
+                    })
+                    .collect()
+            })
+            .collect();
+        for (row, constraints) in puzzle.row_constraints.iter().enumerate() {
+            encode_line(&mut cnf, constraints, &cells[row]);
+        }
+        for (col, constraints) in puzzle.col_constraints.iter().enumerate() {
+            let line: Vec<Vec<Literal>> = cells.iter().map(|row| row[col].clone()).collect();
+            encode_line(&mut cnf, constraints, &line);
+        }
+        Self { cnf, cells }
+    }
+
+    /// Decodes a model of the formula into the solution grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `model` - The value of each variable, indexed from 0.
+    pub fn decode(&self, model: &[bool]) -> NonogramSolution {
+        let solution_grid = self
+            .cells
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|variables| {
+                        variables
+                            .iter()
+                            .position(|&variable| model[variable as usize - 1])
+                            .unwrap_or(BACKGROUND)
+                    })
+                    .collect()
+            })
+            .collect();
+        NonogramSolution { solution_grid }
+    }
+
+    /// Returns the clause excluding a solution, to look for another one.
+    pub fn blocking_clause(&self, solution: &NonogramSolution) -> Vec<Literal> {
+        self.cells
+            .iter()
+            .zip(&solution.solution_grid)
+            .flat_map(|(row, colors)| row.iter().zip(colors))
+            .map(|(variables, &color)| -variables[color])
+            .collect()
+    }
+
+    /// Adds the clause excluding a solution to the formula, to look for another one.
+    pub fn block(&mut self, solution: &NonogramSolution) {
+        let clause = self.blocking_clause(solution);
+        self.cnf.clause(clause);
+    }
+}
+
+/// Encodes the constraints of a line, given the color variables of its cells.
+fn encode_line(cnf: &mut Cnf, constraints: &[NonogramSegment], line: &[Vec<Literal>]) {
+    let n = line.len();
+    // The gap needed after each segment, before the next one.
+    let gaps: Vec<usize> = (0..constraints.len())
+        .map(|j| {
+            usize::from(
+                constraints
+                    .get(j + 1)
+                    .is_some_and(|next| next.color == constraints[j].color),
+            )
+        })
+        .collect();
+    let total: usize = constraints
+        .iter()
+        .map(|segment| segment.length)
+        .sum::<usize>()
+        + gaps.iter().sum::<usize>();
+    if total > n {
+        cnf.clause(Vec::new());
+        return;
+    }
+    // The variable of each possible start of each segment, with the start.
+    let mut starts: Vec<Vec<(usize, Literal)>> = Vec::with_capacity(constraints.len());
+    let mut earliest = 0;
+    for (j, segment) in constraints.iter().enumerate() {
+        let latest = earliest + n - total;
+        starts.push(
+            (earliest..=latest)
+                .map(|start| (start, cnf.variable()))
+                .collect(),
+        );
+        let variables: Vec<Literal> = starts[j].iter().map(|&(_, variable)| variable).collect();
+        cnf.exactly_one(&variables);
+        for &(start, variable) in &starts[j] {
+            for cell in &line[start..start + segment.length] {
+                cnf.clause(vec![-variable, cell[segment.color]]);
+            }
+        }
+        earliest += segment.length + gaps[j];
+    }
+    for j in 1..constraints.len() {
+        let after = constraints[j - 1].length + gaps[j - 1];
+        for &(start, variable) in &starts[j - 1] {
+            let mut clause = vec![-variable];
+            clause.extend(
+                starts[j]
+                    .iter()
+                    .filter(|&&(next, _)| next >= start + after)
+                    .map(|&(_, next)| next),
+            );
+            cnf.clause(clause);
+        }
+    }
+    for (x, cell) in line.iter().enumerate() {
+        for (color, &variable) in cell.iter().enumerate().skip(BACKGROUND + 1) {
+            let mut clause = vec![-variable];
+            for (segment, starts) in constraints.iter().zip(&starts) {
+                if segment.color == color {
+                    clause.extend(
+                        starts
+                            .iter()
+                            .filter(|&&(start, _)| start <= x && x < start + segment.length)
+                            .map(|&(_, start)| start),
+                    );
+                }
+            }
+            cnf.clause(clause);
+        }
+    }
+}
+
+/// Loads a formula into a CDCL SAT solver (varisat), with clause learning and activity-based
+/// decisions, so the puzzles far beyond the reach of the backtracking search are solved.
+fn load(cnf: &Cnf) -> Solver<'static> {
+    let mut solver = Solver::new();
+    for clause in &cnf.clauses {
+        solver.add_clause(&to_lits(clause));
+    }
+    solver
+}
+
+/// Converts DIMACS literals into the literals of the solver.
+fn to_lits(clause: &[Literal]) -> Vec<Lit> {
+    clause
+        .iter()
+        .map(|&literal| Lit::from_dimacs(literal as isize))
+        .collect()
+}
+
+/// Searches for a model of the formula loaded in the solver.
+///
+/// # Arguments
+///
+/// * `solver` - The solver with the formula loaded.
+/// * `variables` - The number of variables of the formula.
+///
+/// # Returns
+///
+/// The value of each variable (indexed from 0), or `None` if the formula is unsatisfiable.
+fn search(solver: &mut Solver, variables: usize) -> Option<Vec<bool>> {
+    // The search only fails when interrupted or writing proofs, which are never requested.
+    if !solver.solve().unwrap_or(false) {
+        return None;
+    }
+    let mut model = vec![false; variables];
+    for literal in solver.model()? {
+        if let Some(value) = model.get_mut(literal.index()) {
+            *value = literal.is_positive();
+        }
+    }
+    Some(model)
+}
+
+/// Solves a CNF formula.
+///
+/// # Returns
+///
+/// The value of each variable (indexed from 0) in a model, or `None` if it's unsatisfiable.
+pub fn solve_cnf(cnf: &Cnf) -> Option<Vec<bool>> {
+    search(&mut load(cnf), cnf.variables)
+}
+
+/// Finds up to `limit` solutions of a puzzle with the SAT solver.
+///
+/// The line solver deduces what it can first, and the colors it rules out are added to the
+/// formula, so the SAT solver only searches the cells it couldn't deduce. Each solution found is
+/// then excluded, to search for another one.
+///
+/// # Arguments
+///
+/// * `puzzle` - The puzzle to solve.
+/// * `limit` - The maximum number of solutions searched.
+///
+/// # Returns
+///
+/// The solutions found, in no particular order.
+pub fn find_solutions_sat(puzzle: &NonogramPuzzle, limit: usize) -> Vec<NonogramSolution> {
+    let mut grid = initial_states(puzzle);
+    if limit == 0 || !propagate(puzzle, &mut grid) {
+        return Vec::new();
+    }
+    if grid.iter().flatten().all(|state| state.color().is_some()) {
+        return vec![to_solution(&grid)];
+    }
+    let encoding = Encoding::new(puzzle);
+    let mut solver = load(&encoding.cnf);
+    // The colors ruled out by the line solver, so the solver only searches the undeduced cells.
+    for (states, variables) in grid.iter().zip(&encoding.cells) {
+        for (state, variables) in states.iter().zip(variables) {
+            for (color, &variable) in variables.iter().enumerate() {
+                if !state.allows(color) {
+                    solver.add_clause(&to_lits(&[-variable]));
+                }
+            }
+        }
+    }
+    let mut solutions = Vec::new();
+    while solutions.len() < limit {
+        let Some(model) = search(&mut solver, encoding.cnf.variables) else {
+            break;
+        };
+        let solution = encoding.decode(&model);
+        // The solver keeps what it learned, so the next solution is searched incrementally.
+        solver.add_clause(&to_lits(&encoding.blocking_clause(&solution)));
+        solutions.push(solution);
+    }
+    solutions
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::generator::seeded_puzzle;
+    use crate::nonogram::logic::find_solutions_within;
+    use crate::nonogram::puzzles::{tree_nonogram_file, tree_nonogram_puzzle};
+
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    // Test that the solver finds models and detects unsatisfiable formulas.
+    #[test]
+    fn cnf_models() {
+        let mut cnf = Cnf::default();
+        let (a, b, c) = (cnf.variable(), cnf.variable(), cnf.variable());
+        cnf.exactly_one(&[a, b, c]);
+        cnf.clause(vec![-a]);
+        cnf.clause(vec![-c, a]);
+        assert_eq!(solve_cnf(&cnf), Some(vec![false, true, false]));
+        assert!(cnf.to_dimacs().starts_with("p cnf 3 6\n"));
+        cnf.clause(vec![-b]);
+        assert_eq!(solve_cnf(&cnf), None);
+    }
+
+    // Test that the multicolor puzzles are solved exactly, and that ambiguity is detected.
+    #[test]
+    fn sat_solutions() {
+        let tree = tree_nonogram_puzzle();
+        assert_eq!(
+            find_solutions_sat(&tree, 2),
+            vec![tree_nonogram_file().solution]
+        );
+
+        let ambiguous = NonogramPuzzle::from_solution(&NonogramSolution {
+            solution_grid: vec![vec![1, 0], vec![0, 1]],
+        });
+        assert_eq!(find_solutions_sat(&ambiguous, 10).len(), 2);
+
+        let mut impossible = tree_nonogram_puzzle();
+        impossible.row_constraints[0].clear();
+        assert!(find_solutions_sat(&impossible, 1).is_empty());
+
+        let generated = NonogramPuzzle::from_solution(&seeded_puzzle(8, 8, 3, 7).solution);
+        let solution = find_solutions_sat(&generated, 1).pop().unwrap();
+        assert_eq!(NonogramPuzzle::from_solution(&solution), generated);
+    }
+
+    // Test that the SAT solver settles the random multicolor puzzles that need many guesses,
+    // finding the same number of solutions as the backtracking search.
+    #[test]
+    fn sat_beyond_backtracking() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let (mut settled, mut agreed) = (0, 0);
+        for _ in 0..6 {
+            let solution = NonogramSolution {
+                solution_grid: (0..20)
+                    .map(|_| {
+                        (0..20)
+                            .map(|_| usize::from(rng.gen_bool(0.5)) * rng.gen_range(1..=2))
+                            .collect()
+                    })
+                    .collect(),
+            };
+            let puzzle = NonogramPuzzle::from_solution(&solution);
+            let solutions = find_solutions_sat(&puzzle, 2);
+            assert!(!solutions.is_empty());
+            for found in &solutions {
+                assert_eq!(NonogramPuzzle::from_solution(found), puzzle);
+            }
+            let backtracked = find_solutions_within(&puzzle, 2, 10).or_else(|| {
+                settled += 1;
+                find_solutions_within(&puzzle, 2, 2000)
+            });
+            if let Some(backtracked) = backtracked {
+                agreed += 1;
+                assert_eq!(backtracked.len(), solutions.len());
+            }
+        }
+        assert!(settled > 0 && agreed > 0);
+    }
+}
@@ -0,0 +1,307 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the archives the session puzzles are exported to.
+use super::archive::Archive;
+
+// Import the definitions of the puzzles kept in the session history.
+use super::definitions::{NonogramFile, NonogramSolution};
+
+// Import the stems of the Nonogram filenames, naming the progress files.
+use super::filetype::ngram_stem;
+
+// Import the assist levels the puzzles were completed with.
+use super::settings::AssistLevel;
+
+// Import the ordered collections of the puzzles flagged as ambiguous and the completed puzzles.
+use std::collections::{BTreeMap, BTreeSet};
+
+// Import serialization support, so the pages can be named in the snapshots of the application.
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of puzzles kept in the session history.
+pub const MAX_SESSION_PUZZLES: usize = 12;
+
+/// The page where a puzzle of the session was opened.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum SessionPage {
+    /// The Nonogram Solver.
+    Solver,
+    /// The Nonogram Editor.
+    Editor,
+}
+
+/// A puzzle touched during the session, with enough state to reopen it.
+#[derive(Clone)]
+pub struct SessionPuzzle {
+    /// The name of the puzzle, usually its filename.
+    pub name: String,
+    /// The page where the puzzle was opened.
+    pub page: SessionPage,
+    /// The puzzle file: the puzzle being solved, or the drawing being edited.
+    pub file: NonogramFile,
+    /// The current state of the grid.
+    pub grid: NonogramSolution,
+}
+
+/// The puzzles touched during the session, most recent first.
+///
+/// The pages record their puzzle every time an action is logged in their journal, and reopen a
+/// puzzle when a jump to it is requested (for instance from the header).
+#[derive(Clone, Default)]
+pub struct SessionHistory {
+    /// The touched puzzles, most recent first.
+    puzzles: Vec<SessionPuzzle>,
+    /// The puzzle requested to be reopened, if any.
+    jump: Option<SessionPuzzle>,
+    /// The names of the puzzles found to have alternate solutions.
+    ambiguous: BTreeSet<String>,
+    /// The strictest assist level each completed puzzle was solved with, by name.
+    completions: BTreeMap<String, AssistLevel>,
+    /// The names of the puzzles whose solve buttons or hints were unlocked in the strict mode.
+    assisted: BTreeSet<String>,
+}
+
+impl SessionHistory {
+    /// Records the current state of a puzzle, moving it to the front of the history.
+    ///
+    /// Puzzles are identified by their name and page. The oldest puzzles are forgotten once the
+    /// history holds `MAX_SESSION_PUZZLES` puzzles.
+    pub fn touch(&mut self, puzzle: SessionPuzzle) {
+        self.puzzles
+            .retain(|touched| touched.name != puzzle.name || touched.page != puzzle.page);
+        self.puzzles.insert(0, puzzle);
+        self.puzzles.truncate(MAX_SESSION_PUZZLES);
+    }
+
+    /// Returns the touched puzzles, most recent first.
+    pub fn puzzles(&self) -> &[SessionPuzzle] {
+        &self.puzzles
+    }
+
+    /// Adds the touched puzzles to an archive, under `progress/`: each puzzle file, and for the
+    /// puzzles being solved, the current grid as a `.progress.ngram` file.
+    ///
+    /// # Arguments
+    ///
+    /// * `archive` - The archive the files are added to.
+    pub fn add_to_archive(&self, archive: &mut Archive) {
+        for puzzle in self.puzzles.iter().rev() {
+            let stem = ngram_stem(&puzzle.name).replace(['/', '\\'], "_");
+            if let Ok(json) = serde_json::to_string(&puzzle.file) {
+                archive.add(&format!("progress/{stem}.ngram"), json.into_bytes());
+            }
+            if puzzle.page != SessionPage::Solver {
+                continue;
+            }
+            let progress = NonogramFile {
+                solution: puzzle.grid.clone(),
+                ..puzzle.file.clone()
+            };
+            if let Ok(json) = serde_json::to_string(&progress) {
+                archive.add(
+                    &format!("progress/{stem}.progress.ngram"),
+                    json.into_bytes(),
+                );
+            }
+        }
+    }
+
+    /// Flags a puzzle as ambiguous, after a solution different from its stored one was found.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the puzzle wasn't flagged yet.
+    pub fn flag_ambiguous(&mut self, name: &str) -> bool {
+        self.ambiguous.insert(name.to_string())
+    }
+
+    /// Checks if a puzzle was flagged as ambiguous.
+    pub fn is_ambiguous(&self, name: &str) -> bool {
+        self.ambiguous.contains(name)
+    }
+
+    /// Records the completion of a puzzle, keeping the strictest assist level it was solved with.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the puzzle.
+    /// * `level` - The loosest assist level used while solving it.
+    pub fn record_completion(&mut self, name: &str, level: AssistLevel) {
+        self.completions
+            .entry(name.to_string())
+            .and_modify(|recorded| *recorded = (*recorded).min(level))
+            .or_insert(level);
+    }
+
+    /// Returns the strictest assist level a puzzle was completed with, if it was completed.
+    pub fn completion(&self, name: &str) -> Option<AssistLevel> {
+        self.completions.get(name).copied()
+    }
+
+    /// Marks a puzzle as assisted, after the solve buttons and hints were unlocked in the strict
+    /// mode while solving it.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the puzzle wasn't marked yet.
+    pub fn mark_assisted(&mut self, name: &str) -> bool {
+        self.assisted.insert(name.to_string())
+    }
+
+    /// Checks if a puzzle was marked as assisted.
+    pub fn is_assisted(&self, name: &str) -> bool {
+        self.assisted.contains(name)
+    }
+
+    /// Requests to reopen a puzzle of the history.
+    ///
+    /// # Returns
+    ///
+    /// The page where the puzzle must be reopened, or `None` if there's no such puzzle.
+    pub fn request_jump(&mut self, index: usize) -> Option<SessionPage> {
+        self.jump = self.puzzles.get(index).cloned();
+        self.jump.as_ref().map(|puzzle| puzzle.page)
+    }
+
+    /// Checks if a puzzle was requested to be reopened in a page.
+    pub fn has_jump(&self, page: SessionPage) -> bool {
+        self.jump.as_ref().is_some_and(|puzzle| puzzle.page == page)
+    }
+
+    /// Takes the puzzle requested to be reopened in a page, if any.
+    pub fn take_jump(&mut self, page: SessionPage) -> Option<SessionPuzzle> {
+        if self.has_jump(page) {
+            self.jump.take()
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::puzzles::{tree_empty_nonogram_solution, tree_nonogram_file};
+
+    use super::*;
+
+    // Builds a session puzzle with the tree Nonogram.
+    fn puzzle(name: &str, page: SessionPage) -> SessionPuzzle {
+        SessionPuzzle {
+            name: String::from(name),
+            page,
+            file: tree_nonogram_file(),
+            grid: tree_empty_nonogram_solution(),
+        }
+    }
+
+    // Test that touched puzzles are deduplicated, moved to the front and capped.
+    #[test]
+    fn touch_moves_puzzles_to_front() {
+        let mut history = SessionHistory::default();
+        history.touch(puzzle("tree.ngram", SessionPage::Solver));
+        history.touch(puzzle("tree.ngram", SessionPage::Editor));
+        history.touch(puzzle("tree.ngram", SessionPage::Solver));
+        let pages: Vec<SessionPage> = history.puzzles().iter().map(|p| p.page).collect();
+        assert_eq!(pages, vec![SessionPage::Solver, SessionPage::Editor]);
+
+        for index in 0..MAX_SESSION_PUZZLES {
+            history.touch(puzzle(&format!("{index}.ngram"), SessionPage::Editor));
+        }
+        assert_eq!(history.puzzles().len(), MAX_SESSION_PUZZLES);
+        assert_eq!(history.puzzles()[0].name, "11.ngram");
+    }
+
+    // Test that the puzzles being solved are exported with their progress.
+    #[test]
+    fn session_archive() {
+        let mut history = SessionHistory::default();
+        history.touch(puzzle("drawings/tree.ngram", SessionPage::Editor));
+        history.touch(puzzle("tree.ngram", SessionPage::Solver));
+        let mut archive = Archive::default();
+        history.add_to_archive(&mut archive);
+        let names: Vec<&str> = archive
+            .entries()
+            .iter()
+            .map(|entry| entry.name.as_str())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "progress/drawings_tree.ngram",
+                "progress/tree.ngram",
+                "progress/tree.progress.ngram"
+            ]
+        );
+        let progress: NonogramFile = serde_json::from_slice(&archive.entries()[2].data).unwrap();
+        assert_eq!(progress.solution, tree_empty_nonogram_solution());
+    }
+
+    // Test that a jump is only taken by the page of the requested puzzle.
+    #[test]
+    fn jump_is_taken_by_its_page() {
+        let mut history = SessionHistory::default();
+        history.touch(puzzle("tree.ngram", SessionPage::Editor));
+        assert_eq!(history.request_jump(1), None);
+        assert_eq!(history.request_jump(0), Some(SessionPage::Editor));
+        assert!(history.take_jump(SessionPage::Solver).is_none());
+        assert_eq!(
+            history.take_jump(SessionPage::Editor).unwrap().name,
+            "tree.ngram"
+        );
+        assert!(!history.has_jump(SessionPage::Editor));
+    }
+
+    // Test that the ambiguous flag survives the puzzle being touched again.
+    #[test]
+    fn ambiguous_flag_is_kept() {
+        let mut history = SessionHistory::default();
+        history.touch(puzzle("tree.ngram", SessionPage::Solver));
+        assert!(history.flag_ambiguous("tree.ngram"));
+        assert!(!history.flag_ambiguous("tree.ngram"));
+        history.touch(puzzle("tree.ngram", SessionPage::Solver));
+        assert!(history.is_ambiguous("tree.ngram"));
+        assert!(!history.is_ambiguous("other.ngram"));
+    }
+
+    // Test that completions keep the strictest assist level.
+    #[test]
+    fn completions_keep_the_strictest_level() {
+        let mut history = SessionHistory::default();
+        assert_eq!(history.completion("tree.ngram"), None);
+        history.record_completion("tree.ngram", AssistLevel::Relaxed);
+        history.record_completion("tree.ngram", AssistLevel::Purist);
+        history.record_completion("tree.ngram", AssistLevel::Casual);
+        assert_eq!(history.completion("tree.ngram"), Some(AssistLevel::Purist));
+    }
+
+    // Test that a puzzle stays assisted after it's completed.
+    #[test]
+    fn assisted_mark_is_kept() {
+        let mut history = SessionHistory::default();
+        assert!(history.mark_assisted("tree.ngram"));
+        assert!(!history.mark_assisted("tree.ngram"));
+        history.record_completion("tree.ngram", AssistLevel::Purist);
+        assert!(history.is_assisted("tree.ngram"));
+        assert!(!history.is_assisted("other.ngram"));
+    }
+}
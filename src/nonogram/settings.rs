@@ -0,0 +1,745 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the macros recorded in the editor, stored in the settings.
+use super::command::Macro;
+
+// Import the Nonogram structures compared to decide whether a puzzle is completed, and the
+// palette where the colors painted are chosen.
+use super::definitions::{NonogramPalette, NonogramPuzzle, NonogramSolution, BACKGROUND};
+
+// Import the validation of the lines of a grid, counted as progress.
+use super::logic::satisfied_lines;
+
+// Import the profiles, whose settings are stored apart.
+use super::profile::{namespaced, Profile};
+
+// Import the persistence layer.
+use super::storage;
+
+// Import serialization support to persist the settings as JSON.
+use serde::{Deserialize, Serialize};
+
+/// Where the solutions found by the solver are saved automatically.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub enum Autosave {
+    /// The solutions aren't saved.
+    #[default]
+    Off,
+    /// The solutions are saved next to the puzzle, as `<puzzle>.solved.ngram`.
+    SiblingFile,
+    /// The solutions are stored with the run in the database of solver runs (on desktop platforms).
+    RunHistory,
+}
+
+/// What loading the same file as the puzzle being solved does to the progress.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub enum DuplicateLoad {
+    /// Asks whether to keep the progress or to start over.
+    #[default]
+    Ask,
+    /// Keeps the progress, as the file was most likely selected again by accident.
+    KeepProgress,
+    /// Clears the grid, as with any other file.
+    Clear,
+}
+
+/// What completing a puzzle in the solver means.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub enum Completion {
+    /// The grid satisfies the clues, accepting alternate solutions.
+    #[default]
+    Constraints,
+    /// The grid matches the solution stored in the puzzle file.
+    ExactImage,
+}
+
+impl Completion {
+    /// Returns the criterion actually applied: puzzles imported from their clues have no stored
+    /// solution, so they are always completed by their constraints.
+    ///
+    /// # Arguments
+    ///
+    /// * `blind` - Whether the puzzle was imported without its solution.
+    pub fn applied(self, blind: bool) -> Self {
+        if blind {
+            Completion::Constraints
+        } else {
+            self
+        }
+    }
+
+    /// Returns whether a grid completes a puzzle with this criterion.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle being solved.
+    /// * `stored` - The solution stored in the puzzle file.
+    /// * `solution` - The grid filled by the user or the solver.
+    pub fn is_met(
+        self,
+        puzzle: &NonogramPuzzle,
+        stored: &NonogramSolution,
+        solution: &NonogramSolution,
+    ) -> bool {
+        match self {
+            Completion::Constraints => *puzzle == NonogramPuzzle::from_solution(solution),
+            Completion::ExactImage => stored.solution_grid == solution.solution_grid,
+        }
+    }
+
+    /// Estimates how close a grid is to completing a puzzle with this criterion: the fraction of
+    /// lines satisfying their clues, or of cells matching the stored solution.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The puzzle being solved.
+    /// * `stored` - The solution stored in the puzzle file.
+    /// * `solution` - The grid filled by the user or the solver.
+    ///
+    /// # Returns
+    ///
+    /// The progress, from `0.0` to `1.0`.
+    pub fn progress(
+        self,
+        puzzle: &NonogramPuzzle,
+        stored: &NonogramSolution,
+        solution: &NonogramSolution,
+    ) -> f64 {
+        let (matched, total) = match self {
+            Completion::Constraints => {
+                let (rows, cols) = satisfied_lines(puzzle, solution);
+                (
+                    rows.into_iter().chain(cols).filter(|&line| line).count(),
+                    puzzle.rows + puzzle.cols,
+                )
+            }
+            Completion::ExactImage => {
+                let cells = stored.solution_grid.iter().zip(&solution.solution_grid);
+                (
+                    cells
+                        .map(|(expected, row)| {
+                            expected.iter().zip(row).filter(|(a, b)| a == b).count()
+                        })
+                        .sum(),
+                    stored.solution_grid.iter().map(Vec::len).sum(),
+                )
+            }
+        };
+        if total == 0 {
+            0.0
+        } else {
+            matched as f64 / total as f64
+        }
+    }
+}
+
+/// How much the solver helps while a puzzle is solved by hand, bundling the assistance features.
+///
+/// The levels are ordered from the strictest to the loosest.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Default, Deserialize, Serialize)]
+pub enum AssistLevel {
+    /// No assistance at all: the puzzle is solved from the clues alone.
+    Purist,
+    /// The completed lines are crossed, mistakes are flashed, and the clues of the lines that can
+    /// no longer be completed are highlighted.
+    #[default]
+    Casual,
+    /// Besides the casual assistance, hints are offered and the trivial cells are filled.
+    Relaxed,
+}
+
+/// The assistance features enabled by an assist level.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Assists {
+    /// Whether the remaining cells of the lines satisfying their clues are crossed.
+    pub auto_cross: bool,
+    /// Whether painting a cell that doesn't match the solution is flashed and played.
+    pub mistakes: bool,
+    /// Whether the clues of the lines whose painted cells can't be completed are highlighted.
+    pub contradictions: bool,
+    /// Whether a cell deduced by logic can be revealed.
+    pub hints: bool,
+    /// Whether the cells deduced from a single line are filled when a puzzle is loaded.
+    pub auto_fill: bool,
+}
+
+impl AssistLevel {
+    /// Returns the assistance features enabled by the level.
+    pub fn assists(self) -> Assists {
+        Assists {
+            auto_cross: self >= AssistLevel::Casual,
+            mistakes: self >= AssistLevel::Casual,
+            contradictions: self >= AssistLevel::Casual,
+            hints: self >= AssistLevel::Relaxed,
+            auto_fill: self >= AssistLevel::Relaxed,
+        }
+    }
+}
+
+/// How the clues are aligned in their tables, when lines have fewer clues than others.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub enum ClueAlignment {
+    /// The clues end next to the grid: column clues are aligned to the bottom, and row clues
+    /// to the right.
+    #[default]
+    Grid,
+    /// The clues start at the outer edge: column clues are aligned to the top, and row clues
+    /// to the left.
+    Edge,
+}
+
+impl ClueAlignment {
+    /// Returns the clue of a line shown in a slot of its clue table.
+    ///
+    /// # Arguments
+    ///
+    /// * `clues` - The number of clues of the line.
+    /// * `slots` - The number of slots of the table, the most clues of any line.
+    /// * `slot` - The slot, counted from the outer edge.
+    ///
+    /// # Returns
+    ///
+    /// The index of the clue, or `None` if the slot is empty.
+    pub fn clue_index(self, clues: usize, slots: usize, slot: usize) -> Option<usize> {
+        match self {
+            ClueAlignment::Grid => slot.checked_sub(slots.saturating_sub(clues)),
+            ClueAlignment::Edge => Some(slot),
+        }
+        .filter(|index| *index < clues)
+    }
+}
+
+/// What a tap (a press released on the cell where it started) does on the grid.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub enum TapAction {
+    /// Paints the cell with the brush, like a drag.
+    #[default]
+    Paint,
+    /// Toggles the cell between the brush and the background.
+    Toggle,
+}
+
+impl TapAction {
+    /// Returns the color a tap paints on a cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - The current color of the cell.
+    /// * `brush` - The color of the brush.
+    pub fn color(self, cell: usize, brush: usize) -> usize {
+        match self {
+            TapAction::Toggle if cell == brush => BACKGROUND,
+            _ => brush,
+        }
+    }
+}
+
+/// How the pointer interacts with the grid. Drags always paint with the brush.
+#[derive(Clone, Copy, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Interaction {
+    /// What a tap does on a cell.
+    pub tap: TapAction,
+    /// Whether only a stylus draws, so resting the palm or a finger on the grid is ignored.
+    pub stylus_only: bool,
+    /// Whether the pressure of the stylus chooses the color, where it's supported.
+    pub pressure: bool,
+}
+
+impl Interaction {
+    /// Returns whether a pointer draws on the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `pointer_type` - The type of the pointer: `mouse`, `pen` or `touch`.
+    pub fn accepts(&self, pointer_type: &str) -> bool {
+        !self.stylus_only || pointer_type == "pen"
+    }
+
+    /// Returns the color a pointer paints with.
+    ///
+    /// # Arguments
+    ///
+    /// * `palette` - The palette, with the color of the brush.
+    /// * `pointer_type` - The type of the pointer: `mouse`, `pen` or `touch`.
+    /// * `pressure` - The pressure of the pointer, from `0.0` to `1.0`.
+    pub fn color(&self, palette: &NonogramPalette, pointer_type: &str, pressure: f32) -> usize {
+        if self.pressure && pointer_type == "pen" && pressure > 0.0 {
+            palette.pressure_color(pressure)
+        } else {
+            palette.brush
+        }
+    }
+}
+
+/// Accessibility settings, applied to the whole interface through CSS variables and classes.
+#[derive(Clone, Copy, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Accessibility {
+    /// The scale of the text of the interface and the clue numbers, from `0.75` to `1.5`.
+    pub font_scale: f32,
+    /// Whether the hover scaling and the transitions are disabled.
+    pub reduced_motion: bool,
+}
+
+impl Default for Accessibility {
+    fn default() -> Self {
+        Self {
+            font_scale: 1.0,
+            reduced_motion: false,
+        }
+    }
+}
+
+impl Accessibility {
+    /// The smallest font scale.
+    pub const MIN_FONT_SCALE: f32 = 0.75;
+    /// The largest font scale.
+    pub const MAX_FONT_SCALE: f32 = 1.5;
+
+    /// Returns the script applying the settings to the document root: the `--font-scale`
+    /// variable and the `reduced-motion` class (see `main.css`).
+    pub fn script(&self) -> String {
+        format!(
+            "document.documentElement.style.setProperty('--font-scale', '{}'); document.documentElement.classList.toggle('reduced-motion', {});",
+            self.font_scale
+                .clamp(Self::MIN_FONT_SCALE, Self::MAX_FONT_SCALE),
+            self.reduced_motion
+        )
+    }
+}
+
+/// How much the interface saves battery, for long solving sessions on mobile devices.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Deserialize, Serialize)]
+pub enum PowerMode {
+    /// Every effect and live update of the interface.
+    #[default]
+    Normal,
+    /// No hover effects, and fewer updates of the solver, the solve timer and the session
+    /// history.
+    LowPower,
+}
+
+impl PowerMode {
+    /// Returns the milliseconds between the ticks updating the solve timer.
+    pub fn tick_millis(self) -> u32 {
+        match self {
+            PowerMode::Normal => 1000,
+            PowerMode::LowPower => 5000,
+        }
+    }
+
+    /// Returns the generations between the updates of the progress streamed by the running
+    /// solver into the interface.
+    pub fn progress_interval(self) -> usize {
+        match self {
+            PowerMode::Normal => 1,
+            PowerMode::LowPower => 25,
+        }
+    }
+
+    /// Returns the generations between the samples of the population streamed by the running
+    /// solver into the interface.
+    pub fn sample_interval(self) -> usize {
+        match self {
+            PowerMode::Normal => 5,
+            PowerMode::LowPower => 50,
+        }
+    }
+
+    /// Returns the milliseconds the grid must stay untouched before it's recorded in the session
+    /// history, so a stroke is recorded once instead of at every cell.
+    pub fn snapshot_delay_millis(self) -> u32 {
+        match self {
+            PowerMode::Normal => 0,
+            PowerMode::LowPower => 3000,
+        }
+    }
+
+    /// Returns the script applying the mode to the document root: the `low-power` class
+    /// disabling the hover effects (see `main.css`).
+    pub fn script(self) -> String {
+        format!(
+            "document.documentElement.classList.toggle('low-power', {});",
+            self == PowerMode::LowPower
+        )
+    }
+}
+
+/// A custom theme replacing the colors of the interface, loaded from a JSON file of CSS colors
+/// such as `{ "background": "#1e1e2e", "toolbar": "#313244" }`.
+///
+/// Each color is applied through a CSS variable on the document root (see `main.css`); the
+/// missing colors keep the built-in look.
+#[derive(Clone, Default, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Theme {
+    /// The background of the page.
+    pub background: Option<String>,
+    /// The background of the toolbars and the panels.
+    pub toolbar: Option<String>,
+    /// The borders of the buttons and the inputs.
+    pub border: Option<String>,
+    /// The highlight of the hovered buttons.
+    pub highlight: Option<String>,
+}
+
+/// Why a theme file couldn't be loaded.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ThemeError {
+    /// The file isn't a JSON object of the theme colors.
+    Malformed,
+    /// A color has characters that aren't allowed in CSS colors.
+    InvalidColor(String),
+}
+
+impl Theme {
+    /// Parses a theme file, checking that every color is a plain CSS color value.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The content of the theme file.
+    ///
+    /// # Returns
+    ///
+    /// The theme, or why it couldn't be parsed.
+    pub fn parse(json: &str) -> Result<Self, ThemeError> {
+        let theme: Self = serde_json::from_str(json).map_err(|_| ThemeError::Malformed)?;
+        for (name, color) in theme.colors() {
+            let allowed = |c: char| c.is_ascii_alphanumeric() || "#(),.%/ -".contains(c);
+            if let Some(color) =
+                color.filter(|color| color.is_empty() || !color.chars().all(allowed))
+            {
+                return Err(ThemeError::InvalidColor(format!("{name}: {color}")));
+            }
+        }
+        Ok(theme)
+    }
+
+    /// Returns each color of the theme with the name of its CSS variable suffix.
+    fn colors(&self) -> [(&'static str, Option<&String>); 4] {
+        [
+            ("background", self.background.as_ref()),
+            ("toolbar", self.toolbar.as_ref()),
+            ("border", self.border.as_ref()),
+            ("highlight", self.highlight.as_ref()),
+        ]
+    }
+
+    /// Returns the script applying the theme to the document root: the `--theme-<color>`
+    /// variables and the `theme-<color>` classes enabling them (see `main.css`).
+    pub fn script(&self) -> String {
+        self.colors()
+            .into_iter()
+            .map(|(name, color)| match color {
+                Some(color) => format!(
+                    "document.documentElement.style.setProperty('--theme-{name}', '{color}'); document.documentElement.classList.add('theme-{name}');"
+                ),
+                None => format!(
+                    "document.documentElement.style.removeProperty('--theme-{name}'); document.documentElement.classList.remove('theme-{name}');"
+                ),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// The name of the settings file (or local storage key on the web).
+const SETTINGS_KEY: &str = "ngram-settings.json";
+
+/// The user settings, persisted between sessions.
+///
+/// Missing fields take their default value, so settings saved by older versions still load.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The macros recorded in the editor.
+    pub macros: Vec<Macro>,
+    /// Whether the background cells of the grid are rendered with a checkerboard pattern.
+    pub checkerboard: bool,
+    /// The volume of the sound effects, from `0.0` (muted) to `1.0`.
+    pub volume: f32,
+    /// Whether the device vibrates on feedback events (on mobile devices).
+    pub vibration: bool,
+    /// The minutes without input pausing the solve timer.
+    pub idle_minutes: u32,
+    /// Whether a newer release is looked for on startup (on desktop platforms).
+    pub check_updates: bool,
+    /// Where the solutions found by the solver are saved automatically.
+    pub autosave: Autosave,
+    /// What completing a puzzle in the solver means.
+    pub completion: Completion,
+    /// What loading the same file as the puzzle being solved does to the progress.
+    pub duplicate_load: DuplicateLoad,
+    /// How the pointer interacts with the grid.
+    pub interaction: Interaction,
+    /// The font scale and the reduced-motion mode.
+    pub accessibility: Accessibility,
+    /// How the clues are aligned in their tables.
+    pub clue_alignment: ClueAlignment,
+    /// How much the solver helps while a puzzle is solved by hand.
+    pub assist_level: AssistLevel,
+    /// Whether the solve buttons and hints stay locked until they're deliberately unlocked, so
+    /// the completions count as unassisted.
+    pub strict_mode: bool,
+    /// The custom theme of the interface.
+    pub theme: Theme,
+    /// Whether the interface saves battery, with fewer effects and live updates.
+    pub power_mode: PowerMode,
+    /// The storage key of the settings, namespaced in the profile they belong to.
+    #[serde(skip)]
+    key: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            macros: Vec::new(),
+            checkerboard: false,
+            volume: 0.0,
+            vibration: false,
+            idle_minutes: 2,
+            check_updates: true,
+            autosave: Autosave::Off,
+            completion: Completion::Constraints,
+            duplicate_load: DuplicateLoad::Ask,
+            interaction: Interaction::default(),
+            accessibility: Accessibility::default(),
+            clue_alignment: ClueAlignment::Grid,
+            assist_level: AssistLevel::Casual,
+            strict_mode: false,
+            theme: Theme::default(),
+            power_mode: PowerMode::Normal,
+            key: String::from(SETTINGS_KEY),
+        }
+    }
+}
+
+impl Settings {
+    /// Loads the settings stored by a previous session, without a profile.
+    ///
+    /// # Returns
+    ///
+    /// The stored settings, or the default settings if they're missing or unreadable.
+    pub fn load() -> Self {
+        Self::load_profile(None)
+    }
+
+    /// Loads the settings of a profile stored by a previous session.
+    ///
+    /// # Arguments
+    ///
+    /// * `profile` - The profile whose settings are loaded, or `None` for the guest settings.
+    ///
+    /// # Returns
+    ///
+    /// The stored settings, or the default settings if they're missing or unreadable.
+    pub fn load_profile(profile: Option<&Profile>) -> Self {
+        let key = namespaced(profile, SETTINGS_KEY);
+        let mut settings: Self = storage::read(&key)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        settings.key = key;
+        settings
+    }
+
+    /// Removes the stored settings of a profile.
+    pub fn remove_profile(profile: &Profile) -> bool {
+        storage::remove(&profile.namespace(SETTINGS_KEY))
+    }
+
+    /// Replaces the settings with restored ones (for instance from a snapshot of the
+    /// application), keeping the storage key of the profile they belong to.
+    ///
+    /// # Arguments
+    ///
+    /// * `restored` - The settings replacing the current ones.
+    pub fn restore(&mut self, restored: Settings) {
+        let key = std::mem::take(&mut self.key);
+        *self = Self { key, ..restored };
+    }
+
+    /// Stores the settings for the next sessions.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the settings could be stored.
+    pub fn save(&self) -> bool {
+        serde_json::to_string(self).is_ok_and(|json| storage::write(&self.key, &json))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that an alternate solution only completes the puzzle by its constraints.
+    #[test]
+    fn alternate_solution_completion() {
+        let stored = NonogramSolution {
+            solution_grid: vec![vec![1, 0], vec![0, 1]],
+        };
+        let alternate = NonogramSolution {
+            solution_grid: vec![vec![0, 1], vec![1, 0]],
+        };
+        let puzzle = NonogramPuzzle::from_solution(&stored);
+        assert!(Completion::Constraints.is_met(&puzzle, &stored, &alternate));
+        assert!(!Completion::ExactImage.is_met(&puzzle, &stored, &alternate));
+        assert!(Completion::ExactImage.is_met(&puzzle, &stored, &stored));
+        assert_eq!(
+            Completion::ExactImage.applied(true),
+            Completion::Constraints
+        );
+    }
+
+    // Test that the progress counts satisfied lines or matched cells.
+    #[test]
+    fn completion_progress() {
+        let stored = NonogramSolution {
+            solution_grid: vec![vec![1, 0], vec![1, 1]],
+        };
+        let puzzle = NonogramPuzzle::from_solution(&stored);
+        let solution = NonogramSolution {
+            solution_grid: vec![vec![1, 0], vec![0, 0]],
+        };
+        assert_eq!(
+            Completion::Constraints.progress(&puzzle, &stored, &solution),
+            0.25
+        );
+        assert_eq!(
+            Completion::ExactImage.progress(&puzzle, &stored, &solution),
+            0.5
+        );
+        assert_eq!(
+            Completion::Constraints.progress(&puzzle, &stored, &stored),
+            1.0
+        );
+    }
+
+    // Test that the assist levels enable more assistance as they loosen.
+    #[test]
+    fn assist_levels() {
+        let purist = AssistLevel::Purist.assists();
+        assert!(!purist.auto_cross && !purist.mistakes && !purist.hints && !purist.auto_fill);
+        assert!(!purist.contradictions);
+        let casual = AssistLevel::Casual.assists();
+        assert!(casual.auto_cross && casual.mistakes && !casual.hints && !casual.auto_fill);
+        assert!(casual.contradictions);
+        let relaxed = AssistLevel::Relaxed.assists();
+        assert!(relaxed.hints && relaxed.auto_fill);
+    }
+
+    // Test that the theme files are validated, and applied through the CSS variables.
+    #[test]
+    fn theme_files() {
+        let theme =
+            Theme::parse(r##"{ "background": "#1e1e2e", "highlight": "rgb(30, 64, 175)" }"##)
+                .unwrap();
+        assert_eq!(theme.background.as_deref(), Some("#1e1e2e"));
+        assert_eq!(theme.toolbar, None);
+        let script = theme.script();
+        assert!(script.contains("setProperty('--theme-highlight', 'rgb(30, 64, 175)')"));
+        assert!(script.contains("classList.remove('theme-toolbar')"));
+
+        assert_eq!(Theme::parse("[1, 2]"), Err(ThemeError::Malformed));
+        assert_eq!(
+            Theme::parse(r#"{ "foreground": "red" }"#),
+            Err(ThemeError::Malformed)
+        );
+        assert_eq!(
+            Theme::parse(r#"{ "toolbar": "red'); alert('" }"#),
+            Err(ThemeError::InvalidColor(String::from(
+                "toolbar: red'); alert('"
+            )))
+        );
+    }
+
+    // Test that the low-power mode updates the interface less often.
+    #[test]
+    fn low_power_mode() {
+        let (normal, low) = (PowerMode::Normal, PowerMode::LowPower);
+        assert!(low.tick_millis() > normal.tick_millis());
+        assert!(low.progress_interval() > normal.progress_interval());
+        assert!(low.sample_interval() > normal.sample_interval());
+        assert!(low.snapshot_delay_millis() > normal.snapshot_delay_millis());
+        assert!(low.script().contains("'low-power', true"));
+        assert!(normal.script().contains("'low-power', false"));
+    }
+
+    // Test that the clues are placed next to the grid or at the outer edge.
+    #[test]
+    fn clue_alignment() {
+        let grid: Vec<_> = (0..3)
+            .map(|slot| ClueAlignment::Grid.clue_index(2, 3, slot))
+            .collect();
+        assert_eq!(grid, vec![None, Some(0), Some(1)]);
+        let edge: Vec<_> = (0..3)
+            .map(|slot| ClueAlignment::Edge.clue_index(2, 3, slot))
+            .collect();
+        assert_eq!(edge, vec![Some(0), Some(1), None]);
+    }
+
+    // Test that taps toggle cells, and that the stylus pressure chooses from light to dark colors.
+    #[test]
+    fn pointer_interaction() {
+        assert_eq!(TapAction::Toggle.color(2, 2), BACKGROUND);
+        assert_eq!(TapAction::Toggle.color(1, 2), 2);
+        assert_eq!(TapAction::Paint.color(2, 2), 2);
+
+        let palette = NonogramPalette {
+            color_palette: vec![
+                String::from("#ffffff"),
+                String::from("#000000"),
+                String::from("#eeeeee"),
+                String::from("#777777"),
+            ],
+            brush: 3,
+        };
+        let interaction = Interaction {
+            stylus_only: true,
+            pressure: true,
+            ..Default::default()
+        };
+        assert!(!interaction.accepts("touch"));
+        assert!(interaction.accepts("pen"));
+        assert_eq!(interaction.color(&palette, "mouse", 0.1), 3);
+        assert_eq!(interaction.color(&palette, "pen", 0.1), 2);
+        assert_eq!(interaction.color(&palette, "pen", 0.5), 3);
+        assert_eq!(interaction.color(&palette, "pen", 1.0), 1);
+    }
+
+    // Test that restored settings keep the storage key of the profile they're restored into.
+    #[test]
+    fn restore_keeps_the_key() {
+        let mut settings = Settings {
+            key: String::from("profiles/1/ngram-settings.json"),
+            ..Settings::default()
+        };
+        settings.restore(Settings {
+            strict_mode: true,
+            ..Settings::default()
+        });
+        assert!(settings.strict_mode);
+        assert_eq!(settings.key, "profiles/1/ngram-settings.json");
+    }
+}
@@ -0,0 +1,120 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Snapshots of the state of a page of the application: the grids, the palette and the settings.
+//!
+//! With the `debug-state` feature, the pages expose their snapshots to the document, so automated
+//! UI tests can inspect the state after driving the interface, or restore a state before a flow.
+
+// Import the grids and palettes captured.
+use super::definitions::{NonogramFile, NonogramPalette, NonogramPuzzle, NonogramSolution};
+
+// Import the pages captured.
+use super::session::SessionPage;
+
+// Import the settings captured.
+use super::settings::Settings;
+
+// Import serialization support for the JSON snapshots.
+use serde::{Deserialize, Serialize};
+
+/// The state of a page of the application.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct AppSnapshot {
+    /// The page captured.
+    pub page: SessionPage,
+    /// The name of the puzzle, usually its filename.
+    pub filename: String,
+    /// The puzzle file: the puzzle being solved, or the drawing being edited.
+    pub file: NonogramFile,
+    /// The current state of the grid.
+    pub grid: NonogramSolution,
+    /// The current color palette.
+    pub palette: NonogramPalette,
+    /// Whether the puzzle has been completed.
+    pub completed: bool,
+    /// The number of actions recorded in the journal of the page.
+    pub actions: usize,
+    /// The user settings.
+    pub settings: Settings,
+}
+
+impl AppSnapshot {
+    /// Serializes the snapshot as pretty-printed JSON.
+    pub fn to_json(&self) -> Option<String> {
+        serde_json::to_string_pretty(self).ok()
+    }
+
+    /// Parses a snapshot from JSON.
+    ///
+    /// # Returns
+    ///
+    /// The snapshot, or `None` if the JSON isn't a snapshot.
+    pub fn from_json(json: &str) -> Option<Self> {
+        serde_json::from_str(json).ok()
+    }
+
+    /// Returns the puzzle of the page: the clues of the puzzle being solved, or of the drawing
+    /// being edited.
+    pub fn puzzle(&self) -> NonogramPuzzle {
+        match self.page {
+            SessionPage::Solver => NonogramPuzzle::from_solution(&self.file.solution),
+            SessionPage::Editor => NonogramPuzzle::from_solution(&self.grid),
+        }
+    }
+
+    /// Checks if the grid satisfies every clue of the puzzle of the page.
+    pub fn is_solved(&self) -> bool {
+        self.puzzle().score(&self.grid) == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nonogram::puzzles::{tree_empty_nonogram_solution, tree_nonogram_file};
+
+    // Test that a snapshot survives the JSON round trip.
+    #[test]
+    fn snapshot_round_trip() {
+        let file = tree_nonogram_file();
+        let mut settings = Settings::default();
+        settings.strict_mode = true;
+        let snapshot = AppSnapshot {
+            page: SessionPage::Solver,
+            filename: String::from("tree.ngram"),
+            palette: file.palette.clone(),
+            file,
+            grid: tree_empty_nonogram_solution(),
+            completed: false,
+            actions: 2,
+            settings,
+        };
+        let json = snapshot.to_json().unwrap();
+        let restored = AppSnapshot::from_json(&json).unwrap();
+        assert_eq!(restored.to_json().unwrap(), json);
+        assert_eq!(restored.page, SessionPage::Solver);
+        assert!(!restored.is_solved());
+        assert!(restored.settings.strict_mode);
+        assert!(AppSnapshot::from_json("{}").is_none());
+    }
+}
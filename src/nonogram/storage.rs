@@ -0,0 +1,127 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Reads the data stored under a key by a previous session.
+///
+/// On native platforms each key is a file in the `ngram` user configuration directory (keys with
+/// `/` are nested directories); on the web each key is an entry of the local storage.
+///
+/// # Arguments
+///
+/// * `key` - The key of the data, such as `ngram-settings.json`.
+///
+/// # Returns
+///
+/// The stored data, or `None` if it's missing or unreadable.
+pub fn read(key: &str) -> Option<String> {
+    platform::read(key)
+}
+
+/// Stores data under a key for the next sessions.
+///
+/// # Returns
+///
+/// `true` if the data could be stored.
+pub fn write(key: &str, data: &str) -> bool {
+    platform::write(key, data)
+}
+
+/// Returns the file storing a key, for data managed by other libraries (such as databases).
+///
+/// # Returns
+///
+/// The path of the file, or `None` on the web, where there's no file system.
+pub fn path(key: &str) -> Option<std::path::PathBuf> {
+    platform::path(key)
+}
+
+/// Removes the data stored under a key.
+///
+/// # Returns
+///
+/// `true` if the data was removed (or didn't exist).
+pub fn remove(key: &str) -> bool {
+    platform::remove(key)
+}
+
+#[cfg(not(feature = "web"))]
+/// Persistence in files of the user configuration directory.
+mod platform {
+    use std::path::PathBuf;
+
+    /// Returns the path of the file storing a key.
+    pub fn path(key: &str) -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("ngram").join(key))
+    }
+
+    /// Reads the file storing a key.
+    pub fn read(key: &str) -> Option<String> {
+        std::fs::read_to_string(path(key)?).ok()
+    }
+
+    /// Writes the file storing a key, creating its directories if needed.
+    pub fn write(key: &str, data: &str) -> bool {
+        path(key).is_some_and(|path| {
+            path.parent()
+                .is_some_and(|dir| std::fs::create_dir_all(dir).is_ok())
+                && std::fs::write(path, data).is_ok()
+        })
+    }
+
+    /// Removes the file storing a key.
+    pub fn remove(key: &str) -> bool {
+        path(key).is_some_and(|path| !path.exists() || std::fs::remove_file(path).is_ok())
+    }
+}
+
+#[cfg(feature = "web")]
+/// Persistence in the local storage of the browser.
+mod platform {
+    /// Returns the local storage of the browser, unavailable in the native builds (such as the
+    /// tests), which can't reach a browser.
+    fn local_storage() -> Option<web_sys::Storage> {
+        #[cfg(not(target_arch = "wasm32"))]
+        return None;
+        #[cfg(target_arch = "wasm32")]
+        web_sys::window()?.local_storage().ok()?
+    }
+
+    /// There are no files on the web.
+    pub fn path(_key: &str) -> Option<std::path::PathBuf> {
+        None
+    }
+
+    /// Reads the local storage entry of a key.
+    pub fn read(key: &str) -> Option<String> {
+        local_storage()?.get_item(key).ok()?
+    }
+
+    /// Writes the local storage entry of a key.
+    pub fn write(key: &str, data: &str) -> bool {
+        local_storage().is_some_and(|storage| storage.set_item(key, data).is_ok())
+    }
+
+    /// Removes the local storage entry of a key.
+    pub fn remove(key: &str) -> bool {
+        local_storage().is_some_and(|storage| storage.remove_item(key).is_ok())
+    }
+}
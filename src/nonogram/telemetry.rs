@@ -0,0 +1,709 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Nonogram structures for identifying the solved puzzles.
+use super::definitions::NonogramPuzzle;
+
+// Import the parameters of the evolutive search, recorded with each run.
+use super::evolutive::SolverConfig;
+
+// Import the profiles, each with its own database.
+use super::profile::{namespaced, Profile};
+
+// Import the persistence layer, locating the database file.
+use super::storage;
+
+// Import the formatting of the recording dates.
+use super::timer::format_date;
+
+// Import the SQLite bindings storing the runs.
+use rusqlite::{params, Connection, Row};
+
+// Import the serialization of the results matrix as JSON.
+use serde::Serialize;
+
+// Import the ordered grouping of the runs by configuration and puzzle.
+use std::collections::BTreeMap;
+
+// Import the path of the database, next to the rest of the stored data.
+use std::path::Path;
+
+/// The storage key of the database of solver runs.
+pub const RUNS_KEY: &str = "ngram-runs.sqlite3";
+
+/// A solver run recorded in the database.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SolverRun {
+    /// The identifier of the run in the database (ignored when recording it).
+    pub id: i64,
+    /// The hash identifying the puzzle by its constraints (see `NonogramPuzzle::constraint_hash`).
+    pub puzzle_hash: String,
+    /// The number of rows of the puzzle.
+    pub rows: usize,
+    /// The number of columns of the puzzle.
+    pub cols: usize,
+    /// The parameters of the search.
+    pub population_size: usize,
+    pub cross_probability: f64,
+    pub mutation_probability: f64,
+    pub tournament_size: usize,
+    pub slide_tries: usize,
+    pub max_iterations: usize,
+    /// The replacement mode, as displayed by `Replacement`.
+    pub replacement: String,
+    pub repair_rows: usize,
+    /// The dimension of the chromosomes, as displayed by `Representation`.
+    pub representation: String,
+    /// The lines scored, as displayed by `Fitness`.
+    pub fitness: String,
+    /// The weights of the crossover operators, as displayed by `CrossoverWeights`.
+    pub crossover: String,
+    /// The weights of the mutation operators, as displayed by `MutationWeights`.
+    pub mutation: String,
+    /// How the rows are repaired after crossover, as displayed by `Repair`.
+    pub repair: String,
+    pub local_search_rows: usize,
+    /// The self-adaptive control of the probabilities, as displayed by `Adaptation`, if enabled.
+    pub adaptation: Option<String>,
+    pub crowding: Option<usize>,
+    /// The criteria stopping the search besides `max_iterations` (see `StopCriteria`).
+    pub target_score: usize,
+    pub max_millis: Option<u64>,
+    pub stagnation: Option<usize>,
+    /// The seed of the random number generator.
+    pub seed: u64,
+    /// Whether the solver found a solution satisfying every constraint.
+    pub solved: bool,
+    /// The score of the best candidate found, with the fitness the search minimized.
+    pub score: usize,
+    /// The number of generations the solver ran.
+    pub iterations: usize,
+    /// The duration of the run in milliseconds.
+    pub duration_ms: u64,
+    /// When the run was recorded, in seconds since the Unix epoch.
+    pub recorded_at: i64,
+    /// The Nonogram file of the solution found, if it was autosaved with the run.
+    pub solution: Option<String>,
+}
+
+impl SolverRun {
+    /// Creates the record of a run, to be stored with `RunStore::record`.
+    ///
+    /// # Arguments
+    ///
+    /// * `puzzle` - The solved puzzle.
+    /// * `config` - The parameters of the search.
+    /// * `seed` - The seed of the random number generator.
+    /// * `result` - Whether it was solved, the best score and the number of generations.
+    /// * `duration_ms` - The duration of the run in milliseconds.
+    pub fn new(
+        puzzle: &NonogramPuzzle,
+        config: &SolverConfig,
+        seed: u64,
+        (solved, score, iterations): (bool, usize, usize),
+        duration_ms: u64,
+    ) -> Self {
+        let recorded_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() as i64);
+        Self {
+            id: 0,
+            puzzle_hash: puzzle.constraint_hash(),
+            rows: puzzle.rows,
+            cols: puzzle.cols,
+            population_size: config.population_size,
+            cross_probability: config.cross_probability,
+            mutation_probability: config.mutation_probability,
+            tournament_size: config.tournament_size,
+            slide_tries: config.slide_tries,
+            max_iterations: config.stop.max_iterations,
+            replacement: config.replacement.to_string(),
+            repair_rows: config.repair_rows,
+            representation: config.representation.to_string(),
+            fitness: config.fitness.to_string(),
+            crossover: config.crossover.to_string(),
+            mutation: config.mutation.to_string(),
+            repair: config.repair.to_string(),
+            local_search_rows: config.local_search_rows,
+            adaptation: config.adaptation.map(|adaptation| adaptation.to_string()),
+            crowding: config.crowding,
+            target_score: config.stop.target_score,
+            max_millis: config.stop.max_millis,
+            stagnation: config.stop.stagnation,
+            seed,
+            solved,
+            score,
+            iterations,
+            duration_ms,
+            recorded_at,
+            solution: None,
+        }
+    }
+
+    /// Formats when the run was recorded, as `YYYY-MM-DD HH:MM` in UTC.
+    pub fn recorded_date(&self) -> String {
+        format_date(self.recorded_at)
+    }
+
+    /// Describes the configuration of the solver of the run, leaving out its seed, so the runs
+    /// repeated with other seeds are grouped together.
+    ///
+    /// The optional parameters are only described when enabled.
+    pub fn algorithm(&self) -> String {
+        let mut algorithm = format!(
+            "n={} pc={} pm={} k={} s={} g={} {} r={} {} {} x=[{}] m=[{}] {} l={} t={}",
+            self.population_size,
+            self.cross_probability,
+            self.mutation_probability,
+            self.tournament_size,
+            self.slide_tries,
+            self.max_iterations,
+            self.replacement,
+            self.repair_rows,
+            self.representation,
+            self.fitness,
+            self.crossover,
+            self.mutation,
+            self.repair,
+            self.local_search_rows,
+            self.target_score
+        );
+        if let Some(adaptation) = &self.adaptation {
+            algorithm += &format!(" a=[{adaptation}]");
+        }
+        if let Some(crowding) = self.crowding {
+            algorithm += &format!(" c={crowding}");
+        }
+        if let Some(max_millis) = self.max_millis {
+            algorithm += &format!(" ms={max_millis}");
+        }
+        if let Some(stagnation) = self.stagnation {
+            algorithm += &format!(" st={stagnation}");
+        }
+        algorithm
+    }
+
+    /// Describes the puzzle of the run, by its hash and its size.
+    pub fn puzzle(&self) -> String {
+        format!("{} ({}×{})", self.puzzle_hash, self.rows, self.cols)
+    }
+
+    /// Reads a run from a row of the `runs` table, with the columns in declaration order.
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            puzzle_hash: row.get(1)?,
+            rows: row.get(2)?,
+            cols: row.get(3)?,
+            population_size: row.get(4)?,
+            cross_probability: row.get(5)?,
+            mutation_probability: row.get(6)?,
+            tournament_size: row.get(7)?,
+            slide_tries: row.get(8)?,
+            max_iterations: row.get(9)?,
+            replacement: row.get(10)?,
+            repair_rows: row.get(11)?,
+            seed: row.get::<_, i64>(12)? as u64,
+            solved: row.get(13)?,
+            score: row.get(14)?,
+            iterations: row.get(15)?,
+            duration_ms: row.get::<_, i64>(16)? as u64,
+            recorded_at: row.get(17)?,
+            solution: row.get(18)?,
+            representation: row.get(19)?,
+            fitness: row.get(20)?,
+            crossover: row.get(21)?,
+            mutation: row.get(22)?,
+            repair: row.get(23)?,
+            local_search_rows: row.get(24)?,
+            adaptation: row.get(25)?,
+            crowding: row.get(26)?,
+            target_score: row.get(27)?,
+            max_millis: row.get::<_, Option<i64>>(28)?.map(|millis| millis as u64),
+            stagnation: row.get(29)?,
+        })
+    }
+}
+
+/// The filter of the listed runs.
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct RunFilter {
+    /// Only the runs of the puzzle with this hash.
+    pub puzzle_hash: Option<String>,
+    /// Only the solved (or unsolved) runs.
+    pub solved: Option<bool>,
+}
+
+/// The results of the runs of a solver configuration on a puzzle.
+#[derive(Clone, Default, PartialEq, Debug, Serialize)]
+pub struct MatrixCell {
+    /// The number of runs.
+    pub runs: usize,
+    /// The number of runs that solved the puzzle.
+    pub solved: usize,
+    /// The lowest score of the best candidates found.
+    pub best_score: usize,
+    /// The mean number of generations of the runs.
+    pub mean_iterations: f64,
+    /// The mean duration of the runs in milliseconds.
+    pub mean_duration_ms: f64,
+}
+
+/// The matrix of the results of the recorded runs, comparing the solver configurations (its
+/// rows) on each puzzle (its columns), to paste it into issues and papers comparing changes of
+/// the solver.
+#[derive(Clone, Default, PartialEq, Debug, Serialize)]
+pub struct ResultsMatrix {
+    /// The solver configurations, sorted (see `SolverRun::algorithm`).
+    pub algorithms: Vec<String>,
+    /// The puzzles, sorted (see `SolverRun::puzzle`).
+    pub puzzles: Vec<String>,
+    /// The results of each configuration on each puzzle, `None` without runs.
+    pub cells: Vec<Vec<Option<MatrixCell>>>,
+}
+
+impl ResultsMatrix {
+    /// Groups runs by their solver configuration and their puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `runs` - The runs compared, such as the listed ones.
+    pub fn from_runs(runs: &[SolverRun]) -> Self {
+        let mut groups = BTreeMap::<(String, String), Vec<&SolverRun>>::new();
+        for run in runs {
+            groups
+                .entry((run.algorithm(), run.puzzle()))
+                .or_default()
+                .push(run);
+        }
+        let mut algorithms: Vec<String> = groups
+            .keys()
+            .map(|(algorithm, _)| algorithm.clone())
+            .collect();
+        algorithms.dedup();
+        let mut puzzles: Vec<String> = groups.keys().map(|(_, puzzle)| puzzle.clone()).collect();
+        puzzles.sort();
+        puzzles.dedup();
+        let cells = algorithms
+            .iter()
+            .map(|algorithm| {
+                puzzles
+                    .iter()
+                    .map(|puzzle| {
+                        let runs = groups.get(&(algorithm.clone(), puzzle.clone()))?;
+                        let count = runs.len() as f64;
+                        Some(MatrixCell {
+                            runs: runs.len(),
+                            solved: runs.iter().filter(|run| run.solved).count(),
+                            best_score: runs.iter().map(|run| run.score).min().unwrap_or(0),
+                            mean_iterations: runs
+                                .iter()
+                                .map(|run| run.iterations as f64)
+                                .sum::<f64>()
+                                / count,
+                            mean_duration_ms: runs
+                                .iter()
+                                .map(|run| run.duration_ms as f64)
+                                .sum::<f64>()
+                                / count,
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+        Self {
+            algorithms,
+            puzzles,
+            cells,
+        }
+    }
+
+    /// Formats the matrix as a Markdown table, with the solved runs, the mean generations and
+    /// the mean duration of each configuration on each puzzle.
+    pub fn to_markdown(&self) -> String {
+        let mut text = String::from("| Algorithm |");
+        for puzzle in &self.puzzles {
+            text += &format!(" {puzzle} |");
+        }
+        text += "\n|---|";
+        text += &"---|".repeat(self.puzzles.len());
+        text += "\n";
+        for (algorithm, cells) in self.algorithms.iter().zip(&self.cells) {
+            text += &format!("| `{algorithm}` |");
+            for cell in cells {
+                text += &match cell {
+                    Some(cell) if cell.solved > 0 => format!(
+                        " {}/{} solved, {:.1} gen, {:.0} ms |",
+                        cell.solved, cell.runs, cell.mean_iterations, cell.mean_duration_ms
+                    ),
+                    Some(cell) => format!(
+                        " 0/{} solved (best score {}), {:.1} gen, {:.0} ms |",
+                        cell.runs, cell.best_score, cell.mean_iterations, cell.mean_duration_ms
+                    ),
+                    None => String::from(" – |"),
+                };
+            }
+            text += "\n";
+        }
+        text
+    }
+
+    /// Serializes the matrix as JSON, for further analysis.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// The columns added to the `runs` table after its first version, in order, with their
+/// definitions. The runs recorded before them are read with the default parameters.
+const ADDED_COLUMNS: [(&str, &str); 12] = [
+    ("solution", "TEXT"),
+    ("representation", "TEXT NOT NULL DEFAULT 'auto'"),
+    ("fitness", "TEXT NOT NULL DEFAULT 'columns'"),
+    (
+        "crossover",
+        "TEXT NOT NULL DEFAULT 'uniform 1, two-point 1, column 0, block 0, segment 0'",
+    ),
+    (
+        "mutation",
+        "TEXT NOT NULL DEFAULT 'slide 1, reposition 0, reshuffle 0'",
+    ),
+    ("repair", "TEXT NOT NULL DEFAULT 'randomize'"),
+    ("local_search_rows", "INTEGER NOT NULL DEFAULT 0"),
+    ("adaptation", "TEXT"),
+    ("crowding", "INTEGER"),
+    ("target_score", "INTEGER NOT NULL DEFAULT 0"),
+    ("max_millis", "INTEGER"),
+    ("stagnation", "INTEGER"),
+];
+
+/// The local SQLite database of solver runs.
+pub struct RunStore {
+    /// The connection to the database.
+    connection: Connection,
+}
+
+impl RunStore {
+    /// Opens (or creates) the database in a file.
+    pub fn open(path: &Path) -> rusqlite::Result<Self> {
+        if let Some(dir) = path.parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        Self::with_connection(Connection::open(path)?)
+    }
+
+    /// Opens (or creates) the database of a profile, in the user configuration directory.
+    ///
+    /// # Returns
+    ///
+    /// The database, or `None` if it can't be opened on this platform.
+    pub fn open_default(profile: Option<&Profile>) -> Option<Self> {
+        let path = storage::path(&namespaced(profile, RUNS_KEY))?;
+        Self::open(&path).ok()
+    }
+
+    /// Opens a database in memory, discarded when closed.
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::with_connection(Connection::open_in_memory()?)
+    }
+
+    /// Creates the `runs` table if it doesn't exist, and adds the columns missing in older databases.
+    fn with_connection(connection: Connection) -> rusqlite::Result<Self> {
+        connection.execute_batch(
+            "CREATE TABLE IF NOT EXISTS runs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                puzzle_hash TEXT NOT NULL,
+                rows INTEGER NOT NULL,
+                cols INTEGER NOT NULL,
+                population_size INTEGER NOT NULL,
+                cross_probability REAL NOT NULL,
+                mutation_probability REAL NOT NULL,
+                tournament_size INTEGER NOT NULL,
+                slide_tries INTEGER NOT NULL,
+                max_iterations INTEGER NOT NULL,
+                replacement TEXT NOT NULL,
+                repair_rows INTEGER NOT NULL,
+                seed INTEGER NOT NULL,
+                solved INTEGER NOT NULL,
+                score INTEGER NOT NULL,
+                iterations INTEGER NOT NULL,
+                duration_ms INTEGER NOT NULL,
+                recorded_at INTEGER NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS runs_puzzle ON runs (puzzle_hash);",
+        )?;
+        for (column, definition) in ADDED_COLUMNS {
+            if connection
+                .prepare(&format!("SELECT {column} FROM runs LIMIT 0"))
+                .is_err()
+            {
+                connection.execute_batch(&format!(
+                    "ALTER TABLE runs ADD COLUMN {column} {definition};"
+                ))?;
+            }
+        }
+        Ok(Self { connection })
+    }
+
+    /// Records a run.
+    ///
+    /// # Returns
+    ///
+    /// The identifier of the recorded run.
+    pub fn record(&self, run: &SolverRun) -> rusqlite::Result<i64> {
+        self.connection.execute(
+            "INSERT INTO runs (puzzle_hash, rows, cols, population_size, cross_probability,
+                mutation_probability, tournament_size, slide_tries, max_iterations, replacement,
+                repair_rows, seed, solved, score, iterations, duration_ms, recorded_at, solution,
+                representation, fitness, crossover, mutation, repair, local_search_rows,
+                adaptation, crowding, target_score, max_millis, stagnation)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18,
+                ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29)",
+            params![
+                run.puzzle_hash,
+                run.rows,
+                run.cols,
+                run.population_size,
+                run.cross_probability,
+                run.mutation_probability,
+                run.tournament_size,
+                run.slide_tries,
+                run.max_iterations,
+                run.replacement,
+                run.repair_rows,
+                run.seed as i64,
+                run.solved,
+                run.score,
+                run.iterations,
+                run.duration_ms as i64,
+                run.recorded_at,
+                run.solution,
+                run.representation,
+                run.fitness,
+                run.crossover,
+                run.mutation,
+                run.repair,
+                run.local_search_rows,
+                run.adaptation,
+                run.crowding,
+                run.target_score,
+                run.max_millis.map(|millis| millis as i64),
+                run.stagnation,
+            ],
+        )?;
+        Ok(self.connection.last_insert_rowid())
+    }
+
+    /// Lists the runs matching a filter, the most recent first.
+    pub fn runs(&self, filter: &RunFilter) -> rusqlite::Result<Vec<SolverRun>> {
+        let mut statement = self.connection.prepare(
+            "SELECT * FROM runs
+            WHERE (?1 IS NULL OR puzzle_hash = ?1) AND (?2 IS NULL OR solved = ?2)
+            ORDER BY id DESC",
+        )?;
+        let runs = statement
+            .query_map(
+                params![filter.puzzle_hash, filter.solved],
+                SolverRun::from_row,
+            )?
+            .collect();
+        runs
+    }
+
+    /// Deletes a run.
+    pub fn delete(&self, id: i64) -> rusqlite::Result<()> {
+        self.connection
+            .execute("DELETE FROM runs WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    /// Deletes the runs matching a filter.
+    ///
+    /// # Returns
+    ///
+    /// The number of deleted runs.
+    pub fn delete_matching(&self, filter: &RunFilter) -> rusqlite::Result<usize> {
+        self.connection.execute(
+            "DELETE FROM runs WHERE (?1 IS NULL OR puzzle_hash = ?1) AND (?2 IS NULL OR solved = ?2)",
+            params![filter.puzzle_hash, filter.solved],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::evolutive::MutationWeights;
+    use crate::nonogram::puzzles::tree_nonogram_puzzle;
+
+    use super::*;
+
+    // Test recording, filtering and deleting runs.
+    #[test]
+    fn record_filter_delete_runs() {
+        let store = RunStore::open_in_memory().unwrap();
+        let puzzle = tree_nonogram_puzzle();
+        let config = SolverConfig::default();
+        let mut run = SolverRun::new(&puzzle, &config, 23, (true, 0, 12), 40);
+        run.solution = Some(String::from("{}"));
+        let solved = store.record(&run).unwrap();
+        store
+            .record(&SolverRun::new(&puzzle, &config, 11, (false, 3, 300), 900))
+            .unwrap();
+
+        let runs = store.runs(&RunFilter::default()).unwrap();
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[1].id, solved);
+        assert_eq!(runs[1].puzzle_hash, puzzle.constraint_hash());
+        assert_eq!(runs[1].replacement, "plus");
+        assert_eq!(runs[1].solution.as_deref(), Some("{}"));
+        assert_eq!(runs[0].solution, None);
+        let run = SolverRun {
+            recorded_at: 1_709_210_096,
+            ..runs[1].clone()
+        };
+        assert_eq!(run.recorded_date(), "2024-02-29 12:34");
+        let filter = RunFilter {
+            solved: Some(false),
+            ..RunFilter::default()
+        };
+        assert_eq!(store.runs(&filter).unwrap()[0].seed, 11);
+
+        store.delete(solved).unwrap();
+        assert_eq!(store.runs(&RunFilter::default()).unwrap().len(), 1);
+        let other = RunFilter {
+            puzzle_hash: Some(String::from("0")),
+            solved: None,
+        };
+        assert_eq!(store.delete_matching(&other).unwrap(), 0);
+        assert_eq!(store.delete_matching(&filter).unwrap(), 1);
+    }
+
+    // Test that the databases of older versions are migrated, reading their runs with the
+    // default parameters.
+    #[test]
+    fn migrate_older_runs() {
+        let connection = Connection::open_in_memory().unwrap();
+        connection
+            .execute_batch(
+                "CREATE TABLE runs (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    puzzle_hash TEXT NOT NULL,
+                    rows INTEGER NOT NULL,
+                    cols INTEGER NOT NULL,
+                    population_size INTEGER NOT NULL,
+                    cross_probability REAL NOT NULL,
+                    mutation_probability REAL NOT NULL,
+                    tournament_size INTEGER NOT NULL,
+                    slide_tries INTEGER NOT NULL,
+                    max_iterations INTEGER NOT NULL,
+                    replacement TEXT NOT NULL,
+                    repair_rows INTEGER NOT NULL,
+                    seed INTEGER NOT NULL,
+                    solved INTEGER NOT NULL,
+                    score INTEGER NOT NULL,
+                    iterations INTEGER NOT NULL,
+                    duration_ms INTEGER NOT NULL,
+                    recorded_at INTEGER NOT NULL
+                );
+                INSERT INTO runs VALUES (1, 'abc', 5, 5, 100, 0.5, 0.1, 3, 5, 500, 'plus', 0,
+                    7, 1, 0, 12, 40, 0);",
+            )
+            .unwrap();
+        let store = RunStore::with_connection(connection).unwrap();
+        let config = SolverConfig::default();
+        let mut run = SolverRun::new(&tree_nonogram_puzzle(), &config, 7, (true, 0, 12), 40);
+        run.id = store.record(&run).unwrap();
+
+        let runs = store.runs(&RunFilter::default()).unwrap();
+        assert_eq!(runs[0], run);
+        let older = &runs[1];
+        assert_eq!(older.solution, None);
+        assert_eq!(
+            (&older.representation, &older.fitness, &older.repair),
+            (&run.representation, &run.fitness, &run.repair)
+        );
+        assert_eq!(
+            (&older.crossover, &older.mutation),
+            (&run.crossover, &run.mutation)
+        );
+        assert_eq!(
+            (older.adaptation.as_ref(), older.crowding, older.max_millis),
+            (None, None, None)
+        );
+    }
+
+    // Test that the runs are grouped by configuration and puzzle in the results matrix, repeated
+    // seeds together, and exported as Markdown and JSON.
+    #[test]
+    fn results_matrix() {
+        let puzzle = tree_nonogram_puzzle();
+        let config = SolverConfig::default();
+        let other = SolverConfig {
+            population_size: 50,
+            ..SolverConfig::default()
+        };
+        let reshuffled = SolverConfig {
+            mutation: MutationWeights {
+                reshuffle: 1.0,
+                ..MutationWeights::default()
+            },
+            crowding: Some(3),
+            ..SolverConfig::default()
+        };
+        let runs = [
+            SolverRun::new(&puzzle, &config, 1, (true, 0, 10), 100),
+            SolverRun::new(&puzzle, &config, 2, (false, 4, 30), 300),
+            SolverRun::new(&puzzle, &other, 1, (false, 2, 30), 200),
+            SolverRun::new(&puzzle, &reshuffled, 1, (true, 0, 5), 50),
+        ];
+        assert!(runs[3]
+            .algorithm()
+            .contains("m=[slide 1, reposition 0, reshuffle 1]"));
+        assert!(runs[3].algorithm().ends_with(" c=3"));
+        let matrix = ResultsMatrix::from_runs(&runs[..3]);
+        assert_eq!(matrix.algorithms.len(), 2);
+        assert_eq!(ResultsMatrix::from_runs(&runs).algorithms.len(), 3);
+        assert_eq!(matrix.puzzles, [runs[0].puzzle()]);
+        let cell = matrix.cells[matrix
+            .algorithms
+            .iter()
+            .position(|a| *a == runs[0].algorithm())
+            .unwrap()][0]
+            .clone()
+            .unwrap();
+        assert_eq!((cell.runs, cell.solved, cell.best_score), (2, 1, 0));
+        assert_eq!((cell.mean_iterations, cell.mean_duration_ms), (20.0, 200.0));
+
+        let markdown = matrix.to_markdown();
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1], "|---|---|");
+        assert!(markdown.contains("1/2 solved, 20.0 gen, 200 ms"));
+        assert!(markdown.contains("0/1 solved (best score 2), 30.0 gen, 200 ms"));
+        let json: serde_json::Value = serde_json::from_str(&matrix.to_json()).unwrap();
+        assert_eq!(json["cells"].as_array().unwrap().len(), 2);
+        assert_eq!(
+            ResultsMatrix::from_runs(&[]).to_markdown(),
+            "| Algorithm |\n|---|\n"
+        );
+    }
+}
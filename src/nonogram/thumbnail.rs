@@ -0,0 +1,168 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! Downscaled thumbnails of the solution grids, updated incrementally while they're edited.
+//!
+//! Each pixel of a thumbnail covers a square block of cells, and takes the most frequent color of
+//! the block, so large grids can be previewed in a few hundred pixels. When a grid is edited, only
+//! the pixels covering the changed cells are rendered again (see `NonogramSolution::changed_area`).
+
+// Import the definitions of the solution grids and their areas
+use super::definitions::{Area, NonogramSolution, BACKGROUND};
+
+// Import the ranges of pixels rendered
+use std::ops::Range;
+
+/// A downscaled picture of a solution grid, made of color indexes.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Thumbnail {
+    /// The number of cells on each side of the block covered by a pixel.
+    pub scale: usize,
+    /// The rows of pixels, each pixel holding a color index of the palette.
+    pub pixels: Vec<Vec<usize>>,
+    /// The rows and columns of the grid rendered.
+    grid: (usize, usize),
+    /// The longest side of the thumbnail in pixels.
+    max_side: usize,
+}
+
+impl Thumbnail {
+    /// Renders the thumbnail of a solution grid.
+    ///
+    /// The grid is only downscaled if its longest side exceeds `max_side`, so the pixels of the
+    /// small grids are their cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `solution` - The solution grid to render.
+    /// * `max_side` - The longest side of the thumbnail in pixels.
+    ///
+    /// # Returns
+    ///
+    /// The thumbnail, whose pixels cover blocks of `scale` by `scale` cells.
+    pub fn new(solution: &NonogramSolution, max_side: usize) -> Self {
+        let grid = (solution.rows(), solution.cols());
+        let scale = grid.0.max(grid.1).div_ceil(max_side.max(1)).max(1);
+        let mut thumbnail = Self {
+            scale,
+            pixels: vec![vec![BACKGROUND; grid.1.div_ceil(scale)]; grid.0.div_ceil(scale)],
+            grid,
+            max_side,
+        };
+        thumbnail.render(
+            solution,
+            0..thumbnail.pixels.len(),
+            0..grid.1.div_ceil(scale),
+        );
+        thumbnail
+    }
+
+    /// Renders again the pixels covering an area of the grid, after it was edited.
+    ///
+    /// If the grid was resized, the whole thumbnail is rendered again.
+    ///
+    /// # Arguments
+    ///
+    /// * `solution` - The edited solution grid.
+    /// * `area` - The area of the grid containing every edited cell.
+    ///
+    /// # Returns
+    ///
+    /// The number of pixels rendered.
+    pub fn update(&mut self, solution: &NonogramSolution, area: Area) -> usize {
+        if (solution.rows(), solution.cols()) != self.grid {
+            *self = Self::new(solution, self.max_side);
+            return self.pixels.iter().map(Vec::len).sum();
+        }
+        let pixel =
+            |start: usize, end: usize| start.min(end) / self.scale..start.max(end) / self.scale + 1;
+        let rows = pixel(area.start.0, area.end.0);
+        let cols = pixel(area.start.1, area.end.1);
+        let rows = rows.start..rows.end.min(self.pixels.len());
+        let cols = cols.start..cols.end.min(self.grid.1.div_ceil(self.scale));
+        let rendered = rows.len() * cols.len();
+        self.render(solution, rows, cols);
+        rendered
+    }
+
+    /// Renders some pixels of the thumbnail from the solution grid.
+    fn render(&mut self, solution: &NonogramSolution, rows: Range<usize>, cols: Range<usize>) {
+        let scale = self.scale;
+        for i in rows {
+            for j in cols.clone() {
+                let mut counts: Vec<usize> = Vec::new();
+                for row_data in solution.solution_grid.iter().skip(i * scale).take(scale) {
+                    for &color in row_data.iter().skip(j * scale).take(scale) {
+                        if counts.len() <= color {
+                            counts.resize(color + 1, 0);
+                        }
+                        counts[color] += 1;
+                    }
+                }
+                // The painted colors win the ties against the background, keeping thin lines
+                self.pixels[i][j] = counts
+                    .iter()
+                    .enumerate()
+                    .max_by_key(|&(color, &count)| (count, color != BACKGROUND))
+                    .map_or(BACKGROUND, |(color, _)| color);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nonogram::puzzles::tree_nonogram_file;
+
+    // Test that each pixel takes the most frequent color of its block, and small grids aren't scaled.
+    #[test]
+    fn downscaled_pixels() {
+        let solution = NonogramSolution {
+            solution_grid: vec![
+                vec![1, 1, 0, 2, 2],
+                vec![1, 0, 0, 0, 2],
+                vec![0, 0, 0, 0, 0],
+            ],
+        };
+        let thumbnail = Thumbnail::new(&solution, 3);
+        assert_eq!(thumbnail.scale, 2);
+        assert_eq!(thumbnail.pixels, vec![vec![1, 0, 2], vec![0, 0, 0]]);
+        let thumbnail = Thumbnail::new(&solution, 10);
+        assert_eq!(thumbnail.scale, 1);
+        assert_eq!(thumbnail.pixels, solution.solution_grid);
+    }
+
+    // Test that updating the edited area matches rendering the whole grid again.
+    #[test]
+    fn updated_area_matches_new() {
+        let mut solution = tree_nonogram_file().solution;
+        let mut thumbnail = Thumbnail::new(&solution, 4);
+        let original = solution.clone();
+        solution.solution_grid[1][2] = 0;
+        solution.solution_grid[2][3] = 1;
+        let area = solution.changed_area(&original).unwrap();
+        let rendered = thumbnail.update(&solution, area);
+        assert!(rendered < thumbnail.pixels.iter().map(Vec::len).sum());
+        assert_eq!(thumbnail, Thumbnail::new(&solution, 4));
+    }
+}
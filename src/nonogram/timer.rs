@@ -0,0 +1,209 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Why the solve timer is paused.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PauseReason {
+    /// No input occurred for a while.
+    Idle,
+    /// The window lost the focus.
+    Unfocused,
+    /// The puzzle was completed.
+    Completed,
+}
+
+/// A timer measuring the time spent actively solving a puzzle.
+///
+/// The timer pauses when the window loses the focus or no input occurs for a while, and resumes
+/// with the next input, so the idle time isn't counted. Time is given as timestamps in
+/// milliseconds (such as `Date.now()`), so the timer works on every platform.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SolveTimer {
+    /// The active time accumulated before the current run, in milliseconds.
+    accumulated: f64,
+    /// The timestamp when the current run started, if the timer is running.
+    running_since: Option<f64>,
+    /// The timestamp of the last input.
+    last_input: f64,
+    /// Why the timer is paused, if it isn't running.
+    paused: Option<PauseReason>,
+}
+
+impl SolveTimer {
+    /// Creates a timer running from a timestamp.
+    pub fn start(now: f64) -> Self {
+        Self {
+            accumulated: 0.0,
+            running_since: Some(now),
+            last_input: now,
+            paused: None,
+        }
+    }
+
+    /// Returns why the timer is paused, or `None` if it's running.
+    pub fn paused(&self) -> Option<PauseReason> {
+        self.paused
+    }
+
+    /// Returns the active time in milliseconds.
+    pub fn elapsed(&self, now: f64) -> f64 {
+        self.accumulated
+            + self
+                .running_since
+                .map_or(0.0, |since| (now - since).max(0.0))
+    }
+
+    /// Pauses the timer, counting the active time until a timestamp.
+    fn pause(&mut self, until: f64, reason: PauseReason) {
+        if let Some(since) = self.running_since.take() {
+            self.accumulated += (until - since).max(0.0);
+        }
+        self.paused = Some(reason);
+    }
+
+    /// Resumes the timer from a timestamp, unless the puzzle is completed.
+    fn resume(&mut self, now: f64) {
+        if self.paused != Some(PauseReason::Completed) && self.running_since.is_none() {
+            self.running_since = Some(now);
+            self.paused = None;
+        }
+    }
+
+    /// Registers an input, resuming the timer if it was paused by inactivity.
+    pub fn input(&mut self, now: f64) {
+        self.last_input = now;
+        self.resume(now);
+    }
+
+    /// Pauses the timer because the window lost the focus.
+    pub fn blur(&mut self, now: f64) {
+        if self.paused.is_none() {
+            self.pause(now, PauseReason::Unfocused);
+        }
+    }
+
+    /// Resumes the timer because the window got the focus back.
+    pub fn focus(&mut self, now: f64) {
+        self.input(now);
+    }
+
+    /// Pauses the timer if no input occurred in the idle limit.
+    ///
+    /// The idle time isn't counted: the active time stops at the last input.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current timestamp.
+    /// * `idle_limit` - The time without inputs pausing the timer, in milliseconds.
+    pub fn tick(&mut self, now: f64, idle_limit: f64) {
+        if self.running_since.is_some() && now - self.last_input >= idle_limit {
+            let until = self.last_input.max(self.running_since.unwrap_or(now));
+            self.pause(until, PauseReason::Idle);
+        }
+    }
+
+    /// Stops the timer because the puzzle was completed.
+    pub fn complete(&mut self, now: f64) {
+        if self.running_since.is_some() {
+            self.pause(now, PauseReason::Completed);
+        } else {
+            self.paused = Some(PauseReason::Completed);
+        }
+    }
+}
+
+/// Formats a duration in milliseconds as `mm:ss` (or `h:mm:ss` past one hour).
+pub fn format_duration(milliseconds: f64) -> String {
+    let seconds = (milliseconds.max(0.0) / 1000.0) as u64;
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes:02}:{seconds:02}")
+    }
+}
+
+/// Formats a timestamp in seconds since the Unix epoch as `YYYY-MM-DD HH:MM` in UTC.
+pub fn format_date(timestamp: i64) -> String {
+    let (year, month, day, hour, minute) = civil_date(timestamp);
+    format!("{year:04}-{month:02}-{day:02} {hour:02}:{minute:02}")
+}
+
+/// Splits a timestamp in seconds since the Unix epoch into its date and time in UTC.
+///
+/// # Returns
+///
+/// The year, the month (from 1), the day (from 1), the hour and the minute.
+pub fn civil_date(timestamp: i64) -> (i64, i64, i64, i64, i64) {
+    let (days, seconds) = (timestamp.div_euclid(86_400), timestamp.rem_euclid(86_400));
+    // Converts the days since the epoch into a civil date (Howard Hinnant's algorithm).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day, seconds / 3600, seconds / 60 % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test that the idle time and the unfocused time aren't counted.
+    #[test]
+    fn pauses_are_not_counted() {
+        let mut timer = SolveTimer::start(0.0);
+        timer.input(10_000.0);
+        timer.tick(50_000.0, 60_000.0);
+        assert_eq!(timer.paused(), None);
+        timer.tick(70_000.0, 60_000.0);
+        assert_eq!(timer.paused(), Some(PauseReason::Idle));
+        assert_eq!(timer.elapsed(100_000.0), 10_000.0);
+
+        timer.input(100_000.0);
+        timer.blur(105_000.0);
+        assert_eq!(timer.paused(), Some(PauseReason::Unfocused));
+        timer.focus(200_000.0);
+        assert_eq!(timer.elapsed(201_000.0), 16_000.0);
+
+        timer.complete(202_000.0);
+        timer.input(300_000.0);
+        assert_eq!(timer.paused(), Some(PauseReason::Completed));
+        assert_eq!(timer.elapsed(400_000.0), 17_000.0);
+    }
+
+    // Test the formatting of durations.
+    #[test]
+    fn format_durations() {
+        assert_eq!(format_duration(65_400.0), "01:05");
+        assert_eq!(format_duration(3_725_000.0), "1:02:05");
+    }
+}
@@ -0,0 +1,170 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the semantic versions, to compare the running version against the latest release.
+use semver::Version;
+
+// Import the JSON values of the GitHub API responses.
+use serde_json::Value;
+
+// Import formatting traits for displaying the update errors.
+use std::fmt;
+
+// Import the timeout of the request.
+use std::time::Duration;
+
+/// The GitHub API endpoint of the latest release.
+pub const LATEST_RELEASE_URL: &str = "https://api.github.com/repos/artik02/ngram/releases/latest";
+
+/// The version of the running application.
+pub const CURRENT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// How long the update check waits for GitHub before giving up.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A release published on GitHub.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Release {
+    /// The version of the release, without the `v` prefix of the tag.
+    pub version: Version,
+    /// The page of the release, where the packages are downloaded.
+    pub url: String,
+}
+
+/// An error checking for updates.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum UpdateError {
+    /// The request to GitHub failed.
+    Request(String),
+    /// The response isn't a release with a semantic version.
+    InvalidResponse,
+}
+
+impl fmt::Display for UpdateError {
+    /// Formats the error as a message for the logs.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpdateError::Request(err) => write!(f, "the request failed: {err}"),
+            UpdateError::InvalidResponse => write!(f, "the response isn't a valid release"),
+        }
+    }
+}
+
+/// Parses a version, ignoring the `v` prefix of release tags.
+pub fn parse_version(version: &str) -> Option<Version> {
+    Version::parse(version.trim().trim_start_matches('v')).ok()
+}
+
+/// Parses a release from the response of the GitHub releases API.
+///
+/// # Arguments
+///
+/// * `json` - The release, as returned by the GitHub API (with `tag_name` and `html_url`).
+///
+/// # Returns
+///
+/// The release, or `None` if it's a draft, a prerelease or has no semantic version.
+pub fn parse_release(json: &str) -> Option<Release> {
+    let release = serde_json::from_str::<Value>(json).ok()?;
+    let flag = |key: &str| release[key].as_bool().unwrap_or(false);
+    if flag("draft") || flag("prerelease") {
+        return None;
+    }
+    Some(Release {
+        version: parse_version(release["tag_name"].as_str()?)?,
+        url: release["html_url"].as_str()?.to_string(),
+    })
+}
+
+/// Keeps a release only if it's newer than a version.
+///
+/// # Arguments
+///
+/// * `current` - The running version.
+/// * `release` - The latest release.
+///
+/// # Returns
+///
+/// The release if it's newer (an unparsable running version is always outdated).
+pub fn newer_release(current: &str, release: Release) -> Option<Release> {
+    match parse_version(current) {
+        Some(current) if release.version <= current => None,
+        _ => Some(release),
+    }
+}
+
+/// Asks GitHub for the latest release, blocking until it answers (or times out).
+///
+/// Nothing is downloaded nor installed, the caller just notifies the user.
+///
+/// # Arguments
+///
+/// * `current` - The running version, usually `CURRENT_VERSION`.
+///
+/// # Returns
+///
+/// The latest release if it's newer than the running version, or the error of the check.
+pub fn check_for_update(current: &str) -> Result<Option<Release>, UpdateError> {
+    let json = ureq::get(LATEST_RELEASE_URL)
+        .timeout(REQUEST_TIMEOUT)
+        .set("Accept", "application/vnd.github+json")
+        .set("User-Agent", &format!("ngram/{CURRENT_VERSION}"))
+        .call()
+        .map_err(|err| UpdateError::Request(err.to_string()))?
+        .into_string()
+        .map_err(|err| UpdateError::Request(err.to_string()))?;
+    let release = parse_release(&json).ok_or(UpdateError::InvalidResponse)?;
+    Ok(newer_release(current, release))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Test parsing the releases of the GitHub API and comparing their versions.
+    #[test]
+    fn parse_and_compare_releases() {
+        let json = r#"{
+            "tag_name": "v0.3.0",
+            "html_url": "https://github.com/artik02/ngram/releases/tag/v0.3.0",
+            "draft": false,
+            "prerelease": false
+        }"#;
+        let release = parse_release(json).unwrap();
+        assert_eq!(release.version, Version::new(0, 3, 0));
+        assert!(release.url.ends_with("/v0.3.0"));
+
+        assert_eq!(
+            newer_release("0.2.9", release.clone()),
+            Some(release.clone())
+        );
+        assert_eq!(newer_release("0.3.0", release.clone()), None);
+        assert_eq!(newer_release("1.0.0", release.clone()), None);
+
+        assert_eq!(
+            parse_release(&json.replace("\"prerelease\": false", "\"prerelease\": true")),
+            None
+        );
+        assert_eq!(parse_release(&json.replace("v0.3.0\"", "nightly\"")), None);
+        assert_eq!(parse_release("[]"), None);
+    }
+}
@@ -0,0 +1,689 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the solver core exposed to Python.
+use crate::nonogram::clues::{invalid_color_segment, MAX_CLUE_COLORS};
+use crate::nonogram::definitions::{Fitness, NonogramPuzzle, NonogramSegment, NonogramSolution};
+use crate::nonogram::evolutive::{
+    evolutive_search, Adaptation, CrossoverWeights, History, MutationWeights, Repair, Replacement,
+    Representation, SolverConfig, StopCriteria, CROSS_PROBABILITY, MAX_ITERATIONS,
+    MUTATION_PROBABILITY, POPULATION_SIZE, SEED, SLIDE_TRIES, TOURNAMENT_SIZE,
+};
+use crate::nonogram::logic::{line_slack, solve_logic};
+
+// Import the PyO3 prelude to define Python classes, functions and the module itself.
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+// Import the seeded random number generator used by the evolutive search.
+use rand::{rngs::StdRng, SeedableRng};
+
+/// A segment written from Python as a `(color, length)` tuple.
+type PySegment = (usize, usize);
+
+/// Converts Python constraints (lists of `(color, length)` tuples) into Nonogram segments.
+fn to_segments(constraints: Vec<Vec<PySegment>>) -> Vec<Vec<NonogramSegment>> {
+    constraints
+        .into_iter()
+        .map(|segments| {
+            segments
+                .into_iter()
+                .map(|(color, length)| NonogramSegment { color, length })
+                .collect()
+        })
+        .collect()
+}
+
+/// Converts Nonogram segments into Python constraints (lists of `(color, length)` tuples).
+fn from_segments(constraints: &[Vec<NonogramSegment>]) -> Vec<Vec<PySegment>> {
+    constraints
+        .iter()
+        .map(|segments| {
+            segments
+                .iter()
+                .map(|segment| (segment.color, segment.length))
+                .collect()
+        })
+        .collect()
+}
+
+/// Python wrapper of a `NonogramSolution`, a grid of color indices.
+#[pyclass(name = "NonogramSolution", module = "ngram")]
+#[derive(Clone)]
+pub struct PyNonogramSolution {
+    /// The wrapped Nonogram solution.
+    pub inner: NonogramSolution,
+}
+
+#[pymethods]
+impl PyNonogramSolution {
+    /// Creates a solution from a grid (list of rows) of color indices.
+    ///
+    /// Raises `ValueError` if the grid is empty, its rows have different lengths or a color isn't
+    /// below 64.
+    #[new]
+    fn new(grid: Vec<Vec<usize>>) -> PyResult<Self> {
+        if grid.iter().flatten().any(|&color| color >= MAX_CLUE_COLORS) {
+            return Err(PyValueError::new_err(format!(
+                "The colors of the grid must be below {MAX_CLUE_COLORS}"
+            )));
+        }
+        match grid.first() {
+            Some(row) if !row.is_empty() && grid.iter().all(|other| other.len() == row.len()) => {
+                Ok(Self {
+                    inner: NonogramSolution {
+                        solution_grid: grid,
+                    },
+                })
+            }
+            _ => Err(PyValueError::new_err(
+                "The grid must be a non-empty list of rows with the same length",
+            )),
+        }
+    }
+
+    /// The solution grid as a list of rows of color indices.
+    #[getter]
+    fn grid(&self) -> Vec<Vec<usize>> {
+        self.inner.solution_grid.clone()
+    }
+
+    /// The number of rows in the solution.
+    #[getter]
+    fn rows(&self) -> usize {
+        self.inner.rows()
+    }
+
+    /// The number of columns in the solution.
+    #[getter]
+    fn cols(&self) -> usize {
+        self.inner.cols()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "NonogramSolution(rows={}, cols={})",
+            self.inner.rows(),
+            self.inner.cols()
+        )
+    }
+}
+
+/// Python wrapper of a `NonogramPuzzle`, the dimensions and constraints of a puzzle.
+#[pyclass(name = "NonogramPuzzle", module = "ngram")]
+#[derive(Clone)]
+pub struct PyNonogramPuzzle {
+    /// The wrapped Nonogram puzzle.
+    pub inner: NonogramPuzzle,
+}
+
+#[pymethods]
+impl PyNonogramPuzzle {
+    /// Creates a puzzle from its row and column constraints, given as lists of `(color, length)`
+    /// tuples.
+    ///
+    /// Raises `ValueError` if there are no rows or no columns, if a segment isn't painted with a
+    /// color from 1 to 63, or if the segments of a line don't fit in it.
+    #[new]
+    fn new(
+        row_constraints: Vec<Vec<PySegment>>,
+        col_constraints: Vec<Vec<PySegment>>,
+    ) -> PyResult<Self> {
+        if row_constraints.is_empty() || col_constraints.is_empty() {
+            return Err(PyValueError::new_err(
+                "The puzzle must have at least one row and one column",
+            ));
+        }
+        let (rows, cols) = (row_constraints.len(), col_constraints.len());
+        let (row_constraints, col_constraints) =
+            (to_segments(row_constraints), to_segments(col_constraints));
+        for (dimension, lines, length) in [
+            ("row", &row_constraints, cols),
+            ("column", &col_constraints, rows),
+        ] {
+            if let Some((line, segment)) = lines.iter().enumerate().find_map(|(line, segments)| {
+                invalid_color_segment(segments).map(|segment| (line, segment))
+            }) {
+                return Err(PyValueError::new_err(format!(
+                    "The segment {}:{} of {dimension} {line} isn't painted with a color from 1 to {}",
+                    segment.length,
+                    segment.color,
+                    MAX_CLUE_COLORS - 1
+                )));
+            }
+            if let Some(line) = lines
+                .iter()
+                .position(|segments| line_slack(segments, length).is_none())
+            {
+                return Err(PyValueError::new_err(format!(
+                    "The segments of {dimension} {line} don't fit in its {length} cells"
+                )));
+            }
+        }
+        Ok(Self {
+            inner: NonogramPuzzle {
+                rows,
+                cols,
+                row_constraints,
+                col_constraints,
+            },
+        })
+    }
+
+    /// Creates the puzzle whose constraints are satisfied by the given solution.
+    #[staticmethod]
+    fn from_solution(solution: &PyNonogramSolution) -> Self {
+        Self {
+            inner: NonogramPuzzle::from_solution(&solution.inner),
+        }
+    }
+
+    /// The number of rows in the puzzle.
+    #[getter]
+    fn rows(&self) -> usize {
+        self.inner.rows
+    }
+
+    /// The number of columns in the puzzle.
+    #[getter]
+    fn cols(&self) -> usize {
+        self.inner.cols
+    }
+
+    /// The row constraints as lists of `(color, length)` tuples.
+    #[getter]
+    fn row_constraints(&self) -> Vec<Vec<PySegment>> {
+        from_segments(&self.inner.row_constraints)
+    }
+
+    /// The column constraints as lists of `(color, length)` tuples.
+    #[getter]
+    fn col_constraints(&self) -> Vec<Vec<PySegment>> {
+        from_segments(&self.inner.col_constraints)
+    }
+
+    /// Scores a candidate solution, where `0` means the constraints are satisfied.
+    fn score(&self, candidate: &PyNonogramSolution) -> usize {
+        self.inner.score(&candidate.inner)
+    }
+
+    /// Solves the puzzle by exhaustive search, returning `None` if it has no solution.
+    fn solve_exact(&self) -> Option<PyNonogramSolution> {
+        self.inner
+            .solve_exact()
+            .map(|inner| PyNonogramSolution { inner })
+    }
+
+    /// Solves the puzzle by line logic alone, returning the deduced solution (with the background
+    /// in the undeduced cells) and the number of undeduced cells, or `None` if it has no solution.
+    fn solve_logic(&self) -> Option<(PyNonogramSolution, usize)> {
+        solve_logic(&self.inner).map(|logic| {
+            (
+                PyNonogramSolution {
+                    inner: logic.solution,
+                },
+                logic.unknown,
+            )
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "NonogramPuzzle(rows={}, cols={})",
+            self.inner.rows, self.inner.cols
+        )
+    }
+}
+
+/// Python wrapper of the `History` of an evolutive search.
+#[pyclass(name = "History", module = "ngram")]
+#[derive(Clone)]
+pub struct PyHistory {
+    /// The wrapped evolutive search history.
+    pub inner: History,
+}
+
+#[pymethods]
+impl PyHistory {
+    /// The number of generations completed.
+    #[getter]
+    fn iterations(&self) -> usize {
+        self.inner.iterations
+    }
+
+    /// The best score of each generation.
+    #[getter]
+    fn best(&self) -> Vec<usize> {
+        self.inner.best.clone()
+    }
+
+    /// The median score of each generation.
+    #[getter]
+    fn median(&self) -> Vec<f64> {
+        self.inner.median.clone()
+    }
+
+    /// The worst score of each generation.
+    #[getter]
+    fn worst(&self) -> Vec<usize> {
+        self.inner.worst.clone()
+    }
+
+    /// The mean Hamming distance between the individuals of each generation.
+    #[getter]
+    fn diversity(&self) -> Vec<f64> {
+        self.inner.diversity.clone()
+    }
+
+    /// The trade-off candidates of the last generation in the `"nsga2"` replacement, as tuples of
+    /// the solution, its row violations and its column violations.
+    #[getter]
+    fn pareto_front(&self) -> Vec<(PyNonogramSolution, usize, usize)> {
+        self.inner
+            .pareto_front
+            .iter()
+            .map(|candidate| {
+                (
+                    PyNonogramSolution {
+                        inner: candidate.solution.clone(),
+                    },
+                    candidate.row_violations,
+                    candidate.column_violations,
+                )
+            })
+            .collect()
+    }
+
+    /// The final population of the search, best first.
+    #[getter]
+    fn population(&self) -> Vec<PyNonogramSolution> {
+        self.inner
+            .population
+            .iter()
+            .map(|solution| PyNonogramSolution {
+                inner: solution.clone(),
+            })
+            .collect()
+    }
+
+    /// Whether the search found a solution satisfying every constraint.
+    #[getter]
+    fn solved(&self) -> bool {
+        self.inner.winner.is_ok()
+    }
+
+    /// The winner of the search, or its best approach if it wasn't solved.
+    #[getter]
+    fn solution(&self) -> PyNonogramSolution {
+        let (Ok(solution) | Err(solution)) = &self.inner.winner;
+        PyNonogramSolution {
+            inner: solution.clone(),
+        }
+    }
+
+    /// The replacement mode of the search, such as `generational (elitism 5)`.
+    #[getter]
+    fn replacement(&self) -> String {
+        self.inner.replacement.to_string()
+    }
+
+    /// The dimension whose lines made up the chromosomes, `rows` or `columns`.
+    #[getter]
+    fn representation(&self) -> String {
+        self.inner.representation.to_string()
+    }
+
+    /// The number of rows repaired after crossover at each bred generation.
+    #[getter]
+    fn repaired(&self) -> Vec<usize> {
+        self.inner.repaired.clone()
+    }
+
+    /// The number of rows refined by the local search at each bred generation.
+    #[getter]
+    fn refined(&self) -> Vec<usize> {
+        self.inner.refined.clone()
+    }
+
+    /// The crossover probability used at each bred generation, adapted if the search is adaptive.
+    #[getter]
+    fn cross_rates(&self) -> Vec<f64> {
+        self.inner.cross_rates.clone()
+    }
+
+    /// The mutation probability used at each bred generation, adapted if the search is adaptive.
+    #[getter]
+    fn mutation_rates(&self) -> Vec<f64> {
+        self.inner.mutation_rates.clone()
+    }
+
+    /// Why the search stopped, such as `stagnation`, or `None` if it didn't finish.
+    #[getter]
+    fn stop_reason(&self) -> Option<String> {
+        self.inner.stop_reason.map(|reason| reason.to_string())
+    }
+
+    /// Returns the per-generation scores as a dictionary of columns, ready for `pandas.DataFrame`.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("iteration", (0..self.inner.iterations).collect::<Vec<_>>())?;
+        dict.set_item("best", self.inner.best.clone())?;
+        dict.set_item("median", self.inner.median.clone())?;
+        dict.set_item("worst", self.inner.worst.clone())?;
+        dict.set_item("diversity", self.inner.diversity.clone())?;
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "History(iterations={}, solved={})",
+            self.inner.iterations,
+            self.inner.winner.is_ok()
+        )
+    }
+}
+
+/// Runs the evolutive search on a puzzle with the given genetic algorithm parameters.
+///
+/// The defaults are the same parameters used by the application. The GIL is released while the
+/// search runs, so several searches can be run from Python threads.
+///
+/// The `replacement` is `"plus"`, `"generational"` (keeping the `elitism` best parents),
+/// `"steady_state"` (replacing the `replaced` worst parents each generation) or `"nsga2"`
+/// (minimizing the violations of the rows and of the columns as two objectives, with the
+/// trade-offs in `pareto_front`, while also mutating single cells and scoring both dimensions
+/// whatever the `fitness`). With a `crowding` factor, each child of the `"plus"`
+/// replacement replaces the most similar of that many individuals drawn at random if it's not
+/// worse, keeping the population diverse (`0` disables the niching).
+///
+/// The chromosomes are made of `"rows"` or `"columns"`, or with `"auto"` of the dimension whose
+/// lines have the fewest arrangements. The `fitness` scores the `"columns"` only, or both
+/// dimensions with `"symmetric"`.
+///
+/// The `crossover_weights` dictionary sets the relative weights of the `"uniform"`,
+/// `"two_point"`, `"column"`, `"block"` and `"segment"` crossovers, and the `mutation_weights`
+/// dictionary those of the `"slide"`, `"reposition"` and `"reshuffle"` mutations, the missing ones
+/// keeping their defaults. With `adaptive`, the probabilities rise while the best score stagnates
+/// and decay once it improves, recorded in `cross_rates` and `mutation_rates`.
+///
+/// Up to `repair_rows` rows of each child are repaired after crossover (`0` disables the repair),
+/// either re-randomized with `"randomize"` or with their segments shifted towards the columns with
+/// `"shift"` as the `repair`. Up to `local_search_rows` rows are re-sampled after mutation to
+/// agree with the line solver (`0` disables the local search).
+///
+/// Besides `max_iterations`, the search stops once its best score reaches `target_score`, after
+/// `max_millis` milliseconds, or after `stagnation` generations without improving, recorded in
+/// `stop_reason`.
+#[pyfunction(name = "evolutive_search")]
+#[pyo3(signature = (
+    puzzle,
+    population_size = POPULATION_SIZE,
+    cross_probability = CROSS_PROBABILITY,
+    mutation_probability = MUTATION_PROBABILITY,
+    tournament_size = TOURNAMENT_SIZE,
+    slide_tries = SLIDE_TRIES,
+    max_iterations = MAX_ITERATIONS,
+    target_score = 0,
+    max_millis = None,
+    stagnation = None,
+    seed = SEED,
+    replacement = "plus",
+    elitism = 1,
+    replaced = 2,
+    representation = "auto",
+    fitness = "columns",
+    crossover_weights = None,
+    mutation_weights = None,
+    repair_rows = 0,
+    repair = "randomize",
+    local_search_rows = 0,
+    adaptive = false,
+    crowding = 0,
+))]
+#[allow(clippy::too_many_arguments)]
+fn py_evolutive_search(
+    py: Python<'_>,
+    puzzle: &PyNonogramPuzzle,
+    population_size: usize,
+    cross_probability: f64,
+    mutation_probability: f64,
+    tournament_size: usize,
+    slide_tries: usize,
+    max_iterations: usize,
+    target_score: usize,
+    max_millis: Option<u64>,
+    stagnation: Option<usize>,
+    seed: u64,
+    replacement: &str,
+    elitism: usize,
+    replaced: usize,
+    representation: &str,
+    fitness: &str,
+    crossover_weights: Option<&Bound<'_, PyDict>>,
+    mutation_weights: Option<&Bound<'_, PyDict>>,
+    repair_rows: usize,
+    repair: &str,
+    local_search_rows: usize,
+    adaptive: bool,
+    crowding: usize,
+) -> PyResult<PyHistory> {
+    if population_size < 2 || tournament_size == 0 {
+        return Err(PyValueError::new_err(
+            "The population size must be at least 2 and the tournament size at least 1",
+        ));
+    }
+    if !(0.0..=1.0).contains(&cross_probability) || !(0.0..=1.0).contains(&mutation_probability) {
+        return Err(PyValueError::new_err(
+            "The probabilities must be between 0 and 1",
+        ));
+    }
+    let replacement = match replacement {
+        "plus" => Replacement::Plus,
+        "generational" => Replacement::Generational { elitism },
+        "steady_state" => Replacement::SteadyState { replaced },
+        "nsga2" => Replacement::Pareto,
+        _ => {
+            return Err(PyValueError::new_err(
+                "The replacement must be 'plus', 'generational', 'steady_state' or 'nsga2'",
+            ))
+        }
+    };
+    let representation = match representation {
+        "auto" => Representation::Auto,
+        "rows" => Representation::Rows,
+        "columns" => Representation::Columns,
+        _ => {
+            return Err(PyValueError::new_err(
+                "The representation must be 'auto', 'rows' or 'columns'",
+            ))
+        }
+    };
+    let fitness = match fitness {
+        "columns" => Fitness::Columns,
+        "symmetric" => Fitness::Symmetric,
+        _ => {
+            return Err(PyValueError::new_err(
+                "The fitness must be 'columns' or 'symmetric'",
+            ))
+        }
+    };
+    let repair = match repair {
+        "randomize" => Repair::Randomize,
+        "shift" => Repair::Shift,
+        _ => {
+            return Err(PyValueError::new_err(
+                "The repair must be 'randomize' or 'shift'",
+            ))
+        }
+    };
+    let mut crossover = CrossoverWeights::default();
+    for (name, weight) in crossover_weights
+        .into_iter()
+        .flat_map(|weights| weights.iter())
+    {
+        let weight: f64 = weight.extract()?;
+        if weight < 0.0 {
+            return Err(PyValueError::new_err(
+                "The crossover weights can't be negative",
+            ));
+        }
+        match name.extract::<String>()?.as_str() {
+            "uniform" => crossover.uniform = weight,
+            "two_point" => crossover.two_point = weight,
+            "column" => crossover.column = weight,
+            "block" => crossover.block = weight,
+            "segment" => crossover.segment = weight,
+            _ => {
+                return Err(PyValueError::new_err(
+                    "The crossovers are 'uniform', 'two_point', 'column', 'block' and 'segment'",
+                ))
+            }
+        }
+    }
+    let mut mutation = MutationWeights::default();
+    for (name, weight) in mutation_weights
+        .into_iter()
+        .flat_map(|weights| weights.iter())
+    {
+        let weight: f64 = weight.extract()?;
+        if weight < 0.0 {
+            return Err(PyValueError::new_err(
+                "The mutation weights can't be negative",
+            ));
+        }
+        match name.extract::<String>()?.as_str() {
+            "slide" => mutation.slide = weight,
+            "reposition" => mutation.reposition = weight,
+            "reshuffle" => mutation.reshuffle = weight,
+            _ => {
+                return Err(PyValueError::new_err(
+                    "The mutations are 'slide', 'reposition' and 'reshuffle'",
+                ))
+            }
+        }
+    }
+    let config = SolverConfig {
+        population_size,
+        cross_probability,
+        mutation_probability,
+        tournament_size,
+        slide_tries,
+        stop: StopCriteria {
+            max_iterations,
+            target_score,
+            max_millis,
+            stagnation,
+        },
+        replacement,
+        representation,
+        fitness,
+        crossover,
+        mutation,
+        repair_rows,
+        repair,
+        local_search_rows,
+        adaptation: adaptive.then(Adaptation::default),
+        crowding: (crowding > 0).then_some(crowding),
+    };
+    let puzzle = puzzle.inner.clone();
+    let history = py.allow_threads(move || {
+        let mut rng = StdRng::seed_from_u64(seed);
+        evolutive_search(&puzzle, &config, &mut rng)
+    });
+    Ok(PyHistory { inner: history })
+}
+
+/// The `ngram` Python module.
+#[pymodule]
+fn ngram(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyNonogramSolution>()?;
+    module.add_class::<PyNonogramPuzzle>()?;
+    module.add_class::<PyHistory>()?;
+    module.add_function(wrap_pyfunction!(py_evolutive_search, module)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::nonogram::puzzles::tree_nonogram_puzzle;
+
+    use super::*;
+
+    // Test that the constructor rejects the puzzles whose clues don't fit, instead of panicking
+    // in the solvers.
+    #[test]
+    fn puzzle_validation() {
+        let puzzle = tree_nonogram_puzzle();
+        let rows = from_segments(&puzzle.row_constraints);
+        let cols = from_segments(&puzzle.col_constraints);
+        assert!(PyNonogramPuzzle::new(rows.clone(), cols.clone()).is_ok());
+        assert!(PyNonogramPuzzle::new(Vec::new(), cols.clone()).is_err());
+        let mut long_rows = rows.clone();
+        long_rows[0] = vec![(1, puzzle.cols + 1)];
+        assert!(PyNonogramPuzzle::new(long_rows, cols.clone()).is_err());
+        let mut crowded_cols = cols.clone();
+        crowded_cols[0] = vec![(1, 1); puzzle.rows];
+        assert!(PyNonogramPuzzle::new(rows.clone(), crowded_cols).is_err());
+        for color in [0, MAX_CLUE_COLORS] {
+            let mut colored_rows = rows.clone();
+            colored_rows[0] = vec![(color, 1)];
+            assert!(PyNonogramPuzzle::new(colored_rows, cols.clone()).is_err());
+        }
+        assert!(PyNonogramSolution::new(vec![vec![MAX_CLUE_COLORS - 1]]).is_ok());
+        assert!(PyNonogramSolution::new(vec![vec![MAX_CLUE_COLORS]]).is_err());
+    }
+
+    // Test that the exact solve finds the solutions that need guessing, as in the wasm bindings.
+    #[test]
+    fn exact_solve() {
+        let solution = PyNonogramSolution::new(vec![vec![1, 0], vec![0, 1]]).unwrap();
+        let puzzle = PyNonogramPuzzle::from_solution(&solution);
+        let found = puzzle.solve_exact().unwrap();
+        assert_eq!(puzzle.score(&found), 0);
+        let impossible =
+            PyNonogramPuzzle::new(vec![vec![(1, 2)], vec![]], vec![vec![(1, 1)], vec![]]).unwrap();
+        assert!(impossible.solve_exact().is_none());
+    }
+
+    // Test that the logic solve reports the undeduced cells of the puzzle.
+    #[test]
+    fn logic_solve() {
+        let puzzle = PyNonogramPuzzle {
+            inner: tree_nonogram_puzzle(),
+        };
+        let (solution, unknown) = puzzle.solve_logic().unwrap();
+        assert_eq!(unknown, 0);
+        assert_eq!(puzzle.score(&solution), 0);
+        let ambiguous = PyNonogramPuzzle::new(
+            vec![vec![(1, 1)], vec![(1, 1)]],
+            vec![vec![(1, 1)], vec![(1, 1)]],
+        )
+        .unwrap();
+        let (_, unknown) = ambiguous.solve_logic().unwrap();
+        assert_eq!(unknown, 4);
+    }
+}
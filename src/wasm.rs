@@ -0,0 +1,707 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+// Import the solver core exposed to JavaScript.
+use crate::nonogram::clues::{invalid_color_segment, MAX_CLUE_COLORS};
+use crate::nonogram::definitions::{Fitness, NonogramPuzzle, NonogramSegment, NonogramSolution};
+use crate::nonogram::evolutive::{
+    evolutive_search, Adaptation, CrossoverWeights, History, MutationWeights, Repair, Replacement,
+    Representation, SolverConfig, StopCriteria, CROSS_PROBABILITY, MAX_ITERATIONS,
+    MUTATION_PROBABILITY, POPULATION_SIZE, SEED, SLIDE_TRIES, TOURNAMENT_SIZE,
+};
+use crate::nonogram::logic::{line_slack, solve_logic, LogicSolution};
+
+// Import JavaScript arrays and the bindings generator.
+use js_sys::Array;
+use wasm_bindgen::prelude::*;
+
+// Import the seeded random number generator used by the evolutive search.
+use rand::{rngs::StdRng, SeedableRng};
+
+/// Converts JavaScript constraints (arrays of `[color, length]` pairs) into Nonogram segments.
+fn to_segments(constraints: &Array) -> Result<Vec<Vec<NonogramSegment>>, JsError> {
+    constraints
+        .iter()
+        .map(|segments| {
+            Array::from(&segments)
+                .iter()
+                .map(|segment| {
+                    let pair = Array::from(&segment);
+                    match (pair.get(0).as_f64(), pair.get(1).as_f64()) {
+                        (Some(color), Some(length)) if color >= 0.0 && length >= 1.0 => {
+                            Ok(NonogramSegment {
+                                color: color as usize,
+                                length: length as usize,
+                            })
+                        }
+                        _ => Err(JsError::new(
+                            "Each segment must be a [color, length] pair of positive numbers",
+                        )),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Converts Nonogram segments into JavaScript constraints (arrays of `[color, length]` pairs).
+fn from_segments(constraints: &[Vec<NonogramSegment>]) -> Array {
+    constraints
+        .iter()
+        .map(|segments| {
+            segments
+                .iter()
+                .map(|segment| {
+                    Array::of2(
+                        &JsValue::from(segment.color as u32),
+                        &JsValue::from(segment.length as u32),
+                    )
+                })
+                .collect::<Array>()
+        })
+        .collect()
+}
+
+/// JavaScript wrapper of a `NonogramSolution`.
+///
+/// The grid is exchanged as a row-major `Uint32Array` of color indices.
+#[wasm_bindgen(js_name = NonogramSolution)]
+pub struct WasmNonogramSolution {
+    inner: NonogramSolution,
+}
+
+#[wasm_bindgen(js_class = NonogramSolution)]
+impl WasmNonogramSolution {
+    /// Creates a solution with the given dimensions from a row-major array of color indices.
+    ///
+    /// Throws if the cells don't fill the grid or if a color isn't below 64.
+    #[wasm_bindgen(constructor)]
+    pub fn new(rows: usize, cols: usize, cells: &[u32]) -> Result<WasmNonogramSolution, JsError> {
+        if rows == 0 || cols == 0 || cells.len() != rows * cols {
+            return Err(JsError::new(
+                "The cells must be a non-empty row-major grid of rows * cols colors",
+            ));
+        }
+        if cells.iter().any(|&color| color as usize >= MAX_CLUE_COLORS) {
+            return Err(JsError::new(&format!(
+                "The colors of the grid must be below {MAX_CLUE_COLORS}"
+            )));
+        }
+        let solution_grid = cells
+            .chunks(cols)
+            .map(|row| row.iter().map(|&color| color as usize).collect())
+            .collect();
+        Ok(Self {
+            inner: NonogramSolution { solution_grid },
+        })
+    }
+
+    /// The number of rows in the solution.
+    #[wasm_bindgen(getter)]
+    pub fn rows(&self) -> usize {
+        self.inner.rows()
+    }
+
+    /// The number of columns in the solution.
+    #[wasm_bindgen(getter)]
+    pub fn cols(&self) -> usize {
+        self.inner.cols()
+    }
+
+    /// The solution grid as a row-major `Uint32Array` of color indices.
+    #[wasm_bindgen(getter)]
+    pub fn cells(&self) -> Vec<u32> {
+        self.inner
+            .solution_grid
+            .iter()
+            .flatten()
+            .map(|&color| color as u32)
+            .collect()
+    }
+
+    /// Formats the solution as a grid of space-separated color indices.
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
+/// JavaScript wrapper of a `NonogramPuzzle`.
+///
+/// The constraints are exchanged as arrays (one per line) of `[color, length]` pairs.
+#[wasm_bindgen(js_name = NonogramPuzzle)]
+pub struct WasmNonogramPuzzle {
+    inner: NonogramPuzzle,
+}
+
+#[wasm_bindgen(js_class = NonogramPuzzle)]
+impl WasmNonogramPuzzle {
+    /// Creates a puzzle from its row and column constraints.
+    ///
+    /// Throws if there are no rows or no columns, if a segment isn't painted with a color from 1
+    /// to 63, or if the segments of a line don't fit in it.
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        row_constraints: &Array,
+        col_constraints: &Array,
+    ) -> Result<WasmNonogramPuzzle, JsError> {
+        let row_constraints = to_segments(row_constraints)?;
+        let col_constraints = to_segments(col_constraints)?;
+        if row_constraints.is_empty() || col_constraints.is_empty() {
+            return Err(JsError::new(
+                "The puzzle must have at least one row and one column",
+            ));
+        }
+        let (rows, cols) = (row_constraints.len(), col_constraints.len());
+        for (dimension, lines, length) in [
+            ("row", &row_constraints, cols),
+            ("column", &col_constraints, rows),
+        ] {
+            if let Some((line, segment)) = lines.iter().enumerate().find_map(|(line, segments)| {
+                invalid_color_segment(segments).map(|segment| (line, segment))
+            }) {
+                return Err(JsError::new(&format!(
+                    "The segment {}:{} of {dimension} {line} isn't painted with a color from 1 to {}",
+                    segment.length,
+                    segment.color,
+                    MAX_CLUE_COLORS - 1
+                )));
+            }
+            if let Some(line) = lines
+                .iter()
+                .position(|segments| line_slack(segments, length).is_none())
+            {
+                return Err(JsError::new(&format!(
+                    "The segments of {dimension} {line} don't fit in its {length} cells"
+                )));
+            }
+        }
+        Ok(Self {
+            inner: NonogramPuzzle {
+                rows,
+                cols,
+                row_constraints,
+                col_constraints,
+            },
+        })
+    }
+
+    /// Creates the puzzle whose constraints are satisfied by the given solution.
+    #[wasm_bindgen(js_name = fromSolution)]
+    pub fn from_solution(solution: &WasmNonogramSolution) -> WasmNonogramPuzzle {
+        Self {
+            inner: NonogramPuzzle::from_solution(&solution.inner),
+        }
+    }
+
+    /// The number of rows in the puzzle.
+    #[wasm_bindgen(getter)]
+    pub fn rows(&self) -> usize {
+        self.inner.rows
+    }
+
+    /// The number of columns in the puzzle.
+    #[wasm_bindgen(getter)]
+    pub fn cols(&self) -> usize {
+        self.inner.cols
+    }
+
+    /// The row constraints as arrays of `[color, length]` pairs.
+    #[wasm_bindgen(getter, js_name = rowConstraints)]
+    pub fn row_constraints(&self) -> Array {
+        from_segments(&self.inner.row_constraints)
+    }
+
+    /// The column constraints as arrays of `[color, length]` pairs.
+    #[wasm_bindgen(getter, js_name = colConstraints)]
+    pub fn col_constraints(&self) -> Array {
+        from_segments(&self.inner.col_constraints)
+    }
+
+    /// Scores a candidate solution, where `0` means the constraints are satisfied.
+    pub fn score(&self, candidate: &WasmNonogramSolution) -> usize {
+        self.inner.score(&candidate.inner)
+    }
+
+    /// Solves the puzzle by exhaustive search, returning `undefined` if it has no solution.
+    #[wasm_bindgen(js_name = solveExact)]
+    pub fn solve_exact(&self) -> Option<WasmNonogramSolution> {
+        self.inner
+            .solve_exact()
+            .map(|inner| WasmNonogramSolution { inner })
+    }
+
+    /// Solves the puzzle by line logic alone, returning `undefined` if it has no solution.
+    #[wasm_bindgen(js_name = solveLogic)]
+    pub fn solve_logic(&self) -> Option<WasmLogicSolution> {
+        solve_logic(&self.inner).map(|inner| WasmLogicSolution { inner })
+    }
+}
+
+/// JavaScript wrapper of a `LogicSolution`, the cells deduced by line logic.
+#[wasm_bindgen(js_name = LogicSolution)]
+pub struct WasmLogicSolution {
+    inner: LogicSolution,
+}
+
+#[wasm_bindgen(js_class = LogicSolution)]
+impl WasmLogicSolution {
+    /// The deduced grid, with the background in the undeduced cells.
+    #[wasm_bindgen(getter)]
+    pub fn solution(&self) -> WasmNonogramSolution {
+        WasmNonogramSolution {
+            inner: self.inner.solution.clone(),
+        }
+    }
+
+    /// The number of cells that couldn't be deduced.
+    #[wasm_bindgen(getter)]
+    pub fn unknown(&self) -> usize {
+        self.inner.unknown
+    }
+
+    /// Whether every cell was deduced, which proves the puzzle has a single solution.
+    #[wasm_bindgen(getter)]
+    pub fn complete(&self) -> bool {
+        self.inner.is_complete()
+    }
+}
+
+/// How the next generation of the evolutive search is chosen (see `Replacement`).
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReplacementMode {
+    /// Parents and offspring compete together, and the best individuals survive.
+    Plus = "plus",
+    /// The offspring replace the population, except for the `elitism` best parents.
+    Generational = "generational",
+    /// Only `replaced` offspring are bred each generation, replacing the worst parents.
+    SteadyState = "steadyState",
+    /// Parents and offspring compete on the violations of the rows and of the columns, as two
+    /// objectives, and survive by their Pareto front. Single cells are also mutated, breaking
+    /// their rows, and both dimensions are scored whatever the fitness.
+    Nsga2 = "nsga2",
+}
+
+/// The dimension of the grid whose lines make up the chromosomes (see `Representation`).
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RepresentationMode {
+    /// The dimension whose lines have the fewest arrangements.
+    Auto = "auto",
+    /// The chromosomes are made of rows, and the columns are scored.
+    Rows = "rows",
+    /// The chromosomes are made of columns, and the rows are scored.
+    Columns = "columns",
+}
+
+/// The lines of the candidates scored by the evolutive search (see `Fitness`).
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FitnessMode {
+    /// Only the columns are scored.
+    Columns = "columns",
+    /// Both the rows and the columns are scored.
+    Symmetric = "symmetric",
+}
+
+/// How the rows are repaired after crossover (see `Repair`).
+#[wasm_bindgen]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RepairMode {
+    /// The rows are regenerated at random.
+    Randomize = "randomize",
+    /// The segments of the rows are shifted towards the column constraints.
+    Shift = "shift",
+}
+
+/// The relative weights choosing the crossover operator of each pair of ancestors, initialized
+/// with the defaults used by the application.
+#[wasm_bindgen(js_name = CrossoverWeights)]
+#[derive(Clone, Copy)]
+pub struct WasmCrossoverWeights {
+    /// The weight of the uniform crossover.
+    pub uniform: f64,
+    /// The weight of the row-wise two-point crossover.
+    #[wasm_bindgen(js_name = twoPoint)]
+    pub two_point: f64,
+    /// The weight of the column-wise two-point crossover.
+    pub column: f64,
+    /// The weight of the block crossover.
+    pub block: f64,
+    /// The weight of the segment-level crossover.
+    pub segment: f64,
+}
+
+#[wasm_bindgen(js_class = CrossoverWeights)]
+impl WasmCrossoverWeights {
+    /// Creates the weights with the defaults used by the application.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmCrossoverWeights {
+        Self::default()
+    }
+}
+
+impl Default for WasmCrossoverWeights {
+    fn default() -> Self {
+        let weights = CrossoverWeights::default();
+        Self {
+            uniform: weights.uniform,
+            two_point: weights.two_point,
+            column: weights.column,
+            block: weights.block,
+            segment: weights.segment,
+        }
+    }
+}
+
+/// The relative weights choosing the mutation operator of each offspring, initialized with the
+/// defaults used by the application.
+#[wasm_bindgen(js_name = MutationWeights)]
+#[derive(Clone, Copy)]
+pub struct WasmMutationWeights {
+    /// The weight of the slide mutation.
+    pub slide: f64,
+    /// The weight of the segment reposition.
+    pub reposition: f64,
+    /// The weight of the row reshuffle.
+    pub reshuffle: f64,
+}
+
+#[wasm_bindgen(js_class = MutationWeights)]
+impl WasmMutationWeights {
+    /// Creates the weights with the defaults used by the application.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> WasmMutationWeights {
+        Self::default()
+    }
+}
+
+impl Default for WasmMutationWeights {
+    fn default() -> Self {
+        let weights = MutationWeights::default();
+        Self {
+            slide: weights.slide,
+            reposition: weights.reposition,
+            reshuffle: weights.reshuffle,
+        }
+    }
+}
+
+/// Parameters of the evolutive search, initialized with the defaults used by the application.
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub struct SearchOptions {
+    /// The size of the population.
+    #[wasm_bindgen(js_name = populationSize)]
+    pub population_size: usize,
+    /// The probability of crossover between individuals.
+    #[wasm_bindgen(js_name = crossProbability)]
+    pub cross_probability: f64,
+    /// The probability of mutation of each individual.
+    #[wasm_bindgen(js_name = mutationProbability)]
+    pub mutation_probability: f64,
+    /// The number of individuals competing in each tournament.
+    #[wasm_bindgen(js_name = tournamentSize)]
+    pub tournament_size: usize,
+    /// The number of tries for sliding window mutations.
+    #[wasm_bindgen(js_name = slideTries)]
+    pub slide_tries: usize,
+    /// The maximum number of generations.
+    #[wasm_bindgen(js_name = maxIterations)]
+    pub max_iterations: usize,
+    /// The best score good enough to stop the search, `0` to only stop on a solution.
+    #[wasm_bindgen(js_name = targetScore)]
+    pub target_score: usize,
+    /// The maximum running time of the search in milliseconds, or `undefined` for no limit.
+    #[wasm_bindgen(js_name = maxMillis)]
+    pub max_millis: Option<u32>,
+    /// The generations without improving the best score before stopping, or `undefined` to never
+    /// stop on stagnation.
+    pub stagnation: Option<usize>,
+    /// The seed of the random number generator.
+    pub seed: u64,
+    /// How the next generation is chosen.
+    pub replacement: ReplacementMode,
+    /// The number of best parents surviving each generation, in the generational mode.
+    pub elitism: usize,
+    /// The number of worst parents replaced each generation, in the steady-state mode.
+    pub replaced: usize,
+    /// The dimension of the grid whose lines make up the chromosomes.
+    pub representation: RepresentationMode,
+    /// The lines of the candidates scored.
+    pub fitness: FitnessMode,
+    /// The relative weights of the crossover operators.
+    #[wasm_bindgen(js_name = crossoverWeights)]
+    pub crossover_weights: WasmCrossoverWeights,
+    /// The relative weights of the mutation operators.
+    #[wasm_bindgen(js_name = mutationWeights)]
+    pub mutation_weights: WasmMutationWeights,
+    /// The maximum number of rows repaired in each child after crossover, `0` to disable the repair.
+    #[wasm_bindgen(js_name = repairRows)]
+    pub repair_rows: usize,
+    /// How the rows are repaired after crossover.
+    pub repair: RepairMode,
+    /// The maximum number of rows of each child re-sampled after mutation to agree with the line
+    /// solver, `0` to disable the local search.
+    #[wasm_bindgen(js_name = localSearchRows)]
+    pub local_search_rows: usize,
+    /// Whether the probabilities rise while the best score stagnates, and decay once it improves.
+    pub adaptive: bool,
+    /// The number of individuals each child of the plus replacement is compared with, replacing
+    /// the most similar if it's not worse, `0` to disable the niching.
+    pub crowding: usize,
+}
+
+#[wasm_bindgen]
+impl SearchOptions {
+    /// Creates the options with the defaults used by the application.
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> SearchOptions {
+        Self::default()
+    }
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self {
+            population_size: POPULATION_SIZE,
+            cross_probability: CROSS_PROBABILITY,
+            mutation_probability: MUTATION_PROBABILITY,
+            tournament_size: TOURNAMENT_SIZE,
+            slide_tries: SLIDE_TRIES,
+            max_iterations: MAX_ITERATIONS,
+            target_score: 0,
+            max_millis: None,
+            stagnation: None,
+            seed: SEED,
+            replacement: ReplacementMode::Plus,
+            elitism: 1,
+            replaced: 2,
+            representation: RepresentationMode::Auto,
+            fitness: FitnessMode::Columns,
+            crossover_weights: WasmCrossoverWeights::default(),
+            mutation_weights: WasmMutationWeights::default(),
+            repair_rows: 0,
+            repair: RepairMode::Randomize,
+            local_search_rows: 0,
+            adaptive: false,
+            crowding: 0,
+        }
+    }
+}
+
+/// JavaScript wrapper of the `History` of an evolutive search.
+#[wasm_bindgen(js_name = History)]
+pub struct WasmHistory {
+    inner: History,
+}
+
+#[wasm_bindgen(js_class = History)]
+impl WasmHistory {
+    /// The number of generations completed.
+    #[wasm_bindgen(getter)]
+    pub fn iterations(&self) -> usize {
+        self.inner.iterations
+    }
+
+    /// The best score of each generation as a `Uint32Array`.
+    #[wasm_bindgen(getter)]
+    pub fn best(&self) -> Vec<u32> {
+        self.inner.best.iter().map(|&score| score as u32).collect()
+    }
+
+    /// The median score of each generation as a `Float64Array`.
+    #[wasm_bindgen(getter)]
+    pub fn median(&self) -> Vec<f64> {
+        self.inner.median.clone()
+    }
+
+    /// The worst score of each generation as a `Uint32Array`.
+    #[wasm_bindgen(getter)]
+    pub fn worst(&self) -> Vec<u32> {
+        self.inner.worst.iter().map(|&score| score as u32).collect()
+    }
+
+    /// The mean Hamming distance between the individuals of each generation as a `Float64Array`.
+    #[wasm_bindgen(getter)]
+    pub fn diversity(&self) -> Vec<f64> {
+        self.inner.diversity.clone()
+    }
+
+    /// The replacement mode of the search, such as `generational (elitism 5)`.
+    #[wasm_bindgen(getter)]
+    pub fn replacement(&self) -> String {
+        self.inner.replacement.to_string()
+    }
+
+    /// The dimension whose lines made up the chromosomes, `rows` or `columns`.
+    #[wasm_bindgen(getter)]
+    pub fn representation(&self) -> String {
+        self.inner.representation.to_string()
+    }
+
+    /// The number of rows repaired after crossover at each bred generation, as a `Uint32Array`.
+    #[wasm_bindgen(getter)]
+    pub fn repaired(&self) -> Vec<u32> {
+        self.inner
+            .repaired
+            .iter()
+            .map(|&rows| rows as u32)
+            .collect()
+    }
+
+    /// The number of rows refined by the local search at each bred generation, as a `Uint32Array`.
+    #[wasm_bindgen(getter)]
+    pub fn refined(&self) -> Vec<u32> {
+        self.inner.refined.iter().map(|&rows| rows as u32).collect()
+    }
+
+    /// The crossover probability used at each bred generation, as a `Float64Array`.
+    #[wasm_bindgen(getter, js_name = crossRates)]
+    pub fn cross_rates(&self) -> Vec<f64> {
+        self.inner.cross_rates.clone()
+    }
+
+    /// The mutation probability used at each bred generation, as a `Float64Array`.
+    #[wasm_bindgen(getter, js_name = mutationRates)]
+    pub fn mutation_rates(&self) -> Vec<f64> {
+        self.inner.mutation_rates.clone()
+    }
+
+    /// Why the search stopped, such as `stagnation`, or `undefined` if it didn't finish.
+    #[wasm_bindgen(getter, js_name = stopReason)]
+    pub fn stop_reason(&self) -> Option<String> {
+        self.inner.stop_reason.map(|reason| reason.to_string())
+    }
+
+    /// Whether the search found a solution satisfying every constraint.
+    #[wasm_bindgen(getter)]
+    pub fn solved(&self) -> bool {
+        self.inner.winner.is_ok()
+    }
+
+    /// The winner of the search, or its best approach if it wasn't solved.
+    #[wasm_bindgen(getter)]
+    pub fn solution(&self) -> WasmNonogramSolution {
+        let (Ok(solution) | Err(solution)) = &self.inner.winner;
+        WasmNonogramSolution {
+            inner: solution.clone(),
+        }
+    }
+}
+
+/// Runs the evolutive search on a puzzle, using the default options if none are given.
+#[wasm_bindgen(js_name = evolutiveSearch)]
+pub fn wasm_evolutive_search(
+    puzzle: &WasmNonogramPuzzle,
+    options: Option<SearchOptions>,
+) -> Result<WasmHistory, JsError> {
+    let options = options.unwrap_or_default();
+    if options.population_size < 2 || options.tournament_size == 0 {
+        return Err(JsError::new(
+            "The population size must be at least 2 and the tournament size at least 1",
+        ));
+    }
+    if !(0.0..=1.0).contains(&options.cross_probability)
+        || !(0.0..=1.0).contains(&options.mutation_probability)
+    {
+        return Err(JsError::new("The probabilities must be between 0 and 1"));
+    }
+    let weights = options.crossover_weights;
+    let crossover = CrossoverWeights {
+        uniform: weights.uniform,
+        two_point: weights.two_point,
+        column: weights.column,
+        block: weights.block,
+        segment: weights.segment,
+    };
+    if [
+        crossover.uniform,
+        crossover.two_point,
+        crossover.column,
+        crossover.block,
+        crossover.segment,
+    ]
+    .iter()
+    .any(|&weight| weight < 0.0)
+    {
+        return Err(JsError::new("The crossover weights can't be negative"));
+    }
+    let weights = options.mutation_weights;
+    let mutation = MutationWeights {
+        slide: weights.slide,
+        reposition: weights.reposition,
+        reshuffle: weights.reshuffle,
+    };
+    if [mutation.slide, mutation.reposition, mutation.reshuffle]
+        .iter()
+        .any(|&weight| weight < 0.0)
+    {
+        return Err(JsError::new("The mutation weights can't be negative"));
+    }
+    let config = SolverConfig {
+        population_size: options.population_size,
+        cross_probability: options.cross_probability,
+        mutation_probability: options.mutation_probability,
+        tournament_size: options.tournament_size,
+        slide_tries: options.slide_tries,
+        stop: StopCriteria {
+            max_iterations: options.max_iterations,
+            target_score: options.target_score,
+            max_millis: options.max_millis.map(u64::from),
+            stagnation: options.stagnation,
+        },
+        replacement: match options.replacement {
+            ReplacementMode::Generational => Replacement::Generational {
+                elitism: options.elitism,
+            },
+            ReplacementMode::SteadyState => Replacement::SteadyState {
+                replaced: options.replaced,
+            },
+            ReplacementMode::Nsga2 => Replacement::Pareto,
+            _ => Replacement::Plus,
+        },
+        representation: match options.representation {
+            RepresentationMode::Rows => Representation::Rows,
+            RepresentationMode::Columns => Representation::Columns,
+            _ => Representation::Auto,
+        },
+        fitness: match options.fitness {
+            FitnessMode::Symmetric => Fitness::Symmetric,
+            _ => Fitness::Columns,
+        },
+        crossover,
+        mutation,
+        repair_rows: options.repair_rows,
+        repair: match options.repair {
+            RepairMode::Shift => Repair::Shift,
+            _ => Repair::Randomize,
+        },
+        local_search_rows: options.local_search_rows,
+        adaptation: options.adaptive.then(Adaptation::default),
+        crowding: (options.crowding > 0).then_some(options.crowding),
+    };
+    let mut rng = StdRng::seed_from_u64(options.seed);
+    let history = evolutive_search(&puzzle.inner, &config, &mut rng);
+    Ok(WasmHistory { inner: history })
+}
@@ -0,0 +1,356 @@
+// MIT LICENSE
+//
+// Copyright 2024 artik02
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the “Software”), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
+// of the Software, and to permit persons to whom the Software is furnished to do
+// so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED “AS IS”, WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+//! End-to-end flows of the Solver and the Editor (load, paint, solve, save), rendering the real
+//! pages in a virtual DOM and driving them through their debug snapshots (see `AppSnapshot`), as
+//! the automated UI tests do in the browser.
+#![cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+
+// Import the virtual DOM rendering the pages, and the document their scripts are evaluated in.
+use dioxus::dioxus_core::NoOpMutations;
+use dioxus::document::{Document, Eval, EvalError, Evaluator};
+use dioxus::prelude::*;
+
+// Import the translations of the pages.
+use dioxus_i18n::prelude::{use_init_i18n, I18nConfig, Locale};
+use dioxus_i18n::unic_langid::langid;
+
+// Import the channels between the tests and the snapshot script of the page.
+use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures::StreamExt;
+
+// Import the JSON messages of the snapshot script.
+use serde_json::{json, Value};
+
+// Import the shared ownership of the document and of its channels.
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::task::{Context, Poll};
+
+// Import the pages rendered, and the services they expect from the application.
+use ngram::nonogram::component::{use_fullscreen_mode, Editor, Solver};
+use ngram::nonogram::profile::ActiveProfile;
+use ngram::nonogram::rng::SessionRng;
+use ngram::nonogram::session::SessionHistory;
+use ngram::nonogram::settings::Settings;
+
+// Import the commands dispatched to the pages.
+use ngram::nonogram::command::GridCommand;
+use ngram::nonogram::journal::Tool;
+
+// Import the Nonogram definitions loaded, painted and saved.
+use ngram::nonogram::definitions::{NonogramFile, NonogramPuzzle, NonogramSolution, BACKGROUND};
+
+// Import the solvers whose results are dispatched, like the solve buttons do.
+use ngram::nonogram::evolutive::solve_nonogram;
+use ngram::nonogram::logic::solve_logic;
+
+// Import the puzzle opened by default.
+use ngram::nonogram::puzzles::tree_nonogram_file;
+
+// Import the snapshots of the pages.
+use ngram::nonogram::session::SessionPage;
+use ngram::nonogram::snapshot::AppSnapshot;
+
+/// The rounds of events and renders processed after each request, enough for the requests to be
+/// answered and for the effects they trigger to run.
+const SETTLE_ROUNDS: usize = 16;
+
+/// The evaluation of the snapshot script, receiving the requests of the test and sending back
+/// the replies of the page.
+struct SnapshotEvaluator {
+    /// The requests sent by the test.
+    requests: UnboundedReceiver<Value>,
+    /// The replies sent to the test.
+    replies: UnboundedSender<Value>,
+}
+
+impl Evaluator for SnapshotEvaluator {
+    fn send(&self, data: Value) -> Result<(), EvalError> {
+        self.replies
+            .unbounded_send(data)
+            .map_err(|err| EvalError::Communication(err.to_string()))
+    }
+
+    fn poll_recv(&mut self, context: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        self.requests
+            .poll_next_unpin(context)
+            .map(|request| request.ok_or(EvalError::Finished))
+    }
+
+    fn poll_join(&mut self, _: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        Poll::Pending
+    }
+}
+
+/// The evaluation of the other scripts, unsupported without a browser.
+struct UnsupportedEvaluator;
+
+impl Evaluator for UnsupportedEvaluator {
+    fn send(&self, _: Value) -> Result<(), EvalError> {
+        Err(EvalError::Unsupported)
+    }
+
+    fn poll_recv(&mut self, _: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        Poll::Ready(Err(EvalError::Unsupported))
+    }
+
+    fn poll_join(&mut self, _: &mut Context<'_>) -> Poll<Result<Value, EvalError>> {
+        Poll::Ready(Err(EvalError::Unsupported))
+    }
+}
+
+/// The document of a page rendered without a browser, connecting its snapshot script to the
+/// test.
+struct HeadlessDocument {
+    /// The evaluation of the snapshot script, taken by the page when it starts.
+    snapshots: RefCell<Option<SnapshotEvaluator>>,
+    /// The owner of the evaluations, dropped with the document.
+    owner: Owner<UnsyncStorage>,
+}
+
+impl Document for HeadlessDocument {
+    fn eval(&self, js: String) -> Eval {
+        let evaluator: Box<dyn Evaluator> = match js.contains("ngramDebug") {
+            true => match self.snapshots.borrow_mut().take() {
+                Some(snapshots) => Box::new(snapshots),
+                None => Box::new(UnsupportedEvaluator),
+            },
+            false => Box::new(UnsupportedEvaluator),
+        };
+        Eval::new(self.owner.insert(evaluator))
+    }
+}
+
+/// Renders a page with the services provided by the application, in English.
+#[component]
+fn HeadlessApp(page: SessionPage) -> Element {
+    use_init_i18n(|| {
+        let english = langid!("en-US");
+        I18nConfig::new(english.clone()).with_locale(Locale::new_static(
+            english,
+            include_str!("../fluent/en-US.ftl"),
+        ))
+    });
+    use_context_provider(|| Signal::new(Settings::default()));
+    use_context_provider(|| Signal::new(SessionRng::new(1)));
+    use_context_provider(|| Signal::new(SessionHistory::default()));
+    use_context_provider(|| {
+        Signal::new(ActiveProfile {
+            profile: None,
+            chosen: true,
+        })
+    });
+    use_fullscreen_mode();
+    match page {
+        SessionPage::Solver => rsx! { Solver {} },
+        SessionPage::Editor => rsx! { Editor {} },
+    }
+}
+
+/// A page rendered in a virtual DOM, driven through its debug snapshots.
+struct HeadlessPage {
+    /// The virtual DOM rendering the page.
+    dom: VirtualDom,
+    /// The requests sent to the snapshot script of the page.
+    requests: UnboundedSender<Value>,
+    /// The replies of the snapshot script of the page.
+    replies: UnboundedReceiver<Value>,
+}
+
+impl HeadlessPage {
+    /// Renders a page, opening the tree puzzle like the pages do when they start.
+    fn open(page: SessionPage) -> Self {
+        let (requests, requests_receiver) = unbounded();
+        let (replies_sender, replies) = unbounded();
+        let document = HeadlessDocument {
+            snapshots: RefCell::new(Some(SnapshotEvaluator {
+                requests: requests_receiver,
+                replies: replies_sender,
+            })),
+            owner: Owner::default(),
+        };
+        let mut dom = VirtualDom::new_with_props(HeadlessApp, HeadlessAppProps { page })
+            .with_root_context(Rc::new(document) as Rc<dyn Document>);
+        dom.rebuild_in_place();
+        let mut page = Self {
+            dom,
+            requests,
+            replies,
+        };
+        page.settle();
+        page
+    }
+
+    /// Processes the pending events and renders the page again, until the requests are answered.
+    fn settle(&mut self) {
+        for _ in 0..SETTLE_ROUNDS {
+            self.dom.process_events();
+            self.dom.render_immediate(&mut NoOpMutations);
+        }
+    }
+
+    /// Sends a request to the snapshot script of the page, like `window.ngramDebug` does.
+    fn request(&mut self, request: &str, json: Option<String>) {
+        self.requests
+            .unbounded_send(json!([request, json]))
+            .expect("The page should listen to the requests");
+        self.settle();
+    }
+
+    /// Captures the state of the page, like `window.ngramDebug.snapshot()`.
+    fn snapshot(&mut self) -> AppSnapshot {
+        self.request("snapshot", None);
+        let reply = self
+            .replies
+            .try_next()
+            .expect("The page should reply to the snapshot")
+            .expect("The page should keep its snapshot script");
+        AppSnapshot::from_json(reply.as_str().expect("The snapshot should be sent as JSON"))
+            .expect("The snapshot should be valid")
+    }
+
+    /// Replaces the state of the page, like `window.ngramDebug.restore(snapshot)`.
+    fn restore(&mut self, snapshot: &AppSnapshot) {
+        self.request("restore", snapshot.to_json());
+    }
+
+    /// Modifies the grid of the page, like `window.ngramDebug.dispatch(command)`.
+    fn dispatch(&mut self, command: GridCommand) {
+        self.request("dispatch", serde_json::to_string(&command).ok());
+    }
+
+    /// Loads a Nonogram file, like the file inputs: the solver opens its puzzle with an empty
+    /// grid, and the editor opens its drawing.
+    fn load(&mut self, filename: &str, json: &str) {
+        let file: NonogramFile = serde_json::from_str(json).expect("The file should be valid");
+        let mut snapshot = self.snapshot();
+        snapshot.grid = match snapshot.page {
+            SessionPage::Solver => NonogramSolution {
+                solution_grid: vec![vec![BACKGROUND; file.solution.cols()]; file.solution.rows()],
+            },
+            SessionPage::Editor => file.solution.clone(),
+        };
+        snapshot.filename = filename.to_string();
+        snapshot.palette = file.palette.clone();
+        snapshot.file = file;
+        snapshot.completed = false;
+        self.restore(&snapshot);
+    }
+}
+
+// Test that a puzzle loaded in the solver is painted, then solved by logic and completed.
+#[test]
+fn solver_load_paint_solve() {
+    let mut solver = HeadlessPage::open(SessionPage::Solver);
+    let json = serde_json::to_string(&tree_nonogram_file()).unwrap();
+    solver.load("tree.ngram", &json);
+    let loaded = solver.snapshot();
+    assert_eq!(loaded.filename, "tree.ngram");
+    assert!(!loaded.completed);
+    solver.dispatch(GridCommand::Paint {
+        row: 1,
+        col: 0,
+        color: 1,
+    });
+    solver.dispatch(GridCommand::DrawLine {
+        start: (3, 2),
+        end: (4, 2),
+        color: 2,
+    });
+    let painted = solver.snapshot();
+    assert_eq!(painted.actions, loaded.actions + 2);
+    assert_eq!(painted.grid.solution_grid[4][2], 2);
+    assert!(!painted.is_solved());
+
+    let solved = solve_logic(&painted.puzzle()).expect("The tree should be solvable");
+    assert!(solved.is_complete());
+    solver.dispatch(GridCommand::Replace {
+        tool: Tool::Solver,
+        solution: solved.solution,
+    });
+    let snapshot = solver.snapshot();
+    assert!(snapshot.is_solved());
+    assert!(snapshot.completed);
+    assert_eq!(snapshot.actions, painted.actions + 1);
+}
+
+// Test that a drawing made in the editor is saved, then loaded and solved by the genetic
+// algorithm in the solver.
+#[test]
+fn editor_drawing_is_solved() {
+    let mut editor = HeadlessPage::open(SessionPage::Editor);
+    editor.dispatch(GridCommand::Clear);
+    editor.dispatch(GridCommand::DrawLine {
+        start: (0, 0),
+        end: (0, 4),
+        color: 1,
+    });
+    editor.dispatch(GridCommand::DrawLine {
+        start: (1, 2),
+        end: (4, 2),
+        color: 2,
+    });
+    let drawing = editor.snapshot();
+    assert_eq!(drawing.file.solution, drawing.grid);
+    assert_eq!(drawing.grid.solution_grid[0], [1; 5]);
+
+    let mut solver = HeadlessPage::open(SessionPage::Solver);
+    solver.load(
+        "drawing.ngram",
+        &serde_json::to_string(&drawing.file).unwrap(),
+    );
+    let puzzle = solver.snapshot().puzzle();
+    assert_eq!(puzzle, NonogramPuzzle::from_solution(&drawing.grid));
+
+    let history = solve_nonogram(puzzle.clone(), None);
+    let Ok(winner) = history.winner else {
+        panic!("The drawing should be solved");
+    };
+    solver.dispatch(GridCommand::Replace {
+        tool: Tool::Solver,
+        solution: winner,
+    });
+    let solved = solver.snapshot();
+    assert!(solved.is_solved());
+    assert!(solved.completed);
+}
+
+// Test that a snapshot restored into a page gives the same state back.
+#[test]
+fn snapshot_restores_the_page() {
+    let mut editor = HeadlessPage::open(SessionPage::Editor);
+    editor.dispatch(GridCommand::Paint {
+        row: 0,
+        col: 0,
+        color: 2,
+    });
+    let snapshot = editor.snapshot();
+    assert_eq!(snapshot.grid.solution_grid[0][0], 2);
+
+    let mut restored = HeadlessPage::open(SessionPage::Editor);
+    restored.restore(&AppSnapshot::from_json(&snapshot.to_json().unwrap()).unwrap());
+    let restored = restored.snapshot();
+    assert_eq!(restored.page, SessionPage::Editor);
+    assert_eq!(restored.grid, snapshot.grid);
+    assert_eq!(restored.filename, snapshot.filename);
+}